@@ -20,17 +20,17 @@ use linear_cli::cycles::commands::{
 };
 use linear_cli::cycles::types::Cycle;
 use linear_cli::error::CliError;
-use linear_cli::io::Io;
+use linear_cli::io::{Io, TextSource};
 use linear_cli::issues::commands::{
-    handle_archive, handle_block, handle_comment_add, handle_create, handle_duplicate, handle_link,
-    handle_unarchive, handle_update,
+    IssueFields, handle_archive, handle_block, handle_comment_add, handle_create, handle_duplicate,
+    handle_link, handle_unarchive, handle_update,
 };
 use linear_cli::issues::types::{Issue, IssueState, Priority, User};
-use linear_cli::output::OutputFormat;
+use linear_cli::output::{OutputFormat, TableLayout};
 use linear_cli::projects::commands::{
-    handle_list as handle_project_list, handle_view as handle_project_view,
+    ProjectViewOptions, handle_list as handle_project_list, handle_view as handle_project_view,
 };
-use linear_cli::projects::types::Project;
+use linear_cli::projects::types::{Project, ProjectIssue, ProjectMember};
 use linear_cli::teams::commands::{
     handle_list as handle_team_list, handle_view as handle_team_view,
 };
@@ -149,6 +149,22 @@ impl ProjectClient for BenchProjectClient {
     fn list_projects(&self, _token: &str, _limit: usize) -> Result<Vec<Project>, CliError> {
         Ok(vec![self.project.clone()])
     }
+
+    fn list_project_issues(
+        &self,
+        _token: &str,
+        _project_id: &str,
+    ) -> Result<Vec<ProjectIssue>, CliError> {
+        Ok(Vec::new())
+    }
+
+    fn list_project_members(
+        &self,
+        _token: &str,
+        _project_id: &str,
+    ) -> Result<Vec<ProjectMember>, CliError> {
+        Ok(Vec::new())
+    }
 }
 
 struct BenchTeamClient {
@@ -205,6 +221,14 @@ fn sample_issue() -> Issue {
         updated_at: "2026-02-24T00:00:00Z".to_string(),
         url: "https://linear.app/company/issue/ENG-123".to_string(),
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -218,6 +242,10 @@ fn sample_comment() -> Comment {
         updated_at: "2026-02-24T00:00:00Z".to_string(),
         edited_at: None,
         issue_identifier: Some("ENG-123".to_string()),
+        resolved_at: None,
+        parent_id: None,
+        replies: Vec::new(),
+        reactions: Vec::new(),
     }
 }
 
@@ -242,6 +270,10 @@ fn sample_project() -> Project {
         lead_name: Some("Engineer".to_string()),
         created_at: "2026-02-24T00:00:00Z".to_string(),
         updated_at: "2026-02-24T00:00:00Z".to_string(),
+        milestones: Vec::new(),
+        issue_progress: None,
+        open_issues: None,
+        members: None,
     }
 }
 
@@ -302,8 +334,10 @@ fn bench_issue_handler_paths(c: &mut Criterion) {
                     Some("Description".to_string()),
                     Some("@me".to_string()),
                     Some("project-1".to_string()),
+                    None,
                     Some("state-1".to_string()),
                     Some(2),
+                    IssueFields::default(),
                     &issue_client,
                     &config,
                     &storage,
@@ -321,11 +355,14 @@ fn bench_issue_handler_paths(c: &mut Criterion) {
                 handle_update(
                     "ENG-123",
                     Some("Benchmark update".to_string()),
-                    Some("New description".to_string()),
+                    Some(TextSource::Inline("New description".to_string())),
                     Some("user-1".to_string()),
                     Some("project-1".to_string()),
+                    None,
                     Some("state-1".to_string()),
                     Some(1),
+                    None,
+                    IssueFields::default(),
                     &issue_client,
                     &config,
                     &storage,
@@ -451,7 +488,9 @@ fn bench_issue_handler_paths(c: &mut Criterion) {
                     None,
                     None,
                     None,
+                    None,
                     Some(2),
+                    IssueFields::default(),
                     &issue_client,
                     &config,
                     &storage,
@@ -473,12 +512,14 @@ fn bench_issue_handler_paths(c: &mut Criterion) {
                     None,
                     None,
                     None,
+                    None,
                     Some(2),
+                    IssueFields::default(),
                     &issue_client,
                     &config,
                     &storage,
                     &io,
-                    Some(OutputFormat::Table),
+                    Some(OutputFormat::Table(TableLayout::default())),
                 )
                 .unwrap(),
             )
@@ -519,6 +560,7 @@ fn bench_project_handler_paths(c: &mut Criterion) {
             black_box(
                 handle_project_view(
                     "project-1",
+                    &ProjectViewOptions::default(),
                     &client,
                     &config,
                     &storage,
@@ -559,7 +601,7 @@ fn bench_project_handler_paths(c: &mut Criterion) {
                     &config,
                     &storage,
                     &io,
-                    Some(OutputFormat::Table),
+                    Some(OutputFormat::Table(TableLayout::default())),
                 )
                 .unwrap(),
             )
@@ -571,6 +613,7 @@ fn bench_project_handler_paths(c: &mut Criterion) {
             black_box(
                 handle_project_view(
                     "project-1",
+                    &ProjectViewOptions::default(),
                     &client,
                     &config,
                     &storage,
@@ -587,11 +630,12 @@ fn bench_project_handler_paths(c: &mut Criterion) {
             black_box(
                 handle_project_view(
                     "project-1",
+                    &ProjectViewOptions::default(),
                     &client,
                     &config,
                     &storage,
                     &io,
-                    Some(OutputFormat::Table),
+                    Some(OutputFormat::Table(TableLayout::default())),
                 )
                 .unwrap(),
             )
@@ -672,7 +716,7 @@ fn bench_team_handler_paths(c: &mut Criterion) {
                     &config,
                     &storage,
                     &io,
-                    Some(OutputFormat::Table),
+                    Some(OutputFormat::Table(TableLayout::default())),
                 )
                 .unwrap(),
             )
@@ -704,7 +748,7 @@ fn bench_team_handler_paths(c: &mut Criterion) {
                     &config,
                     &storage,
                     &io,
-                    Some(OutputFormat::Table),
+                    Some(OutputFormat::Table(TableLayout::default())),
                 )
                 .unwrap(),
             )
@@ -794,7 +838,7 @@ fn bench_cycle_handler_paths(c: &mut Criterion) {
                     &config,
                     &storage,
                     &io,
-                    Some(OutputFormat::Table),
+                    Some(OutputFormat::Table(TableLayout::default())),
                 )
                 .unwrap(),
             )
@@ -826,7 +870,7 @@ fn bench_cycle_handler_paths(c: &mut Criterion) {
                     &config,
                     &storage,
                     &io,
-                    Some(OutputFormat::Table),
+                    Some(OutputFormat::Table(TableLayout::default())),
                 )
                 .unwrap(),
             )
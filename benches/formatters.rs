@@ -52,6 +52,14 @@ fn create_test_issue(id: usize) -> Issue {
         updated_at: "2025-11-13T14:30:00Z".to_string(),
         url: format!("https://linear.app/team/issue/ENG-{}", id),
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -225,6 +233,10 @@ fn create_test_project(id: usize) -> Project {
         },
         created_at: "2024-01-10T10:00:00Z".to_string(),
         updated_at: "2024-02-15T14:30:00Z".to_string(),
+        milestones: Vec::new(),
+        issue_progress: None,
+        open_issues: None,
+        members: None,
     }
 }
 
@@ -495,6 +507,10 @@ fn create_test_comment(id: usize) -> Comment {
             None
         },
         issue_identifier: Some(format!("ENG-{}", id + 100)),
+        resolved_at: None,
+        parent_id: None,
+        replies: Vec::new(),
+        reactions: Vec::new(),
     }
 }
 
@@ -567,6 +583,10 @@ fn bench_issue_with_comments(c: &mut Criterion) {
                     updated_at: "2024-01-15T10:00:00Z".to_string(),
                     edited_at: None,
                     issue_identifier: Some("ENG-123".to_string()),
+                    resolved_at: None,
+                    parent_id: None,
+                    replies: Vec::new(),
+                    reactions: Vec::new(),
                 })
                 .collect();
             issue.comments = Some(comments);
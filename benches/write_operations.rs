@@ -6,7 +6,7 @@ use linear_cli::client::queries::{
     IssueCreateMutationVariables, IssueRelationCreateInput, IssueRelationCreateMutation,
     IssueRelationCreateMutationVariables, IssueRelationType, IssueUnarchiveMutation,
     IssueUnarchiveMutationVariables, IssueUpdateInput, IssueUpdateMutation,
-    IssueUpdateMutationVariables,
+    IssueUpdateMutationVariables, Nullable,
 };
 use linear_cli::error::CliError;
 use linear_cli::issues::resolver::{
@@ -21,8 +21,10 @@ fn issue_create_vars() -> IssueCreateMutationVariables {
             description: Some("Make token refresh more resilient".to_string()),
             assignee_id: Some("user-1".to_string()),
             project_id: Some("project-1".to_string()),
+            project_milestone_id: None,
             state_id: Some("state-1".to_string()),
             priority: Some(2),
+            ..Default::default()
         },
     }
 }
@@ -33,10 +35,12 @@ fn issue_update_vars() -> IssueUpdateMutationVariables {
         input: IssueUpdateInput {
             title: Some("Improve auth flow (updated)".to_string()),
             description: Some("Updated description".to_string()),
-            assignee_id: Some("user-2".to_string()),
-            project_id: Some("project-2".to_string()),
+            assignee_id: Nullable::Value("user-2".to_string()),
+            project_id: Nullable::Value("project-2".to_string()),
+            project_milestone_id: Nullable::Unset,
             state_id: Some("state-2".to_string()),
             priority: Some(1),
+            ..Default::default()
         },
     }
 }
@@ -46,6 +50,7 @@ fn comment_create_vars() -> CommentCreateMutationVariables {
         input: CommentCreateInput {
             issue_id: Some("issue-123".to_string()),
             body: Some("Investigating now".to_string()),
+            parent_id: None,
         },
     }
 }
@@ -8,7 +8,60 @@ use cynic::QueryFragment;
 use serde::Serialize;
 
 // Re-export schema and scalar types so downstream crates can use them.
-pub use linear_schema::{DateTime, TimelessDate, schema};
+pub use linear_schema::{DateTime, DateTimeOrDuration, TimelessDate, schema};
+
+/// Input field that can be left out, explicitly cleared, or set
+///
+/// `Option` cannot tell "leave unchanged" from "set to null" once serialized,
+/// so update inputs that support clearing use this instead. Pair it with
+/// `skip_serializing_if = "Nullable::is_unset"` so unset fields are omitted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Nullable<T> {
+    /// Omit the field from the input
+    #[default]
+    Unset,
+    /// Send an explicit `null` to clear the field
+    Null,
+    /// Send a value
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    #[must_use]
+    pub fn is_unset(&self) -> bool {
+        matches!(self, Nullable::Unset)
+    }
+
+    /// Apply `f` to a set value, keeping `Unset` and `Null` as they are
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Nullable<U> {
+        match self {
+            Nullable::Unset => Nullable::Unset,
+            Nullable::Null => Nullable::Null,
+            Nullable::Value(value) => Nullable::Value(f(value)),
+        }
+    }
+
+    /// The set value, treating `Unset` and `Null` alike
+    pub fn value(self) -> Option<T> {
+        match self {
+            Nullable::Value(value) => Some(value),
+            Nullable::Unset | Nullable::Null => None,
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Nullable::Value(value) => value.serialize(serializer),
+            Nullable::Unset | Nullable::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<T, U: cynic::schema::IsScalar<T>> cynic::schema::IsScalar<T> for Nullable<U> {
+    type SchemaType = U::SchemaType;
+}
 
 /// User information for authentication
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, QueryFragment)]
@@ -54,6 +107,31 @@ pub struct WorkflowState {
     pub name: String,
 }
 
+/// Team an issue belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, QueryFragment)]
+#[cynic(graphql_type = "Team", schema = "linear")]
+pub struct IssueTeam {
+    pub id: cynic::Id,
+    pub key: String,
+    pub name: String,
+}
+
+/// Cycle an issue is scheduled in
+#[derive(Debug, Clone, PartialEq, Serialize, QueryFragment)]
+#[cynic(graphql_type = "Cycle", schema = "linear")]
+pub struct IssueCycle {
+    pub id: cynic::Id,
+    pub number: f64,
+    pub name: Option<String>,
+}
+
+/// Users subscribed to an issue
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "UserConnection", schema = "linear")]
+pub struct IssueSubscriberConnection {
+    pub nodes: Vec<IssueUser>,
+}
+
 /// Issue information
 #[derive(Debug, Clone, Serialize, QueryFragment)]
 #[cynic(graphql_type = "Issue", schema = "linear")]
@@ -67,6 +145,17 @@ pub struct IssueNode {
     pub assignee: Option<IssueUser>,
     pub creator: Option<IssueUser>,
     pub project: Option<IssueProject>,
+    pub estimate: Option<f64>,
+    #[cynic(rename = "dueDate")]
+    pub due_date: Option<TimelessDate>,
+    #[cynic(rename = "sortOrder")]
+    pub sort_order: f64,
+    pub team: IssueTeam,
+    pub cycle: Option<IssueCycle>,
+    #[arguments(first: 25)]
+    pub subscribers: IssueSubscriberConnection,
+    #[cynic(rename = "branchName")]
+    pub branch_name: String,
     #[cynic(rename = "createdAt")]
     pub created_at: DateTime,
     #[cynic(rename = "updatedAt")]
@@ -92,11 +181,42 @@ pub struct IssueQuery {
     pub issue: Option<IssueNode>,
 }
 
+/// Issue-by-branch query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct IssueVcsBranchSearchQueryVariables {
+    pub branch_name: String,
+}
+
+/// Find the issue linked to a VCS branch name
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "IssueVcsBranchSearchQueryVariables"
+)]
+pub struct IssueVcsBranchSearchQuery {
+    #[arguments(branchName: $branch_name)]
+    #[cynic(rename = "issueVcsBranchSearch")]
+    pub issue_vcs_branch_search: Option<IssueNode>,
+}
+
+/// Cursor information for paginated connections
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "PageInfo", schema = "linear")]
+pub struct PageInfo {
+    #[cynic(rename = "hasNextPage")]
+    pub has_next_page: bool,
+    #[cynic(rename = "endCursor")]
+    pub end_cursor: Option<String>,
+}
+
 /// Issue connection for paginated results
 #[derive(Debug, Clone, QueryFragment)]
 #[cynic(graphql_type = "IssueConnection", schema = "linear")]
 pub struct IssueConnection {
     pub nodes: Vec<IssueNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
 }
 
 /// Issues query variables
@@ -104,16 +224,24 @@ pub struct IssueConnection {
 pub struct IssuesQueryVariables {
     pub first: Option<i32>,
     pub filter: Option<IssueFilterInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
 }
 
 /// Issue filter input
-#[derive(cynic::InputObject, Debug, Clone)]
+#[derive(cynic::InputObject, Debug, Clone, Default)]
 #[cynic(schema = "linear", graphql_type = "IssueFilter")]
 pub struct IssueFilterInput {
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub assignee: Option<NullableUserFilterInput>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub project: Option<NullableProjectFilterInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamFilterInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub number: Option<NumberComparatorInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<IssueFilterInput>>,
 }
 
 /// Nullable user filter input
@@ -172,12 +300,64 @@ pub struct NullableProjectFilterInput {
     variables = "IssuesQueryVariables"
 )]
 pub struct IssuesQuery {
-    #[arguments(first: $first, filter: $filter)]
+    #[arguments(first: $first, filter: $filter, after: $after)]
     pub issues: IssueConnection,
 }
 
+/// Label name on a changelog issue
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "IssueLabel", schema = "linear")]
+pub struct ChangelogLabel {
+    pub name: String,
+}
+
+/// Labels on a changelog issue
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "IssueLabelConnection", schema = "linear")]
+pub struct ChangelogLabelConnection {
+    pub nodes: Vec<ChangelogLabel>,
+}
+
+/// The issue fields release notes need
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Issue", schema = "linear")]
+pub struct ChangelogIssueNode {
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub project: Option<IssueProject>,
+    #[arguments(first: 20)]
+    pub labels: ChangelogLabelConnection,
+}
+
+/// Changelog issue connection
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "IssueConnection", schema = "linear")]
+pub struct ChangelogIssueConnection {
+    pub nodes: Vec<ChangelogIssueNode>,
+}
+
+/// Changelog issues query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ChangelogIssuesQueryVariables {
+    pub first: Option<i32>,
+    pub filter: Option<IssueFilterInput>,
+}
+
+/// Fetch a batch of issues for release notes, including archived (completed) ones
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "ChangelogIssuesQueryVariables"
+)]
+pub struct ChangelogIssuesQuery {
+    #[arguments(first: $first, filter: $filter, includeArchived: true)]
+    pub issues: ChangelogIssueConnection,
+}
+
 /// Issue create input
-#[derive(cynic::InputObject, Debug, Clone)]
+#[derive(cynic::InputObject, Debug, Clone, Default)]
 #[cynic(schema = "linear", graphql_type = "IssueCreateInput")]
 pub struct IssueCreateInput {
     #[cynic(rename = "teamId")]
@@ -190,10 +370,22 @@ pub struct IssueCreateInput {
     pub assignee_id: Option<String>,
     #[cynic(rename = "projectId", skip_serializing_if = "Option::is_none")]
     pub project_id: Option<String>,
+    #[cynic(rename = "projectMilestoneId", skip_serializing_if = "Option::is_none")]
+    pub project_milestone_id: Option<String>,
     #[cynic(rename = "stateId", skip_serializing_if = "Option::is_none")]
     pub state_id: Option<String>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<i32>,
+    #[cynic(rename = "dueDate", skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<TimelessDate>,
+    #[cynic(rename = "cycleId", skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    #[cynic(rename = "subscriberIds", skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    #[cynic(rename = "sortOrder", skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
 }
 
 /// Issue create mutation variables
@@ -223,21 +415,38 @@ pub struct IssueCreateMutation {
 }
 
 /// Issue update input
-#[derive(cynic::InputObject, Debug, Clone)]
+#[derive(cynic::InputObject, Debug, Clone, Default)]
 #[cynic(schema = "linear", graphql_type = "IssueUpdateInput")]
 pub struct IssueUpdateInput {
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[cynic(rename = "assigneeId", skip_serializing_if = "Option::is_none")]
-    pub assignee_id: Option<String>,
-    #[cynic(rename = "projectId", skip_serializing_if = "Option::is_none")]
-    pub project_id: Option<String>,
+    #[cynic(rename = "assigneeId", skip_serializing_if = "Nullable::is_unset")]
+    pub assignee_id: Nullable<String>,
+    #[cynic(rename = "projectId", skip_serializing_if = "Nullable::is_unset")]
+    pub project_id: Nullable<String>,
+    #[cynic(
+        rename = "projectMilestoneId",
+        skip_serializing_if = "Nullable::is_unset"
+    )]
+    pub project_milestone_id: Nullable<String>,
     #[cynic(rename = "stateId", skip_serializing_if = "Option::is_none")]
     pub state_id: Option<String>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
+    #[cynic(skip_serializing_if = "Nullable::is_unset")]
+    pub estimate: Nullable<i32>,
+    #[cynic(rename = "dueDate", skip_serializing_if = "Nullable::is_unset")]
+    pub due_date: Nullable<TimelessDate>,
+    #[cynic(rename = "cycleId", skip_serializing_if = "Nullable::is_unset")]
+    pub cycle_id: Nullable<String>,
+    #[cynic(rename = "teamId", skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    #[cynic(rename = "subscriberIds", skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    #[cynic(rename = "sortOrder", skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
 }
 
 /// Issue update mutation variables
@@ -306,6 +515,38 @@ pub struct IssueUnarchiveMutation {
     pub issue_unarchive: IssueArchivePayload,
 }
 
+/// Issue subscribe/unsubscribe mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct IssueSubscriptionMutationVariables {
+    pub id: String,
+}
+
+/// Subscribe the current user to an issue
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "IssueSubscriptionMutationVariables"
+)]
+pub struct IssueSubscribeMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "issueSubscribe")]
+    pub issue_subscribe: IssuePayload,
+}
+
+/// Unsubscribe the current user from an issue
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "IssueSubscriptionMutationVariables"
+)]
+pub struct IssueUnsubscribeMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "issueUnsubscribe")]
+    pub issue_unsubscribe: IssuePayload,
+}
+
 /// Issue relation type enum
 #[derive(cynic::Enum, Debug, Clone, Copy, PartialEq, Eq)]
 #[cynic(schema = "linear", graphql_type = "IssueRelationType")]
@@ -487,6 +728,9 @@ pub struct ProjectNode {
     pub created_at: DateTime,
     #[cynic(rename = "updatedAt")]
     pub updated_at: DateTime,
+    #[arguments(first: 50)]
+    #[cynic(rename = "projectMilestones")]
+    pub project_milestones: ProjectMilestoneConnection,
 }
 
 /// Project query variables
@@ -613,6 +857,55 @@ pub struct CyclesQuery {
     pub cycles: CycleConnection,
 }
 
+/// Number comparator input
+#[derive(cynic::InputObject, Debug, Clone, Default)]
+#[cynic(schema = "linear", graphql_type = "NumberComparator")]
+pub struct NumberComparatorInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub eq: Option<f64>,
+    #[cynic(rename = "in", skip_serializing_if = "Option::is_none")]
+    pub in_: Option<Vec<f64>>,
+}
+
+/// Cycle filter input for finding one team's cycle
+#[derive(cynic::InputObject, Debug, Clone, Default)]
+#[cynic(schema = "linear", graphql_type = "CycleFilter")]
+pub struct CycleFilterInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamFilterInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub number: Option<NumberComparatorInput>,
+    #[cynic(rename = "isActive", skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<BooleanComparatorInput>,
+    #[cynic(rename = "isNext", skip_serializing_if = "Option::is_none")]
+    pub is_next: Option<BooleanComparatorInput>,
+}
+
+/// Cycle ID lookup variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CycleLookupQueryVariables {
+    pub filter: Option<CycleFilterInput>,
+}
+
+/// Cycle ID lookup, used to resolve `current`, `next` or a cycle number
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "CycleLookupQueryVariables"
+)]
+pub struct CycleLookupQuery {
+    #[arguments(first: 1, filter: $filter)]
+    pub cycles: CycleLookupConnection,
+}
+
+/// Cycle connection returned by the lookup query
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "CycleConnection", schema = "linear")]
+pub struct CycleLookupConnection {
+    pub nodes: Vec<IssueCycle>,
+}
+
 /// User information for comments
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, QueryFragment)]
 #[cynic(graphql_type = "User", schema = "linear")]
@@ -628,6 +921,13 @@ pub struct CommentIssue {
     pub identifier: String,
 }
 
+/// Parent comment reference for threaded replies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, QueryFragment)]
+#[cynic(graphql_type = "Comment", schema = "linear")]
+pub struct CommentParent {
+    pub id: cynic::Id,
+}
+
 /// Comment information
 #[derive(Debug, Clone, Serialize, QueryFragment)]
 #[cynic(graphql_type = "Comment", schema = "linear")]
@@ -641,7 +941,11 @@ pub struct CommentNode {
     pub updated_at: DateTime,
     #[cynic(rename = "editedAt")]
     pub edited_at: Option<DateTime>,
+    #[cynic(rename = "resolvedAt")]
+    pub resolved_at: Option<DateTime>,
+    pub parent: Option<CommentParent>,
     pub issue: Option<CommentIssue>,
+    pub reactions: Vec<ReactionNode>,
 }
 
 /// Comment connection for paginated results
@@ -691,6 +995,8 @@ pub struct CommentCreateInput {
     pub issue_id: Option<String>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub body: Option<String>,
+    #[cynic(rename = "parentId", skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
 }
 
 /// Comment create mutation variables
@@ -719,12 +1025,74 @@ pub struct CommentCreateMutation {
     pub comment_create: CommentPayload,
 }
 
+/// Comment update input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "CommentUpdateInput")]
+pub struct CommentUpdateInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+/// Comment update mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CommentUpdateMutationVariables {
+    pub id: String,
+    pub input: CommentUpdateInput,
+}
+
+/// Comment update mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "CommentUpdateMutationVariables"
+)]
+pub struct CommentUpdateMutation {
+    #[arguments(id: $id, input: $input)]
+    #[cynic(rename = "commentUpdate")]
+    pub comment_update: CommentPayload,
+}
+
+/// Comment resolve/unresolve mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CommentResolveMutationVariables {
+    pub id: String,
+}
+
+/// Comment resolve mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "CommentResolveMutationVariables"
+)]
+pub struct CommentResolveMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "commentResolve")]
+    pub comment_resolve: CommentPayload,
+}
+
+/// Comment unresolve mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "CommentResolveMutationVariables"
+)]
+pub struct CommentUnresolveMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "commentUnresolve")]
+    pub comment_unresolve: CommentPayload,
+}
+
 // ── Discovery queries (states, labels, users) ──
 
 /// Team filter input for scoping queries to a specific team
-#[derive(cynic::InputObject, Debug, Clone)]
+#[derive(cynic::InputObject, Debug, Clone, Default)]
 #[cynic(schema = "linear", graphql_type = "TeamFilter")]
 pub struct TeamFilterInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub id: Option<IDComparatorInput>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub key: Option<StringComparatorInput>,
 }
@@ -1043,6 +1411,9 @@ pub struct SemanticProjectInfo {
 #[cynic(graphql_type = "Document", schema = "linear")]
 pub struct SemanticDocumentInfo {
     pub title: String,
+    #[cynic(rename = "slugId")]
+    pub slug_id: String,
+    pub url: String,
 }
 
 /// Initiative info within a semantic search result
@@ -1050,6 +1421,9 @@ pub struct SemanticDocumentInfo {
 #[cynic(graphql_type = "Initiative", schema = "linear")]
 pub struct SemanticInitiativeInfo {
     pub name: String,
+    #[cynic(rename = "slugId")]
+    pub slug_id: String,
+    pub url: String,
 }
 
 /// Semantic search result node
@@ -1094,3 +1468,1447 @@ pub struct SemanticSearchQuery {
     #[cynic(rename = "semanticSearch")]
     pub semantic_search: SemanticSearchPayloadResult,
 }
+
+// ── Attachments ──
+
+/// User information for attachments
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, QueryFragment)]
+#[cynic(graphql_type = "User", schema = "linear")]
+pub struct AttachmentUser {
+    pub name: String,
+    pub email: String,
+}
+
+/// Attachment (external link) on an issue
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "Attachment", schema = "linear")]
+pub struct AttachmentNode {
+    pub id: cynic::Id,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub url: String,
+    #[cynic(rename = "sourceType")]
+    pub source_type: Option<String>,
+    pub creator: Option<AttachmentUser>,
+    #[cynic(rename = "createdAt")]
+    pub created_at: DateTime,
+    #[cynic(rename = "updatedAt")]
+    pub updated_at: DateTime,
+}
+
+/// Attachment connection
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "AttachmentConnection", schema = "linear")]
+pub struct AttachmentConnection {
+    pub nodes: Vec<AttachmentNode>,
+}
+
+/// Issue attachments query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct IssueAttachmentsQueryVariables {
+    pub id: String,
+    pub first: Option<i32>,
+}
+
+/// Issue with its attachments
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(
+    graphql_type = "Issue",
+    schema = "linear",
+    variables = "IssueAttachmentsQueryVariables"
+)]
+pub struct IssueWithAttachments {
+    pub identifier: String,
+    #[arguments(first: $first)]
+    pub attachments: AttachmentConnection,
+}
+
+/// Issue attachments query
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "IssueAttachmentsQueryVariables"
+)]
+pub struct IssueAttachmentsQuery {
+    #[arguments(id: $id)]
+    pub issue: Option<IssueWithAttachments>,
+}
+
+/// Variables for the attachment link mutations (URL and GitHub PR)
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AttachmentLinkMutationVariables {
+    #[cynic(rename = "issueId")]
+    pub issue_id: String,
+    pub url: String,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// Payload returned by attachment mutations
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "AttachmentPayload", schema = "linear")]
+pub struct AttachmentPayload {
+    pub attachment: AttachmentNode,
+}
+
+/// Link an arbitrary URL to an issue
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "AttachmentLinkMutationVariables"
+)]
+pub struct AttachmentLinkUrlMutation {
+    #[arguments(issueId: $issue_id, url: $url, title: $title)]
+    #[cynic(rename = "attachmentLinkURL")]
+    pub attachment_link_url: AttachmentPayload,
+}
+
+/// Link a GitHub pull request to an issue
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "AttachmentLinkMutationVariables"
+)]
+pub struct AttachmentLinkGitHubPrMutation {
+    #[arguments(issueId: $issue_id, url: $url, title: $title)]
+    #[cynic(rename = "attachmentLinkGitHubPR")]
+    pub attachment_link_github_pr: AttachmentPayload,
+}
+
+/// Attachment create input (used for uploaded files)
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "AttachmentCreateInput")]
+pub struct AttachmentCreateInput {
+    #[cynic(rename = "issueId")]
+    pub issue_id: String,
+    pub title: String,
+    pub url: String,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+}
+
+/// Attachment create mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AttachmentCreateMutationVariables {
+    pub input: AttachmentCreateInput,
+}
+
+/// Attachment create mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "AttachmentCreateMutationVariables"
+)]
+pub struct AttachmentCreateMutation {
+    #[arguments(input: $input)]
+    #[cynic(rename = "attachmentCreate")]
+    pub attachment_create: AttachmentPayload,
+}
+
+/// Attachment delete mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AttachmentDeleteMutationVariables {
+    pub id: String,
+}
+
+/// Attachment delete mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "AttachmentDeleteMutationVariables"
+)]
+pub struct AttachmentDeleteMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "attachmentDelete")]
+    pub attachment_delete: DeletePayload,
+}
+
+// ── Reactions ──
+
+/// Reacting user, used to find our own reactions
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "User", schema = "linear")]
+pub struct ReactionUser {
+    #[cynic(rename = "isMe")]
+    pub is_me: bool,
+}
+
+/// Emoji reaction on an issue or comment
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "Reaction", schema = "linear")]
+pub struct ReactionNode {
+    pub id: cynic::Id,
+    pub emoji: String,
+    pub user: Option<ReactionUser>,
+}
+
+/// Reaction lookup variables (issue or comment ID)
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReactionsQueryVariables {
+    pub id: String,
+}
+
+/// Issue with its reactions
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "Issue", schema = "linear")]
+pub struct IssueWithReactions {
+    pub reactions: Vec<ReactionNode>,
+}
+
+/// Reactions on an issue
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "ReactionsQueryVariables"
+)]
+pub struct IssueReactionsQuery {
+    #[arguments(id: $id)]
+    pub issue: Option<IssueWithReactions>,
+}
+
+/// Comment with its reactions
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "Comment", schema = "linear")]
+pub struct CommentWithReactions {
+    pub reactions: Vec<ReactionNode>,
+}
+
+/// Reactions on a comment
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "ReactionsQueryVariables"
+)]
+pub struct CommentReactionsQuery {
+    #[arguments(id: $id)]
+    pub comment: CommentWithReactions,
+}
+
+/// Reaction create input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "ReactionCreateInput")]
+pub struct ReactionCreateInput {
+    pub emoji: String,
+    #[cynic(rename = "issueId", skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    #[cynic(rename = "commentId", skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+}
+
+/// Reaction create mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReactionCreateMutationVariables {
+    pub input: ReactionCreateInput,
+}
+
+/// Payload returned by `reactionCreate`
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "ReactionPayload", schema = "linear")]
+pub struct ReactionPayload {
+    pub success: bool,
+    pub reaction: ReactionNode,
+}
+
+/// Reaction create mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "ReactionCreateMutationVariables"
+)]
+pub struct ReactionCreateMutation {
+    #[arguments(input: $input)]
+    #[cynic(rename = "reactionCreate")]
+    pub reaction_create: ReactionPayload,
+}
+
+/// Reaction delete mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReactionDeleteMutationVariables {
+    pub id: String,
+}
+
+/// Reaction delete mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "ReactionDeleteMutationVariables"
+)]
+pub struct ReactionDeleteMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "reactionDelete")]
+    pub reaction_delete: DeletePayload,
+}
+
+// ── Documents ──
+
+/// User who created a document
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "User", schema = "linear")]
+pub struct DocumentUser {
+    pub name: String,
+}
+
+/// Project a document belongs to
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Project", schema = "linear")]
+pub struct DocumentProject {
+    pub id: cynic::Id,
+    pub name: String,
+}
+
+/// Document with its markdown content
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Document", schema = "linear")]
+pub struct DocumentNode {
+    pub id: cynic::Id,
+    pub title: String,
+    #[cynic(rename = "slugId")]
+    pub slug_id: String,
+    pub url: String,
+    pub icon: Option<String>,
+    pub content: Option<String>,
+    pub project: Option<DocumentProject>,
+    pub creator: Option<DocumentUser>,
+    #[cynic(rename = "createdAt")]
+    pub created_at: DateTime,
+    #[cynic(rename = "updatedAt")]
+    pub updated_at: DateTime,
+}
+
+/// Document without its content, for listings
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Document", schema = "linear")]
+pub struct DocumentSummaryNode {
+    pub id: cynic::Id,
+    pub title: String,
+    #[cynic(rename = "slugId")]
+    pub slug_id: String,
+    pub url: String,
+    pub icon: Option<String>,
+    pub project: Option<DocumentProject>,
+    pub creator: Option<DocumentUser>,
+    #[cynic(rename = "createdAt")]
+    pub created_at: DateTime,
+    #[cynic(rename = "updatedAt")]
+    pub updated_at: DateTime,
+}
+
+/// Document connection (summaries)
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "DocumentConnection", schema = "linear")]
+pub struct DocumentSummaryConnection {
+    pub nodes: Vec<DocumentSummaryNode>,
+}
+
+/// Document connection (with content)
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "DocumentConnection", schema = "linear")]
+pub struct DocumentConnection {
+    pub nodes: Vec<DocumentNode>,
+}
+
+/// Project filter input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "ProjectFilter")]
+pub struct ProjectFilterInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub id: Option<IDComparatorInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub name: Option<StringComparatorInput>,
+    #[cynic(rename = "slugId", skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<StringComparatorInput>,
+}
+
+/// Document filter input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "DocumentFilter")]
+pub struct DocumentFilterInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub project: Option<ProjectFilterInput>,
+    #[cynic(rename = "slugId", skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<StringComparatorInput>,
+}
+
+/// Documents query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DocumentsQueryVariables {
+    pub first: Option<i32>,
+    pub filter: Option<DocumentFilterInput>,
+}
+
+/// Documents query (summaries)
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "DocumentsQueryVariables"
+)]
+pub struct DocumentsQuery {
+    #[arguments(first: $first, filter: $filter)]
+    pub documents: DocumentSummaryConnection,
+}
+
+/// Documents query with content, used to look a document up by slug
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "DocumentsQueryVariables"
+)]
+pub struct DocumentsContentQuery {
+    #[arguments(first: $first, filter: $filter)]
+    pub documents: DocumentConnection,
+}
+
+/// Single document query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DocumentQueryVariables {
+    pub id: String,
+}
+
+/// Single document query
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "DocumentQueryVariables"
+)]
+pub struct DocumentQuery {
+    #[arguments(id: $id)]
+    pub document: DocumentNode,
+}
+
+/// Document create input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "DocumentCreateInput")]
+pub struct DocumentCreateInput {
+    pub title: String,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[cynic(rename = "projectId", skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// Document create mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DocumentCreateMutationVariables {
+    pub input: DocumentCreateInput,
+}
+
+/// Payload returned by document create/update mutations
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "DocumentPayload", schema = "linear")]
+pub struct DocumentPayload {
+    pub success: bool,
+    pub document: DocumentNode,
+}
+
+/// Document create mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "DocumentCreateMutationVariables"
+)]
+pub struct DocumentCreateMutation {
+    #[arguments(input: $input)]
+    #[cynic(rename = "documentCreate")]
+    pub document_create: DocumentPayload,
+}
+
+/// Document update input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "DocumentUpdateInput")]
+pub struct DocumentUpdateInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+/// Document update mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DocumentUpdateMutationVariables {
+    pub id: String,
+    pub input: DocumentUpdateInput,
+}
+
+/// Document update mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "DocumentUpdateMutationVariables"
+)]
+pub struct DocumentUpdateMutation {
+    #[arguments(id: $id, input: $input)]
+    #[cynic(rename = "documentUpdate")]
+    pub document_update: DocumentPayload,
+}
+
+/// Document delete mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DocumentDeleteMutationVariables {
+    pub id: String,
+}
+
+/// Payload returned by `documentDelete`
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "DocumentArchivePayload", schema = "linear")]
+pub struct DocumentArchivePayload {
+    pub success: bool,
+}
+
+/// Document delete (trash) mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "DocumentDeleteMutationVariables"
+)]
+pub struct DocumentDeleteMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "documentDelete")]
+    pub document_delete: DocumentArchivePayload,
+}
+
+// ── Project milestones ──
+
+/// Project milestone status enum
+#[derive(cynic::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+#[cynic(schema = "linear", graphql_type = "ProjectMilestoneStatus")]
+pub enum ProjectMilestoneStatus {
+    #[cynic(rename = "unstarted")]
+    Unstarted,
+    #[cynic(rename = "next")]
+    Next,
+    #[cynic(rename = "overdue")]
+    Overdue,
+    #[cynic(rename = "done")]
+    Done,
+}
+
+/// Project milestone with its issue progress
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "ProjectMilestone", schema = "linear")]
+pub struct ProjectMilestoneNode {
+    pub id: cynic::Id,
+    pub name: String,
+    pub description: Option<String>,
+    #[cynic(rename = "targetDate")]
+    pub target_date: Option<TimelessDate>,
+    pub status: ProjectMilestoneStatus,
+    pub progress: f64,
+    #[cynic(rename = "sortOrder")]
+    pub sort_order: f64,
+}
+
+/// Project milestone connection
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "ProjectMilestoneConnection", schema = "linear")]
+pub struct ProjectMilestoneConnection {
+    pub nodes: Vec<ProjectMilestoneNode>,
+}
+
+/// Project with its milestones, for `project milestone list`
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Project", schema = "linear")]
+pub struct MilestoneProjectNode {
+    pub id: cynic::Id,
+    pub name: String,
+    #[arguments(first: 100)]
+    #[cynic(rename = "projectMilestones")]
+    pub project_milestones: ProjectMilestoneConnection,
+}
+
+/// Project connection for milestone listing
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "ProjectConnection", schema = "linear")]
+pub struct MilestoneProjectConnection {
+    pub nodes: Vec<MilestoneProjectNode>,
+}
+
+/// Project milestones query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProjectMilestonesQueryVariables {
+    pub filter: Option<ProjectFilterInput>,
+}
+
+/// Milestones of the project matching a filter
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "ProjectMilestonesQueryVariables"
+)]
+pub struct ProjectMilestonesQuery {
+    #[arguments(first: 1, filter: $filter)]
+    pub projects: MilestoneProjectConnection,
+}
+
+/// Project milestone create input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "ProjectMilestoneCreateInput")]
+pub struct ProjectMilestoneCreateInput {
+    pub name: String,
+    #[cynic(rename = "projectId")]
+    pub project_id: String,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[cynic(rename = "targetDate", skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+}
+
+/// Project milestone create mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProjectMilestoneCreateMutationVariables {
+    pub input: ProjectMilestoneCreateInput,
+}
+
+/// Payload returned by milestone create/update mutations
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "ProjectMilestonePayload", schema = "linear")]
+pub struct ProjectMilestonePayload {
+    pub success: bool,
+    #[cynic(rename = "projectMilestone")]
+    pub project_milestone: ProjectMilestoneNode,
+}
+
+/// Project milestone create mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "ProjectMilestoneCreateMutationVariables"
+)]
+pub struct ProjectMilestoneCreateMutation {
+    #[arguments(input: $input)]
+    #[cynic(rename = "projectMilestoneCreate")]
+    pub project_milestone_create: ProjectMilestonePayload,
+}
+
+/// Project milestone update input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "ProjectMilestoneUpdateInput")]
+pub struct ProjectMilestoneUpdateInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[cynic(rename = "targetDate", skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+}
+
+/// Project milestone update mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProjectMilestoneUpdateMutationVariables {
+    pub id: String,
+    pub input: ProjectMilestoneUpdateInput,
+}
+
+/// Project milestone update mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "ProjectMilestoneUpdateMutationVariables"
+)]
+pub struct ProjectMilestoneUpdateMutation {
+    #[arguments(id: $id, input: $input)]
+    #[cynic(rename = "projectMilestoneUpdate")]
+    pub project_milestone_update: ProjectMilestonePayload,
+}
+
+/// Project milestone delete mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProjectMilestoneDeleteMutationVariables {
+    pub id: String,
+}
+
+/// Project milestone delete mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "ProjectMilestoneDeleteMutationVariables"
+)]
+pub struct ProjectMilestoneDeleteMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "projectMilestoneDelete")]
+    pub project_milestone_delete: DeletePayload,
+}
+
+// ── Project details ──
+
+/// Workflow state of an issue within a project
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "WorkflowState", schema = "linear")]
+pub struct ProjectIssueState {
+    pub name: String,
+    #[cynic(rename = "type")]
+    pub state_type: String,
+}
+
+/// Issue assignee within a project
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "User", schema = "linear")]
+pub struct ProjectIssueAssignee {
+    pub name: String,
+}
+
+/// Issue summary used for project breakdowns
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "Issue", schema = "linear")]
+pub struct ProjectIssueNode {
+    pub id: cynic::Id,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub estimate: Option<f64>,
+    pub state: ProjectIssueState,
+    pub assignee: Option<ProjectIssueAssignee>,
+}
+
+/// Issue connection for a project
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "IssueConnection", schema = "linear")]
+pub struct ProjectIssueConnection {
+    pub nodes: Vec<ProjectIssueNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Project with its issues
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Project", schema = "linear")]
+#[cynic(variables = "ProjectIssuesQueryVariables")]
+pub struct ProjectIssuesProject {
+    #[arguments(first: $first, after: $after)]
+    pub issues: ProjectIssueConnection,
+}
+
+/// Project issues query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProjectIssuesQueryVariables {
+    pub id: String,
+    pub first: Option<i32>,
+    pub after: Option<String>,
+}
+
+/// Project issues query
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "ProjectIssuesQueryVariables"
+)]
+pub struct ProjectIssuesQuery {
+    #[arguments(id: $id)]
+    pub project: ProjectIssuesProject,
+}
+
+/// Project member
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "User", schema = "linear")]
+pub struct ProjectMemberNode {
+    pub id: cynic::Id,
+    pub name: String,
+    #[cynic(rename = "displayName")]
+    pub display_name: String,
+    pub email: String,
+}
+
+/// Member connection for a project
+#[derive(Debug, Clone, Serialize, QueryFragment)]
+#[cynic(graphql_type = "UserConnection", schema = "linear")]
+pub struct ProjectMemberConnection {
+    pub nodes: Vec<ProjectMemberNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Project with its members
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Project", schema = "linear")]
+#[cynic(variables = "ProjectMembersQueryVariables")]
+pub struct ProjectMembersProject {
+    #[arguments(first: $first, after: $after)]
+    pub members: ProjectMemberConnection,
+}
+
+/// Project members query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProjectMembersQueryVariables {
+    pub id: String,
+    pub first: Option<i32>,
+    pub after: Option<String>,
+}
+
+/// Project members query
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "ProjectMembersQueryVariables"
+)]
+pub struct ProjectMembersQuery {
+    #[arguments(id: $id)]
+    pub project: ProjectMembersProject,
+}
+
+// ── Initiatives ──
+
+/// Initiative status enum
+#[derive(cynic::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+#[cynic(schema = "linear", graphql_type = "InitiativeStatus")]
+pub enum InitiativeStatus {
+    #[cynic(rename = "Planned")]
+    Planned,
+    #[cynic(rename = "Active")]
+    Active,
+    #[cynic(rename = "Completed")]
+    Completed,
+}
+
+/// Health reported by an initiative update
+#[derive(cynic::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+#[cynic(schema = "linear", graphql_type = "InitiativeUpdateHealthType")]
+pub enum InitiativeUpdateHealthType {
+    #[cynic(rename = "onTrack")]
+    OnTrack,
+    #[cynic(rename = "atRisk")]
+    AtRisk,
+    #[cynic(rename = "offTrack")]
+    OffTrack,
+}
+
+/// User who owns an initiative or posted an update
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "User", schema = "linear")]
+pub struct InitiativeUser {
+    pub name: String,
+}
+
+/// Project linked to an initiative, with its status and progress
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Project", schema = "linear")]
+pub struct InitiativeProjectNode {
+    pub id: cynic::Id,
+    pub name: String,
+    #[cynic(rename = "slugId")]
+    pub slug_id: String,
+    pub url: String,
+    pub status: ProjectStatus,
+    pub progress: f64,
+    #[cynic(rename = "targetDate")]
+    pub target_date: Option<TimelessDate>,
+}
+
+/// Project connection within an initiative
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "ProjectConnection", schema = "linear")]
+pub struct InitiativeProjectConnection {
+    pub nodes: Vec<InitiativeProjectNode>,
+}
+
+/// Initiative with its linked projects
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Initiative", schema = "linear")]
+pub struct InitiativeNode {
+    pub id: cynic::Id,
+    pub name: String,
+    #[cynic(rename = "slugId")]
+    pub slug_id: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub status: InitiativeStatus,
+    pub health: Option<InitiativeUpdateHealthType>,
+    #[cynic(rename = "targetDate")]
+    pub target_date: Option<TimelessDate>,
+    pub owner: Option<InitiativeUser>,
+    #[cynic(rename = "createdAt")]
+    pub created_at: DateTime,
+    #[cynic(rename = "updatedAt")]
+    pub updated_at: DateTime,
+    #[arguments(first: 50)]
+    pub projects: InitiativeProjectConnection,
+}
+
+/// Initiative connection
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "InitiativeConnection", schema = "linear")]
+pub struct InitiativeConnection {
+    pub nodes: Vec<InitiativeNode>,
+}
+
+/// Initiative filter input
+#[derive(cynic::InputObject, Debug, Clone, Default)]
+#[cynic(schema = "linear", graphql_type = "InitiativeFilter")]
+pub struct InitiativeFilterInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub id: Option<IDComparatorInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub name: Option<StringComparatorInput>,
+    #[cynic(rename = "slugId", skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<StringComparatorInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub status: Option<StringComparatorInput>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<InitiativeFilterInput>>,
+}
+
+/// Initiatives query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct InitiativesQueryVariables {
+    pub first: Option<i32>,
+    pub filter: Option<InitiativeFilterInput>,
+}
+
+/// Initiatives query
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "InitiativesQueryVariables"
+)]
+pub struct InitiativesQuery {
+    #[arguments(first: $first, filter: $filter)]
+    pub initiatives: InitiativeConnection,
+}
+
+/// Initiative create input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "InitiativeCreateInput")]
+pub struct InitiativeCreateInput {
+    pub name: String,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InitiativeStatus>,
+    #[cynic(rename = "ownerId", skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    #[cynic(rename = "targetDate", skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+}
+
+/// Initiative create mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct InitiativeCreateMutationVariables {
+    pub input: InitiativeCreateInput,
+}
+
+/// Payload returned by initiative create/update mutations
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "InitiativePayload", schema = "linear")]
+pub struct InitiativePayload {
+    pub success: bool,
+    pub initiative: InitiativeNode,
+}
+
+/// Initiative create mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "InitiativeCreateMutationVariables"
+)]
+pub struct InitiativeCreateMutation {
+    #[arguments(input: $input)]
+    #[cynic(rename = "initiativeCreate")]
+    pub initiative_create: InitiativePayload,
+}
+
+/// Initiative update input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "InitiativeUpdateInput")]
+pub struct InitiativeUpdateInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InitiativeStatus>,
+    #[cynic(rename = "ownerId", skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    #[cynic(rename = "targetDate", skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+}
+
+/// Initiative update mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct InitiativeUpdateMutationVariables {
+    pub id: String,
+    pub input: InitiativeUpdateInput,
+}
+
+/// Initiative update mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "InitiativeUpdateMutationVariables"
+)]
+pub struct InitiativeUpdateMutation {
+    #[arguments(id: $id, input: $input)]
+    #[cynic(rename = "initiativeUpdate")]
+    pub initiative_update: InitiativePayload,
+}
+
+/// Input linking a project to an initiative
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "InitiativeToProjectCreateInput")]
+pub struct InitiativeToProjectCreateInput {
+    #[cynic(rename = "initiativeId")]
+    pub initiative_id: String,
+    #[cynic(rename = "projectId")]
+    pub project_id: String,
+}
+
+/// Initiative-to-project create mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct InitiativeToProjectCreateMutationVariables {
+    pub input: InitiativeToProjectCreateInput,
+}
+
+/// Payload returned by `initiativeToProjectCreate`
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "InitiativeToProjectPayload", schema = "linear")]
+pub struct InitiativeToProjectPayload {
+    pub success: bool,
+}
+
+/// Link a project to an initiative
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "InitiativeToProjectCreateMutationVariables"
+)]
+pub struct InitiativeToProjectCreateMutation {
+    #[arguments(input: $input)]
+    #[cynic(rename = "initiativeToProjectCreate")]
+    pub initiative_to_project_create: InitiativeToProjectPayload,
+}
+
+/// Projects query variables for resolving a project by ID, name or slug
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ProjectLookupQueryVariables {
+    pub first: Option<i32>,
+    pub filter: Option<ProjectFilterInput>,
+}
+
+/// Projects query used to resolve a project reference
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "ProjectLookupQueryVariables"
+)]
+pub struct ProjectLookupQuery {
+    #[arguments(first: $first, filter: $filter)]
+    pub projects: InitiativeProjectConnection,
+}
+
+/// Status update posted on an initiative
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "InitiativeUpdate", schema = "linear")]
+pub struct InitiativeUpdateNode {
+    pub id: cynic::Id,
+    pub body: String,
+    pub health: InitiativeUpdateHealthType,
+    pub url: String,
+    pub user: InitiativeUser,
+    #[cynic(rename = "createdAt")]
+    pub created_at: DateTime,
+}
+
+/// Initiative update connection
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "InitiativeUpdateConnection", schema = "linear")]
+pub struct InitiativeUpdateConnection {
+    pub nodes: Vec<InitiativeUpdateNode>,
+}
+
+/// Initiative update filter input
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "InitiativeUpdateFilter")]
+pub struct InitiativeUpdateFilterInput {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<InitiativeFilterInput>,
+}
+
+/// Initiative updates query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct InitiativeUpdatesQueryVariables {
+    pub first: Option<i32>,
+    pub filter: Option<InitiativeUpdateFilterInput>,
+}
+
+/// Initiative updates query
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "InitiativeUpdatesQueryVariables"
+)]
+pub struct InitiativeUpdatesQuery {
+    #[arguments(first: $first, filter: $filter)]
+    #[cynic(rename = "initiativeUpdates")]
+    pub initiative_updates: InitiativeUpdateConnection,
+}
+
+// ── Notifications ──
+
+/// User who triggered a notification
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "User", schema = "linear")]
+pub struct NotificationActor {
+    pub name: String,
+}
+
+/// Inbox notification (fields shared by every notification type)
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "Notification", schema = "linear")]
+pub struct NotificationNode {
+    pub id: cynic::Id,
+    #[cynic(rename = "type")]
+    pub kind: String,
+    pub title: String,
+    pub subtitle: String,
+    pub url: String,
+    pub actor: Option<NotificationActor>,
+    #[cynic(rename = "createdAt")]
+    pub created_at: DateTime,
+    #[cynic(rename = "readAt")]
+    pub read_at: Option<DateTime>,
+    #[cynic(rename = "snoozedUntilAt")]
+    pub snoozed_until_at: Option<DateTime>,
+}
+
+/// Notification connection
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "NotificationConnection", schema = "linear")]
+pub struct NotificationConnection {
+    pub nodes: Vec<NotificationNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Notifications query variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NotificationsQueryVariables {
+    pub first: Option<i32>,
+    pub after: Option<String>,
+}
+
+/// Notifications in the current user's inbox, newest first
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "NotificationsQueryVariables"
+)]
+pub struct NotificationsQuery {
+    #[arguments(first: $first, after: $after)]
+    pub notifications: NotificationConnection,
+}
+
+/// Notification update input (mark read, snooze)
+#[derive(cynic::InputObject, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "NotificationUpdateInput")]
+pub struct NotificationUpdateInput {
+    #[cynic(rename = "readAt", skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    #[cynic(rename = "snoozedUntilAt", skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+}
+
+/// Notification update mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NotificationUpdateMutationVariables {
+    pub id: String,
+    pub input: NotificationUpdateInput,
+}
+
+/// Payload returned by `notificationUpdate`
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "NotificationPayload", schema = "linear")]
+pub struct NotificationPayload {
+    pub success: bool,
+    pub notification: NotificationNode,
+}
+
+/// Notification update mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "NotificationUpdateMutationVariables"
+)]
+pub struct NotificationUpdateMutation {
+    #[arguments(id: $id, input: $input)]
+    #[cynic(rename = "notificationUpdate")]
+    pub notification_update: NotificationPayload,
+}
+
+/// Notification archive mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NotificationArchiveMutationVariables {
+    pub id: String,
+}
+
+/// Payload returned by `notificationArchive`
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "NotificationArchivePayload", schema = "linear")]
+pub struct NotificationArchivePayload {
+    pub success: bool,
+}
+
+/// Notification archive mutation
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "NotificationArchiveMutationVariables"
+)]
+pub struct NotificationArchiveMutation {
+    #[arguments(id: $id)]
+    #[cynic(rename = "notificationArchive")]
+    pub notification_archive: NotificationArchivePayload,
+}
+
+// ── File uploads ──
+
+/// Header that must be sent with the upload PUT request
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "UploadFileHeader", schema = "linear")]
+pub struct UploadFileHeader {
+    pub key: String,
+    pub value: String,
+}
+
+/// Signed upload target returned by `fileUpload`
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "UploadFile", schema = "linear")]
+pub struct UploadFileNode {
+    #[cynic(rename = "uploadUrl")]
+    pub upload_url: String,
+    #[cynic(rename = "assetUrl")]
+    pub asset_url: String,
+    pub headers: Vec<UploadFileHeader>,
+}
+
+/// Payload returned by `fileUpload`
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "UploadPayload", schema = "linear")]
+pub struct UploadPayload {
+    pub success: bool,
+    #[cynic(rename = "uploadFile")]
+    pub upload_file: Option<UploadFileNode>,
+}
+
+/// File upload mutation variables
+#[derive(cynic::QueryVariables, Debug)]
+pub struct FileUploadMutationVariables {
+    #[cynic(rename = "contentType")]
+    pub content_type: String,
+    pub filename: String,
+    pub size: i32,
+}
+
+/// Request a signed URL for uploading a file
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "FileUploadMutationVariables"
+)]
+pub struct FileUploadMutation {
+    #[arguments(contentType: $content_type, filename: $filename, size: $size)]
+    #[cynic(rename = "fileUpload")]
+    pub file_upload: UploadPayload,
+}
+
+// ── Sync queries (offline mirror) ──
+
+/// Variables shared by the incremental sync queries
+///
+/// `since` filters on `updatedAt > since`; when omitted every record is returned.
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SyncQueryVariables {
+    pub first: Option<i32>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTimeOrDuration>,
+}
+
+/// Issues updated since the sync watermark
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "SyncQueryVariables"
+)]
+pub struct SyncIssuesQuery {
+    #[arguments(first: $first, after: $after, filter: { updatedAt: { gt: $since } })]
+    pub issues: IssueConnection,
+}
+
+/// Comment connection with cursor information
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "CommentConnection", schema = "linear")]
+pub struct SyncCommentConnection {
+    pub nodes: Vec<CommentNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Comments updated since the sync watermark
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "SyncQueryVariables"
+)]
+pub struct SyncCommentsQuery {
+    #[arguments(first: $first, after: $after, filter: { updatedAt: { gt: $since } })]
+    pub comments: SyncCommentConnection,
+}
+
+/// Team connection with cursor information
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "TeamConnection", schema = "linear")]
+pub struct SyncTeamConnection {
+    pub nodes: Vec<TeamNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Teams updated since the sync watermark
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "SyncQueryVariables"
+)]
+pub struct SyncTeamsQuery {
+    #[arguments(first: $first, after: $after, filter: { updatedAt: { gt: $since } })]
+    pub teams: SyncTeamConnection,
+}
+
+/// Project connection with cursor information
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "ProjectConnection", schema = "linear")]
+pub struct SyncProjectConnection {
+    pub nodes: Vec<ProjectNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Projects updated since the sync watermark
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "SyncQueryVariables"
+)]
+pub struct SyncProjectsQuery {
+    #[arguments(first: $first, after: $after, filter: { updatedAt: { gt: $since } })]
+    pub projects: SyncProjectConnection,
+}
+
+/// Cycle connection with cursor information
+#[derive(Debug, Clone, QueryFragment)]
+#[cynic(graphql_type = "CycleConnection", schema = "linear")]
+pub struct SyncCycleConnection {
+    pub nodes: Vec<CycleNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Cycles updated since the sync watermark
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "SyncQueryVariables"
+)]
+pub struct SyncCyclesQuery {
+    #[arguments(first: $first, after: $after, filter: { updatedAt: { gt: $since } })]
+    pub cycles: SyncCycleConnection,
+}
+
+/// User connection with cursor information
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "UserConnection", schema = "linear")]
+pub struct SyncUserConnection {
+    pub nodes: Vec<UserNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Users updated since the sync watermark
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "SyncQueryVariables"
+)]
+pub struct SyncUsersQuery {
+    #[arguments(first: $first, after: $after, filter: { updatedAt: { gt: $since } })]
+    pub users: SyncUserConnection,
+}
+
+/// Issue label connection with cursor information
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "IssueLabelConnection", schema = "linear")]
+pub struct SyncIssueLabelConnection {
+    pub nodes: Vec<IssueLabelNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Issue labels updated since the sync watermark
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "SyncQueryVariables"
+)]
+pub struct SyncIssueLabelsQuery {
+    #[arguments(first: $first, after: $after, filter: { updatedAt: { gt: $since } })]
+    #[cynic(rename = "issueLabels")]
+    pub issue_labels: SyncIssueLabelConnection,
+}
+
+/// Workflow state connection with cursor information
+#[derive(Debug, QueryFragment)]
+#[cynic(graphql_type = "WorkflowStateConnection", schema = "linear")]
+pub struct SyncWorkflowStateConnection {
+    pub nodes: Vec<WorkflowStateNode>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+/// Workflow states updated since the sync watermark
+#[derive(Debug, QueryFragment)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "SyncQueryVariables"
+)]
+pub struct SyncWorkflowStatesQuery {
+    #[arguments(first: $first, after: $after, filter: { updatedAt: { gt: $since } })]
+    #[cynic(rename = "workflowStates")]
+    pub workflow_states: SyncWorkflowStateConnection,
+}
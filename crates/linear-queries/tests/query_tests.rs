@@ -22,6 +22,7 @@ fn test_issues_query_builds() {
     let vars = linear_queries::IssuesQueryVariables {
         first: Some(10),
         filter: None,
+        after: None,
     };
     let operation = linear_queries::IssuesQuery::build(vars);
     assert!(operation.query.contains("issues"));
@@ -36,8 +37,10 @@ fn test_issue_create_mutation_builds() {
             description: None,
             assignee_id: None,
             project_id: None,
+            project_milestone_id: None,
             state_id: None,
             priority: None,
+            ..Default::default()
         },
     };
     let operation = linear_queries::IssueCreateMutation::build(vars);
@@ -25,3 +25,10 @@ pub struct TimelessDate(pub String);
 impl cynic::schema::IsScalar<schema::TimelessDate> for TimelessDate {
     type SchemaType = schema::TimelessDate;
 }
+
+/// DateTimeOrDuration scalar — an ISO-8601 timestamp or duration string
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct DateTimeOrDuration(pub String);
+
+cynic::impl_scalar!(DateTimeOrDuration, schema::DateTimeOrDuration);
@@ -0,0 +1,107 @@
+use crate::api::schema::Schema;
+use crate::api::{END_CURSOR_VARIABLE, find_connection, merge_page, next_cursor, read_source};
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::api::ApiClient;
+use crate::client::graphql::GraphQLRequest;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::generic_json_formatter;
+use secrecy::ExposeSecret;
+use serde_json::Value;
+
+/// Handle the api command
+///
+/// Optionally validates the document against the bundled schema, sends it
+/// (following `pageInfo.endCursor` with `paginate`) and prints `data` as JSON.
+pub fn handle_api(
+    mut request: GraphQLRequest,
+    paginate: bool,
+    validate: bool,
+    client: &dyn ApiClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+) -> Result<(), CliError> {
+    if validate {
+        let diagnostics = Schema::linear().validate(&request.query);
+        if !diagnostics.is_empty() {
+            let details: Vec<String> = diagnostics.iter().map(ToString::to_string).collect();
+            return Err(CliError::InvalidArgs(format!(
+                "Query does not match the Linear schema:\n{}",
+                details.join("\n")
+            )));
+        }
+    }
+
+    if paginate && !declares_end_cursor(&request.query) {
+        return Err(CliError::InvalidArgs(format!(
+            "--paginate requires the query to declare a ${END_CURSOR_VARIABLE}: String variable"
+        )));
+    }
+
+    let token = get_token_with_provider(config, storage)?;
+    let mut data = client.execute_raw(token.expose_secret(), &request)?;
+
+    if paginate {
+        let path = find_connection(&data)?;
+        let mut cursor = next_cursor(&data, &path);
+        while let Some(after) = cursor {
+            request.variables.insert(
+                END_CURSOR_VARIABLE.to_string(),
+                Value::String(after.clone()),
+            );
+            let page = client.execute_raw(token.expose_secret(), &request)?;
+            merge_page(&mut data, &page, &path);
+            // Stop if the server hands back the same cursor rather than looping forever
+            cursor = next_cursor(&page, &path).filter(|next| *next != after);
+        }
+    }
+
+    io.print(&generic_json_formatter(&data)?);
+
+    Ok(())
+}
+
+/// Whether any operation in `query` declares `$endCursor`.
+///
+/// Unparseable documents pass so the API can report the syntax error.
+fn declares_end_cursor(query: &str) -> bool {
+    cynic_parser::parse_executable_document(query).map_or(true, |document| {
+        document.operations().any(|operation| {
+            operation
+                .variable_definitions()
+                .any(|variable| variable.name() == END_CURSOR_VARIABLE)
+        })
+    })
+}
+
+/// Handle the api validate command
+///
+/// Checks each document against the bundled schema and prints diagnostics as
+/// `path:line:column: message`, failing if any document has problems.
+pub fn handle_api_validate(files: &[String], io: &dyn Io) -> Result<(), CliError> {
+    let schema = Schema::linear();
+    let mut problems = 0;
+
+    for file in files {
+        let source = read_source(file)?;
+        let label = if file == "-" { "<stdin>" } else { file };
+        let diagnostics = schema.validate(&source);
+
+        if diagnostics.is_empty() {
+            io.print(&format!("{label}: valid"));
+        }
+        for diagnostic in &diagnostics {
+            io.print_error(&format!("{label}:{diagnostic}"));
+        }
+        problems += diagnostics.len();
+    }
+
+    match problems {
+        0 => Ok(()),
+        1 => Err(CliError::InvalidArgs("1 problem found".to_string())),
+        n => Err(CliError::InvalidArgs(format!("{n} problems found"))),
+    }
+}
@@ -0,0 +1,237 @@
+//! Raw GraphQL passthrough (`linear api`).
+//!
+//! Sends a user-supplied document through [`LinearClient`](crate::client::LinearClient)
+//! so it shares token resolution, the API endpoint, timeouts and error mapping
+//! with every other command. Variables follow `gh api graphql`:
+//!
+//! - `-F key=value` converts `true`, `false`, `null` and numbers to JSON and reads
+//!   `@path` (or `@-` for stdin) as a string
+//! - `-f key=value` always sends a string
+//! - `key[field]=value` builds nested input objects and `key[]=value` appends
+//!   to a list
+//!
+//! With `--paginate` the document must declare `$endCursor` and select
+//! `pageInfo { hasNextPage endCursor }` on one connection; pages are requested
+//! until `hasNextPage` is false and their `nodes`/`edges` are merged.
+//!
+//! [`schema`] validates documents offline for `--validate` and `linear api validate`.
+
+pub mod commands;
+pub mod schema;
+
+use crate::error::CliError;
+use serde_json::{Map, Value};
+use std::io::Read;
+
+/// Variable that `--paginate` sets to the previous page's `endCursor`
+pub const END_CURSOR_VARIABLE: &str = "endCursor";
+
+/// Build the variables object from typed (`-F`) and raw (`-f`) fields
+pub fn build_variables(
+    typed_fields: &[String],
+    raw_fields: &[String],
+) -> Result<Map<String, Value>, CliError> {
+    let mut variables = Value::Object(Map::new());
+
+    for field in typed_fields {
+        let (key, value) = split_field(field)?;
+        insert_field(&mut variables, field, &parse_key(key)?, typed_value(value)?)?;
+    }
+    for field in raw_fields {
+        let (key, value) = split_field(field)?;
+        let value = Value::String(value.to_string());
+        insert_field(&mut variables, field, &parse_key(key)?, value)?;
+    }
+
+    match variables {
+        Value::Object(map) => Ok(map),
+        _ => unreachable!("variables root is always an object"),
+    }
+}
+
+fn split_field(field: &str) -> Result<(&str, &str), CliError> {
+    field
+        .split_once('=')
+        .filter(|(key, _)| !key.is_empty())
+        .ok_or_else(|| {
+            CliError::InvalidArgs(format!("Invalid field '{field}': expected key=value"))
+        })
+}
+
+/// Convert a `-F` value: literals become JSON, `@path` reads a file
+fn typed_value(value: &str) -> Result<Value, CliError> {
+    if let Some(path) = value.strip_prefix('@') {
+        return read_source(path).map(Value::String);
+    }
+
+    Ok(match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        "null" => Value::Null,
+        _ => {
+            if let Ok(int) = value.parse::<i64>() {
+                Value::from(int)
+            } else if let Some(float) = value
+                .parse::<f64>()
+                .ok()
+                .filter(|f| f.is_finite())
+                .and_then(serde_json::Number::from_f64)
+            {
+                Value::Number(float)
+            } else {
+                Value::String(value.to_string())
+            }
+        }
+    })
+}
+
+/// Read a file, or stdin for `-`
+pub fn read_source(path: &str) -> Result<String, CliError> {
+    if path == "-" {
+        let mut contents = String::new();
+        std::io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|e| CliError::InvalidArgs(format!("Failed to read stdin: {e}")))?;
+        Ok(contents)
+    } else {
+        std::fs::read_to_string(path)
+            .map_err(|e| CliError::InvalidArgs(format!("Failed to read '{path}': {e}")))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum KeySegment<'a> {
+    Key(&'a str),
+    Append,
+}
+
+/// Split `input[labels][]` into `input`, `labels`, append
+fn parse_key(key: &str) -> Result<Vec<KeySegment<'_>>, CliError> {
+    let invalid = || CliError::InvalidArgs(format!("Invalid field key '{key}'"));
+
+    let (head, mut rest) = key.split_at(key.find('[').unwrap_or(key.len()));
+    if head.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut segments = vec![KeySegment::Key(head)];
+    while !rest.is_empty() {
+        let close = rest.find(']').ok_or_else(invalid)?;
+        let segment = rest.strip_prefix('[').ok_or_else(invalid)?;
+        segments.push(match &segment[..close - 1] {
+            "" => KeySegment::Append,
+            name => KeySegment::Key(name),
+        });
+        rest = &rest[close + 1..];
+    }
+
+    // Appending is only supported as the final step (`key[]=value`)
+    if segments[..segments.len() - 1].contains(&KeySegment::Append) {
+        return Err(invalid());
+    }
+    Ok(segments)
+}
+
+fn insert_field(
+    target: &mut Value,
+    field: &str,
+    path: &[KeySegment<'_>],
+    value: Value,
+) -> Result<(), CliError> {
+    let conflict =
+        || CliError::InvalidArgs(format!("Field '{field}' conflicts with another field"));
+
+    match path {
+        [] => Ok(()),
+        [KeySegment::Append] => match target {
+            Value::Array(items) => {
+                items.push(value);
+                Ok(())
+            }
+            _ => Err(conflict()),
+        },
+        [KeySegment::Key(key), rest @ ..] => {
+            let Value::Object(map) = target else {
+                return Err(conflict());
+            };
+            match rest.first() {
+                None => {
+                    map.insert((*key).to_string(), value);
+                    Ok(())
+                }
+                Some(next) => {
+                    let child = map.entry(*key).or_insert_with(|| match next {
+                        KeySegment::Append => Value::Array(Vec::new()),
+                        KeySegment::Key(_) => Value::Object(Map::new()),
+                    });
+                    insert_field(child, field, rest, value)
+                }
+            }
+        }
+        [KeySegment::Append, ..] => Err(conflict()),
+    }
+}
+
+/// Path to the first object carrying `pageInfo`, searching depth-first
+fn connection_path(value: &Value) -> Option<Vec<String>> {
+    let Value::Object(map) = value else {
+        return None;
+    };
+    if map.contains_key("pageInfo") {
+        return Some(Vec::new());
+    }
+    map.iter().find_map(|(key, child)| {
+        let mut path = connection_path(child)?;
+        path.insert(0, key.clone());
+        Some(path)
+    })
+}
+
+fn at_path<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |value, key| value.get(key))
+}
+
+/// Cursor for the next page, when the connection reports one
+pub(crate) fn next_cursor(data: &Value, path: &[String]) -> Option<String> {
+    let page_info = at_path(data, path)?.get("pageInfo")?;
+    if page_info.get("hasNextPage")?.as_bool()? {
+        page_info.get("endCursor")?.as_str().map(str::to_string)
+    } else {
+        None
+    }
+}
+
+/// Find the paginated connection in the first page of results
+pub(crate) fn find_connection(data: &Value) -> Result<Vec<String>, CliError> {
+    connection_path(data).ok_or_else(|| {
+        CliError::InvalidArgs(
+            "--paginate requires selecting pageInfo { hasNextPage endCursor } on a connection"
+                .to_string(),
+        )
+    })
+}
+
+/// Append a later page's `nodes`/`edges` to `merged` and take its `pageInfo`
+pub(crate) fn merge_page(merged: &mut Value, page: &Value, path: &[String]) {
+    let Some(source) = at_path(page, path).and_then(Value::as_object) else {
+        return;
+    };
+    let Some(target) = path
+        .iter()
+        .try_fold(&mut *merged, |value, key| value.get_mut(key))
+        .and_then(Value::as_object_mut)
+    else {
+        return;
+    };
+
+    for key in ["nodes", "edges"] {
+        if let (Some(Value::Array(items)), Some(Value::Array(more))) =
+            (target.get_mut(key), source.get(key))
+        {
+            items.extend(more.iter().cloned());
+        }
+    }
+    if let Some(page_info) = source.get("pageInfo") {
+        target.insert("pageInfo".to_string(), page_info.clone());
+    }
+}
@@ -0,0 +1,1018 @@
+//! Offline validation of GraphQL documents against the bundled schema.
+//!
+//! `schema/linear.graphql` is the SDL the `linear-schema` crate is generated
+//! from; it is embedded in the binary so `linear api validate` and
+//! `linear api --validate` work without network access.
+//!
+//! Validation follows the GraphQL specification's rules for the mistakes that
+//! matter in hand-written queries: syntax, fields and their selections,
+//! arguments and input values, variable definitions and usages, fragments and
+//! the `@include`/`@skip` directives.
+
+use crate::error::CliError;
+use cynic_parser::Value;
+use cynic_parser::common::{OperationType, WrappingType};
+use cynic_parser::executable::{
+    Directive, ExecutableDocument, FieldSelection, Iter, OperationDefinition, Selection,
+};
+use cynic_parser::type_system::{
+    Definition, FieldDefinition, InputValueDefinition, TypeDefinition,
+};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// The Linear schema shipped with the repository
+const LINEAR_SCHEMA: &str = include_str!("../../schema/linear.graphql");
+
+/// Scalars every GraphQL schema provides without declaring them
+const BUILTIN_SCALARS: [&str; 5] = ["Int", "Float", "String", "Boolean", "ID"];
+
+/// A validation problem at a 1-based line and column of the document
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn at(source: &str, offset: usize, message: String) -> Self {
+        let before = source.get(..offset).unwrap_or(source);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before
+                .rsplit('\n')
+                .next()
+                .map_or(0, |line| line.chars().count())
+                + 1,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+impl TypeKind {
+    /// Object, interface and union types require a selection set
+    fn is_composite(self) -> bool {
+        matches!(
+            self,
+            TypeKind::Object | TypeKind::Interface | TypeKind::Union
+        )
+    }
+
+    /// Scalar, enum and input object types can be used for variables
+    fn is_input(self) -> bool {
+        matches!(
+            self,
+            TypeKind::Scalar | TypeKind::Enum | TypeKind::InputObject
+        )
+    }
+}
+
+/// A named type with its list/non-null wrappers, outermost first
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TypeRef {
+    name: String,
+    wrappers: Vec<WrappingType>,
+}
+
+impl TypeRef {
+    fn new(name: &str, wrappers: impl Iterator<Item = WrappingType>) -> Self {
+        Self {
+            name: name.to_string(),
+            wrappers: wrappers.collect(),
+        }
+    }
+
+    fn is_non_null(&self) -> bool {
+        self.wrappers.first() == Some(&WrappingType::NonNull)
+    }
+
+    /// The same type without a leading non-null wrapper
+    fn nullable(&self) -> TypeRef {
+        let skip = usize::from(self.is_non_null());
+        TypeRef {
+            name: self.name.clone(),
+            wrappers: self.wrappers[skip..].to_vec(),
+        }
+    }
+
+    /// Item type when this is a (possibly non-null) list
+    fn item(&self) -> Option<TypeRef> {
+        let nullable = self.nullable();
+        match nullable.wrappers.split_first() {
+            Some((WrappingType::List, rest)) => Some(TypeRef {
+                name: nullable.name.clone(),
+                wrappers: rest.to_vec(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = self
+            .wrappers
+            .iter()
+            .rev()
+            .fold(self.name.clone(), |inner, wrapper| match wrapper {
+                WrappingType::NonNull => format!("{inner}!"),
+                WrappingType::List => format!("[{inner}]"),
+            });
+        f.write_str(&rendered)
+    }
+}
+
+/// Whether a value of type `variable` may be passed where `location` is expected
+/// (the specification's `AreTypesCompatible`)
+fn types_compatible(variable: &[WrappingType], location: &[WrappingType]) -> bool {
+    match (variable.split_first(), location.split_first()) {
+        (_, Some((WrappingType::NonNull, location_rest))) => match variable.split_first() {
+            Some((WrappingType::NonNull, variable_rest)) => {
+                types_compatible(variable_rest, location_rest)
+            }
+            _ => false,
+        },
+        (Some((WrappingType::NonNull, variable_rest)), _) => {
+            types_compatible(variable_rest, location)
+        }
+        (Some((WrappingType::List, variable_rest)), Some((WrappingType::List, location_rest))) => {
+            types_compatible(variable_rest, location_rest)
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[derive(Debug)]
+struct InputValue {
+    name: String,
+    ty: TypeRef,
+    has_default: bool,
+}
+
+impl From<InputValueDefinition<'_>> for InputValue {
+    fn from(value: InputValueDefinition<'_>) -> Self {
+        Self {
+            name: value.name().to_string(),
+            ty: TypeRef::new(value.ty().name(), value.ty().wrappers()),
+            has_default: value.default_value().is_some(),
+        }
+    }
+}
+
+/// Find an argument or input field by name
+fn input_value<'a>(values: &'a [InputValue], name: &str) -> Option<&'a InputValue> {
+    values.iter().find(|value| value.name == name)
+}
+
+#[derive(Debug)]
+struct Field {
+    ty: TypeRef,
+    arguments: Vec<InputValue>,
+}
+
+impl From<FieldDefinition<'_>> for Field {
+    fn from(field: FieldDefinition<'_>) -> Self {
+        Self {
+            ty: TypeRef::new(field.ty().name(), field.ty().wrappers()),
+            arguments: field.arguments().map(InputValue::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct NamedType {
+    kind: TypeKind,
+    fields: HashMap<String, Field>,
+    input_fields: Vec<InputValue>,
+    enum_values: HashSet<String>,
+    /// Object types a value of this type can be (itself, union members or implementations)
+    possible_types: HashSet<String>,
+}
+
+impl NamedType {
+    fn new(kind: TypeKind) -> Self {
+        Self {
+            kind,
+            fields: HashMap::new(),
+            input_fields: Vec::new(),
+            enum_values: HashSet::new(),
+            possible_types: HashSet::new(),
+        }
+    }
+}
+
+/// Type information needed to validate executable documents
+#[derive(Debug)]
+pub struct Schema {
+    types: HashMap<String, NamedType>,
+    query: Option<String>,
+    mutation: Option<String>,
+    subscription: Option<String>,
+}
+
+impl Schema {
+    /// The bundled Linear schema, parsed on first use
+    pub fn linear() -> &'static Schema {
+        static SCHEMA: OnceLock<Schema> = OnceLock::new();
+        SCHEMA.get_or_init(|| Schema::parse(LINEAR_SCHEMA).expect("bundled schema is valid SDL"))
+    }
+
+    /// Build a schema from SDL
+    pub fn parse(sdl: &str) -> Result<Self, CliError> {
+        let document = cynic_parser::parse_type_system_document(sdl)
+            .map_err(|e| CliError::General(format!("Failed to parse GraphQL schema: {e}")))?;
+
+        let mut schema = Schema {
+            types: BUILTIN_SCALARS
+                .iter()
+                .map(|name| (name.to_string(), NamedType::new(TypeKind::Scalar)))
+                .collect(),
+            query: None,
+            mutation: None,
+            subscription: None,
+        };
+        let mut explicit_roots = false;
+        let mut implementations = Vec::new();
+
+        for definition in document.definitions() {
+            match definition {
+                Definition::Schema(def) | Definition::SchemaExtension(def) => {
+                    explicit_roots = true;
+                    for root in def.root_operations() {
+                        let name = Some(root.named_type().to_string());
+                        match root.operation_type() {
+                            OperationType::Query => schema.query = name,
+                            OperationType::Mutation => schema.mutation = name,
+                            OperationType::Subscription => schema.subscription = name,
+                        }
+                    }
+                }
+                Definition::Type(def) | Definition::TypeExtension(def) => {
+                    if let TypeDefinition::Object(object) = def {
+                        implementations.extend(
+                            object.implements_interfaces().map(|interface| {
+                                (interface.to_string(), object.name().to_string())
+                            }),
+                        );
+                    }
+                    schema.add_type(def);
+                }
+                Definition::Directive(_) => {}
+            }
+        }
+
+        for (interface, object) in implementations {
+            if let Some(interface) = schema.types.get_mut(&interface) {
+                interface.possible_types.insert(object);
+            }
+        }
+
+        if !explicit_roots {
+            let root = |name: &str| schema.types.contains_key(name).then(|| name.to_string());
+            (schema.query, schema.mutation, schema.subscription) =
+                (root("Query"), root("Mutation"), root("Subscription"));
+        }
+
+        Ok(schema)
+    }
+
+    fn add_type(&mut self, definition: TypeDefinition<'_>) {
+        let name = definition.name().to_string();
+        let kind = match definition {
+            TypeDefinition::Scalar(_) => TypeKind::Scalar,
+            TypeDefinition::Object(_) => TypeKind::Object,
+            TypeDefinition::Interface(_) => TypeKind::Interface,
+            TypeDefinition::Union(_) => TypeKind::Union,
+            TypeDefinition::Enum(_) => TypeKind::Enum,
+            TypeDefinition::InputObject(_) => TypeKind::InputObject,
+        };
+
+        // Extensions add to an existing definition
+        let entry = self
+            .types
+            .entry(name.clone())
+            .or_insert_with(|| NamedType::new(kind));
+
+        match definition {
+            TypeDefinition::Scalar(_) => {}
+            TypeDefinition::Object(object) => {
+                entry.possible_types.insert(name);
+                entry.fields.extend(
+                    object
+                        .fields()
+                        .map(|field| (field.name().to_string(), Field::from(field))),
+                );
+            }
+            TypeDefinition::Interface(interface) => entry.fields.extend(
+                interface
+                    .fields()
+                    .map(|field| (field.name().to_string(), Field::from(field))),
+            ),
+            TypeDefinition::Union(union) => entry
+                .possible_types
+                .extend(union.members().map(|member| member.name().to_string())),
+            TypeDefinition::Enum(enumeration) => entry
+                .enum_values
+                .extend(enumeration.values().map(|value| value.value().to_string())),
+            TypeDefinition::InputObject(input) => entry
+                .input_fields
+                .extend(input.fields().map(InputValue::from)),
+        }
+    }
+
+    fn root(&self, operation: OperationType) -> Option<&str> {
+        match operation {
+            OperationType::Query => self.query.as_deref(),
+            OperationType::Mutation => self.mutation.as_deref(),
+            OperationType::Subscription => self.subscription.as_deref(),
+        }
+    }
+
+    /// Whether fragments on `a` and `b` can ever apply to the same object
+    fn types_overlap(&self, a: &str, b: &str) -> bool {
+        match (self.types.get(a), self.types.get(b)) {
+            (Some(a), Some(b)) => !a.possible_types.is_disjoint(&b.possible_types),
+            _ => true,
+        }
+    }
+
+    /// Check `source` against the schema, returning every problem found in
+    /// document order.
+    #[must_use]
+    pub fn validate(&self, source: &str) -> Vec<Diagnostic> {
+        let document = match cynic_parser::parse_executable_document(source) {
+            Ok(document) => document,
+            Err(e) => {
+                let offset = e.span().map_or(source.len(), |span| span.start);
+                return vec![Diagnostic::at(source, offset, syntax_message(&e))];
+            }
+        };
+
+        let mut validator = Validator {
+            schema: self,
+            source,
+            document: &document,
+            diagnostics: Vec::new(),
+            scope: Scope::default(),
+        };
+        validator.document();
+
+        let mut diagnostics = validator.diagnostics;
+        diagnostics.sort();
+        diagnostics.dedup();
+        diagnostics
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Parser messages list grammar tokens; keep them short and readable
+fn syntax_message(error: &cynic_parser::Error) -> String {
+    match error {
+        cynic_parser::Error::UnrecognizedToken {
+            token: (_, token, _),
+            ..
+        } => format!("Syntax error: unexpected `{token}`"),
+        cynic_parser::Error::UnrecognizedEof { .. } => {
+            "Syntax error: unexpected end of document".to_string()
+        }
+        cynic_parser::Error::EmptyExecutableDocument => "Document is empty".to_string(),
+        other => format!("Syntax error: {other}"),
+    }
+}
+
+/// A variable referenced somewhere in a selection set
+#[derive(Debug, Clone)]
+struct VariableUsage {
+    name: String,
+    expected: TypeRef,
+    /// The argument or input field has a default, so a nullable variable is fine
+    location_has_default: bool,
+    offset: usize,
+}
+
+/// Variables and fragment spreads used directly by one operation or fragment
+#[derive(Debug, Default)]
+struct Scope {
+    usages: Vec<VariableUsage>,
+    spreads: Vec<(String, usize)>,
+}
+
+struct Validator<'a> {
+    schema: &'a Schema,
+    source: &'a str,
+    document: &'a ExecutableDocument,
+    diagnostics: Vec<Diagnostic>,
+    /// Scope of the definition currently being walked
+    scope: Scope,
+}
+
+impl<'a> Validator<'a> {
+    fn error(&mut self, offset: usize, message: String) {
+        self.diagnostics
+            .push(Diagnostic::at(self.source, offset, message));
+    }
+
+    /// Offset of `name` at or after `offset` (type condition spans include `on`)
+    fn name_offset(&self, offset: usize, name: &str) -> usize {
+        self.source
+            .get(offset..)
+            .and_then(|rest| rest.find(name))
+            .map_or(offset, |position| offset + position)
+    }
+
+    fn document(&mut self) {
+        let document = self.document;
+        let operation_count = document.operations().count();
+
+        let mut operation_names = HashSet::new();
+        let mut operations = Vec::new();
+        for operation in document.operations() {
+            // Anonymous operations still carry an empty name span after the keyword
+            let offset = operation
+                .name_span()
+                .filter(|_| operation.name().is_some())
+                .or(operation.operation_type_span())
+                .unwrap_or(operation.selection_set_span())
+                .start;
+            match operation.name() {
+                Some(name) if !operation_names.insert(name) => self.error(
+                    offset,
+                    format!("There can be only one operation named `{name}`"),
+                ),
+                None if operation_count > 1 => self.error(
+                    offset,
+                    "Anonymous operations must be the only operation in the document".to_string(),
+                ),
+                _ => {}
+            }
+
+            match self.schema.root(operation.operation_type()) {
+                Some(root) => self.selection_set(root, operation.selection_set()),
+                None => self.error(
+                    offset,
+                    format!(
+                        "Schema does not support {} operations",
+                        operation.operation_type()
+                    ),
+                ),
+            }
+            operations.push((operation, std::mem::take(&mut self.scope)));
+        }
+
+        let mut fragments: HashMap<&str, Scope> = HashMap::new();
+        for fragment in document.fragments() {
+            let name = fragment.name();
+            let type_condition = fragment.type_condition();
+            let offset = self.name_offset(fragment.type_condition_span().start, type_condition);
+            if self.composite_type(type_condition, offset) {
+                self.selection_set(type_condition, fragment.selection_set());
+            }
+            let scope = std::mem::take(&mut self.scope);
+            if fragments.insert(name, scope).is_some() {
+                self.error(
+                    fragment.name_span().start,
+                    format!("There can be only one fragment named `{name}`"),
+                );
+            }
+        }
+
+        self.fragment_cycles(&fragments);
+
+        let mut used_fragments = HashSet::new();
+        for (operation, scope) in &operations {
+            let usages = self.operation_usages(scope, &fragments, &mut used_fragments);
+            self.variables(*operation, &usages);
+        }
+
+        for fragment in document.fragments() {
+            if !used_fragments.contains(fragment.name()) {
+                self.error(
+                    fragment.name_span().start,
+                    format!("Fragment `{}` is never used", fragment.name()),
+                );
+            }
+        }
+    }
+
+    /// Collect variable usages for an operation, following fragment spreads
+    fn operation_usages<'f>(
+        &self,
+        scope: &Scope,
+        fragments: &HashMap<&'f str, Scope>,
+        visited: &mut HashSet<&'f str>,
+    ) -> Vec<VariableUsage> {
+        let mut usages = scope.usages.clone();
+        let mut seen = HashSet::new();
+        let mut pending: Vec<&str> = scope
+            .spreads
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        while let Some(name) = pending.pop() {
+            let Some((&key, fragment)) = fragments.get_key_value(name) else {
+                continue;
+            };
+            if !seen.insert(key) {
+                continue;
+            }
+            visited.insert(key);
+            usages.extend(fragment.usages.iter().cloned());
+            pending.extend(fragment.spreads.iter().map(|(name, _)| name.as_str()));
+        }
+
+        usages
+    }
+
+    /// Report fragments that spread themselves, directly or indirectly
+    fn fragment_cycles(&mut self, fragments: &HashMap<&str, Scope>) {
+        fn visit<'f>(
+            name: &'f str,
+            fragments: &'f HashMap<&str, Scope>,
+            stack: &mut Vec<&'f str>,
+            done: &mut HashSet<&'f str>,
+            cycles: &mut Vec<(String, usize)>,
+        ) {
+            let Some(scope) = fragments.get(name) else {
+                return;
+            };
+            stack.push(name);
+            for (spread, offset) in &scope.spreads {
+                if stack.contains(&spread.as_str()) {
+                    cycles.push((spread.clone(), *offset));
+                } else if !done.contains(spread.as_str()) {
+                    visit(spread, fragments, stack, done, cycles);
+                }
+            }
+            stack.pop();
+            done.insert(name);
+        }
+
+        let mut names: Vec<&str> = fragments.keys().copied().collect();
+        names.sort_unstable();
+
+        let mut done = HashSet::new();
+        let mut cycles = Vec::new();
+        for name in names {
+            visit(name, fragments, &mut Vec::new(), &mut done, &mut cycles);
+        }
+        for (name, offset) in cycles {
+            self.error(
+                offset,
+                format!("Cannot spread fragment `{name}` within itself"),
+            );
+        }
+    }
+
+    /// Check variable definitions against how the operation uses them
+    fn variables(&mut self, operation: OperationDefinition<'_>, usages: &[VariableUsage]) {
+        let mut defined: HashMap<&str, (TypeRef, bool)> = HashMap::new();
+
+        for variable in operation.variable_definitions() {
+            let name = variable.name();
+            let offset = variable.name_span().start;
+            let ty = TypeRef::new(variable.ty().name(), variable.ty().wrappers());
+
+            match self.schema.types.get(&ty.name) {
+                Some(named) if named.kind.is_input() => {}
+                Some(_) => self.error(
+                    variable.ty().span().start,
+                    format!("Variable `${name}` cannot be of non-input type `{ty}`"),
+                ),
+                None => self.error(
+                    variable.ty().span().start,
+                    format!("Unknown type `{}`", ty.name),
+                ),
+            }
+
+            if defined
+                .insert(name, (ty, variable.default_value().is_some()))
+                .is_some()
+            {
+                self.error(
+                    offset,
+                    format!("There can be only one variable named `${name}`"),
+                );
+            }
+        }
+
+        for usage in usages {
+            let Some((ty, has_default)) = defined.get(usage.name.as_str()) else {
+                self.error(
+                    usage.offset,
+                    format!("Variable `${}` is not defined", usage.name),
+                );
+                continue;
+            };
+
+            let expected = &usage.expected;
+            let allowed = if expected.is_non_null() && !ty.is_non_null() {
+                (*has_default || usage.location_has_default)
+                    && types_compatible(&ty.wrappers, &expected.nullable().wrappers)
+            } else {
+                types_compatible(&ty.wrappers, &expected.wrappers)
+            };
+            if !allowed || ty.name != expected.name {
+                self.error(
+                    usage.offset,
+                    format!(
+                        "Variable `${}` of type `{ty}` cannot be used where `{expected}` is expected",
+                        usage.name
+                    ),
+                );
+            }
+        }
+
+        for variable in operation.variable_definitions() {
+            if !usages.iter().any(|usage| usage.name == variable.name()) {
+                self.error(
+                    variable.name_span().start,
+                    format!("Variable `${}` is never used", variable.name()),
+                );
+            }
+        }
+    }
+
+    /// Report unless `name` is an object, interface or union type
+    fn composite_type(&mut self, name: &str, offset: usize) -> bool {
+        match self.schema.types.get(name) {
+            Some(ty) if ty.kind.is_composite() => true,
+            Some(_) => {
+                self.error(
+                    offset,
+                    format!("Fragment cannot condition on non-composite type `{name}`"),
+                );
+                false
+            }
+            None => {
+                self.error(offset, format!("Unknown type `{name}`"));
+                false
+            }
+        }
+    }
+
+    fn selection_set(&mut self, parent: &str, selections: Iter<'_, Selection<'_>>) {
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => {
+                    self.directives(field.directives());
+                    self.field(parent, field);
+                }
+                Selection::InlineFragment(fragment) => {
+                    self.directives(fragment.directives());
+                    match fragment.type_condition() {
+                        Some(type_condition) => {
+                            let offset = fragment
+                                .type_condition_span()
+                                .map_or(fragment.selection_set_span().start, |span| {
+                                    self.name_offset(span.start, type_condition)
+                                });
+                            if self.composite_type(type_condition, offset) {
+                                self.spread_applies(
+                                    parent,
+                                    type_condition,
+                                    offset,
+                                    "Inline fragment",
+                                );
+                                self.selection_set(type_condition, fragment.selection_set());
+                            }
+                        }
+                        None => self.selection_set(parent, fragment.selection_set()),
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    self.directives(spread.directives());
+                    let name = spread.fragment_name();
+                    let offset = spread.fragment_name_span().start;
+                    match spread.fragment() {
+                        Some(fragment) => self.spread_applies(
+                            parent,
+                            fragment.type_condition(),
+                            offset,
+                            &format!("Fragment `{name}`"),
+                        ),
+                        None => self.error(offset, format!("Unknown fragment `{name}`")),
+                    }
+                    // Fragment bodies are validated once, against their own type condition
+                    self.scope.spreads.push((name.to_string(), offset));
+                }
+            }
+        }
+    }
+
+    fn spread_applies(&mut self, parent: &str, type_condition: &str, offset: usize, what: &str) {
+        if !self.schema.types_overlap(parent, type_condition) {
+            self.error(
+                offset,
+                format!(
+                    "{what} cannot be spread here: `{parent}` can never be of type `{type_condition}`"
+                ),
+            );
+        }
+    }
+
+    /// Check `@include`/`@skip`, the only directives executable documents may use here
+    fn directives(&mut self, directives: Iter<'_, Directive<'_>>) {
+        let boolean = TypeRef::new("Boolean", std::iter::once(WrappingType::NonNull));
+        for directive in directives {
+            let offset = directive.name_span().start;
+            match directive.name() {
+                "include" | "skip" => {
+                    let argument = InputValue {
+                        name: "if".to_string(),
+                        ty: boolean.clone(),
+                        has_default: false,
+                    };
+                    let what = format!("directive `@{}`", directive.name());
+                    self.arguments(
+                        &what,
+                        offset,
+                        std::slice::from_ref(&argument),
+                        directive.arguments(),
+                    );
+                }
+                name => self.error(offset, format!("Unknown directive `@{name}`")),
+            }
+        }
+    }
+
+    /// Check supplied arguments against their definitions
+    fn arguments(
+        &mut self,
+        what: &str,
+        offset: usize,
+        definitions: &[InputValue],
+        arguments: Iter<'_, cynic_parser::executable::Argument<'_>>,
+    ) {
+        let mut supplied = HashSet::new();
+        for argument in arguments {
+            supplied.insert(argument.name());
+            match input_value(definitions, argument.name()) {
+                Some(definition) => {
+                    self.value(argument.value(), &definition.ty, definition.has_default);
+                }
+                None => self.error(
+                    argument.name_span().start,
+                    format!("Unknown argument `{}` on {what}", argument.name()),
+                ),
+            }
+        }
+
+        for definition in definitions {
+            if definition.ty.is_non_null()
+                && !definition.has_default
+                && !supplied.contains(definition.name.as_str())
+            {
+                self.error(
+                    offset,
+                    format!(
+                        "{} requires argument `{}` of type `{}`",
+                        capitalize(what),
+                        definition.name,
+                        definition.ty
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Check a literal value against `expected`, recording variable usages
+    fn value(&mut self, value: Value<'_>, expected: &TypeRef, has_default: bool) {
+        let offset = value.span().start;
+
+        if let Value::Variable(variable) = value {
+            self.scope.usages.push(VariableUsage {
+                name: variable.name().to_string(),
+                expected: expected.clone(),
+                location_has_default: has_default,
+                offset,
+            });
+            return;
+        }
+
+        if let Value::Null(_) = value {
+            if expected.is_non_null() {
+                self.error(
+                    offset,
+                    format!("Expected a value of type `{expected}`, found null"),
+                );
+            }
+            return;
+        }
+
+        if let Some(item) = expected.item() {
+            match value {
+                Value::List(list) => {
+                    for entry in list.items() {
+                        self.value(entry, &item, false);
+                    }
+                }
+                // A single value is coerced to a one-item list
+                other => self.value(other, &item, false),
+            }
+            return;
+        }
+
+        let Some(named) = self.schema.types.get(&expected.name) else {
+            return;
+        };
+        let mismatch =
+            |found: &str| format!("Expected a value of type `{expected}`, found {found}");
+
+        match (value, named.kind) {
+            (Value::List(_), _) => self.error(offset, mismatch("a list")),
+            (Value::Object(object), TypeKind::InputObject) => {
+                let mut supplied = HashSet::new();
+                for field in object.fields() {
+                    supplied.insert(field.name());
+                    match input_value(&named.input_fields, field.name()) {
+                        Some(definition) => {
+                            self.value(field.value(), &definition.ty, definition.has_default);
+                        }
+                        None => self.error(
+                            field.name_span().start,
+                            format!(
+                                "Unknown field `{}` on input type `{}`",
+                                field.name(),
+                                expected.name
+                            ),
+                        ),
+                    }
+                }
+                for definition in &named.input_fields {
+                    if definition.ty.is_non_null()
+                        && !definition.has_default
+                        && !supplied.contains(definition.name.as_str())
+                    {
+                        self.error(
+                            offset,
+                            format!(
+                                "Input `{}` requires field `{}` of type `{}`",
+                                expected.name, definition.name, definition.ty
+                            ),
+                        );
+                    }
+                }
+            }
+            (Value::Object(_), _) => self.error(offset, mismatch("an object")),
+            (Value::Enum(value), TypeKind::Enum) if !named.enum_values.contains(value.name()) => {
+                self.error(
+                    offset,
+                    format!(
+                        "Value `{}` does not exist in enum `{}`",
+                        value.name(),
+                        expected.name
+                    ),
+                );
+            }
+            (Value::Enum(_), TypeKind::Enum) => {}
+            (_, TypeKind::Enum) => self.error(offset, mismatch("a non-enum value")),
+            (_, TypeKind::InputObject) => self.error(offset, mismatch("a scalar")),
+            (value, TypeKind::Scalar) => {
+                // Custom scalars (DateTime, JSON, ...) accept any literal
+                let accepted: &[&str] = match value {
+                    Value::Int(_) => &["Int", "Float", "ID"],
+                    Value::Float(_) => &["Float"],
+                    Value::String(_) => &["String", "ID"],
+                    Value::Boolean(_) => &["Boolean"],
+                    Value::Enum(_) => &[],
+                    _ => return,
+                };
+                if BUILTIN_SCALARS.contains(&expected.name.as_str())
+                    && !accepted.contains(&expected.name.as_str())
+                {
+                    let found = match value {
+                        Value::Int(_) => "an integer",
+                        Value::Float(_) => "a float",
+                        Value::String(_) => "a string",
+                        Value::Boolean(_) => "a boolean",
+                        _ => "an enum value",
+                    };
+                    self.error(offset, mismatch(found));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn field(&mut self, parent: &str, selection: FieldSelection<'_>) {
+        let name = selection.name();
+        let offset = selection.name_span().start;
+        // Introspection fields are answered by the server itself
+        if name.starts_with("__") {
+            return;
+        }
+
+        let schema = self.schema;
+        let Some(field) = schema.types.get(parent).and_then(|ty| ty.fields.get(name)) else {
+            self.error(
+                offset,
+                format!("Cannot query field `{name}` on type `{parent}`"),
+            );
+            return;
+        };
+
+        self.arguments(
+            &format!("field `{parent}.{name}`"),
+            offset,
+            &field.arguments,
+            selection.arguments(),
+        );
+
+        let composite = schema
+            .types
+            .get(&field.ty.name)
+            .is_some_and(|ty| ty.kind.is_composite());
+        let subselections = selection.selection_set();
+        match (composite, subselections.len()) {
+            (true, 0) => self.error(
+                offset,
+                format!(
+                    "Field `{parent}.{name}` of type `{}` must have a selection of subfields",
+                    field.ty.name
+                ),
+            ),
+            (true, _) => self.selection_set(&field.ty.name, subselections),
+            (false, 0) => {}
+            (false, _) => self.error(
+                selection
+                    .selection_set_span()
+                    .map_or(offset, |span| span.start),
+                format!(
+                    "Field `{parent}.{name}` of type `{}` cannot have a selection of subfields",
+                    field.ty.name
+                ),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_schema_parses_with_roots() {
+        let schema = Schema::linear();
+        assert_eq!(schema.query.as_deref(), Some("Query"));
+        assert_eq!(schema.mutation.as_deref(), Some("Mutation"));
+        assert!(schema.types["Issue"].fields.contains_key("identifier"));
+        assert!(schema.types["Node"].possible_types.contains("Issue"));
+    }
+
+    #[test]
+    fn test_diagnostic_positions_are_one_based() {
+        let source = "query {\n  viewer { nope }\n}";
+        let offset = source.find("nope").unwrap();
+        let diagnostic = Diagnostic::at(source, offset, "x".to_string());
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 12));
+    }
+
+    #[test]
+    fn test_types_compatible_follows_the_spec() {
+        use WrappingType::{List, NonNull};
+
+        assert!(types_compatible(&[NonNull], &[]));
+        assert!(types_compatible(&[NonNull], &[NonNull]));
+        assert!(!types_compatible(&[], &[NonNull]));
+        assert!(types_compatible(&[List, NonNull], &[List]));
+        assert!(!types_compatible(&[List], &[]));
+        assert!(!types_compatible(&[], &[List]));
+    }
+
+    #[test]
+    fn test_type_ref_display_and_item() {
+        use WrappingType::{List, NonNull};
+
+        let ty = TypeRef::new("String", [NonNull, List, NonNull].into_iter());
+        assert_eq!(ty.to_string(), "[String!]!");
+        assert_eq!(ty.item().unwrap().to_string(), "String!");
+        assert_eq!(ty.nullable().to_string(), "[String!]");
+    }
+}
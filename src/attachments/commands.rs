@@ -0,0 +1,133 @@
+use crate::attachments::types::AttachmentList;
+use crate::attachments::upload::upload_file;
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::attachments::{AttachmentClient, AttachmentLinkKind, LinkAttachmentInput};
+use crate::client::uploads::UploadClient;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output, format_output_to_writer,
+    get_format_with_provider,
+};
+use secrecy::ExposeSecret;
+use std::path::Path;
+
+/// Handle `issue attachments <issue-id>` command
+pub fn handle_list(
+    client: &dyn AttachmentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    issue_id: &str,
+    limit: usize,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let attachments = client.list_attachments(token.expose_secret(), issue_id, limit)?;
+
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+
+    let mut output = Vec::new();
+    format_output_to_writer(&AttachmentList(attachments), format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle `issue attach <issue-id>` command
+#[allow(clippy::too_many_arguments)]
+pub fn handle_attach(
+    issue_id: &str,
+    url: &str,
+    title: Option<String>,
+    kind: AttachmentLinkKind,
+    client: &dyn AttachmentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let attachment = client.link_attachment(
+        token.expose_secret(),
+        LinkAttachmentInput {
+            issue_id: issue_id.to_string(),
+            url: url.to_string(),
+            title,
+            kind,
+        },
+    )?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&attachment, format)?);
+
+    Ok(())
+}
+
+/// Handle `issue attach <issue-id> --file <path>` command
+///
+/// Uploads the file to Linear's asset storage and links the asset to the issue,
+/// titled with the file name unless `title` is given.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_attach_file(
+    issue_id: &str,
+    path: &Path,
+    title: Option<String>,
+    client: &dyn AttachmentClient,
+    uploads: &dyn UploadClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let uploaded = upload_file(uploads, token.expose_secret(), path)?;
+
+    let attachment = client.link_attachment(
+        token.expose_secret(),
+        LinkAttachmentInput {
+            issue_id: issue_id.to_string(),
+            url: uploaded.asset_url,
+            title: Some(title.unwrap_or(uploaded.filename)),
+            kind: AttachmentLinkKind::Upload,
+        },
+    )?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&attachment, format)?);
+
+    Ok(())
+}
+
+/// Handle `issue detach <attachment-id>` command
+pub fn handle_detach(
+    attachment_id: &str,
+    client: &dyn AttachmentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    client.delete_attachment(token.expose_secret(), attachment_id)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let msg = if matches!(format, OutputFormat::Json) {
+        serde_json::json!({
+            "deleted": true,
+            "id": attachment_id,
+        })
+        .to_string()
+    } else {
+        format!("Deleted attachment {attachment_id}")
+    };
+    io.print(&msg);
+
+    Ok(())
+}
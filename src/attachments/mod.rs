@@ -0,0 +1,5 @@
+pub mod commands;
+pub mod types;
+pub mod upload;
+
+pub use types::{Attachment, AttachmentList};
@@ -0,0 +1,224 @@
+use crate::client::queries;
+use crate::error::CliError;
+use crate::output::{
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+
+/// Attachment (external link) on an issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub url: String,
+    /// Integration that created the attachment (e.g. `github`), when known
+    pub source_type: Option<String>,
+    pub creator_name: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl Attachment {
+    /// Source type for display, `url` for plain links
+    #[must_use]
+    pub fn source(&self) -> &str {
+        self.source_type.as_deref().unwrap_or("url")
+    }
+}
+
+impl TableFormatter for Attachment {
+    fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        let mut rows = vec![(Cow::Borrowed("Title"), Cow::Borrowed(self.title.as_str()))];
+
+        if let Some(subtitle) = &self.subtitle {
+            rows.push((Cow::Borrowed("Subtitle"), Cow::Borrowed(subtitle.as_str())));
+        }
+
+        rows.push((Cow::Borrowed("Source"), Cow::Borrowed(self.source())));
+        rows.push((Cow::Borrowed("URL"), Cow::Borrowed(self.url.as_str())));
+
+        if let Some(creator) = &self.creator_name {
+            rows.push((Cow::Borrowed("Creator"), Cow::Borrowed(creator.as_str())));
+        }
+
+        rows.push((
+            Cow::Borrowed("Created"),
+            Cow::Borrowed(self.created_at.as_str()),
+        ));
+        rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.id.as_str())));
+
+        rows
+    }
+}
+
+impl MarkdownFormatter for Attachment {
+    fn markdown_capacity_hint(&self) -> usize {
+        150 + self.title.len()
+            + self.url.len()
+            + self.id.len()
+            + self.subtitle.as_ref().map_or(0, |s| s.len())
+    }
+
+    fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
+        writeln!(output, "# [{}]({})\n", self.title, self.url)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        if let Some(subtitle) = &self.subtitle {
+            writeln!(output, "{}\n", subtitle)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        writeln!(output, "**Source:** {}", self.source())
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        if let Some(creator) = &self.creator_name {
+            writeln!(output, "**Creator:** {}", creator)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        writeln!(output, "**Created:** {}", self.created_at)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        writeln!(output, "**ID:** {}", self.id)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        Ok(())
+    }
+}
+
+impl Formattable for Attachment {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        AttachmentList(vec![self.clone()]).to_csv()
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        fast_markdown_formatter(self)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        generic_table_formatter(self)
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
+}
+
+/// Wrapper for a list of attachments
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct AttachmentList(pub Vec<Attachment>);
+
+impl Formattable for AttachmentList {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_list_formatter(&self.0)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record(["id", "title", "source", "url", "creator", "created_at"])
+            .csv_err("Failed to write CSV header")?;
+
+        for attachment in &self.0 {
+            wtr.write_record([
+                attachment.id.as_str(),
+                attachment.title.as_str(),
+                attachment.source(),
+                attachment.url.as_str(),
+                attachment.creator_name.as_deref().unwrap_or(""),
+                attachment.created_at.as_str(),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        let capacity = self
+            .0
+            .iter()
+            .map(|a| 40 + a.title.len() + a.url.len())
+            .sum();
+        let mut output = String::with_capacity(capacity);
+
+        writeln!(output, "## Attachments ({})\n", self.0.len())
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        for attachment in &self.0 {
+            writeln!(
+                output,
+                "- [{}]({}) ({})",
+                attachment.title,
+                attachment.url,
+                attachment.source()
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(output)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new("Title").flexible(),
+                TableColumn::new("Source"),
+                TableColumn::new("URL"),
+                TableColumn::new("Created").wide_only(),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
+            |attachment| {
+                vec![
+                    attachment.title.clone(),
+                    attachment.source().to_string(),
+                    attachment.url.clone(),
+                    attachment.created_at.clone(),
+                    attachment.id.clone(),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
+}
+
+impl From<queries::AttachmentNode> for Attachment {
+    fn from(node: queries::AttachmentNode) -> Self {
+        Attachment {
+            id: node.id.inner().to_string(),
+            title: node.title,
+            subtitle: node.subtitle.filter(|s| !s.is_empty()),
+            url: node.url,
+            source_type: node.source_type.filter(|s| !s.is_empty()),
+            creator_name: node.creator.map(|u| u.name),
+            created_at: node.created_at.0,
+            updated_at: node.updated_at.0,
+        }
+    }
+}
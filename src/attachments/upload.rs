@@ -0,0 +1,92 @@
+use crate::client::uploads::{UploadClient, UploadRequest};
+use crate::error::CliError;
+use std::path::Path;
+
+/// File that has been uploaded to Linear's asset storage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadedFile {
+    pub filename: String,
+    pub content_type: String,
+    pub asset_url: String,
+}
+
+impl UploadedFile {
+    /// Markdown reference to the asset: an inline image for images, a link otherwise
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        if self.content_type.starts_with("image/") {
+            format!("![{}]({})", self.filename, self.asset_url)
+        } else {
+            format!("[{}]({})", self.filename, self.asset_url)
+        }
+    }
+}
+
+/// Guess a MIME type from a file extension
+#[must_use]
+pub fn content_type_for(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+
+    match extension.as_deref() {
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        Some("pdf") => "application/pdf",
+        Some("json") => "application/json",
+        Some("zip") => "application/zip",
+        Some("txt" | "log") => "text/plain",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("html" | "htm") => "text/html",
+        Some("mp4") => "video/mp4",
+        Some("mov") => "video/quicktime",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Upload a local file: request a signed URL, then PUT the bytes to it
+///
+/// # Errors
+/// Returns `InvalidArgs` if `path` is not a readable file, or any error from
+/// the upload client.
+pub fn upload_file(
+    client: &dyn UploadClient,
+    token: &str,
+    path: &Path,
+) -> Result<UploadedFile, CliError> {
+    let metadata = std::fs::metadata(path)
+        .map_err(|e| CliError::InvalidArgs(format!("Cannot read {}: {e}", path.display())))?;
+    if !metadata.is_file() {
+        return Err(CliError::InvalidArgs(format!(
+            "{} is not a file",
+            path.display()
+        )));
+    }
+
+    let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| CliError::InvalidArgs(format!("{} is not a file", path.display())))?;
+    let content_type = content_type_for(path);
+
+    let target = client.request_upload(
+        token,
+        &UploadRequest {
+            filename: filename.clone(),
+            content_type: content_type.to_string(),
+            size: metadata.len(),
+        },
+    )?;
+    client.put_file(&target, path, content_type)?;
+
+    Ok(UploadedFile {
+        filename,
+        content_type: content_type.to_string(),
+        asset_url: target.asset_url,
+    })
+}
@@ -16,6 +16,24 @@ impl ConfigProvider for EnvConfigProvider {
     }
 }
 
+/// Environment variables plus the global `--utc` flag
+///
+/// `--utc` reads as `LINEAR_CLI_UTC=1`, so output formats pick up the flag and
+/// the environment variable the same way.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CliConfigProvider {
+    pub utc: bool,
+}
+
+impl ConfigProvider for CliConfigProvider {
+    fn get_var(&self, key: &str) -> Option<String> {
+        if self.utc && key == "LINEAR_CLI_UTC" {
+            return Some("1".to_string());
+        }
+        env::var(key).ok()
+    }
+}
+
 /// Test implementation using in-memory HashMap
 /// Only available when building tests or with test-utils feature
 pub struct TestConfigProvider {
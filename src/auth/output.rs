@@ -1,5 +1,5 @@
 use crate::error::CliError;
-use crate::output::Formattable;
+use crate::output::{Formattable, generic_jsonl_formatter, generic_template_formatter};
 use comfy_table::{Cell, Table, presets::UTF8_FULL};
 use serde::{Deserialize, Serialize};
 use std::fmt::Write as FmtWrite;
@@ -41,6 +41,10 @@ impl Formattable for AuthStatus {
             .map_err(|e| CliError::General(format!("Failed to serialize auth status to JSON: {e}")))
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -141,6 +145,10 @@ impl Formattable for AuthStatus {
 
         Ok(output)
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
 
 /// Logout result output
@@ -157,6 +165,10 @@ impl Formattable for LogoutResult {
         })
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -212,4 +224,8 @@ impl Formattable for LogoutResult {
 
         Ok(table.to_string())
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
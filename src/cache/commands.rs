@@ -0,0 +1,55 @@
+use crate::auth::config::ConfigProvider;
+use crate::cache::types::CacheClearResult;
+use crate::cache::{CacheTtls, cache_dir, clear, stats};
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use std::path::PathBuf;
+
+fn require_cache_dir(config: &dyn ConfigProvider) -> Result<PathBuf, CliError> {
+    cache_dir(config).ok_or_else(|| {
+        CliError::General(
+            "Cannot determine cache directory: set XDG_CACHE_HOME or HOME".to_string(),
+        )
+    })
+}
+
+/// Handle `cache clear`
+pub fn handle_clear(
+    config: &dyn ConfigProvider,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let dir = require_cache_dir(config)?;
+    let removed = clear(&dir)?;
+
+    let result = CacheClearResult {
+        directory: dir.display().to_string(),
+        removed,
+    };
+
+    let format = get_format_with_provider(format_flag, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&result, format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle `cache stats`
+pub fn handle_stats(
+    config: &dyn ConfigProvider,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let dir = require_cache_dir(config)?;
+    let ttls = CacheTtls::from_config(config)?;
+    let cache_stats = stats(&dir, &ttls)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&cache_stats, format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
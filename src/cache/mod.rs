@@ -0,0 +1,523 @@
+//! On-disk response cache for slow-changing reference data.
+//!
+//! Teams, workflow states, labels, users and the viewer rarely change, so their
+//! GraphQL responses are cached under `$XDG_CACHE_HOME/linear-cli` (falling back
+//! to `~/.cache/linear-cli`). Entries are keyed by profile (a hash of the API
+//! token, which is never written to disk), operation and variables, and expire
+//! after a per-resource TTL:
+//!
+//! - `LINEAR_CLI_CACHE_TTL` — default TTL for every resource
+//! - `LINEAR_CLI_CACHE_TTL_<RESOURCE>` — per-resource override
+//!   (`TEAMS`, `STATES`, `LABELS`, `USERS`, `VIEWER`)
+//!
+//! TTLs accept seconds or a `s`/`m`/`h`/`d` suffix (`90`, `30m`, `6h`); `0`
+//! disables caching for that resource.
+
+pub mod commands;
+pub mod types;
+
+use crate::auth::config::ConfigProvider;
+use crate::error::CliError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Directory name under the platform cache directory
+const CACHE_DIR_NAME: &str = "linear-cli";
+
+/// How the cache is used for this invocation (`--no-cache` / `--refresh`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheMode {
+    /// Serve fresh entries from the cache and store new responses
+    #[default]
+    Use,
+    /// Skip cached entries but store the fresh responses (`--refresh`)
+    Refresh,
+    /// Neither read nor write the cache (`--no-cache`)
+    Bypass,
+}
+
+/// Cacheable reference-data resources
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheResource {
+    Teams,
+    States,
+    Labels,
+    Users,
+    Viewer,
+}
+
+impl CacheResource {
+    pub const ALL: [CacheResource; 5] = [
+        CacheResource::Teams,
+        CacheResource::States,
+        CacheResource::Labels,
+        CacheResource::Users,
+        CacheResource::Viewer,
+    ];
+
+    /// Map a GraphQL operation name to the resource it reads, if cacheable.
+    #[must_use]
+    pub fn from_operation(name: &str) -> Option<Self> {
+        match name {
+            "TeamsQuery" | "TeamQuery" => Some(CacheResource::Teams),
+            "WorkflowStatesQuery" => Some(CacheResource::States),
+            "IssueLabelsQuery" => Some(CacheResource::Labels),
+            "UsersQuery" => Some(CacheResource::Users),
+            "ViewerQuery" => Some(CacheResource::Viewer),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CacheResource::Teams => "teams",
+            CacheResource::States => "states",
+            CacheResource::Labels => "labels",
+            CacheResource::Users => "users",
+            CacheResource::Viewer => "viewer",
+        }
+    }
+
+    fn ttl_var(self) -> &'static str {
+        match self {
+            CacheResource::Teams => "LINEAR_CLI_CACHE_TTL_TEAMS",
+            CacheResource::States => "LINEAR_CLI_CACHE_TTL_STATES",
+            CacheResource::Labels => "LINEAR_CLI_CACHE_TTL_LABELS",
+            CacheResource::Users => "LINEAR_CLI_CACHE_TTL_USERS",
+            CacheResource::Viewer => "LINEAR_CLI_CACHE_TTL_VIEWER",
+        }
+    }
+
+    fn default_ttl(self) -> Duration {
+        match self {
+            CacheResource::Viewer => Duration::from_secs(24 * 60 * 60),
+            _ => Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// Per-resource time-to-live settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheTtls {
+    ttls: [Duration; CacheResource::ALL.len()],
+}
+
+impl Default for CacheTtls {
+    fn default() -> Self {
+        Self {
+            ttls: CacheResource::ALL.map(CacheResource::default_ttl),
+        }
+    }
+}
+
+impl CacheTtls {
+    /// Read TTLs from `LINEAR_CLI_CACHE_TTL` and `LINEAR_CLI_CACHE_TTL_<RESOURCE>`.
+    pub fn from_config(config: &dyn ConfigProvider) -> Result<Self, CliError> {
+        let mut ttls = Self::default();
+        let default = config
+            .get_var("LINEAR_CLI_CACHE_TTL")
+            .map(|value| parse_ttl("LINEAR_CLI_CACHE_TTL", &value))
+            .transpose()?;
+
+        for resource in CacheResource::ALL {
+            let ttl = match config.get_var(resource.ttl_var()) {
+                Some(value) => Some(parse_ttl(resource.ttl_var(), &value)?),
+                None => default,
+            };
+            if let Some(ttl) = ttl {
+                ttls.set(resource, ttl);
+            }
+        }
+
+        Ok(ttls)
+    }
+
+    #[must_use]
+    pub fn get(&self, resource: CacheResource) -> Duration {
+        self.ttls[resource as usize]
+    }
+
+    pub fn set(&mut self, resource: CacheResource, ttl: Duration) {
+        self.ttls[resource as usize] = ttl;
+    }
+}
+
+/// Parse a TTL such as `90`, `45s`, `30m`, `6h` or `1d`.
+fn parse_ttl(var: &str, value: &str) -> Result<Duration, CliError> {
+    let value = value.trim();
+    let (digits, unit) = match value.char_indices().last() {
+        Some((idx, c)) if c.is_ascii_alphabetic() => (&value[..idx], c.to_ascii_lowercase()),
+        _ => (value, 's'),
+    };
+    let multiplier = match unit {
+        's' => 1,
+        'm' => 60,
+        'h' => 60 * 60,
+        'd' => 24 * 60 * 60,
+        _ => 0,
+    };
+
+    match digits.parse::<u64>() {
+        Ok(amount) if multiplier > 0 => Ok(Duration::from_secs(amount * multiplier)),
+        _ => Err(CliError::InvalidArgs(format!(
+            "Invalid {var} value '{value}': expected seconds or a duration like 30m, 6h, 1d"
+        ))),
+    }
+}
+
+/// Resolve the cache directory from `XDG_CACHE_HOME`, falling back to `~/.cache`.
+#[must_use]
+pub fn cache_dir(config: &dyn ConfigProvider) -> Option<PathBuf> {
+    let base = config
+        .get_var("XDG_CACHE_HOME")
+        .filter(|dir| Path::new(dir).is_absolute())
+        .map(PathBuf::from)
+        .or_else(|| {
+            config
+                .get_var("HOME")
+                .filter(|home| !home.is_empty())
+                .map(|home| PathBuf::from(home).join(".cache"))
+        })
+        .or_else(|| {
+            config
+                .get_var("LOCALAPPDATA")
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from)
+        })?;
+    Some(base.join(CACHE_DIR_NAME))
+}
+
+/// A cached response body with the time it was stored
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    stored_at: u64,
+    body: String,
+}
+
+/// On-disk GraphQL response cache
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    mode: CacheMode,
+    ttls: CacheTtls,
+}
+
+impl ResponseCache {
+    #[must_use]
+    pub fn new(dir: PathBuf, mode: CacheMode, ttls: CacheTtls) -> Self {
+        Self { dir, mode, ttls }
+    }
+
+    /// Build the cache from environment configuration.
+    ///
+    /// Returns `Ok(None)` when caching is bypassed or no cache directory can be
+    /// determined; invalid TTL values are reported as errors.
+    pub fn from_config(
+        config: &dyn ConfigProvider,
+        mode: CacheMode,
+    ) -> Result<Option<Self>, CliError> {
+        let ttls = CacheTtls::from_config(config)?;
+        if mode == CacheMode::Bypass {
+            return Ok(None);
+        }
+        Ok(cache_dir(config).map(|dir| Self::new(dir, mode, ttls)))
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Look up a fresh response body for `request` (the serialized operation).
+    #[must_use]
+    pub fn get(&self, token: &str, resource: CacheResource, request: &[u8]) -> Option<String> {
+        self.get_at(token, resource, request, unix_now())
+    }
+
+    fn get_at(
+        &self,
+        token: &str,
+        resource: CacheResource,
+        request: &[u8],
+        now: u64,
+    ) -> Option<String> {
+        let ttl = self.ttls.get(resource);
+        if self.mode != CacheMode::Use || ttl.is_zero() {
+            return None;
+        }
+
+        let raw = fs::read(self.entry_path(token, resource, request)).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&raw).ok()?;
+        let age = now.saturating_sub(entry.stored_at);
+        (age < ttl.as_secs()).then_some(entry.body)
+    }
+
+    /// Store a response body. Failures are ignored: the cache is best-effort.
+    pub fn put(&self, token: &str, resource: CacheResource, request: &[u8], body: &str) {
+        if self.mode == CacheMode::Bypass || self.ttls.get(resource).is_zero() {
+            return;
+        }
+        let _ = self.write_entry(token, resource, request, body, unix_now());
+    }
+
+    fn write_entry(
+        &self,
+        token: &str,
+        resource: CacheResource,
+        request: &[u8],
+        body: &str,
+        stored_at: u64,
+    ) -> std::io::Result<()> {
+        let path = self.entry_path(token, resource, request);
+        let Some(parent) = path.parent() else {
+            return Ok(());
+        };
+        create_private_dir(parent)?;
+
+        let entry = CacheEntry {
+            stored_at,
+            body: body.to_string(),
+        };
+        let contents = serde_json::to_vec(&entry)?;
+
+        // Write-then-rename so concurrent readers never see a partial entry
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::write(&tmp, contents)?;
+        fs::rename(&tmp, &path)
+    }
+
+    fn entry_path(&self, token: &str, resource: CacheResource, request: &[u8]) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}", fnv1a(&[token.as_bytes()])))
+            .join(resource.as_str())
+            .join(format!("{:016x}.json", fnv1a(&[request])))
+    }
+}
+
+/// Create `path` and any missing parents readable only by the current user (on unix)
+#[cfg(unix)]
+pub(crate) fn create_private_dir(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::DirBuilderExt;
+    fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(path)
+}
+
+#[cfg(not(unix))]
+pub(crate) fn create_private_dir(path: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(path)
+}
+
+/// 64-bit FNV-1a: stable across builds, unlike `DefaultHasher`.
+fn fnv1a(parts: &[&[u8]]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    parts
+        .iter()
+        .flat_map(|part| part.iter())
+        .fold(OFFSET, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+        })
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Remove every cached entry under `dir`, returning how many were removed.
+pub fn clear(dir: &Path) -> Result<usize, CliError> {
+    let entries = collect_entries(dir)?.len();
+    match fs::remove_dir_all(dir) {
+        Ok(()) => Ok(entries),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        Err(e) => Err(CliError::General(format!(
+            "Failed to clear cache at {}: {e}",
+            dir.display()
+        ))),
+    }
+}
+
+/// Summarize the cache contents under `dir` using `ttls` to count expired entries.
+pub fn stats(dir: &Path, ttls: &CacheTtls) -> Result<types::CacheStats, CliError> {
+    let now = unix_now();
+    let mut resources: Vec<types::CacheResourceStats> = CacheResource::ALL
+        .iter()
+        .map(|&resource| types::CacheResourceStats {
+            resource: resource.as_str().to_string(),
+            entries: 0,
+            expired: 0,
+            size_bytes: 0,
+            ttl_seconds: ttls.get(resource).as_secs(),
+        })
+        .collect();
+
+    for (resource, path) in collect_entries(dir)? {
+        let stats = &mut resources[resource as usize];
+        let Ok(raw) = fs::read(&path) else {
+            continue;
+        };
+        stats.entries += 1;
+        stats.size_bytes += raw.len() as u64;
+
+        let fresh = serde_json::from_slice::<CacheEntry>(&raw)
+            .is_ok_and(|entry| now.saturating_sub(entry.stored_at) < stats.ttl_seconds);
+        if !fresh {
+            stats.expired += 1;
+        }
+    }
+
+    Ok(types::CacheStats {
+        directory: dir.display().to_string(),
+        entries: resources.iter().map(|r| r.entries).sum(),
+        expired: resources.iter().map(|r| r.expired).sum(),
+        size_bytes: resources.iter().map(|r| r.size_bytes).sum(),
+        resources,
+    })
+}
+
+/// List `(resource, path)` for every entry file in every profile under `dir`.
+fn collect_entries(dir: &Path) -> Result<Vec<(CacheResource, PathBuf)>, CliError> {
+    let read_dir = |path: &Path| -> Result<Vec<PathBuf>, CliError> {
+        match fs::read_dir(path) {
+            Ok(entries) => Ok(entries.filter_map(|e| e.ok().map(|e| e.path())).collect()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(CliError::General(format!(
+                "Failed to read cache directory {}: {e}",
+                path.display()
+            ))),
+        }
+    };
+
+    let mut entries = Vec::new();
+    for profile in read_dir(dir)?.into_iter().filter(|p| p.is_dir()) {
+        for resource in CacheResource::ALL {
+            for path in read_dir(&profile.join(resource.as_str()))? {
+                if path.extension().is_some_and(|ext| ext == "json") {
+                    entries.push((resource, path));
+                }
+            }
+        }
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::config::TestConfigProvider;
+    use std::collections::HashMap;
+
+    fn config(pairs: &[(&str, &str)]) -> TestConfigProvider {
+        TestConfigProvider {
+            values: pairs
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path().into(), CacheMode::Use, CacheTtls::default());
+
+        cache
+            .write_entry("tok", CacheResource::Teams, b"q", "{}", 1_000)
+            .unwrap();
+
+        assert_eq!(
+            cache.get_at("tok", CacheResource::Teams, b"q", 1_000 + 3_599),
+            Some("{}".to_string())
+        );
+        assert_eq!(
+            cache.get_at("tok", CacheResource::Teams, b"q", 1_000 + 3_600),
+            None
+        );
+    }
+
+    #[test]
+    fn test_entries_are_scoped_by_token_and_request() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path().into(), CacheMode::Use, CacheTtls::default());
+
+        cache.put("tok-a", CacheResource::Users, b"q1", "a");
+
+        assert_eq!(
+            cache.get("tok-a", CacheResource::Users, b"q1"),
+            Some("a".to_string())
+        );
+        assert_eq!(cache.get("tok-b", CacheResource::Users, b"q1"), None);
+        assert_eq!(cache.get("tok-a", CacheResource::Users, b"q2"), None);
+    }
+
+    #[test]
+    fn test_refresh_mode_skips_reads_but_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let refresh =
+            ResponseCache::new(dir.path().into(), CacheMode::Refresh, CacheTtls::default());
+        refresh.put("tok", CacheResource::Labels, b"q", "fresh");
+        assert_eq!(refresh.get("tok", CacheResource::Labels, b"q"), None);
+
+        let normal = ResponseCache::new(dir.path().into(), CacheMode::Use, CacheTtls::default());
+        assert_eq!(
+            normal.get("tok", CacheResource::Labels, b"q"),
+            Some("fresh".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ttls_from_config_with_default_and_override() {
+        let ttls = CacheTtls::from_config(&config(&[
+            ("LINEAR_CLI_CACHE_TTL", "30m"),
+            ("LINEAR_CLI_CACHE_TTL_VIEWER", "0"),
+            ("LINEAR_CLI_CACHE_TTL_TEAMS", "2d"),
+        ]))
+        .unwrap();
+
+        assert_eq!(ttls.get(CacheResource::Labels), Duration::from_secs(1_800));
+        assert_eq!(ttls.get(CacheResource::Viewer), Duration::ZERO);
+        assert_eq!(ttls.get(CacheResource::Teams), Duration::from_secs(172_800));
+    }
+
+    #[test]
+    fn test_invalid_ttl_is_invalid_args() {
+        let err =
+            CacheTtls::from_config(&config(&[("LINEAR_CLI_CACHE_TTL_USERS", "soon")])).unwrap_err();
+        assert!(matches!(err, CliError::InvalidArgs(_)));
+    }
+
+    #[test]
+    fn test_cache_dir_prefers_xdg_cache_home() {
+        assert_eq!(
+            cache_dir(&config(&[("XDG_CACHE_HOME", "/xdg"), ("HOME", "/home/me")])),
+            Some(PathBuf::from("/xdg/linear-cli"))
+        );
+        assert_eq!(
+            cache_dir(&config(&[
+                ("XDG_CACHE_HOME", "relative"),
+                ("HOME", "/home/me")
+            ])),
+            Some(PathBuf::from("/home/me/.cache/linear-cli"))
+        );
+        assert_eq!(cache_dir(&config(&[])), None);
+    }
+
+    #[test]
+    fn test_token_is_not_written_to_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path().into(), CacheMode::Use, CacheTtls::default());
+        cache.put("lin_api_secret", CacheResource::Viewer, b"q", "{}");
+
+        for (_, path) in collect_entries(dir.path()).unwrap() {
+            assert!(!path.to_string_lossy().contains("lin_api_secret"));
+            assert!(!fs::read_to_string(path).unwrap().contains("lin_api_secret"));
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::error::CliError;
+use crate::output::{
+    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
+    generic_json_formatter, generic_jsonl_formatter, generic_table_formatter,
+    generic_template_formatter,
+};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+
+/// Cache usage for a single resource
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheResourceStats {
+    pub resource: String,
+    pub entries: usize,
+    pub expired: usize,
+    pub size_bytes: u64,
+    pub ttl_seconds: u64,
+}
+
+/// Output of `linear cache stats`
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheStats {
+    pub directory: String,
+    pub entries: usize,
+    pub expired: usize,
+    pub size_bytes: u64,
+    pub resources: Vec<CacheResourceStats>,
+}
+
+impl TableFormatter for CacheStats {
+    fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        let mut rows = vec![
+            (
+                Cow::Borrowed("Directory"),
+                Cow::Borrowed(self.directory.as_str()),
+            ),
+            (
+                Cow::Borrowed("Entries"),
+                Cow::Owned(self.entries.to_string()),
+            ),
+            (
+                Cow::Borrowed("Expired"),
+                Cow::Owned(self.expired.to_string()),
+            ),
+            (
+                Cow::Borrowed("Size"),
+                Cow::Owned(format_size(self.size_bytes)),
+            ),
+        ];
+
+        for stats in &self.resources {
+            rows.push((
+                Cow::Borrowed(stats.resource.as_str()),
+                Cow::Owned(format!(
+                    "{} entries ({} expired), TTL {}s",
+                    stats.entries, stats.expired, stats.ttl_seconds
+                )),
+            ));
+        }
+
+        rows
+    }
+}
+
+impl MarkdownFormatter for CacheStats {
+    fn markdown_capacity_hint(&self) -> usize {
+        200 + self.directory.len() + self.resources.len() * 60
+    }
+
+    fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
+        writeln!(output, "# Cache\n")
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        writeln!(output, "**Directory:** {}", self.directory)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        writeln!(
+            output,
+            "**Entries:** {} ({} expired) | **Size:** {}\n",
+            self.entries,
+            self.expired,
+            format_size(self.size_bytes)
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        writeln!(
+            output,
+            "| Resource | Entries | Expired | TTL (s) |\n|---|---|---|---|"
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        for stats in &self.resources {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} |",
+                stats.resource, stats.entries, stats.expired, stats.ttl_seconds
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Formattable for CacheStats {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record([
+            "resource",
+            "entries",
+            "expired",
+            "size_bytes",
+            "ttl_seconds",
+        ])
+        .csv_err("Failed to write CSV header")?;
+
+        for stats in &self.resources {
+            wtr.write_record([
+                stats.resource.clone(),
+                stats.entries.to_string(),
+                stats.expired.to_string(),
+                stats.size_bytes.to_string(),
+                stats.ttl_seconds.to_string(),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        fast_markdown_formatter(self)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        generic_table_formatter(self)
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
+}
+
+/// Output of `linear cache clear`
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheClearResult {
+    pub directory: String,
+    pub removed: usize,
+}
+
+impl TableFormatter for CacheClearResult {
+    fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        vec![
+            (
+                Cow::Borrowed("Directory"),
+                Cow::Borrowed(self.directory.as_str()),
+            ),
+            (
+                Cow::Borrowed("Removed"),
+                Cow::Owned(self.removed.to_string()),
+            ),
+        ]
+    }
+}
+
+impl MarkdownFormatter for CacheClearResult {
+    fn markdown_capacity_hint(&self) -> usize {
+        80 + self.directory.len()
+    }
+
+    fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
+        writeln!(
+            output,
+            "Removed {} cache entries from `{}`",
+            self.removed, self.directory
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))
+    }
+}
+
+impl Formattable for CacheClearResult {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record(["directory", "removed"])
+            .csv_err("Failed to write CSV header")?;
+        wtr.write_record([self.directory.clone(), self.removed.to_string()])
+            .csv_err("Failed to write CSV data")?;
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        fast_markdown_formatter(self)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        generic_table_formatter(self)
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
+}
+
+/// Human-readable byte size (B, KiB, MiB)
+fn format_size(bytes: u64) -> String {
+    const KIB: u64 = 1024;
+    const MIB: u64 = 1024 * KIB;
+
+    if bytes >= MIB {
+        format!("{:.1} MiB", bytes as f64 / MIB as f64)
+    } else if bytes >= KIB {
+        format!("{:.1} KiB", bytes as f64 / KIB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
@@ -0,0 +1,89 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::changelog::types::{Changelog, ChangelogGrouping};
+use crate::client::changelog::ChangelogClient;
+use crate::client::teams::TeamClient;
+use crate::error::CliError;
+use crate::git::GitRepo;
+use crate::io::Io;
+use crate::issues::commands::branch::commit_issue_identifiers;
+use crate::output::{
+    OutputFormat, apply_terminal_layout, detect_format_with_provider, format_output_to_writer,
+};
+use secrecy::ExposeSecret;
+use std::collections::HashMap;
+
+/// Identifiers fetched per request
+pub const BATCH_SIZE: usize = 50;
+
+/// Options for `linear changelog`
+#[derive(Debug, Clone, Default)]
+pub struct ChangelogOptions {
+    /// Revision the previous release was cut from, e.g. `v1.2.0`
+    pub since: String,
+    /// Revision of this release, e.g. `HEAD`
+    pub until: String,
+    pub group_by: ChangelogGrouping,
+    /// Team keys to look for; every workspace team when empty
+    pub teams: Vec<String>,
+}
+
+/// Handle `linear changelog`: release notes for the issues referenced between two revisions
+///
+/// Issues are fetched [`BATCH_SIZE`] at a time and listed in order of their
+/// newest mention; identifiers Linear does not know are reported on stderr.
+/// Renders Markdown unless a format is requested.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_changelog(
+    options: &ChangelogOptions,
+    repo: &GitRepo,
+    changelog_client: &dyn ChangelogClient,
+    team_client: &dyn TeamClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let range = format!("{}..{}", options.since, options.until);
+    let token = get_token_with_provider(config, storage)?;
+    let identifiers = commit_issue_identifiers(
+        &range,
+        &options.teams,
+        repo,
+        team_client,
+        token.expose_secret(),
+    )?;
+
+    let mut found = HashMap::with_capacity(identifiers.len());
+    for batch in identifiers.chunks(BATCH_SIZE) {
+        for issue in changelog_client.changelog_issues(token.expose_secret(), batch)? {
+            found.insert(issue.identifier.clone(), issue);
+        }
+    }
+
+    let mut issues = Vec::with_capacity(found.len());
+    let mut missing = Vec::new();
+    for identifier in identifiers {
+        match found.remove(&identifier) {
+            Some(issue) => issues.push(issue),
+            None => {
+                io.print_error(&format!("Skipping {identifier}: issue not found"));
+                missing.push(identifier);
+            }
+        }
+    }
+
+    let changelog = Changelog::new(range, issues, missing, options.group_by);
+
+    let format = format_flag.unwrap_or_else(|| match detect_format_with_provider(config) {
+        OutputFormat::Auto => OutputFormat::Markdown(Default::default()),
+        format => format,
+    });
+    let format = apply_terminal_layout(format, io, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&changelog, format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use crate::client::queries;
+use crate::error::CliError;
+use crate::output::{
+    CsvResultExt, Formattable, TableColumn, TableLayout, generic_json_formatter,
+    generic_jsonl_list_formatter, generic_table_list_formatter, generic_template_list_formatter,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as FmtWrite;
+
+/// Issue referenced in a release
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangelogIssue {
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub project: Option<String>,
+    pub labels: Vec<String>,
+}
+
+impl From<queries::ChangelogIssueNode> for ChangelogIssue {
+    fn from(node: queries::ChangelogIssueNode) -> Self {
+        Self {
+            identifier: node.identifier,
+            title: node.title,
+            url: node.url,
+            project: node.project.map(|project| project.name),
+            labels: node
+                .labels
+                .nodes
+                .into_iter()
+                .map(|label| label.name)
+                .collect(),
+        }
+    }
+}
+
+/// How `linear changelog` splits issues into sections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ChangelogGrouping {
+    /// Features, bug fixes and chores, by label
+    #[default]
+    Label,
+    /// One section per project
+    Project,
+}
+
+/// Label-based section headings, in the order they are rendered
+const KINDS: [(&str, &[&str]); 3] = [
+    ("Features", &["feature", "enhancement", "improvement"]),
+    ("Bug fixes", &["bug", "fix"]),
+    ("Chores", &["chore", "maintenance", "refactor"]),
+];
+
+const OTHER_SECTION: &str = "Other changes";
+const NO_PROJECT_SECTION: &str = "No project";
+
+/// Section heading for an issue's labels: the first kind any label mentions
+///
+/// Labels are compared word by word, so "Bug" and "type: bugs" count as bug
+/// fixes but "Debug tooling" and "Prefix" do not.
+fn kind_section(labels: &[String]) -> &'static str {
+    KINDS
+        .iter()
+        .find(|(_, words)| {
+            labels.iter().any(|label| {
+                label
+                    .to_lowercase()
+                    .split(|c: char| !c.is_alphanumeric())
+                    .any(|token| words.iter().any(|word| is_word_form(token, word)))
+            })
+        })
+        .map_or(OTHER_SECTION, |(title, _)| title)
+}
+
+/// `token` is `word` or its plural ("bugs", "fixes")
+fn is_word_form(token: &str, word: &str) -> bool {
+    token
+        .strip_prefix(word)
+        .is_some_and(|rest| matches!(rest, "" | "s" | "es"))
+}
+
+/// Issues under one heading of the release notes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogSection {
+    pub title: String,
+    pub issues: Vec<ChangelogIssue>,
+}
+
+/// Release notes for a revision range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Changelog {
+    pub range: String,
+    pub sections: Vec<ChangelogSection>,
+    /// Identifiers mentioned in commits that Linear did not return
+    pub missing: Vec<String>,
+}
+
+impl Changelog {
+    /// Group `issues` into sections, keeping their order within each section
+    ///
+    /// Label sections follow a fixed order; project sections follow the first
+    /// issue in each, with issues outside any project last. Empty sections are
+    /// left out.
+    #[must_use]
+    pub fn new(
+        range: impl Into<String>,
+        issues: Vec<ChangelogIssue>,
+        missing: Vec<String>,
+        grouping: ChangelogGrouping,
+    ) -> Self {
+        let mut sections: Vec<ChangelogSection> = match grouping {
+            ChangelogGrouping::Label => KINDS
+                .iter()
+                .map(|(title, _)| *title)
+                .chain([OTHER_SECTION])
+                .map(|title| ChangelogSection {
+                    title: title.to_string(),
+                    issues: Vec::new(),
+                })
+                .collect(),
+            ChangelogGrouping::Project => Vec::new(),
+        };
+
+        let mut unassigned = Vec::new();
+        for issue in issues {
+            let title = match grouping {
+                ChangelogGrouping::Label => kind_section(&issue.labels),
+                ChangelogGrouping::Project => match &issue.project {
+                    Some(project) => project.as_str(),
+                    None => {
+                        unassigned.push(issue);
+                        continue;
+                    }
+                },
+            };
+            match sections.iter_mut().find(|section| section.title == title) {
+                Some(section) => section.issues.push(issue),
+                None => sections.push(ChangelogSection {
+                    title: title.to_string(),
+                    issues: vec![issue],
+                }),
+            }
+        }
+        sections.push(ChangelogSection {
+            title: NO_PROJECT_SECTION.to_string(),
+            issues: unassigned,
+        });
+        sections.retain(|section| !section.issues.is_empty());
+
+        Self {
+            range: range.into(),
+            sections,
+            missing,
+        }
+    }
+
+    /// One row per issue, tagged with its section
+    fn rows(&self) -> Vec<ChangelogRow<'_>> {
+        self.sections
+            .iter()
+            .flat_map(|section| {
+                section.issues.iter().map(|issue| ChangelogRow {
+                    section: &section.title,
+                    issue,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Flattened issue for the per-issue formats (CSV, table, JSON Lines, templates)
+#[derive(Serialize)]
+struct ChangelogRow<'a> {
+    section: &'a str,
+    #[serde(flatten)]
+    issue: &'a ChangelogIssue,
+}
+
+impl Formattable for Changelog {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.rows())
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record(["section", "identifier", "title", "project", "labels", "url"])
+            .csv_err("Failed to write CSV header")?;
+
+        for row in self.rows() {
+            wtr.write_record([
+                row.section,
+                row.issue.identifier.as_str(),
+                row.issue.title.as_str(),
+                row.issue.project.as_deref().unwrap_or(""),
+                row.issue.labels.join(", ").as_str(),
+                row.issue.url.as_str(),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        let capacity = 40
+            + self.range.len()
+            + self
+                .rows()
+                .iter()
+                .map(|row| 10 + row.issue.title.len() + row.issue.url.len())
+                .sum::<usize>();
+        let mut output = String::with_capacity(capacity);
+
+        writeln!(output, "# Release notes ({})", self.range)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        if self.sections.is_empty() {
+            writeln!(output, "\nNo issues referenced.")
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            return Ok(output);
+        }
+
+        for section in &self.sections {
+            writeln!(output, "\n## {}\n", section.title)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            for issue in &section.issues {
+                writeln!(
+                    output,
+                    "- {} ([{}]({}))",
+                    issue.title.trim(),
+                    issue.identifier,
+                    issue.url
+                )
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.rows(),
+            &[
+                TableColumn::new("Section"),
+                TableColumn::new("ID"),
+                TableColumn::new("Title").flexible(),
+                TableColumn::new("Project").wide_only(),
+                TableColumn::new("URL").wide_only(),
+            ],
+            layout,
+            |row| {
+                vec![
+                    row.section.to_string(),
+                    row.issue.identifier.clone(),
+                    row.issue.title.clone(),
+                    row.issue.project.clone().unwrap_or_default(),
+                    row.issue.url.clone(),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.rows(), template)
+    }
+}
@@ -5,20 +5,59 @@ use clap::{Args, Parser, Subcommand};
 #[command(about = "Linear CLI tool", long_about = None)]
 #[command(version)]
 pub struct Cli {
+    /// Bypass the local response cache for this invocation
+    #[arg(long, global = true)]
+    pub no_cache: bool,
+
+    /// Ignore cached responses and refresh the cache from the API
+    #[arg(long, global = true, conflicts_with = "no_cache")]
+    pub refresh: bool,
+
+    /// Serve reads from the local mirror populated by `linear sync`
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Show table and Markdown timestamps in UTC instead of the local timezone (`LINEAR_CLI_UTC`)
+    #[arg(long, global = true)]
+    pub utc: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl Cli {
+    /// Cache mode selected by `--no-cache` / `--refresh`
+    #[must_use]
+    pub fn cache_mode(&self) -> crate::cache::CacheMode {
+        use crate::cache::CacheMode;
+
+        if self.no_cache {
+            CacheMode::Bypass
+        } else if self.refresh {
+            CacheMode::Refresh
+        } else {
+            CacheMode::Use
+        }
+    }
+}
+
 /// Output format flags (mutually exclusive).
 ///
 /// JSON style can be configured via `LINEAR_CLI_JSON_STYLE=compact|pretty`.
+///
+/// The group members are listed explicitly because clap leaves groups empty
+/// when the struct flattens other args (the table layout flags below).
 #[derive(Args, Debug, Clone)]
-#[group(multiple = false)]
+#[group(multiple = false, args = FORMAT_ARGS)]
 pub struct FormatFlags {
     /// Output as JSON
     #[arg(long)]
     pub json: bool,
 
+    /// Output as JSON Lines (one compact object per line, streamed)
+    #[arg(long)]
+    pub jsonl: bool,
+
     /// Output as CSV
     #[arg(long)]
     pub csv: bool,
@@ -30,6 +69,73 @@ pub struct FormatFlags {
     /// Output as table
     #[arg(long)]
     pub table: bool,
+
+    /// Output as YAML
+    #[arg(long)]
+    pub yaml: bool,
+
+    /// Output as TOML
+    #[arg(long)]
+    pub toml: bool,
+
+    /// Render each item through a Handlebars-style template (e.g. '{{identifier}}\t{{title}}')
+    #[arg(long, value_name = "TEMPLATE")]
+    pub template: Option<String>,
+
+    /// Read the output template from a file
+    #[arg(long, value_name = "PATH", value_parser = read_template_file)]
+    pub template_file: Option<String>,
+
+    #[command(flatten)]
+    pub layout: TableLayoutFlags,
+}
+
+/// List table layout flags; these imply table output.
+#[derive(Args, Debug, Clone, Default)]
+#[group(skip)]
+pub struct TableLayoutFlags {
+    /// Show all columns in list tables without truncating to the terminal width
+    #[arg(long, conflicts_with_all = NON_TABLE_FORMATS)]
+    pub wide: bool,
+
+    /// Omit the header row from list tables (for awk/cut)
+    #[arg(long, conflicts_with_all = NON_TABLE_FORMATS)]
+    pub no_headers: bool,
+}
+
+const FORMAT_ARGS: [&str; 9] = [
+    "json",
+    "jsonl",
+    "csv",
+    "markdown",
+    "table",
+    "yaml",
+    "toml",
+    "template",
+    "template_file",
+];
+
+const NON_TABLE_FORMATS: [&str; 8] = [
+    "json",
+    "jsonl",
+    "csv",
+    "markdown",
+    "yaml",
+    "toml",
+    "template",
+    "template_file",
+];
+
+/// Load a template file for `--template-file`, dropping one trailing newline
+/// so each rendered item still occupies a single line.
+fn read_template_file(path: &str) -> Result<String, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read template file '{path}': {e}"))?;
+    let trimmed = contents
+        .strip_suffix("\r\n")
+        .or_else(|| contents.strip_suffix('\n'))
+        .unwrap_or(&contents);
+    Ok(trimmed.to_string())
 }
 
 impl FormatFlags {
@@ -41,18 +147,36 @@ impl FormatFlags {
 
         if self.json {
             Some(OutputFormat::Json)
+        } else if self.jsonl {
+            Some(OutputFormat::Jsonl)
         } else if self.csv {
             Some(OutputFormat::Csv)
         } else if self.markdown {
-            Some(OutputFormat::Markdown)
-        } else if self.table {
-            Some(OutputFormat::Table)
+            Some(OutputFormat::Markdown(Default::default()))
+        } else if self.table || self.layout.wide || self.layout.no_headers {
+            Some(OutputFormat::Table(crate::output::TableLayout {
+                wide: self.layout.wide,
+                no_headers: self.layout.no_headers,
+                ..Default::default()
+            }))
+        } else if self.yaml {
+            Some(OutputFormat::Yaml)
+        } else if self.toml {
+            Some(OutputFormat::Toml)
+        } else if let Some(template) = &self.template {
+            Some(OutputFormat::Template(
+                crate::output::template::unescape_template(template),
+            ))
         } else {
-            None
+            self.template_file
+                .as_ref()
+                .map(|template| OutputFormat::Template(template.clone()))
         }
     }
 }
 
+// Parsed once per run, so the size of the largest variant doesn't matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Authentication commands
@@ -80,6 +204,21 @@ pub enum Commands {
         #[command(subcommand)]
         action: CycleCommands,
     },
+    /// Document commands
+    Doc {
+        #[command(subcommand)]
+        action: DocCommands,
+    },
+    /// Initiative commands
+    Initiative {
+        #[command(subcommand)]
+        action: InitiativeCommands,
+    },
+    /// Notification inbox commands
+    Notification {
+        #[command(subcommand)]
+        action: NotificationCommands,
+    },
     /// Semantic search across issues, projects, documents, initiatives
     Search {
         /// Search query
@@ -111,6 +250,106 @@ pub enum Commands {
         #[command(subcommand)]
         action: UserCommands,
     },
+    /// Local response cache commands
+    Cache {
+        #[command(subcommand)]
+        action: CacheCommands,
+    },
+    /// Mirror workspace data into a local SQLite database for offline use
+    Sync {
+        /// Ignore watermarks and refetch every record
+        #[arg(long)]
+        full: bool,
+
+        /// Only sync these resources (comma-separated)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        only: Vec<crate::mirror::SyncResource>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Release notes for the issues referenced in git commits between two revisions
+    Changelog {
+        /// Revision of the previous release (e.g., v1.2.0)
+        #[arg(long)]
+        since: String,
+
+        /// Revision of this release
+        #[arg(long, default_value = "HEAD")]
+        until: String,
+
+        /// Group issues by label (features, bug fixes, chores) or by project
+        #[arg(long, value_enum, default_value_t)]
+        group_by: crate::changelog::ChangelogGrouping,
+
+        /// Only match identifiers of these team keys (repeatable; default: all teams)
+        #[arg(long = "team", value_name = "KEY")]
+        teams: Vec<String>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Send a raw GraphQL query or mutation to the Linear API
+    #[command(subcommand_negates_reqs = true, args_conflicts_with_subcommands = true)]
+    Api {
+        #[command(subcommand)]
+        action: Option<ApiCommands>,
+
+        /// GraphQL document to send
+        #[arg(
+            long,
+            required_unless_present = "query_file",
+            conflicts_with = "query_file"
+        )]
+        query: Option<String>,
+
+        /// Read the GraphQL document from a file (`-` for stdin)
+        #[arg(long, value_name = "PATH")]
+        query_file: Option<String>,
+
+        /// Typed variable; true/false/null and numbers become JSON, @path reads a file
+        #[arg(short = 'F', long = "field", value_name = "KEY=VALUE")]
+        fields: Vec<String>,
+
+        /// String variable, sent as-is
+        #[arg(short = 'f', long = "raw-field", value_name = "KEY=VALUE")]
+        raw_fields: Vec<String>,
+
+        /// Operation to run when the document defines several
+        #[arg(long)]
+        operation_name: Option<String>,
+
+        /// Follow pageInfo.endCursor (via $endCursor) and merge every page
+        #[arg(long)]
+        paginate: bool,
+
+        /// Check the document against the bundled schema before sending
+        #[arg(long)]
+        validate: bool,
+    },
+    /// Run a read-only SQL query against the offline mirror
+    Sql {
+        /// SQL statement (e.g. "select team, count(*) from issues group by 1")
+        #[arg(required_unless_present = "schema")]
+        query: Option<String>,
+
+        /// List the mirror's tables and columns instead of running a query
+        #[arg(long, conflicts_with = "query")]
+        schema: bool,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ApiCommands {
+    /// Check GraphQL documents against the bundled schema without network access
+    Validate {
+        /// Documents to check (`-` for stdin)
+        #[arg(required = true, value_name = "PATH")]
+        files: Vec<String>,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -151,6 +390,14 @@ pub struct IssueUpdatePatchArgs {
     #[arg(long)]
     pub description: Option<String>,
 
+    /// Read the new description from a file (`-` for stdin)
+    #[arg(long, value_name = "PATH", conflicts_with = "description")]
+    pub description_file: Option<String>,
+
+    /// Edit the current description in $VISUAL/$EDITOR
+    #[arg(long, conflicts_with_all = ["description", "description_file"])]
+    pub editor: bool,
+
     /// Assignee reference (@me, email, ID, or null to clear)
     #[arg(long)]
     pub assignee: Option<String>,
@@ -159,6 +406,10 @@ pub struct IssueUpdatePatchArgs {
     #[arg(long)]
     pub project: Option<String>,
 
+    /// Project milestone ID, or null to clear
+    #[arg(long)]
+    pub milestone: Option<String>,
+
     /// Workflow state name or ID
     #[arg(long)]
     pub state: Option<String>,
@@ -166,6 +417,13 @@ pub struct IssueUpdatePatchArgs {
     /// Priority: 0=None, 1=Urgent, 2=High, 3=Medium, 4=Low
     #[arg(long, value_parser = clap::value_parser!(u8).range(0..=4))]
     pub priority: Option<u8>,
+
+    /// Move the issue to another team (key or ID)
+    #[arg(long)]
+    pub team: Option<String>,
+
+    #[command(flatten)]
+    pub fields: IssueFieldArgs,
 }
 
 impl IssueUpdatePatchArgs {
@@ -173,10 +431,50 @@ impl IssueUpdatePatchArgs {
     pub fn has_any_field(&self) -> bool {
         self.title.is_some()
             || self.description.is_some()
+            || self.description_file.is_some()
+            || self.editor
             || self.assignee.is_some()
             || self.project.is_some()
+            || self.milestone.is_some()
             || self.state.is_some()
             || self.priority.is_some()
+            || self.team.is_some()
+            || self.fields.has_any_field()
+    }
+}
+
+/// Planning flags shared by `issue create` and `issue update`
+#[derive(Args, Debug, Clone, Default)]
+pub struct IssueFieldArgs {
+    /// Estimate in points, or null to clear
+    #[arg(long, value_name = "POINTS")]
+    pub estimate: Option<String>,
+
+    /// Due date: YYYY-MM-DD, today, tomorrow, a weekday, +3d, +2w, or null to clear
+    #[arg(long, value_name = "DATE")]
+    pub due_date: Option<String>,
+
+    /// Cycle: current, next, a cycle number, an ID, or null to clear
+    #[arg(long)]
+    pub cycle: Option<String>,
+
+    /// Subscriber user ID (repeatable; replaces existing subscribers, null to clear)
+    #[arg(long = "subscriber", value_name = "USER_ID")]
+    pub subscribers: Vec<String>,
+
+    /// Position of the issue in manually ordered views
+    #[arg(long, allow_negative_numbers = true)]
+    pub sort_order: Option<f64>,
+}
+
+impl IssueFieldArgs {
+    #[must_use]
+    pub fn has_any_field(&self) -> bool {
+        self.estimate.is_some()
+            || self.due_date.is_some()
+            || self.cycle.is_some()
+            || !self.subscribers.is_empty()
+            || self.sort_order.is_some()
     }
 }
 
@@ -253,9 +551,79 @@ pub enum IssueCommentCommands {
         identifier: String,
 
         /// Comment body text
+        #[arg(
+            long,
+            required_unless_present_any = ["attach", "body_file", "editor"]
+        )]
+        body: Option<String>,
+
+        /// Read the comment body from a file (`-` for stdin)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<String>,
+
+        /// Write the comment body in $VISUAL/$EDITOR
+        #[arg(long, conflicts_with_all = ["body", "body_file"])]
+        editor: bool,
+
+        /// Upload a file and embed it in the comment (repeatable)
+        #[arg(long, value_name = "PATH")]
+        attach: Vec<String>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Edit the body of a comment
+    Edit {
+        /// Comment ID (UUID)
+        id: String,
+
+        /// New comment body text
         #[arg(long)]
         body: String,
 
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Reply to a comment in its thread
+    Reply {
+        /// Parent comment ID (UUID)
+        id: String,
+
+        /// Reply body text
+        #[arg(long)]
+        body: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Mark a comment thread as resolved
+    Resolve {
+        /// Comment ID (UUID) of the thread's first comment
+        id: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Reopen a resolved comment thread
+    Unresolve {
+        /// Comment ID (UUID) of the thread's first comment
+        id: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Add or remove an emoji reaction on a comment
+    React {
+        /// Comment ID (UUID)
+        id: String,
+
+        /// Emoji as :name:, name, or the emoji itself
+        emoji: String,
+
+        /// Remove your reaction instead of adding one
+        #[arg(long)]
+        remove: bool,
+
         #[command(flatten)]
         format: FormatFlags,
     },
@@ -279,6 +647,55 @@ pub enum IssueCommands {
         #[command(flatten)]
         format: FormatFlags,
     },
+    /// View the issue named by the current git branch (e.g. alice/eng-123-fix-login)
+    Current {
+        /// Include comments in the output
+        #[arg(long)]
+        with_comments: bool,
+
+        /// Maximum number of comments to return (only used with --with-comments)
+        #[arg(long, default_value = "50")]
+        comment_limit: usize,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Print the git branch name Linear suggests for an issue
+    Branch {
+        /// Issue identifier (e.g., ENG-123)
+        identifier: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Create or switch to the issue's git branch in the current repository
+    Checkout {
+        /// Issue identifier (e.g., ENG-123)
+        identifier: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Show the issue linked to a git branch name
+    FromBranch {
+        /// Branch name (e.g., alice/eng-123-fix-login)
+        branch: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// List issues referenced in the messages of a range of git commits
+    FromCommits {
+        /// Revision range passed to `git log` (e.g., v1.2.0..HEAD)
+        range: String,
+
+        /// Only match identifiers of these team keys (repeatable; default: all teams)
+        #[arg(long = "team", value_name = "KEY")]
+        teams: Vec<String>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
     /// List issues with optional filters
     List {
         /// Filter by assignee user ID (use @me for current user)
@@ -308,6 +725,14 @@ pub enum IssueCommands {
         #[arg(long)]
         description: Option<String>,
 
+        /// Read the description from a file (`-` for stdin)
+        #[arg(long, value_name = "PATH", conflicts_with = "description")]
+        description_file: Option<String>,
+
+        /// Write the description in $VISUAL/$EDITOR
+        #[arg(long, conflicts_with_all = ["description", "description_file"])]
+        editor: bool,
+
         /// Assignee reference (@me, email, or ID)
         #[arg(long)]
         assignee: Option<String>,
@@ -316,6 +741,10 @@ pub enum IssueCommands {
         #[arg(long)]
         project: Option<String>,
 
+        /// Project milestone ID
+        #[arg(long)]
+        milestone: Option<String>,
+
         /// Workflow state name or ID
         #[arg(long)]
         state: Option<String>,
@@ -324,6 +753,9 @@ pub enum IssueCommands {
         #[arg(long, value_parser = clap::value_parser!(u8).range(0..=4))]
         priority: Option<u8>,
 
+        #[command(flatten)]
+        fields: IssueFieldArgs,
+
         #[command(flatten)]
         format: FormatFlags,
     },
@@ -393,6 +825,84 @@ pub enum IssueCommands {
         #[arg(long, default_value = "50")]
         limit: usize,
 
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Attach a link or GitHub pull request to an issue
+    Attach {
+        /// Issue identifier (e.g., ENG-123)
+        identifier: String,
+
+        /// URL to link
+        #[arg(
+            long,
+            required_unless_present_any = ["github_pr", "file"],
+            conflicts_with_all = ["github_pr", "file"]
+        )]
+        url: Option<String>,
+
+        /// GitHub pull request URL to link through the GitHub integration
+        #[arg(long, value_name = "URL", conflicts_with = "file")]
+        github_pr: Option<String>,
+
+        /// Local file to upload and attach
+        #[arg(long, value_name = "PATH")]
+        file: Option<String>,
+
+        /// Attachment title (defaults to one derived from the URL, or the file name)
+        #[arg(long)]
+        title: Option<String>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// List attachments for an issue
+    Attachments {
+        /// Issue identifier (e.g., ENG-123)
+        issue_id: String,
+        /// Maximum number of attachments to return
+        #[arg(long, default_value = "50")]
+        limit: usize,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Remove an attachment from its issue
+    Detach {
+        /// Attachment ID (UUID)
+        id: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Subscribe to an issue's notifications
+    Subscribe {
+        /// Issue identifier (e.g., ENG-123)
+        identifier: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Unsubscribe from an issue's notifications
+    Unsubscribe {
+        /// Issue identifier (e.g., ENG-123)
+        identifier: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Add or remove an emoji reaction on an issue
+    React {
+        /// Issue identifier (e.g., ENG-123)
+        identifier: String,
+
+        /// Emoji as :name:, name, or the emoji itself
+        emoji: String,
+
+        /// Remove your reaction instead of adding one
+        #[arg(long)]
+        remove: bool,
+
         #[command(flatten)]
         format: FormatFlags,
     },
@@ -421,11 +931,23 @@ pub enum TeamCommands {
 
 #[derive(Subcommand, Debug)]
 pub enum ProjectCommands {
-    /// View a project by ID
+    /// View a project by slug, name, or ID
     View {
-        /// Project ID
+        /// Project slug, name, or ID
         id: String,
 
+        /// Include open issues grouped by assignee
+        #[arg(long)]
+        issues: bool,
+
+        /// Include project members
+        #[arg(long)]
+        members: bool,
+
+        /// Include an issue breakdown by state with scope and completed counts
+        #[arg(long)]
+        progress: bool,
+
         #[command(flatten)]
         format: FormatFlags,
     },
@@ -435,6 +957,72 @@ pub enum ProjectCommands {
         #[arg(long, default_value = "50")]
         limit: usize,
 
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Project milestone commands
+    Milestone {
+        #[command(subcommand)]
+        action: ProjectMilestoneCommands,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ProjectMilestoneCommands {
+    /// List a project's milestones
+    List {
+        /// Project slug, name, or ID
+        project: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Create a milestone in a project
+    Create {
+        /// Project slug, name, or ID
+        #[arg(long)]
+        project: String,
+
+        /// Milestone name
+        #[arg(long)]
+        name: String,
+
+        /// Target date (YYYY-MM-DD)
+        #[arg(long)]
+        target_date: Option<String>,
+
+        /// Milestone description
+        #[arg(long)]
+        description: Option<String>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Update a milestone
+    Update {
+        /// Milestone ID
+        id: String,
+
+        /// New milestone name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// New target date (YYYY-MM-DD)
+        #[arg(long)]
+        target_date: Option<String>,
+
+        /// New milestone description
+        #[arg(long)]
+        description: Option<String>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Delete a milestone
+    Delete {
+        /// Milestone ID
+        id: String,
+
         #[command(flatten)]
         format: FormatFlags,
     },
@@ -466,6 +1054,236 @@ pub enum CycleCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+pub enum DocCommands {
+    /// List documents
+    List {
+        /// Only documents in this project (slug, name, or ID)
+        #[arg(long)]
+        project: Option<String>,
+
+        /// Maximum number of documents to return
+        #[arg(long, default_value = "50")]
+        limit: usize,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// View a document and its content
+    View {
+        /// Document ID, slug, or URL
+        id: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Create a document
+    Create {
+        /// Document title
+        #[arg(long)]
+        title: String,
+
+        /// Project slug or ID to add the document to
+        #[arg(long)]
+        project: Option<String>,
+
+        /// Markdown content
+        #[arg(long)]
+        content: Option<String>,
+
+        /// Read the content from a file (`-` for stdin)
+        #[arg(long, value_name = "PATH", conflicts_with = "content")]
+        file: Option<String>,
+
+        /// Write the content in $VISUAL/$EDITOR
+        #[arg(long, conflicts_with_all = ["content", "file"])]
+        editor: bool,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Update a document's title or content
+    Update {
+        /// Document ID or slug
+        id: String,
+
+        /// New title
+        #[arg(long)]
+        title: Option<String>,
+
+        /// New markdown content
+        #[arg(long)]
+        content: Option<String>,
+
+        /// Read the new content from a file (`-` for stdin)
+        #[arg(long, value_name = "PATH", conflicts_with = "content")]
+        file: Option<String>,
+
+        /// Edit the current content in $VISUAL/$EDITOR
+        #[arg(long, conflicts_with_all = ["content", "file"])]
+        editor: bool,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Delete (trash) a document
+    Delete {
+        /// Document ID or slug
+        id: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum InitiativeCommands {
+    /// List initiatives with their project roll-up
+    List {
+        /// Only initiatives with this status
+        #[arg(long, value_enum)]
+        status: Option<crate::initiatives::types::InitiativeStatus>,
+
+        /// Maximum number of initiatives to return
+        #[arg(long, default_value = "50")]
+        limit: usize,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// View an initiative and its projects' status and progress
+    View {
+        /// Initiative ID, slug, name, or URL
+        id: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Create an initiative
+    Create {
+        /// Initiative name
+        #[arg(long)]
+        name: String,
+
+        /// Short description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Initial status
+        #[arg(long, value_enum)]
+        status: Option<crate::initiatives::types::InitiativeStatus>,
+
+        /// Target date (YYYY-MM-DD)
+        #[arg(long)]
+        target_date: Option<String>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Update an initiative
+    Update {
+        /// Initiative ID, slug, name, or URL
+        id: String,
+
+        /// New name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// New description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// New status
+        #[arg(long, value_enum)]
+        status: Option<crate::initiatives::types::InitiativeStatus>,
+
+        /// New target date (YYYY-MM-DD)
+        #[arg(long)]
+        target_date: Option<String>,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Add a project to an initiative
+    AddProject {
+        /// Initiative ID, slug, name, or URL
+        initiative: String,
+
+        /// Project slug, name, or ID
+        project: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// List status updates posted on an initiative
+    Updates {
+        /// Initiative ID, slug, name, or URL
+        id: String,
+
+        /// Maximum number of updates to return
+        #[arg(long, default_value = "20")]
+        limit: usize,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum NotificationCommands {
+    /// List notifications in your inbox
+    List {
+        /// Only show unread notifications
+        #[arg(long)]
+        unread: bool,
+
+        /// Maximum number of notifications to return
+        #[arg(long, default_value = "50")]
+        limit: usize,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Mark a notification, or every unread notification, as read
+    Read {
+        /// Notification ID (UUID)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        id: Option<String>,
+
+        /// Mark every unread notification as read
+        #[arg(long)]
+        all: bool,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Archive a notification, or every notification in the inbox
+    Archive {
+        /// Notification ID (UUID)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        id: Option<String>,
+
+        /// Archive every notification in the inbox
+        #[arg(long)]
+        all: bool,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Snooze a notification until later
+    Snooze {
+        /// Notification ID (UUID)
+        id: String,
+
+        /// Duration (30m, 4h, 2d, 1w), date (YYYY-MM-DD) or RFC 3339 timestamp
+        #[arg(long)]
+        until: String,
+
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum StateCommands {
     /// List workflow states
@@ -512,3 +1330,17 @@ pub enum UserCommands {
         format: FormatFlags,
     },
 }
+
+#[derive(Subcommand, Debug)]
+pub enum CacheCommands {
+    /// Remove all cached responses
+    Clear {
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+    /// Show cache location, entry counts and TTLs
+    Stats {
+        #[command(flatten)]
+        format: FormatFlags,
+    },
+}
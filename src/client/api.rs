@@ -0,0 +1,54 @@
+use crate::client::graphql::GraphQLRequest;
+use crate::client::{GraphQlErrorType, LinearClient, check_graphql_errors, parse_graphql_response};
+use crate::error::CliError;
+use serde_json::Value;
+use std::sync::Mutex;
+
+/// Trait for sending raw GraphQL documents (`linear api`)
+pub trait ApiClient {
+    /// Send `request` and return the response's `data` object.
+    ///
+    /// GraphQL errors are mapped to `CliError` the same way as typed queries.
+    fn execute_raw(&self, token: &str, request: &GraphQLRequest) -> Result<Value, CliError>;
+}
+
+impl ApiClient for LinearClient {
+    fn execute_raw(&self, token: &str, request: &GraphQLRequest) -> Result<Value, CliError> {
+        let body = serde_json::to_vec(request)
+            .map_err(|e| CliError::General(format!("Failed to serialize GraphQL request: {e}")))?;
+
+        let (status, body) = self.post_graphql(token, body)?;
+        let response: cynic::GraphQlResponse<Value> = parse_graphql_response(status, &body)?;
+
+        if let Some(errors) = &response.errors {
+            check_graphql_errors(errors, GraphQlErrorType::General)?;
+        }
+
+        response
+            .data
+            .ok_or_else(|| CliError::General("Linear API returned no data".to_string()))
+    }
+}
+
+/// Mock implementation for testing
+///
+/// Request `n` is answered with `responses[n]` (the last entry once exhausted).
+/// Every request is recorded.
+#[derive(Default)]
+pub struct MockApiClient {
+    pub responses: Vec<Result<Value, CliError>>,
+    pub requests: Mutex<Vec<GraphQLRequest>>,
+}
+
+impl ApiClient for MockApiClient {
+    fn execute_raw(&self, _token: &str, request: &GraphQLRequest) -> Result<Value, CliError> {
+        let mut requests = self.requests.lock().expect("mock request log poisoned");
+        requests.push(request.clone());
+
+        self.responses
+            .get(requests.len() - 1)
+            .or_else(|| self.responses.last())
+            .cloned()
+            .unwrap_or(Ok(Value::Null))
+    }
+}
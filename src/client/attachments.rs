@@ -0,0 +1,239 @@
+use crate::attachments::types::Attachment;
+use crate::client::LinearClient;
+use crate::client::queries::{
+    AttachmentCreateInput, AttachmentCreateMutation, AttachmentCreateMutationVariables,
+    AttachmentDeleteMutation, AttachmentDeleteMutationVariables, AttachmentLinkGitHubPrMutation,
+    AttachmentLinkMutationVariables, AttachmentLinkUrlMutation, IssueAttachmentsQuery,
+    IssueAttachmentsQueryVariables,
+};
+use crate::error::CliError;
+use cynic::{MutationBuilder, QueryBuilder};
+use std::sync::Mutex;
+
+/// What kind of link to attach to an issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentLinkKind {
+    /// Any URL; Linear detects known integrations from it
+    Url,
+    /// A GitHub pull request, linked through the GitHub integration
+    GitHubPr,
+    /// A file uploaded to Linear's asset storage
+    Upload,
+}
+
+/// Link attachment request payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkAttachmentInput {
+    pub issue_id: String,
+    pub url: String,
+    pub title: Option<String>,
+    pub kind: AttachmentLinkKind,
+}
+
+/// Trait for issue attachment operations with Linear API
+pub trait AttachmentClient: Send + Sync {
+    /// List attachments for an issue
+    fn list_attachments(
+        &self,
+        token: &str,
+        issue_id: &str,
+        limit: usize,
+    ) -> Result<Vec<Attachment>, CliError>;
+
+    /// Link a URL, pull request or uploaded file to an issue
+    fn link_attachment(
+        &self,
+        token: &str,
+        input: LinkAttachmentInput,
+    ) -> Result<Attachment, CliError>;
+
+    /// Delete an attachment
+    fn delete_attachment(&self, token: &str, id: &str) -> Result<(), CliError>;
+}
+
+/// Production implementation using Linear GraphQL API
+impl AttachmentClient for LinearClient {
+    fn list_attachments(
+        &self,
+        token: &str,
+        issue_id: &str,
+        limit: usize,
+    ) -> Result<Vec<Attachment>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = IssueAttachmentsQuery::build(IssueAttachmentsQueryVariables {
+            id: issue_id.to_string(),
+            first: Some(limit as i32),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let issue = response
+            .data
+            .and_then(|d| d.issue)
+            .ok_or_else(|| CliError::NotFound(format!("Issue {issue_id} not found")))?;
+
+        Ok(issue
+            .attachments
+            .nodes
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    fn link_attachment(
+        &self,
+        token: &str,
+        input: LinkAttachmentInput,
+    ) -> Result<Attachment, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let LinkAttachmentInput {
+            issue_id,
+            url,
+            title,
+            kind,
+        } = input;
+
+        let attachment = match kind {
+            AttachmentLinkKind::Url => {
+                let operation = AttachmentLinkUrlMutation::build(AttachmentLinkMutationVariables {
+                    issue_id,
+                    url,
+                    title,
+                });
+                let response =
+                    self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+                response
+                    .data
+                    .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                    .attachment_link_url
+                    .attachment
+            }
+            AttachmentLinkKind::GitHubPr => {
+                let operation =
+                    AttachmentLinkGitHubPrMutation::build(AttachmentLinkMutationVariables {
+                        issue_id,
+                        url,
+                        title,
+                    });
+                let response =
+                    self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+                response
+                    .data
+                    .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                    .attachment_link_github_pr
+                    .attachment
+            }
+            AttachmentLinkKind::Upload => {
+                // Uploaded assets have no integration to resolve, so they are created directly
+                let operation =
+                    AttachmentCreateMutation::build(AttachmentCreateMutationVariables {
+                        input: AttachmentCreateInput {
+                            issue_id,
+                            title: title.unwrap_or_else(|| url.clone()),
+                            url,
+                            subtitle: None,
+                        },
+                    });
+                let response =
+                    self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+                response
+                    .data
+                    .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                    .attachment_create
+                    .attachment
+            }
+        };
+
+        Ok(attachment.into())
+    }
+
+    fn delete_attachment(&self, token: &str, id: &str) -> Result<(), CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = AttachmentDeleteMutation::build(AttachmentDeleteMutationVariables {
+            id: id.to_string(),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .attachment_delete;
+
+        if !payload.success {
+            return Err(CliError::General("Attachment delete failed".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Mock implementation for testing
+///
+/// Link and delete requests are recorded.
+pub struct MockAttachmentClient {
+    pub list_result: Result<Vec<Attachment>, CliError>,
+    pub link_result: Result<Attachment, CliError>,
+    pub delete_result: Result<(), CliError>,
+    pub links: Mutex<Vec<LinkAttachmentInput>>,
+    pub deletes: Mutex<Vec<String>>,
+}
+
+impl MockAttachmentClient {
+    #[must_use]
+    pub fn new(attachments: Vec<Attachment>) -> Self {
+        let link_result = attachments
+            .first()
+            .cloned()
+            .ok_or_else(|| CliError::General("no attachment".to_string()));
+        Self {
+            list_result: Ok(attachments),
+            link_result,
+            delete_result: Ok(()),
+            links: Mutex::new(Vec::new()),
+            deletes: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl AttachmentClient for MockAttachmentClient {
+    fn list_attachments(
+        &self,
+        _token: &str,
+        _issue_id: &str,
+        _limit: usize,
+    ) -> Result<Vec<Attachment>, CliError> {
+        self.list_result.clone()
+    }
+
+    fn link_attachment(
+        &self,
+        _token: &str,
+        input: LinkAttachmentInput,
+    ) -> Result<Attachment, CliError> {
+        self.links
+            .lock()
+            .expect("mock link log poisoned")
+            .push(input);
+        self.link_result.clone()
+    }
+
+    fn delete_attachment(&self, _token: &str, id: &str) -> Result<(), CliError> {
+        self.deletes
+            .lock()
+            .expect("mock delete log poisoned")
+            .push(id.to_string());
+        self.delete_result.clone()
+    }
+}
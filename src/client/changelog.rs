@@ -0,0 +1,133 @@
+use crate::changelog::types::ChangelogIssue;
+use crate::client::LinearClient;
+use crate::client::queries::{
+    ChangelogIssuesQuery, ChangelogIssuesQueryVariables, IssueFilterInput, NumberComparatorInput,
+    StringComparatorInput, TeamFilterInput,
+};
+use crate::error::CliError;
+use cynic::QueryBuilder;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Match any of `identifiers` (`ENG-12`), one `team.key` + `number in` clause per team
+///
+/// Identifiers that are not `KEY-number` are ignored.
+#[must_use]
+pub fn changelog_filter(identifiers: &[String]) -> IssueFilterInput {
+    let mut numbers: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+    for identifier in identifiers {
+        if let Some((key, number)) = identifier.rsplit_once('-')
+            && let Ok(number) = number.parse::<u32>()
+        {
+            numbers
+                .entry(key.to_ascii_uppercase())
+                .or_default()
+                .push(f64::from(number));
+        }
+    }
+
+    IssueFilterInput {
+        or: Some(
+            numbers
+                .into_iter()
+                .map(|(key, numbers)| IssueFilterInput {
+                    team: Some(TeamFilterInput {
+                        key: Some(StringComparatorInput { eq: Some(key) }),
+                        ..Default::default()
+                    }),
+                    number: Some(NumberComparatorInput {
+                        in_: Some(numbers),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    }
+}
+
+/// Trait for fetching the issues that go into release notes
+pub trait ChangelogClient: Send + Sync {
+    /// Fetch the issues with the given identifiers in one request
+    ///
+    /// Identifiers that do not resolve are left out of the result, which is
+    /// in no particular order.
+    fn changelog_issues(
+        &self,
+        token: &str,
+        identifiers: &[String],
+    ) -> Result<Vec<ChangelogIssue>, CliError>;
+}
+
+/// Production implementation using Linear GraphQL API
+impl ChangelogClient for LinearClient {
+    fn changelog_issues(
+        &self,
+        token: &str,
+        identifiers: &[String],
+    ) -> Result<Vec<ChangelogIssue>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+        if identifiers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let operation = ChangelogIssuesQuery::build(ChangelogIssuesQueryVariables {
+            first: Some(identifiers.len() as i32),
+            filter: Some(changelog_filter(identifiers)),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        Ok(response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .issues
+            .nodes
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+}
+
+/// Mock implementation for testing
+///
+/// Returns the known `issues` that were asked for and records each batch.
+pub struct MockChangelogClient {
+    pub issues: Vec<ChangelogIssue>,
+    pub batches: Mutex<Vec<Vec<String>>>,
+}
+
+impl MockChangelogClient {
+    #[must_use]
+    pub fn new(issues: Vec<ChangelogIssue>) -> Self {
+        Self {
+            issues,
+            batches: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl ChangelogClient for MockChangelogClient {
+    fn changelog_issues(
+        &self,
+        _token: &str,
+        identifiers: &[String],
+    ) -> Result<Vec<ChangelogIssue>, CliError> {
+        self.batches
+            .lock()
+            .expect("mock changelog log poisoned")
+            .push(identifiers.to_vec());
+        // Reversed: the API makes no promise about order
+        Ok(self
+            .issues
+            .iter()
+            .rev()
+            .filter(|issue| identifiers.contains(&issue.identifier))
+            .cloned()
+            .collect())
+    }
+}
@@ -1,7 +1,9 @@
 use crate::client::LinearClient;
 use crate::client::queries::{
     CommentCreateInput, CommentCreateMutation, CommentCreateMutationVariables,
-    CommentDeleteMutation, CommentDeleteMutationVariables, IssueCommentsQuery,
+    CommentDeleteMutation, CommentDeleteMutationVariables, CommentResolveMutation,
+    CommentResolveMutationVariables, CommentUnresolveMutation, CommentUpdateInput,
+    CommentUpdateMutation, CommentUpdateMutationVariables, IssueCommentsQuery,
     IssueCommentsQueryVariables,
 };
 use crate::comments::types::Comment;
@@ -11,8 +13,11 @@ use cynic::{MutationBuilder, QueryBuilder};
 /// Create comment request payload.
 #[derive(Debug, Clone, Default)]
 pub struct CreateCommentInput {
+    /// Issue to comment on; may be empty for replies, which inherit the parent's issue
     pub issue_id: String,
     pub body: String,
+    /// Parent comment ID when replying in a thread
+    pub parent_id: Option<String>,
 }
 
 /// Trait for comment operations with Linear API
@@ -40,6 +45,27 @@ pub trait CommentClient: Send + Sync {
             "comment create is not implemented for this client".to_string(),
         ))
     }
+
+    /// Replace the body of a comment
+    fn update_comment(&self, token: &str, id: &str, body: &str) -> Result<Comment, CliError> {
+        let _ = (token, id, body);
+        Err(CliError::InvalidArgs(
+            "comment edit is not implemented for this client".to_string(),
+        ))
+    }
+
+    /// Resolve or unresolve a comment thread
+    fn set_comment_resolved(
+        &self,
+        token: &str,
+        id: &str,
+        resolved: bool,
+    ) -> Result<Comment, CliError> {
+        let _ = (token, id, resolved);
+        Err(CliError::InvalidArgs(
+            "comment resolve is not implemented for this client".to_string(),
+        ))
+    }
 }
 
 /// Production implementation using Linear GraphQL API
@@ -86,8 +112,9 @@ impl CommentClient for LinearClient {
 
         let operation = CommentCreateMutation::build(CommentCreateMutationVariables {
             input: CommentCreateInput {
-                issue_id: Some(input.issue_id),
+                issue_id: Some(input.issue_id).filter(|id| !id.is_empty()),
                 body: Some(input.body),
+                parent_id: input.parent_id,
             },
         });
 
@@ -102,6 +129,60 @@ impl CommentClient for LinearClient {
         Ok(payload.comment.into())
     }
 
+    fn update_comment(&self, token: &str, id: &str, body: &str) -> Result<Comment, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = CommentUpdateMutation::build(CommentUpdateMutationVariables {
+            id: id.to_string(),
+            input: CommentUpdateInput {
+                body: Some(body.to_string()),
+            },
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .comment_update;
+
+        Ok(payload.comment.into())
+    }
+
+    fn set_comment_resolved(
+        &self,
+        token: &str,
+        id: &str,
+        resolved: bool,
+    ) -> Result<Comment, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let variables = CommentResolveMutationVariables { id: id.to_string() };
+
+        let comment = if resolved {
+            let operation = CommentResolveMutation::build(variables);
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?
+                .data
+                .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                .comment_resolve
+                .comment
+        } else {
+            let operation = CommentUnresolveMutation::build(variables);
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?
+                .data
+                .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                .comment_unresolve
+                .comment
+        };
+
+        Ok(comment.into())
+    }
+
     fn delete_comment(&self, token: &str, id: &str) -> Result<(), CliError> {
         if token.is_empty() {
             return Err(CliError::auth_error("Token cannot be empty"));
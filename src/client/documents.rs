@@ -0,0 +1,295 @@
+use crate::client::LinearClient;
+use crate::client::projects::project_filter;
+use crate::client::queries::{
+    DocumentCreateInput, DocumentCreateMutation, DocumentCreateMutationVariables,
+    DocumentDeleteMutation, DocumentDeleteMutationVariables, DocumentFilterInput, DocumentQuery,
+    DocumentQueryVariables, DocumentUpdateInput, DocumentUpdateMutation,
+    DocumentUpdateMutationVariables, DocumentsContentQuery, DocumentsQuery,
+    DocumentsQueryVariables, StringComparatorInput,
+};
+use crate::documents::types::{Document, DocumentRef};
+use crate::error::CliError;
+use cynic::{MutationBuilder, QueryBuilder};
+use std::sync::Mutex;
+
+/// Create document request payload used by the document client.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CreateDocumentInput {
+    pub title: String,
+    pub content: Option<String>,
+    /// Project slug, name, or ID
+    pub project: Option<String>,
+}
+
+/// Update document request payload used by the document client.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateDocumentInput {
+    pub title: Option<String>,
+    pub content: Option<String>,
+}
+
+/// Trait for document operations with Linear API
+pub trait DocumentClient: Send + Sync {
+    /// List documents, optionally limited to one project
+    fn list_documents(
+        &self,
+        token: &str,
+        project: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<Document>, CliError>;
+
+    /// Fetch a document with its content by ID, slug or URL
+    fn get_document(&self, token: &str, reference: &str) -> Result<Document, CliError>;
+
+    /// Create a document
+    fn create_document(
+        &self,
+        token: &str,
+        input: CreateDocumentInput,
+    ) -> Result<Document, CliError>;
+
+    /// Update a document's title or content
+    fn update_document(
+        &self,
+        token: &str,
+        id: &str,
+        input: UpdateDocumentInput,
+    ) -> Result<Document, CliError>;
+
+    /// Delete (trash) a document
+    fn delete_document(&self, token: &str, id: &str) -> Result<(), CliError>;
+}
+
+/// Production implementation using Linear GraphQL API
+impl DocumentClient for LinearClient {
+    fn list_documents(
+        &self,
+        token: &str,
+        project: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<Document>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = DocumentsQuery::build(DocumentsQueryVariables {
+            first: Some(limit as i32),
+            filter: project.map(|project| DocumentFilterInput {
+                project: Some(project_filter(project)),
+                slug_id: None,
+            }),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        Ok(response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .documents
+            .nodes
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    fn get_document(&self, token: &str, reference: &str) -> Result<Document, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        match DocumentRef::parse(reference) {
+            DocumentRef::Id(id) => {
+                let operation = DocumentQuery::build(DocumentQueryVariables { id });
+                let response =
+                    self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+                Ok(response
+                    .data
+                    .ok_or_else(|| CliError::NotFound(format!("Document {reference} not found")))?
+                    .document
+                    .into())
+            }
+            DocumentRef::Slug(slug) => {
+                let operation = DocumentsContentQuery::build(DocumentsQueryVariables {
+                    first: Some(1),
+                    filter: Some(DocumentFilterInput {
+                        project: None,
+                        slug_id: Some(StringComparatorInput { eq: Some(slug) }),
+                    }),
+                });
+                let response =
+                    self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+                response
+                    .data
+                    .and_then(|d| d.documents.nodes.into_iter().next())
+                    .map(Into::into)
+                    .ok_or_else(|| CliError::NotFound(format!("Document {reference} not found")))
+            }
+        }
+    }
+
+    fn create_document(
+        &self,
+        token: &str,
+        input: CreateDocumentInput,
+    ) -> Result<Document, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let project_id = input
+            .project
+            .map(|project| self.resolve_project_id(token, &project))
+            .transpose()?;
+        let operation = DocumentCreateMutation::build(DocumentCreateMutationVariables {
+            input: DocumentCreateInput {
+                title: input.title,
+                content: input.content,
+                project_id,
+            },
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .document_create;
+
+        if !payload.success {
+            return Err(CliError::General("Document create failed".to_string()));
+        }
+
+        Ok(payload.document.into())
+    }
+
+    fn update_document(
+        &self,
+        token: &str,
+        id: &str,
+        input: UpdateDocumentInput,
+    ) -> Result<Document, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = DocumentUpdateMutation::build(DocumentUpdateMutationVariables {
+            id: id.to_string(),
+            input: DocumentUpdateInput {
+                title: input.title,
+                content: input.content,
+            },
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .document_update;
+
+        if !payload.success {
+            return Err(CliError::General("Document update failed".to_string()));
+        }
+
+        Ok(payload.document.into())
+    }
+
+    fn delete_document(&self, token: &str, id: &str) -> Result<(), CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation =
+            DocumentDeleteMutation::build(DocumentDeleteMutationVariables { id: id.to_string() });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .document_delete;
+
+        if !payload.success {
+            return Err(CliError::General("Document delete failed".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Mock implementation for testing
+///
+/// `get_document` and mutations return `document`; creates, updates and
+/// deletes are recorded.
+pub struct MockDocumentClient {
+    pub document: Result<Document, CliError>,
+    pub list_result: Result<Vec<Document>, CliError>,
+    pub creates: Mutex<Vec<CreateDocumentInput>>,
+    pub updates: Mutex<Vec<(String, UpdateDocumentInput)>>,
+    pub deletes: Mutex<Vec<String>>,
+}
+
+impl MockDocumentClient {
+    #[must_use]
+    pub fn new(document: Document) -> Self {
+        Self {
+            list_result: Ok(vec![document.clone()]),
+            document: Ok(document),
+            creates: Mutex::new(Vec::new()),
+            updates: Mutex::new(Vec::new()),
+            deletes: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl DocumentClient for MockDocumentClient {
+    fn list_documents(
+        &self,
+        _token: &str,
+        _project: Option<&str>,
+        _limit: usize,
+    ) -> Result<Vec<Document>, CliError> {
+        self.list_result.clone()
+    }
+
+    fn get_document(&self, _token: &str, _reference: &str) -> Result<Document, CliError> {
+        self.document.clone()
+    }
+
+    fn create_document(
+        &self,
+        _token: &str,
+        input: CreateDocumentInput,
+    ) -> Result<Document, CliError> {
+        self.creates
+            .lock()
+            .expect("mock document log poisoned")
+            .push(input);
+        self.document.clone()
+    }
+
+    fn update_document(
+        &self,
+        _token: &str,
+        id: &str,
+        input: UpdateDocumentInput,
+    ) -> Result<Document, CliError> {
+        self.updates
+            .lock()
+            .expect("mock document log poisoned")
+            .push((id.to_string(), input));
+        self.document.clone()
+    }
+
+    fn delete_document(&self, _token: &str, id: &str) -> Result<(), CliError> {
+        self.deletes
+            .lock()
+            .expect("mock document log poisoned")
+            .push(id.to_string());
+        Ok(())
+    }
+}
@@ -1,9 +1,15 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 /// GraphQL request structure
-#[derive(Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GraphQLRequest {
     pub query: String,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub variables: Map<String, Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_name: Option<String>,
 }
 
 /// GraphQL error structure
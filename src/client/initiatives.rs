@@ -0,0 +1,418 @@
+use crate::client::LinearClient;
+use crate::client::issues::is_uuid_like;
+use crate::client::queries::{
+    IDComparatorInput, InitiativeCreateInput, InitiativeCreateMutation,
+    InitiativeCreateMutationVariables, InitiativeFilterInput, InitiativeToProjectCreateInput,
+    InitiativeToProjectCreateMutation, InitiativeToProjectCreateMutationVariables,
+    InitiativeUpdateFilterInput, InitiativeUpdateInput, InitiativeUpdateMutation,
+    InitiativeUpdateMutationVariables, InitiativeUpdatesQuery, InitiativeUpdatesQueryVariables,
+    InitiativesQuery, InitiativesQueryVariables, StringComparatorInput, TimelessDate,
+};
+use crate::error::CliError;
+use crate::initiatives::types::{Initiative, InitiativeStatus, InitiativeUpdate};
+use cynic::{MutationBuilder, QueryBuilder};
+use std::sync::Mutex;
+
+/// Create initiative request payload used by the initiative client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateInitiativeInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub status: Option<InitiativeStatus>,
+    /// Target date as `YYYY-MM-DD`
+    pub target_date: Option<String>,
+}
+
+/// Update initiative request payload used by the initiative client.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateInitiativeInput {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<InitiativeStatus>,
+    /// Target date as `YYYY-MM-DD`
+    pub target_date: Option<String>,
+}
+
+/// Match an initiative by ID, or by slug or exact name
+///
+/// Slugs may be given bare, as `name-slug`, or as the initiative URL.
+#[must_use]
+pub fn initiative_filter(reference: &str) -> InitiativeFilterInput {
+    let reference = reference.trim().trim_end_matches('/');
+    if is_uuid_like(reference) {
+        return InitiativeFilterInput {
+            id: Some(IDComparatorInput {
+                eq: Some(cynic::Id::new(reference)),
+            }),
+            ..Default::default()
+        };
+    }
+
+    let segment = if reference.contains("/initiative/") {
+        reference
+            .split("/initiative/")
+            .nth(1)
+            .and_then(|rest| rest.split('/').next())
+            .unwrap_or(reference)
+    } else {
+        reference
+    };
+    let slug = segment.rsplit('-').next().unwrap_or(segment);
+
+    InitiativeFilterInput {
+        or: Some(vec![
+            InitiativeFilterInput {
+                slug_id: Some(StringComparatorInput {
+                    eq: Some(slug.to_string()),
+                }),
+                ..Default::default()
+            },
+            InitiativeFilterInput {
+                name: Some(StringComparatorInput {
+                    eq: Some(reference.to_string()),
+                }),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    }
+}
+
+/// Trait for initiative operations with Linear API
+pub trait InitiativeClient: Send + Sync {
+    /// List initiatives, optionally only those with a given status
+    fn list_initiatives(
+        &self,
+        token: &str,
+        status: Option<InitiativeStatus>,
+        limit: usize,
+    ) -> Result<Vec<Initiative>, CliError>;
+
+    /// Fetch an initiative with its projects by ID, slug, name or URL
+    fn get_initiative(&self, token: &str, reference: &str) -> Result<Initiative, CliError>;
+
+    /// Create an initiative
+    fn create_initiative(
+        &self,
+        token: &str,
+        input: CreateInitiativeInput,
+    ) -> Result<Initiative, CliError>;
+
+    /// Update an initiative's fields
+    fn update_initiative(
+        &self,
+        token: &str,
+        reference: &str,
+        input: UpdateInitiativeInput,
+    ) -> Result<Initiative, CliError>;
+
+    /// Link a project to an initiative, returning the initiative with its projects
+    fn add_project(
+        &self,
+        token: &str,
+        initiative: &str,
+        project: &str,
+    ) -> Result<Initiative, CliError>;
+
+    /// List status updates posted on an initiative, newest first
+    fn list_updates(
+        &self,
+        token: &str,
+        initiative: &str,
+        limit: usize,
+    ) -> Result<Vec<InitiativeUpdate>, CliError>;
+}
+
+impl LinearClient {
+    /// Resolve an initiative reference to its ID, looking up slugs and names
+    fn initiative_id(&self, token: &str, reference: &str) -> Result<String, CliError> {
+        if is_uuid_like(reference) {
+            return Ok(reference.to_string());
+        }
+        Ok(self.get_initiative(token, reference)?.id)
+    }
+}
+
+/// Production implementation using Linear GraphQL API
+impl InitiativeClient for LinearClient {
+    fn list_initiatives(
+        &self,
+        token: &str,
+        status: Option<InitiativeStatus>,
+        limit: usize,
+    ) -> Result<Vec<Initiative>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = InitiativesQuery::build(InitiativesQueryVariables {
+            first: Some(limit as i32),
+            filter: status.map(|status| InitiativeFilterInput {
+                status: Some(StringComparatorInput {
+                    eq: Some(status.as_str().to_string()),
+                }),
+                ..Default::default()
+            }),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        Ok(response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .initiatives
+            .nodes
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    fn get_initiative(&self, token: &str, reference: &str) -> Result<Initiative, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = InitiativesQuery::build(InitiativesQueryVariables {
+            first: Some(1),
+            filter: Some(initiative_filter(reference)),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        response
+            .data
+            .and_then(|d| d.initiatives.nodes.into_iter().next())
+            .map(Into::into)
+            .ok_or_else(|| CliError::NotFound(format!("Initiative {reference} not found")))
+    }
+
+    fn create_initiative(
+        &self,
+        token: &str,
+        input: CreateInitiativeInput,
+    ) -> Result<Initiative, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = InitiativeCreateMutation::build(InitiativeCreateMutationVariables {
+            input: InitiativeCreateInput {
+                name: input.name,
+                description: input.description,
+                status: input.status.map(Into::into),
+                owner_id: None,
+                target_date: input.target_date.map(TimelessDate),
+            },
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .initiative_create;
+
+        if !payload.success {
+            return Err(CliError::General("Initiative create failed".to_string()));
+        }
+
+        Ok(payload.initiative.into())
+    }
+
+    fn update_initiative(
+        &self,
+        token: &str,
+        reference: &str,
+        input: UpdateInitiativeInput,
+    ) -> Result<Initiative, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let id = self.initiative_id(token, reference)?;
+        let operation = InitiativeUpdateMutation::build(InitiativeUpdateMutationVariables {
+            id,
+            input: InitiativeUpdateInput {
+                name: input.name,
+                description: input.description,
+                status: input.status.map(Into::into),
+                owner_id: None,
+                target_date: input.target_date.map(TimelessDate),
+            },
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .initiative_update;
+
+        if !payload.success {
+            return Err(CliError::General("Initiative update failed".to_string()));
+        }
+
+        Ok(payload.initiative.into())
+    }
+
+    fn add_project(
+        &self,
+        token: &str,
+        initiative: &str,
+        project: &str,
+    ) -> Result<Initiative, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let initiative_id = self.initiative_id(token, initiative)?;
+        let project_id = self.resolve_project_id(token, project)?;
+
+        let operation =
+            InitiativeToProjectCreateMutation::build(InitiativeToProjectCreateMutationVariables {
+                input: InitiativeToProjectCreateInput {
+                    initiative_id: initiative_id.clone(),
+                    project_id,
+                },
+            });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .initiative_to_project_create;
+
+        if !payload.success {
+            return Err(CliError::General(
+                "Adding project to initiative failed".to_string(),
+            ));
+        }
+
+        self.get_initiative(token, &initiative_id)
+    }
+
+    fn list_updates(
+        &self,
+        token: &str,
+        initiative: &str,
+        limit: usize,
+    ) -> Result<Vec<InitiativeUpdate>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = InitiativeUpdatesQuery::build(InitiativeUpdatesQueryVariables {
+            first: Some(limit as i32),
+            filter: Some(InitiativeUpdateFilterInput {
+                initiative: Some(initiative_filter(initiative)),
+            }),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        Ok(response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .initiative_updates
+            .nodes
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+}
+
+/// Mock implementation for testing
+///
+/// `get_initiative`, mutations and `add_project` return `initiative`;
+/// creates, updates and project links are recorded.
+pub struct MockInitiativeClient {
+    pub initiative: Result<Initiative, CliError>,
+    pub list_result: Result<Vec<Initiative>, CliError>,
+    pub updates_result: Result<Vec<InitiativeUpdate>, CliError>,
+    pub creates: Mutex<Vec<CreateInitiativeInput>>,
+    pub edits: Mutex<Vec<(String, UpdateInitiativeInput)>>,
+    pub links: Mutex<Vec<(String, String)>>,
+}
+
+impl MockInitiativeClient {
+    #[must_use]
+    pub fn new(initiative: Initiative) -> Self {
+        Self {
+            list_result: Ok(vec![initiative.clone()]),
+            initiative: Ok(initiative),
+            updates_result: Ok(Vec::new()),
+            creates: Mutex::new(Vec::new()),
+            edits: Mutex::new(Vec::new()),
+            links: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl InitiativeClient for MockInitiativeClient {
+    fn list_initiatives(
+        &self,
+        _token: &str,
+        _status: Option<InitiativeStatus>,
+        _limit: usize,
+    ) -> Result<Vec<Initiative>, CliError> {
+        self.list_result.clone()
+    }
+
+    fn get_initiative(&self, _token: &str, _reference: &str) -> Result<Initiative, CliError> {
+        self.initiative.clone()
+    }
+
+    fn create_initiative(
+        &self,
+        _token: &str,
+        input: CreateInitiativeInput,
+    ) -> Result<Initiative, CliError> {
+        self.creates
+            .lock()
+            .expect("mock initiative log poisoned")
+            .push(input);
+        self.initiative.clone()
+    }
+
+    fn update_initiative(
+        &self,
+        _token: &str,
+        reference: &str,
+        input: UpdateInitiativeInput,
+    ) -> Result<Initiative, CliError> {
+        self.edits
+            .lock()
+            .expect("mock initiative log poisoned")
+            .push((reference.to_string(), input));
+        self.initiative.clone()
+    }
+
+    fn add_project(
+        &self,
+        _token: &str,
+        initiative: &str,
+        project: &str,
+    ) -> Result<Initiative, CliError> {
+        self.links
+            .lock()
+            .expect("mock initiative log poisoned")
+            .push((initiative.to_string(), project.to_string()));
+        self.initiative.clone()
+    }
+
+    fn list_updates(
+        &self,
+        _token: &str,
+        _initiative: &str,
+        _limit: usize,
+    ) -> Result<Vec<InitiativeUpdate>, CliError> {
+        self.updates_result.clone()
+    }
+}
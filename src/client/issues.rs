@@ -1,38 +1,66 @@
 use crate::client::LinearClient;
 use crate::client::queries::{
-    BooleanComparatorInput, IDComparatorInput, IssueArchiveMutation, IssueArchiveMutationVariables,
-    IssueCreateInput, IssueCreateMutation, IssueCreateMutationVariables, IssueDeleteMutation,
+    BooleanComparatorInput, CycleFilterInput, CycleLookupQuery, CycleLookupQueryVariables,
+    IDComparatorInput, IssueArchiveMutation, IssueArchiveMutationVariables, IssueCreateInput,
+    IssueCreateMutation, IssueCreateMutationVariables, IssueDeleteMutation,
     IssueDeleteMutationVariables, IssueFilterInput, IssueQuery, IssueQueryVariables,
     IssueRelationCreateInput, IssueRelationCreateMutation, IssueRelationCreateMutationVariables,
-    IssueRelationType, IssueUnarchiveMutation, IssueUnarchiveMutationVariables, IssueUpdateInput,
-    IssueUpdateMutation, IssueUpdateMutationVariables, IssuesQuery, IssuesQueryVariables,
-    NullableProjectFilterInput, NullableUserFilterInput, StringComparatorInput,
+    IssueRelationType, IssueSubscribeMutation, IssueSubscriptionMutationVariables,
+    IssueUnarchiveMutation, IssueUnarchiveMutationVariables, IssueUnsubscribeMutation,
+    IssueUpdateInput, IssueUpdateMutation, IssueUpdateMutationVariables, IssueVcsBranchSearchQuery,
+    IssueVcsBranchSearchQueryVariables, IssuesQuery, IssuesQueryVariables, Nullable,
+    NullableProjectFilterInput, NullableUserFilterInput, NumberComparatorInput, PageInfo,
+    StringComparatorInput, TeamFilterInput, TimelessDate,
 };
 use crate::error::CliError;
 use crate::issues::types::Issue;
 use cynic::{MutationBuilder, QueryBuilder};
 
+/// Page size used when streaming issue lists page by page
+const ISSUE_PAGE_SIZE: usize = 50;
+
 /// Create issue request payload used by the issue client.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct CreateIssueInput {
     pub team_id: String,
     pub title: String,
     pub description: Option<String>,
     pub assignee_id: Option<String>,
     pub project_id: Option<String>,
+    pub milestone_id: Option<String>,
     pub state_id: Option<String>,
     pub priority: Option<i32>,
+    pub estimate: Option<i32>,
+    /// Due date as `YYYY-MM-DD`
+    pub due_date: Option<String>,
+    /// Cycle reference: `current`, `next`, a cycle number, or an ID
+    pub cycle: Option<String>,
+    pub subscriber_ids: Vec<String>,
+    pub sort_order: Option<f64>,
 }
 
 /// Update issue request payload used by the issue client.
-#[derive(Debug, Clone, Default)]
+///
+/// `Nullable` fields are cleared when set to `Nullable::Null`.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct UpdateIssueInput {
     pub title: Option<String>,
     pub description: Option<String>,
-    pub assignee_id: Option<String>,
-    pub project_id: Option<String>,
+    pub assignee_id: Nullable<String>,
+    pub project_id: Nullable<String>,
+    pub milestone_id: Nullable<String>,
     pub state_id: Option<String>,
     pub priority: Option<i32>,
+    pub estimate: Nullable<i32>,
+    /// Due date as `YYYY-MM-DD`
+    pub due_date: Nullable<String>,
+    /// Cycle reference: `current`, `next`, a cycle number, or an ID
+    pub cycle: Nullable<String>,
+    /// Team key or ID to move the issue to
+    pub team_id: Option<String>,
+    /// Replaces the issue's subscribers; an empty list removes them all
+    pub subscriber_ids: Option<Vec<String>>,
+    pub sort_order: Option<f64>,
 }
 
 /// Create issue relation request payload.
@@ -104,11 +132,12 @@ fn build_list_filter(assignee: Option<&str>, project: Option<&str>) -> Option<Is
         Some(IssueFilterInput {
             assignee: assignee_filter,
             project: project_filter,
+            ..Default::default()
         })
     }
 }
 
-fn is_uuid_like(value: &str) -> bool {
+pub(crate) fn is_uuid_like(value: &str) -> bool {
     let bytes = value.as_bytes();
     if bytes.len() != 36 {
         return false;
@@ -131,6 +160,67 @@ fn is_uuid_like(value: &str) -> bool {
     true
 }
 
+/// Match a team by ID, or by key
+fn team_filter(team: &str) -> TeamFilterInput {
+    if is_uuid_like(team) {
+        TeamFilterInput {
+            id: Some(IDComparatorInput {
+                eq: Some(cynic::Id::new(team)),
+            }),
+            ..Default::default()
+        }
+    } else {
+        TeamFilterInput {
+            key: Some(StringComparatorInput {
+                eq: Some(team.to_string()),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+impl LinearClient {
+    /// Resolve `current`, `next` or a cycle number within a team to a cycle ID
+    ///
+    /// Anything else is taken to be a cycle ID already.
+    fn resolve_cycle_id(
+        &self,
+        token: &str,
+        team: &str,
+        reference: &str,
+    ) -> Result<String, CliError> {
+        let mut filter = CycleFilterInput {
+            team: Some(team_filter(team)),
+            ..Default::default()
+        };
+        match reference.to_ascii_lowercase().as_str() {
+            "current" => filter.is_active = Some(BooleanComparatorInput { eq: Some(true) }),
+            "next" => filter.is_next = Some(BooleanComparatorInput { eq: Some(true) }),
+            other => match other.parse::<u32>() {
+                Ok(number) => {
+                    filter.number = Some(NumberComparatorInput {
+                        eq: Some(f64::from(number)),
+                        ..Default::default()
+                    });
+                }
+                Err(_) => return Ok(reference.to_string()),
+            },
+        }
+
+        let operation = CycleLookupQuery::build(CycleLookupQueryVariables {
+            filter: Some(filter),
+        });
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        response
+            .data
+            .and_then(|d| d.cycles.nodes.into_iter().next())
+            .map(|cycle| cycle.id.inner().to_string())
+            .ok_or_else(|| CliError::NotFound(format!("No {reference} cycle in team {team}")))
+    }
+}
+
 /// Trait for issue operations with Linear API
 pub trait IssueClient: Send + Sync {
     /// Get an issue by identifier
@@ -145,6 +235,29 @@ pub trait IssueClient: Send + Sync {
         limit: usize,
     ) -> Result<Vec<Issue>, CliError>;
 
+    /// List issues, handing each page to `on_page` as soon as it is fetched
+    ///
+    /// The default implementation delivers the whole `list_issues` result as a single page.
+    fn list_issues_paged(
+        &self,
+        token: &str,
+        assignee: Option<String>,
+        project: Option<String>,
+        limit: usize,
+        on_page: &mut dyn FnMut(Vec<Issue>) -> Result<(), CliError>,
+    ) -> Result<(), CliError> {
+        let issues = self.list_issues(token, assignee, project, limit)?;
+        on_page(issues)
+    }
+
+    /// Find the issue linked to a git branch name
+    fn find_issue_by_branch(&self, token: &str, branch: &str) -> Result<Issue, CliError> {
+        let _ = (token, branch);
+        Err(CliError::InvalidArgs(
+            "issue from-branch is not implemented for this client".to_string(),
+        ))
+    }
+
     /// Create a new issue
     fn create_issue(&self, token: &str, input: CreateIssueInput) -> Result<Issue, CliError> {
         let _ = (token, input);
@@ -182,6 +295,19 @@ pub trait IssueClient: Send + Sync {
         ))
     }
 
+    /// Subscribe the current user to an issue's notifications, or unsubscribe
+    fn set_issue_subscribed(
+        &self,
+        token: &str,
+        id: &str,
+        subscribed: bool,
+    ) -> Result<Issue, CliError> {
+        let _ = (token, id, subscribed);
+        Err(CliError::InvalidArgs(
+            "issue subscribe is not implemented for this client".to_string(),
+        ))
+    }
+
     /// Delete an issue
     fn delete_issue(&self, token: &str, id: &str, permanently: bool) -> Result<(), CliError> {
         let _ = (token, id, permanently);
@@ -229,11 +355,35 @@ impl IssueClient for LinearClient {
         issue_node.try_into()
     }
 
+    fn find_issue_by_branch(&self, token: &str, branch: &str) -> Result<Issue, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = IssueVcsBranchSearchQuery::build(IssueVcsBranchSearchQueryVariables {
+            branch_name: branch.to_string(),
+        });
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        response
+            .data
+            .and_then(|d| d.issue_vcs_branch_search)
+            .ok_or_else(|| CliError::NotFound(format!("No issue found for branch '{branch}'")))?
+            .try_into()
+    }
+
     fn create_issue(&self, token: &str, input: CreateIssueInput) -> Result<Issue, CliError> {
         if token.is_empty() {
             return Err(CliError::auth_error("Token cannot be empty"));
         }
 
+        let cycle_id = input
+            .cycle
+            .as_deref()
+            .map(|cycle| self.resolve_cycle_id(token, &input.team_id, cycle))
+            .transpose()?;
+
         let operation = IssueCreateMutation::build(IssueCreateMutationVariables {
             input: IssueCreateInput {
                 team_id: input.team_id,
@@ -241,8 +391,14 @@ impl IssueClient for LinearClient {
                 description: input.description,
                 assignee_id: input.assignee_id,
                 project_id: input.project_id,
+                project_milestone_id: input.milestone_id,
                 state_id: input.state_id,
                 priority: input.priority,
+                estimate: input.estimate,
+                due_date: input.due_date.map(TimelessDate),
+                cycle_id,
+                subscriber_ids: (!input.subscriber_ids.is_empty()).then_some(input.subscriber_ids),
+                sort_order: input.sort_order,
             },
         });
 
@@ -271,6 +427,25 @@ impl IssueClient for LinearClient {
             return Err(CliError::auth_error("Token cannot be empty"));
         }
 
+        // Cycle numbers are per team: use the destination team when moving
+        let cycle_id = match input.cycle {
+            Nullable::Value(cycle) => {
+                let team = match &input.team_id {
+                    Some(team) => team.clone(),
+                    None => self
+                        .get_issue(token, id)?
+                        .team
+                        .map(|team| team.id)
+                        .ok_or_else(|| {
+                            CliError::General(format!("Could not determine team of issue {id}"))
+                        })?,
+                };
+                Nullable::Value(self.resolve_cycle_id(token, &team, &cycle)?)
+            }
+            Nullable::Null => Nullable::Null,
+            Nullable::Unset => Nullable::Unset,
+        };
+
         let operation = IssueUpdateMutation::build(IssueUpdateMutationVariables {
             id: id.to_string(),
             input: IssueUpdateInput {
@@ -278,8 +453,15 @@ impl IssueClient for LinearClient {
                 description: input.description,
                 assignee_id: input.assignee_id,
                 project_id: input.project_id,
+                project_milestone_id: input.milestone_id,
                 state_id: input.state_id,
                 priority: input.priority,
+                estimate: input.estimate,
+                due_date: input.due_date.map(TimelessDate),
+                cycle_id,
+                team_id: input.team_id,
+                subscriber_ids: input.subscriber_ids,
+                sort_order: input.sort_order,
             },
         });
 
@@ -346,6 +528,39 @@ impl IssueClient for LinearClient {
         issue_node.try_into()
     }
 
+    fn set_issue_subscribed(
+        &self,
+        token: &str,
+        id: &str,
+        subscribed: bool,
+    ) -> Result<Issue, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let variables = IssueSubscriptionMutationVariables { id: id.to_string() };
+
+        let payload = if subscribed {
+            let operation = IssueSubscribeMutation::build(variables);
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?
+                .data
+                .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                .issue_subscribe
+        } else {
+            let operation = IssueUnsubscribeMutation::build(variables);
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?
+                .data
+                .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                .issue_unsubscribe
+        };
+
+        let issue_node = payload.issue.ok_or_else(|| {
+            CliError::General("No issue returned from subscription mutation".to_string())
+        })?;
+
+        issue_node.try_into()
+    }
+
     fn delete_issue(&self, token: &str, id: &str, permanently: bool) -> Result<(), CliError> {
         if token.is_empty() {
             return Err(CliError::auth_error("Token cannot be empty"));
@@ -403,10 +618,62 @@ impl IssueClient for LinearClient {
         // Build filter if assignee or project is provided
         let filter = build_list_filter(assignee.as_deref(), project.as_deref());
 
+        let (issues, _) = self.fetch_issues_page(token, filter, limit, None)?;
+        Ok(issues)
+    }
+
+    fn list_issues_paged(
+        &self,
+        token: &str,
+        assignee: Option<String>,
+        project: Option<String>,
+        limit: usize,
+        on_page: &mut dyn FnMut(Vec<Issue>) -> Result<(), CliError>,
+    ) -> Result<(), CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let filter = build_list_filter(assignee.as_deref(), project.as_deref());
+
+        let mut remaining = limit;
+        let mut after = None;
+        while remaining > 0 {
+            let first = remaining.min(ISSUE_PAGE_SIZE);
+            let (issues, page_info) =
+                self.fetch_issues_page(token, filter.clone(), first, after.take())?;
+
+            let fetched = issues.len();
+            remaining = remaining.saturating_sub(fetched);
+            on_page(issues)?;
+
+            if fetched == 0 || !page_info.has_next_page {
+                break;
+            }
+            let Some(cursor) = page_info.end_cursor else {
+                break;
+            };
+            after = Some(cursor);
+        }
+
+        Ok(())
+    }
+}
+
+impl LinearClient {
+    /// Fetch a single page of issues starting after `after`
+    fn fetch_issues_page(
+        &self,
+        token: &str,
+        filter: Option<IssueFilterInput>,
+        first: usize,
+        after: Option<String>,
+    ) -> Result<(Vec<Issue>, PageInfo), CliError> {
         // Build the issues query using Cynic
         let operation = IssuesQuery::build(IssuesQueryVariables {
-            first: Some(limit as i32),
+            first: Some(first as i32),
             filter,
+            after,
         });
 
         // Execute the query using shared method
@@ -420,11 +687,13 @@ impl IssueClient for LinearClient {
             .issues;
 
         // Convert from Cynic types to our public Issue type using TryFrom
-        issues_connection
+        let issues = issues_connection
             .nodes
             .into_iter()
             .map(|issue_node| issue_node.try_into())
-            .collect()
+            .collect::<Result<Vec<Issue>, CliError>>()?;
+
+        Ok((issues, issues_connection.page_info))
     }
 }
 
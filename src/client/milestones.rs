@@ -0,0 +1,251 @@
+use crate::client::LinearClient;
+use crate::client::projects::project_filter;
+use crate::client::queries::{
+    ProjectMilestoneCreateInput, ProjectMilestoneCreateMutation,
+    ProjectMilestoneCreateMutationVariables, ProjectMilestoneDeleteMutation,
+    ProjectMilestoneDeleteMutationVariables, ProjectMilestoneUpdateInput,
+    ProjectMilestoneUpdateMutation, ProjectMilestoneUpdateMutationVariables,
+    ProjectMilestonesQuery, ProjectMilestonesQueryVariables, TimelessDate,
+};
+use crate::error::CliError;
+use crate::milestones::types::{ProjectMilestone, milestones_from_connection};
+use cynic::{MutationBuilder, QueryBuilder};
+use std::sync::Mutex;
+
+/// Create milestone request payload used by the milestone client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateMilestoneInput {
+    /// Project slug, name, or ID
+    pub project: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Target date as `YYYY-MM-DD`
+    pub target_date: Option<String>,
+}
+
+/// Update milestone request payload used by the milestone client.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateMilestoneInput {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// Target date as `YYYY-MM-DD`
+    pub target_date: Option<String>,
+}
+
+/// Trait for project milestone operations with Linear API
+pub trait MilestoneClient: Send + Sync {
+    /// List a project's milestones in their sort order
+    fn list_milestones(
+        &self,
+        token: &str,
+        project: &str,
+    ) -> Result<Vec<ProjectMilestone>, CliError>;
+
+    /// Create a milestone in a project
+    fn create_milestone(
+        &self,
+        token: &str,
+        input: CreateMilestoneInput,
+    ) -> Result<ProjectMilestone, CliError>;
+
+    /// Update a milestone's name, description or target date
+    fn update_milestone(
+        &self,
+        token: &str,
+        id: &str,
+        input: UpdateMilestoneInput,
+    ) -> Result<ProjectMilestone, CliError>;
+
+    /// Delete a milestone; its issues stay in the project
+    fn delete_milestone(&self, token: &str, id: &str) -> Result<(), CliError>;
+}
+
+/// Production implementation using Linear GraphQL API
+impl MilestoneClient for LinearClient {
+    fn list_milestones(
+        &self,
+        token: &str,
+        project: &str,
+    ) -> Result<Vec<ProjectMilestone>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = ProjectMilestonesQuery::build(ProjectMilestonesQueryVariables {
+            filter: Some(project_filter(project)),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let project_node = response
+            .data
+            .and_then(|d| d.projects.nodes.into_iter().next())
+            .ok_or_else(|| CliError::NotFound(format!("Project {project} not found")))?;
+
+        Ok(milestones_from_connection(project_node.project_milestones))
+    }
+
+    fn create_milestone(
+        &self,
+        token: &str,
+        input: CreateMilestoneInput,
+    ) -> Result<ProjectMilestone, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let project_id = self.resolve_project_id(token, &input.project)?;
+        let operation =
+            ProjectMilestoneCreateMutation::build(ProjectMilestoneCreateMutationVariables {
+                input: ProjectMilestoneCreateInput {
+                    name: input.name,
+                    project_id,
+                    description: input.description,
+                    target_date: input.target_date.map(TimelessDate),
+                },
+            });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .project_milestone_create;
+
+        if !payload.success {
+            return Err(CliError::General("Milestone create failed".to_string()));
+        }
+
+        Ok(payload.project_milestone.into())
+    }
+
+    fn update_milestone(
+        &self,
+        token: &str,
+        id: &str,
+        input: UpdateMilestoneInput,
+    ) -> Result<ProjectMilestone, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation =
+            ProjectMilestoneUpdateMutation::build(ProjectMilestoneUpdateMutationVariables {
+                id: id.to_string(),
+                input: ProjectMilestoneUpdateInput {
+                    name: input.name,
+                    description: input.description,
+                    target_date: input.target_date.map(TimelessDate),
+                },
+            });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .project_milestone_update;
+
+        if !payload.success {
+            return Err(CliError::General("Milestone update failed".to_string()));
+        }
+
+        Ok(payload.project_milestone.into())
+    }
+
+    fn delete_milestone(&self, token: &str, id: &str) -> Result<(), CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation =
+            ProjectMilestoneDeleteMutation::build(ProjectMilestoneDeleteMutationVariables {
+                id: id.to_string(),
+            });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .project_milestone_delete;
+
+        if !payload.success {
+            return Err(CliError::General("Milestone delete failed".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Mock implementation for testing
+///
+/// `list_milestones` returns `list_result` and mutations return `milestone`;
+/// creates, updates and deletes are recorded.
+pub struct MockMilestoneClient {
+    pub milestone: Result<ProjectMilestone, CliError>,
+    pub list_result: Result<Vec<ProjectMilestone>, CliError>,
+    pub creates: Mutex<Vec<CreateMilestoneInput>>,
+    pub updates: Mutex<Vec<(String, UpdateMilestoneInput)>>,
+    pub deletes: Mutex<Vec<String>>,
+}
+
+impl MockMilestoneClient {
+    #[must_use]
+    pub fn new(milestone: ProjectMilestone) -> Self {
+        Self {
+            list_result: Ok(vec![milestone.clone()]),
+            milestone: Ok(milestone),
+            creates: Mutex::new(Vec::new()),
+            updates: Mutex::new(Vec::new()),
+            deletes: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl MilestoneClient for MockMilestoneClient {
+    fn list_milestones(
+        &self,
+        _token: &str,
+        _project: &str,
+    ) -> Result<Vec<ProjectMilestone>, CliError> {
+        self.list_result.clone()
+    }
+
+    fn create_milestone(
+        &self,
+        _token: &str,
+        input: CreateMilestoneInput,
+    ) -> Result<ProjectMilestone, CliError> {
+        self.creates
+            .lock()
+            .expect("mock milestone log poisoned")
+            .push(input);
+        self.milestone.clone()
+    }
+
+    fn update_milestone(
+        &self,
+        _token: &str,
+        id: &str,
+        input: UpdateMilestoneInput,
+    ) -> Result<ProjectMilestone, CliError> {
+        self.updates
+            .lock()
+            .expect("mock milestone log poisoned")
+            .push((id.to_string(), input));
+        self.milestone.clone()
+    }
+
+    fn delete_milestone(&self, _token: &str, id: &str) -> Result<(), CliError> {
+        self.deletes
+            .lock()
+            .expect("mock milestone log poisoned")
+            .push(id.to_string());
+        Ok(())
+    }
+}
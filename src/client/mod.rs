@@ -1,19 +1,30 @@
+pub mod api;
+pub mod attachments;
 pub mod auth;
+pub mod changelog;
 pub mod comments;
 pub mod cycles;
+pub mod documents;
 pub mod graphql;
+pub mod initiatives;
 pub mod issues;
 pub mod labels;
+pub mod milestones;
+pub mod notifications;
 pub mod projects;
 pub mod queries;
+pub mod reactions;
 pub mod search;
 pub mod semantic_search;
 pub mod states;
+pub mod sync;
 pub mod teams;
+pub mod uploads;
 pub mod users;
 
 use std::time::Duration;
 
+use crate::cache::{CacheResource, ResponseCache};
 use crate::error::CliError;
 use cynic::GraphQlError;
 use reqwest::blocking::Client;
@@ -61,6 +72,7 @@ pub(crate) enum GraphQlErrorType {
 /// Production Linear API client that implements all domain traits
 pub struct LinearClient {
     http_client: Client,
+    cache: Option<ResponseCache>,
 }
 
 impl Default for LinearClient {
@@ -77,7 +89,19 @@ impl LinearClient {
             .timeout(REQUEST_TIMEOUT)
             .build()
             .expect("failed to build HTTP client");
-        Self { http_client }
+        Self {
+            http_client,
+            cache: None,
+        }
+    }
+
+    /// Create a client that serves reference-data queries from `cache`
+    #[must_use]
+    pub fn with_cache(cache: Option<ResponseCache>) -> Self {
+        Self {
+            cache,
+            ..Self::new()
+        }
     }
 
     /// Get the HTTP client for use in trait implementations
@@ -121,6 +145,15 @@ impl LinearClient {
     {
         use cynic::http::ReqwestBlockingExt;
 
+        let cached_resource = self
+            .cache
+            .as_ref()
+            .zip(operation.operation_name.as_deref())
+            .and_then(|(cache, name)| Some((cache, CacheResource::from_operation(name)?)));
+        if let Some((cache, resource)) = cached_resource {
+            return self.execute_cached_query(cache, resource, token, operation, error_type);
+        }
+
         // Execute the query with auth header
         let response = self
             .client()
@@ -136,4 +169,84 @@ impl LinearClient {
 
         Ok(response)
     }
+
+    /// Execute a cacheable query, serving it from `cache` when a fresh entry exists.
+    ///
+    /// The raw response body is stored only for successful responses with data,
+    /// so errors are never cached.
+    fn execute_cached_query<ResponseData, Vars>(
+        &self,
+        cache: &ResponseCache,
+        resource: CacheResource,
+        token: &str,
+        operation: cynic::Operation<ResponseData, Vars>,
+        error_type: GraphQlErrorType,
+    ) -> Result<cynic::GraphQlResponse<ResponseData>, CliError>
+    where
+        ResponseData: serde::de::DeserializeOwned + 'static,
+        Vars: serde::Serialize,
+    {
+        let request = serde_json::to_vec(&operation)
+            .map_err(|e| CliError::General(format!("Failed to serialize GraphQL request: {e}")))?;
+
+        if let Some(body) = cache.get(token, resource, &request)
+            && let Ok(response) = serde_json::from_str(&body)
+        {
+            return Ok(response);
+        }
+
+        let (status, body) = self.post_graphql(token, request.clone())?;
+        let response: cynic::GraphQlResponse<ResponseData> = parse_graphql_response(status, &body)?;
+
+        if let Some(errors) = &response.errors {
+            check_graphql_errors(errors, error_type)?;
+        }
+
+        if status.is_success() && response.data.is_some() {
+            cache.put(token, resource, &request, &body);
+        }
+
+        Ok(response)
+    }
+
+    /// POST a serialized GraphQL request, returning the HTTP status and raw body
+    pub(crate) fn post_graphql(
+        &self,
+        token: &str,
+        request: Vec<u8>,
+    ) -> Result<(reqwest::StatusCode, String), CliError> {
+        let http_response = self
+            .client()
+            .post(self.base_url())
+            .header("Authorization", token)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(request)
+            .send()
+            .map_err(|e| CliError::NetworkError(format!("Failed to connect to Linear API: {e}")))?;
+        let status = http_response.status();
+        let body = http_response
+            .text()
+            .map_err(|e| CliError::NetworkError(format!("Failed to connect to Linear API: {e}")))?;
+        Ok((status, body))
+    }
+}
+
+/// Parse a raw GraphQL response body.
+///
+/// Error responses usually still carry a GraphQL body; only bodies that fail to
+/// parse on a non-success status are reported as network errors.
+pub(crate) fn parse_graphql_response<ResponseData>(
+    status: reqwest::StatusCode,
+    body: &str,
+) -> Result<cynic::GraphQlResponse<ResponseData>, CliError>
+where
+    ResponseData: serde::de::DeserializeOwned,
+{
+    serde_json::from_str(body).map_err(|e| {
+        if status.is_success() {
+            CliError::General(format!("Failed to parse Linear API response: {e}"))
+        } else {
+            CliError::NetworkError(format!("Failed to connect to Linear API: HTTP {status}"))
+        }
+    })
 }
@@ -0,0 +1,228 @@
+use crate::client::LinearClient;
+use crate::client::queries::{
+    DateTime, NotificationArchiveMutation, NotificationArchiveMutationVariables,
+    NotificationUpdateInput, NotificationUpdateMutation, NotificationUpdateMutationVariables,
+    NotificationsQuery, NotificationsQueryVariables,
+};
+use crate::error::CliError;
+use crate::notifications::types::Notification;
+use cynic::{MutationBuilder, QueryBuilder};
+use std::sync::Mutex;
+
+/// Notifications requested per page by `list_notifications`
+const NOTIFICATION_PAGE_SIZE: usize = 100;
+
+/// Update notification request payload used by the notification client.
+///
+/// Timestamps are RFC 3339 strings; `None` fields are left unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateNotificationInput {
+    pub read_at: Option<String>,
+    pub snoozed_until_at: Option<String>,
+}
+
+/// Trait for inbox notification operations with Linear API
+pub trait NotificationClient: Send + Sync {
+    /// List up to `limit` of the current user's notifications, newest first
+    ///
+    /// With `unread_only`, read notifications are skipped and paging continues
+    /// until `limit` unread ones are found or the inbox ends. Pass `usize::MAX`
+    /// to page through the whole inbox.
+    fn list_notifications(
+        &self,
+        token: &str,
+        limit: usize,
+        unread_only: bool,
+    ) -> Result<Vec<Notification>, CliError>;
+
+    /// Mark a notification read or snooze it
+    fn update_notification(
+        &self,
+        token: &str,
+        id: &str,
+        input: UpdateNotificationInput,
+    ) -> Result<Notification, CliError>;
+
+    /// Archive a notification
+    fn archive_notification(&self, token: &str, id: &str) -> Result<(), CliError>;
+}
+
+/// Production implementation using Linear GraphQL API
+impl NotificationClient for LinearClient {
+    fn list_notifications(
+        &self,
+        token: &str,
+        limit: usize,
+        unread_only: bool,
+    ) -> Result<Vec<Notification>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let mut notifications = Vec::new();
+        let mut after = None;
+        while notifications.len() < limit {
+            let operation = NotificationsQuery::build(NotificationsQueryVariables {
+                first: Some((limit - notifications.len()).min(NOTIFICATION_PAGE_SIZE) as i32),
+                after: after.take(),
+            });
+
+            let response =
+                self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+            let connection = response
+                .data
+                .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                .notifications;
+
+            let fetched = connection.nodes.len();
+            notifications.extend(
+                connection
+                    .nodes
+                    .into_iter()
+                    .map(Notification::from)
+                    .filter(|n| !unread_only || n.is_unread())
+                    .take(limit - notifications.len()),
+            );
+
+            match connection.page_info.end_cursor {
+                Some(cursor) if connection.page_info.has_next_page && fetched > 0 => {
+                    after = Some(cursor);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(notifications)
+    }
+
+    fn update_notification(
+        &self,
+        token: &str,
+        id: &str,
+        input: UpdateNotificationInput,
+    ) -> Result<Notification, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = NotificationUpdateMutation::build(NotificationUpdateMutationVariables {
+            id: id.to_string(),
+            input: NotificationUpdateInput {
+                read_at: input.read_at.map(DateTime),
+                snoozed_until_at: input.snoozed_until_at.map(DateTime),
+            },
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .notification_update;
+
+        if !payload.success {
+            return Err(CliError::General("Notification update failed".to_string()));
+        }
+
+        Ok(payload.notification.into())
+    }
+
+    fn archive_notification(&self, token: &str, id: &str) -> Result<(), CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let operation = NotificationArchiveMutation::build(NotificationArchiveMutationVariables {
+            id: id.to_string(),
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .notification_archive;
+
+        if !payload.success {
+            return Err(CliError::General("Notification archive failed".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Mock implementation for testing
+///
+/// Updates are applied to the matching notification from `notifications`
+/// and recorded, as are archived IDs.
+#[derive(Default)]
+pub struct MockNotificationClient {
+    pub notifications: Vec<Notification>,
+    pub updates: Mutex<Vec<(String, UpdateNotificationInput)>>,
+    pub archived: Mutex<Vec<String>>,
+}
+
+impl MockNotificationClient {
+    #[must_use]
+    pub fn new(notifications: Vec<Notification>) -> Self {
+        Self {
+            notifications,
+            ..Default::default()
+        }
+    }
+}
+
+impl NotificationClient for MockNotificationClient {
+    fn list_notifications(
+        &self,
+        _token: &str,
+        limit: usize,
+        unread_only: bool,
+    ) -> Result<Vec<Notification>, CliError> {
+        Ok(self
+            .notifications
+            .iter()
+            .filter(|n| !unread_only || n.is_unread())
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    fn update_notification(
+        &self,
+        _token: &str,
+        id: &str,
+        input: UpdateNotificationInput,
+    ) -> Result<Notification, CliError> {
+        let mut notification = self
+            .notifications
+            .iter()
+            .find(|n| n.id == id)
+            .cloned()
+            .ok_or_else(|| CliError::NotFound(format!("Notification {id} not found")))?;
+
+        if let Some(read_at) = &input.read_at {
+            notification.read_at = Some(read_at.clone());
+        }
+        if let Some(until) = &input.snoozed_until_at {
+            notification.snoozed_until_at = Some(until.clone());
+        }
+
+        self.updates
+            .lock()
+            .expect("mock notification log poisoned")
+            .push((id.to_string(), input));
+        Ok(notification)
+    }
+
+    fn archive_notification(&self, _token: &str, id: &str) -> Result<(), CliError> {
+        self.archived
+            .lock()
+            .expect("mock notification log poisoned")
+            .push(id.to_string());
+        Ok(())
+    }
+}
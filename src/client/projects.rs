@@ -1,30 +1,112 @@
 use crate::client::LinearClient;
+use crate::client::issues::is_uuid_like;
 use crate::client::queries::{
-    ProjectQuery, ProjectQueryVariables, ProjectsQuery, ProjectsQueryVariables,
+    IDComparatorInput, ProjectFilterInput, ProjectIssuesQuery, ProjectIssuesQueryVariables,
+    ProjectLookupQuery, ProjectLookupQueryVariables, ProjectMembersQuery,
+    ProjectMembersQueryVariables, ProjectQuery, ProjectQueryVariables, ProjectsQuery,
+    ProjectsQueryVariables, StringComparatorInput,
 };
 use crate::error::CliError;
-use crate::projects::types::Project;
+use crate::projects::types::{Project, ProjectIssue, ProjectMember};
 use cynic::QueryBuilder;
 use cynic::http::ReqwestBlockingExt;
 
+/// Issues requested per page by `list_project_issues`
+const PROJECT_ISSUE_PAGE_SIZE: i32 = 250;
+
+/// Members requested per page by `list_project_members`
+const PROJECT_MEMBER_PAGE_SIZE: i32 = 100;
+
+/// Match a project by ID, name (when it contains spaces) or slug, like `issue list --project`
+pub(crate) fn project_filter(project: &str) -> ProjectFilterInput {
+    if is_uuid_like(project) {
+        ProjectFilterInput {
+            id: Some(IDComparatorInput {
+                eq: Some(cynic::Id::new(project)),
+            }),
+            name: None,
+            slug_id: None,
+        }
+    } else if project.contains(' ') {
+        ProjectFilterInput {
+            id: None,
+            name: Some(StringComparatorInput {
+                eq: Some(project.to_string()),
+            }),
+            slug_id: None,
+        }
+    } else {
+        ProjectFilterInput {
+            id: None,
+            name: None,
+            slug_id: Some(StringComparatorInput {
+                eq: Some(project.to_string()),
+            }),
+        }
+    }
+}
+
+impl LinearClient {
+    /// Resolve a project reference to its ID, looking up slugs and names
+    pub(crate) fn resolve_project_id(
+        &self,
+        token: &str,
+        reference: &str,
+    ) -> Result<String, CliError> {
+        if is_uuid_like(reference) {
+            return Ok(reference.to_string());
+        }
+
+        let operation = ProjectLookupQuery::build(ProjectLookupQueryVariables {
+            first: Some(1),
+            filter: Some(project_filter(reference)),
+        });
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        response
+            .data
+            .and_then(|d| d.projects.nodes.into_iter().next())
+            .map(|p| p.id.inner().to_string())
+            .ok_or_else(|| CliError::NotFound(format!("Project {reference} not found")))
+    }
+}
+
 /// Trait for project operations with Linear API
 pub trait ProjectClient: Send + Sync {
-    /// Get a project by ID
-    fn get_project(&self, token: &str, id: &str) -> Result<Project, CliError>;
+    /// Get a project by ID, slug, or name
+    fn get_project(&self, token: &str, reference: &str) -> Result<Project, CliError>;
 
     /// List projects with optional filters
     fn list_projects(&self, token: &str, limit: usize) -> Result<Vec<Project>, CliError>;
+
+    /// List every issue in a project, fetching it page by page
+    fn list_project_issues(
+        &self,
+        token: &str,
+        project_id: &str,
+    ) -> Result<Vec<ProjectIssue>, CliError>;
+
+    /// List every member of a project, fetching them page by page
+    fn list_project_members(
+        &self,
+        token: &str,
+        project_id: &str,
+    ) -> Result<Vec<ProjectMember>, CliError>;
 }
 
 /// Production implementation using Linear GraphQL API
 impl ProjectClient for LinearClient {
-    fn get_project(&self, token: &str, id: &str) -> Result<Project, CliError> {
+    fn get_project(&self, token: &str, reference: &str) -> Result<Project, CliError> {
         if token.is_empty() {
             return Err(CliError::auth_error("Token cannot be empty"));
         }
 
+        // Slugs and names are looked up first; the project query only takes an ID
+        let id = self.resolve_project_id(token, reference)?;
+
         // Build the project query using Cynic
-        let operation = ProjectQuery::build(ProjectQueryVariables { id: id.to_string() });
+        let operation = ProjectQuery::build(ProjectQueryVariables { id });
 
         // Execute the query
         let response = self
@@ -42,7 +124,7 @@ impl ProjectClient for LinearClient {
         // Extract project data
         let project_node = response
             .data
-            .ok_or_else(|| CliError::NotFound(format!("Project {id} not found")))?
+            .ok_or_else(|| CliError::NotFound(format!("Project {reference} not found")))?
             .project;
 
         // Convert from Cynic types to our public Project type using From
@@ -85,20 +167,120 @@ impl ProjectClient for LinearClient {
             .map(Into::into)
             .collect())
     }
+
+    fn list_project_issues(
+        &self,
+        token: &str,
+        project_id: &str,
+    ) -> Result<Vec<ProjectIssue>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let mut issues = Vec::new();
+        let mut after = None;
+        loop {
+            let operation = ProjectIssuesQuery::build(ProjectIssuesQueryVariables {
+                id: project_id.to_string(),
+                first: Some(PROJECT_ISSUE_PAGE_SIZE),
+                after: after.take(),
+            });
+
+            let response =
+                self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+            let connection = response
+                .data
+                .ok_or_else(|| CliError::NotFound(format!("Project {project_id} not found")))?
+                .project
+                .issues;
+
+            let fetched = connection.nodes.len();
+            issues.extend(connection.nodes.into_iter().map(Into::into));
+
+            match connection.page_info.end_cursor {
+                Some(cursor) if connection.page_info.has_next_page && fetched > 0 => {
+                    after = Some(cursor);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(issues)
+    }
+
+    fn list_project_members(
+        &self,
+        token: &str,
+        project_id: &str,
+    ) -> Result<Vec<ProjectMember>, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let mut members = Vec::new();
+        let mut after = None;
+        loop {
+            let operation = ProjectMembersQuery::build(ProjectMembersQueryVariables {
+                id: project_id.to_string(),
+                first: Some(PROJECT_MEMBER_PAGE_SIZE),
+                after: after.take(),
+            });
+
+            let response =
+                self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+            let connection = response
+                .data
+                .ok_or_else(|| CliError::NotFound(format!("Project {project_id} not found")))?
+                .project
+                .members;
+
+            let fetched = connection.nodes.len();
+            members.extend(connection.nodes.into_iter().map(Into::into));
+
+            match connection.page_info.end_cursor {
+                Some(cursor) if connection.page_info.has_next_page && fetched > 0 => {
+                    after = Some(cursor);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(members)
+    }
 }
 
 /// Mock implementation for testing
 pub struct MockProjectClient {
     pub result: Result<Project, CliError>,
     pub list_result: Result<Vec<Project>, CliError>,
+    pub issues_result: Result<Vec<ProjectIssue>, CliError>,
+    pub members_result: Result<Vec<ProjectMember>, CliError>,
 }
 
 impl ProjectClient for MockProjectClient {
-    fn get_project(&self, _token: &str, _id: &str) -> Result<Project, CliError> {
+    fn get_project(&self, _token: &str, _reference: &str) -> Result<Project, CliError> {
         self.result.clone()
     }
 
     fn list_projects(&self, _token: &str, _limit: usize) -> Result<Vec<Project>, CliError> {
         self.list_result.clone()
     }
+
+    fn list_project_issues(
+        &self,
+        _token: &str,
+        _project_id: &str,
+    ) -> Result<Vec<ProjectIssue>, CliError> {
+        self.issues_result.clone()
+    }
+
+    fn list_project_members(
+        &self,
+        _token: &str,
+        _project_id: &str,
+    ) -> Result<Vec<ProjectMember>, CliError> {
+        self.members_result.clone()
+    }
 }
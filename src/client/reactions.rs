@@ -0,0 +1,208 @@
+use crate::client::LinearClient;
+use crate::client::queries::{
+    CommentReactionsQuery, IssueReactionsQuery, ReactionCreateInput, ReactionCreateMutation,
+    ReactionCreateMutationVariables, ReactionDeleteMutation, ReactionDeleteMutationVariables,
+    ReactionNode, ReactionsQueryVariables,
+};
+use crate::error::CliError;
+use cynic::{MutationBuilder, QueryBuilder};
+use std::sync::Mutex;
+
+/// Entity a reaction is attached to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReactionTarget {
+    /// Issue identifier or ID
+    Issue(String),
+    /// Comment ID
+    Comment(String),
+}
+
+impl ReactionTarget {
+    /// Identifier for messages
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            ReactionTarget::Issue(id) | ReactionTarget::Comment(id) => id,
+        }
+    }
+}
+
+/// Trait for emoji reaction operations with Linear API
+pub trait ReactionClient: Send + Sync {
+    /// React to an issue or comment, returning the new reaction's ID
+    fn add_reaction(
+        &self,
+        token: &str,
+        target: &ReactionTarget,
+        emoji: &str,
+    ) -> Result<String, CliError>;
+
+    /// Remove the current user's reactions with `emoji`, returning how many were removed
+    fn remove_reaction(
+        &self,
+        token: &str,
+        target: &ReactionTarget,
+        emoji: &str,
+    ) -> Result<usize, CliError>;
+}
+
+impl LinearClient {
+    fn list_reactions(
+        &self,
+        token: &str,
+        target: &ReactionTarget,
+    ) -> Result<Vec<ReactionNode>, CliError> {
+        let variables = ReactionsQueryVariables {
+            id: target.id().to_string(),
+        };
+
+        match target {
+            ReactionTarget::Issue(id) => {
+                let operation = IssueReactionsQuery::build(variables);
+                let response =
+                    self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+                Ok(response
+                    .data
+                    .and_then(|d| d.issue)
+                    .ok_or_else(|| CliError::NotFound(format!("Issue {id} not found")))?
+                    .reactions)
+            }
+            ReactionTarget::Comment(_) => {
+                let operation = CommentReactionsQuery::build(variables);
+                let response =
+                    self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+                Ok(response
+                    .data
+                    .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                    .comment
+                    .reactions)
+            }
+        }
+    }
+}
+
+/// Production implementation using Linear GraphQL API
+impl ReactionClient for LinearClient {
+    fn add_reaction(
+        &self,
+        token: &str,
+        target: &ReactionTarget,
+        emoji: &str,
+    ) -> Result<String, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let (issue_id, comment_id) = match target {
+            ReactionTarget::Issue(id) => (Some(id.clone()), None),
+            ReactionTarget::Comment(id) => (None, Some(id.clone())),
+        };
+
+        let operation = ReactionCreateMutation::build(ReactionCreateMutationVariables {
+            input: ReactionCreateInput {
+                emoji: emoji.to_string(),
+                issue_id,
+                comment_id,
+            },
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .reaction_create;
+
+        if !payload.success {
+            return Err(CliError::General("Reaction create failed".to_string()));
+        }
+
+        Ok(payload.reaction.id.inner().to_string())
+    }
+
+    fn remove_reaction(
+        &self,
+        token: &str,
+        target: &ReactionTarget,
+        emoji: &str,
+    ) -> Result<usize, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let own: Vec<String> = self
+            .list_reactions(token, target)?
+            .into_iter()
+            .filter(|r| r.emoji == emoji && r.user.as_ref().is_some_and(|u| u.is_me))
+            .map(|r| r.id.inner().to_string())
+            .collect();
+
+        for id in &own {
+            let operation =
+                ReactionDeleteMutation::build(ReactionDeleteMutationVariables { id: id.clone() });
+            let response =
+                self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+            let payload = response
+                .data
+                .ok_or_else(|| CliError::General("No data returned".to_string()))?
+                .reaction_delete;
+
+            if !payload.success {
+                return Err(CliError::General("Reaction delete failed".to_string()));
+            }
+        }
+
+        Ok(own.len())
+    }
+}
+
+/// Mock implementation for testing
+///
+/// Added and removed reactions are recorded as `(target, emoji)`.
+pub struct MockReactionClient {
+    pub add_result: Result<String, CliError>,
+    pub remove_result: Result<usize, CliError>,
+    pub added: Mutex<Vec<(ReactionTarget, String)>>,
+    pub removed: Mutex<Vec<(ReactionTarget, String)>>,
+}
+
+impl Default for MockReactionClient {
+    fn default() -> Self {
+        Self {
+            add_result: Ok("reaction-1".to_string()),
+            remove_result: Ok(1),
+            added: Mutex::new(Vec::new()),
+            removed: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl ReactionClient for MockReactionClient {
+    fn add_reaction(
+        &self,
+        _token: &str,
+        target: &ReactionTarget,
+        emoji: &str,
+    ) -> Result<String, CliError> {
+        self.added
+            .lock()
+            .expect("mock reaction log poisoned")
+            .push((target.clone(), emoji.to_string()));
+        self.add_result.clone()
+    }
+
+    fn remove_reaction(
+        &self,
+        _token: &str,
+        target: &ReactionTarget,
+        emoji: &str,
+    ) -> Result<usize, CliError> {
+        self.removed
+            .lock()
+            .expect("mock reaction log poisoned")
+            .push((target.clone(), emoji.to_string()));
+        self.remove_result.clone()
+    }
+}
@@ -64,14 +64,14 @@ impl SemanticSearchClient for LinearClient {
                     }
                     SemanticSearchResultType::Document => {
                         if let Some(doc) = node.document {
-                            (doc.title, None, None)
+                            (doc.title, Some(doc.slug_id), Some(doc.url))
                         } else {
                             ("Unknown document".to_string(), None, None)
                         }
                     }
                     SemanticSearchResultType::Initiative => {
                         if let Some(init) = node.initiative {
-                            (init.name, None, None)
+                            (init.name, Some(init.slug_id), Some(init.url))
                         } else {
                             ("Unknown initiative".to_string(), None, None)
                         }
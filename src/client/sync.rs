@@ -0,0 +1,292 @@
+use crate::client::LinearClient;
+use crate::client::auth::{AuthClient, UserInfo};
+use crate::client::queries::{
+    DateTimeOrDuration, PageInfo, SyncCommentsQuery, SyncCyclesQuery, SyncIssueLabelsQuery,
+    SyncIssuesQuery, SyncProjectsQuery, SyncQueryVariables, SyncTeamsQuery, SyncUsersQuery,
+    SyncWorkflowStatesQuery,
+};
+use crate::comments::types::Comment;
+use crate::cycles::types::Cycle;
+use crate::error::CliError;
+use crate::issues::types::Issue;
+use crate::labels::types::IssueLabel;
+use crate::mirror::SyncResource;
+use crate::projects::types::Project;
+use crate::states::types::WorkflowState;
+use crate::teams::types::Team;
+use crate::users::types::User;
+use cynic::QueryBuilder;
+use std::sync::Mutex;
+
+/// Page size used when syncing the offline mirror
+const SYNC_PAGE_SIZE: i32 = 100;
+
+/// Records fetched for a single resource
+#[derive(Debug, Clone)]
+pub enum SyncRecords {
+    Teams(Vec<Team>),
+    Users(Vec<User>),
+    States(Vec<WorkflowState>),
+    Labels(Vec<IssueLabel>),
+    Projects(Vec<Project>),
+    Cycles(Vec<Cycle>),
+    Issues(Vec<Issue>),
+    Comments(Vec<Comment>),
+}
+
+impl SyncRecords {
+    /// An empty page for `resource`
+    #[must_use]
+    pub fn empty(resource: SyncResource) -> Self {
+        match resource {
+            SyncResource::Teams => SyncRecords::Teams(Vec::new()),
+            SyncResource::Users => SyncRecords::Users(Vec::new()),
+            SyncResource::States => SyncRecords::States(Vec::new()),
+            SyncResource::Labels => SyncRecords::Labels(Vec::new()),
+            SyncResource::Projects => SyncRecords::Projects(Vec::new()),
+            SyncResource::Cycles => SyncRecords::Cycles(Vec::new()),
+            SyncResource::Issues => SyncRecords::Issues(Vec::new()),
+            SyncResource::Comments => SyncRecords::Comments(Vec::new()),
+        }
+    }
+
+    /// Resource these records belong to
+    #[must_use]
+    pub fn resource(&self) -> SyncResource {
+        match self {
+            SyncRecords::Teams(_) => SyncResource::Teams,
+            SyncRecords::Users(_) => SyncResource::Users,
+            SyncRecords::States(_) => SyncResource::States,
+            SyncRecords::Labels(_) => SyncResource::Labels,
+            SyncRecords::Projects(_) => SyncResource::Projects,
+            SyncRecords::Cycles(_) => SyncResource::Cycles,
+            SyncRecords::Issues(_) => SyncResource::Issues,
+            SyncRecords::Comments(_) => SyncResource::Comments,
+        }
+    }
+}
+
+/// One page of records updated since a watermark
+#[derive(Debug, Clone)]
+pub struct SyncPage {
+    pub records: SyncRecords,
+    /// Cursor for the next page, or `None` when this was the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Trait for fetching incremental updates for the offline mirror
+pub trait SyncClient: Send + Sync {
+    /// Fetch one page of `resource` records updated after `since`
+    fn fetch_sync_page(
+        &self,
+        token: &str,
+        resource: SyncResource,
+        since: Option<&str>,
+        after: Option<String>,
+    ) -> Result<SyncPage, CliError>;
+
+    /// The authenticated user, recorded so `--assignee @me` works offline
+    fn viewer(&self, token: &str) -> Result<UserInfo, CliError>;
+}
+
+fn next_cursor(page_info: PageInfo) -> Option<String> {
+    if page_info.has_next_page {
+        page_info.end_cursor
+    } else {
+        None
+    }
+}
+
+/// Production implementation using Linear GraphQL API
+impl SyncClient for LinearClient {
+    fn fetch_sync_page(
+        &self,
+        token: &str,
+        resource: SyncResource,
+        since: Option<&str>,
+        after: Option<String>,
+    ) -> Result<SyncPage, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let variables = SyncQueryVariables {
+            first: Some(SYNC_PAGE_SIZE),
+            after,
+            since: since.map(|value| DateTimeOrDuration(value.to_string())),
+        };
+        let no_data = || CliError::General("No data returned".to_string());
+
+        let page = match resource {
+            SyncResource::Teams => {
+                let response = self.execute_query(
+                    token,
+                    SyncTeamsQuery::build(variables),
+                    crate::client::GraphQlErrorType::General,
+                )?;
+                let connection = response.data.ok_or_else(no_data)?.teams;
+                SyncPage {
+                    records: SyncRecords::Teams(
+                        connection.nodes.into_iter().map(Into::into).collect(),
+                    ),
+                    next_cursor: next_cursor(connection.page_info),
+                }
+            }
+            SyncResource::Users => {
+                let response = self.execute_query(
+                    token,
+                    SyncUsersQuery::build(variables),
+                    crate::client::GraphQlErrorType::General,
+                )?;
+                let connection = response.data.ok_or_else(no_data)?.users;
+                SyncPage {
+                    records: SyncRecords::Users(
+                        connection.nodes.into_iter().map(Into::into).collect(),
+                    ),
+                    next_cursor: next_cursor(connection.page_info),
+                }
+            }
+            SyncResource::States => {
+                let response = self.execute_query(
+                    token,
+                    SyncWorkflowStatesQuery::build(variables),
+                    crate::client::GraphQlErrorType::General,
+                )?;
+                let connection = response.data.ok_or_else(no_data)?.workflow_states;
+                SyncPage {
+                    records: SyncRecords::States(
+                        connection.nodes.into_iter().map(Into::into).collect(),
+                    ),
+                    next_cursor: next_cursor(connection.page_info),
+                }
+            }
+            SyncResource::Labels => {
+                let response = self.execute_query(
+                    token,
+                    SyncIssueLabelsQuery::build(variables),
+                    crate::client::GraphQlErrorType::General,
+                )?;
+                let connection = response.data.ok_or_else(no_data)?.issue_labels;
+                SyncPage {
+                    records: SyncRecords::Labels(
+                        connection.nodes.into_iter().map(Into::into).collect(),
+                    ),
+                    next_cursor: next_cursor(connection.page_info),
+                }
+            }
+            SyncResource::Projects => {
+                let response = self.execute_query(
+                    token,
+                    SyncProjectsQuery::build(variables),
+                    crate::client::GraphQlErrorType::General,
+                )?;
+                let connection = response.data.ok_or_else(no_data)?.projects;
+                SyncPage {
+                    records: SyncRecords::Projects(
+                        connection.nodes.into_iter().map(Into::into).collect(),
+                    ),
+                    next_cursor: next_cursor(connection.page_info),
+                }
+            }
+            SyncResource::Cycles => {
+                let response = self.execute_query(
+                    token,
+                    SyncCyclesQuery::build(variables),
+                    crate::client::GraphQlErrorType::General,
+                )?;
+                let connection = response.data.ok_or_else(no_data)?.cycles;
+                SyncPage {
+                    records: SyncRecords::Cycles(
+                        connection.nodes.into_iter().map(Into::into).collect(),
+                    ),
+                    next_cursor: next_cursor(connection.page_info),
+                }
+            }
+            SyncResource::Issues => {
+                let response = self.execute_query(
+                    token,
+                    SyncIssuesQuery::build(variables),
+                    crate::client::GraphQlErrorType::General,
+                )?;
+                let connection = response.data.ok_or_else(no_data)?.issues;
+                SyncPage {
+                    records: SyncRecords::Issues(
+                        connection
+                            .nodes
+                            .into_iter()
+                            .map(TryInto::try_into)
+                            .collect::<Result<Vec<Issue>, CliError>>()?,
+                    ),
+                    next_cursor: next_cursor(connection.page_info),
+                }
+            }
+            SyncResource::Comments => {
+                let response = self.execute_query(
+                    token,
+                    SyncCommentsQuery::build(variables),
+                    crate::client::GraphQlErrorType::General,
+                )?;
+                let connection = response.data.ok_or_else(no_data)?.comments;
+                SyncPage {
+                    records: SyncRecords::Comments(
+                        connection.nodes.into_iter().map(Into::into).collect(),
+                    ),
+                    next_cursor: next_cursor(connection.page_info),
+                }
+            }
+        };
+
+        Ok(page)
+    }
+
+    fn viewer(&self, token: &str) -> Result<UserInfo, CliError> {
+        self.validate_token(token)
+    }
+}
+
+/// Mock implementation for testing
+///
+/// Each entry in `pages` is served as one page for its resource, in order.
+/// Every request is recorded as `(resource, since)`.
+#[derive(Default)]
+pub struct MockSyncClient {
+    pub pages: Vec<SyncRecords>,
+    pub viewer: Option<UserInfo>,
+    pub requests: Mutex<Vec<(SyncResource, Option<String>)>>,
+}
+
+impl SyncClient for MockSyncClient {
+    fn fetch_sync_page(
+        &self,
+        _token: &str,
+        resource: SyncResource,
+        since: Option<&str>,
+        after: Option<String>,
+    ) -> Result<SyncPage, CliError> {
+        self.requests
+            .lock()
+            .expect("mock request log poisoned")
+            .push((resource, since.map(str::to_string)));
+
+        let index = after
+            .and_then(|cursor| cursor.parse::<usize>().ok())
+            .unwrap_or(0);
+        let mut pages = self.pages.iter().filter(|page| page.resource() == resource);
+        let records = pages
+            .nth(index)
+            .cloned()
+            .unwrap_or_else(|| SyncRecords::empty(resource));
+        let next_cursor = pages.next().map(|_| (index + 1).to_string());
+
+        Ok(SyncPage {
+            records,
+            next_cursor,
+        })
+    }
+
+    fn viewer(&self, _token: &str) -> Result<UserInfo, CliError> {
+        self.viewer
+            .clone()
+            .ok_or_else(|| CliError::AuthError("No viewer configured".to_string()))
+    }
+}
@@ -0,0 +1,204 @@
+use crate::client::LinearClient;
+use crate::client::queries::{FileUploadMutation, FileUploadMutationVariables};
+use crate::error::CliError;
+use cynic::MutationBuilder;
+use reqwest::blocking::{Body, Client};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Overall timeout for a single upload PUT; large files need more than the API timeout
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// File metadata sent to `fileUpload` to obtain a signed URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadRequest {
+    pub filename: String,
+    pub content_type: String,
+    pub size: u64,
+}
+
+/// Signed upload destination returned by Linear
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadTarget {
+    /// Pre-signed URL the file bytes are PUT to
+    pub upload_url: String,
+    /// Permanent URL of the uploaded asset
+    pub asset_url: String,
+    /// Headers that must accompany the PUT request
+    pub headers: Vec<(String, String)>,
+}
+
+/// Trait for file upload operations with Linear API
+pub trait UploadClient: Send + Sync {
+    /// Request a signed upload URL for a file
+    fn request_upload(
+        &self,
+        token: &str,
+        request: &UploadRequest,
+    ) -> Result<UploadTarget, CliError>;
+
+    /// Upload the file at `path` to a signed target
+    fn put_file(
+        &self,
+        target: &UploadTarget,
+        path: &Path,
+        content_type: &str,
+    ) -> Result<(), CliError>;
+}
+
+/// Production implementation using Linear GraphQL API
+impl UploadClient for LinearClient {
+    fn request_upload(
+        &self,
+        token: &str,
+        request: &UploadRequest,
+    ) -> Result<UploadTarget, CliError> {
+        if token.is_empty() {
+            return Err(CliError::auth_error("Token cannot be empty"));
+        }
+
+        let size = i32::try_from(request.size).map_err(|_| {
+            CliError::InvalidArgs(format!("{} is too large to upload", request.filename))
+        })?;
+
+        let operation = FileUploadMutation::build(FileUploadMutationVariables {
+            content_type: request.content_type.clone(),
+            filename: request.filename.clone(),
+            size,
+        });
+
+        let response =
+            self.execute_query(token, operation, crate::client::GraphQlErrorType::General)?;
+
+        let payload = response
+            .data
+            .ok_or_else(|| CliError::General("No data returned".to_string()))?
+            .file_upload;
+
+        let upload_file = payload
+            .upload_file
+            .filter(|_| payload.success)
+            .ok_or_else(|| CliError::General(format!("Upload of {} failed", request.filename)))?;
+
+        Ok(UploadTarget {
+            upload_url: upload_file.upload_url,
+            asset_url: upload_file.asset_url,
+            headers: upload_file
+                .headers
+                .into_iter()
+                .map(|h| (h.key, h.value))
+                .collect(),
+        })
+    }
+
+    fn put_file(
+        &self,
+        target: &UploadTarget,
+        path: &Path,
+        content_type: &str,
+    ) -> Result<(), CliError> {
+        put_file(self.client(), target, path, content_type)
+    }
+}
+
+/// PUT the file at `path` to `target`, streaming it from disk
+///
+/// The body is sized from file metadata so the storage backend receives a
+/// `Content-Length` without the file being read into memory.
+///
+/// # Errors
+/// Returns `InvalidArgs` if the file cannot be opened and `NetworkError` if
+/// the request fails or the server rejects the upload.
+pub fn put_file(
+    http: &Client,
+    target: &UploadTarget,
+    path: &Path,
+    content_type: &str,
+) -> Result<(), CliError> {
+    let file = File::open(path)
+        .map_err(|e| CliError::InvalidArgs(format!("Cannot read {}: {e}", path.display())))?;
+    let size = file
+        .metadata()
+        .map_err(|e| CliError::InvalidArgs(format!("Cannot read {}: {e}", path.display())))?
+        .len();
+
+    let mut request = http
+        .put(&target.upload_url)
+        .timeout(UPLOAD_TIMEOUT)
+        .header("Content-Type", content_type)
+        .header("Cache-Control", "public, max-age=31536000");
+    for (key, value) in &target.headers {
+        request = request.header(key, value);
+    }
+
+    let response = request
+        .body(Body::sized(file, size))
+        .send()
+        .map_err(|e| CliError::NetworkError(format!("Failed to upload {}: {e}", path.display())))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(CliError::NetworkError(format!(
+            "Failed to upload {}: HTTP {status}",
+            path.display()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Mock implementation for testing
+///
+/// Upload requests and PUTs are recorded; no bytes are sent.
+pub struct MockUploadClient {
+    pub target_result: Result<UploadTarget, CliError>,
+    pub put_result: Result<(), CliError>,
+    pub requests: Mutex<Vec<UploadRequest>>,
+    pub puts: Mutex<Vec<(PathBuf, String)>>,
+}
+
+impl MockUploadClient {
+    /// Mock that hands out `asset_url` for every upload
+    #[must_use]
+    pub fn new(asset_url: &str) -> Self {
+        Self {
+            target_result: Ok(UploadTarget {
+                upload_url: "https://uploads.example.com/signed".to_string(),
+                asset_url: asset_url.to_string(),
+                headers: Vec::new(),
+            }),
+            put_result: Ok(()),
+            requests: Mutex::new(Vec::new()),
+            puts: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl UploadClient for MockUploadClient {
+    fn request_upload(
+        &self,
+        _token: &str,
+        request: &UploadRequest,
+    ) -> Result<UploadTarget, CliError> {
+        self.requests
+            .lock()
+            .expect("mock upload request log poisoned")
+            .push(request.clone());
+        self.target_result.clone()
+    }
+
+    fn put_file(
+        &self,
+        _target: &UploadTarget,
+        path: &Path,
+        content_type: &str,
+    ) -> Result<(), CliError> {
+        self.puts
+            .lock()
+            .expect("mock upload put log poisoned")
+            .push((path.to_path_buf(), content_type.to_string()));
+        self.put_result.clone()
+    }
+}
@@ -2,10 +2,12 @@ use crate::auth::config::ConfigProvider;
 use crate::auth::storage::TokenStorage;
 use crate::auth::token::get_token_with_provider;
 use crate::client::comments::CommentClient;
-use crate::comments::types::CommentList;
+use crate::comments::types::{CommentList, thread_comments};
 use crate::error::CliError;
 use crate::io::Io;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use secrecy::ExposeSecret;
 
 /// Handle `issue comments <issue-id>` command
@@ -24,11 +26,11 @@ pub fn handle_list(
     // Fetch comments from API
     let comments = client.list_comments(token.expose_secret(), issue_id, limit)?;
 
-    // Determine output format (CLI flag > env var > auto-detect)
-    let format = get_format_with_provider(format_flag, config);
+    // Determine output format (CLI flag > env var > auto-detect), sized to the terminal
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     // Format and output via streaming writer API
-    let comment_list = CommentList(comments);
+    let comment_list = CommentList(thread_comments(comments));
     let mut output = Vec::new();
     format_output_to_writer(&comment_list, format, &mut output)?;
     io.print_bytes(&output);
@@ -1,11 +1,15 @@
 use crate::error::CliError;
 use crate::output::{
-    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
-    generic_json_formatter, generic_json_list_formatter, generic_table_formatter,
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
 };
-use comfy_table::{Table, presets::UTF8_FULL};
+use crate::reactions::ReactionSummary;
+use crate::reactions::types::{format_reactions, summarize_reactions};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write as FmtWrite;
 
 /// Comment details returned from Linear API
@@ -22,9 +26,101 @@ pub struct Comment {
     pub created_at: String,
     pub updated_at: String,
     pub edited_at: Option<String>,
+    pub resolved_at: Option<String>,
 
     // Context
     pub issue_identifier: Option<String>,
+
+    // Threading
+    pub parent_id: Option<String>,
+    /// Replies nested under this comment by [`thread_comments`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replies: Vec<Comment>,
+
+    /// Emoji reaction counts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<ReactionSummary>,
+}
+
+/// Nest replies under their parent comments, preserving order.
+///
+/// Replies whose parent is not in `comments` (e.g. cut off by `--limit`) stay
+/// at the top level so nothing is dropped.
+#[must_use]
+pub fn thread_comments(comments: Vec<Comment>) -> Vec<Comment> {
+    let ids: HashSet<String> = comments.iter().map(|c| c.id.clone()).collect();
+    let mut children: HashMap<String, Vec<Comment>> = HashMap::new();
+    let mut roots = Vec::new();
+
+    for comment in comments {
+        match comment
+            .parent_id
+            .clone()
+            .filter(|parent| parent != &comment.id && ids.contains(parent))
+        {
+            Some(parent) => children.entry(parent).or_default().push(comment),
+            None => roots.push(comment),
+        }
+    }
+
+    fn attach_replies(comment: &mut Comment, children: &mut HashMap<String, Vec<Comment>>) {
+        if let Some(mut replies) = children.remove(&comment.id) {
+            for reply in &mut replies {
+                attach_replies(reply, children);
+            }
+            comment.replies = replies;
+        }
+    }
+
+    for root in &mut roots {
+        attach_replies(root, &mut children);
+    }
+
+    // Only a parent cycle leaves replies unattached; keep them visible
+    roots.extend(children.into_values().flatten());
+    roots
+}
+
+/// Total number of comments in a thread tree, replies included
+#[must_use]
+pub fn count_comments(comments: &[Comment]) -> usize {
+    comments
+        .iter()
+        .map(|c| 1 + count_comments(&c.replies))
+        .sum()
+}
+
+/// Depth-first walk of a thread tree as `(depth, comment)` pairs
+fn flatten_thread(comments: &[Comment]) -> Vec<(usize, &Comment)> {
+    fn walk<'a>(comments: &'a [Comment], depth: usize, out: &mut Vec<(usize, &'a Comment)>) {
+        for comment in comments {
+            out.push((depth, comment));
+            walk(&comment.replies, depth + 1, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(comments, 0, &mut out);
+    out
+}
+
+/// Prefix every line of `text` with `depth` levels of Markdown blockquote
+#[must_use]
+pub fn quote_lines(text: &str, depth: usize) -> String {
+    if depth == 0 {
+        return text.to_string();
+    }
+    let marker = ">".repeat(depth);
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                marker.clone()
+            } else {
+                format!("{marker} {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 // Trait implementations for generic formatters
@@ -51,6 +147,21 @@ impl TableFormatter for Comment {
             rows.push((Cow::Borrowed("Edited"), Cow::Borrowed(edited.as_str())));
         }
 
+        if let Some(resolved) = &self.resolved_at {
+            rows.push((Cow::Borrowed("Resolved"), Cow::Borrowed(resolved.as_str())));
+        }
+
+        if let Some(parent) = &self.parent_id {
+            rows.push((Cow::Borrowed("Reply To"), Cow::Borrowed(parent.as_str())));
+        }
+
+        if !self.reactions.is_empty() {
+            rows.push((
+                Cow::Borrowed("Reactions"),
+                Cow::Owned(format_reactions(&self.reactions)),
+            ));
+        }
+
         rows.push((Cow::Borrowed("Body"), Cow::Borrowed(self.body.as_str())));
         rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.id.as_str())));
         rows.push((
@@ -100,6 +211,26 @@ impl MarkdownFormatter for Comment {
                 .map_err(|e| CliError::General(format!("Failed to write markdown edited: {e}")))?;
         }
 
+        if let Some(resolved) = &self.resolved_at {
+            writeln!(output, "**Resolved:** {}", resolved).map_err(|e| {
+                CliError::General(format!("Failed to write markdown resolved: {e}"))
+            })?;
+        }
+
+        if let Some(parent) = &self.parent_id {
+            writeln!(output, "**Reply to:** {}", parent)
+                .map_err(|e| CliError::General(format!("Failed to write markdown parent: {e}")))?;
+        }
+
+        if !self.reactions.is_empty() {
+            writeln!(
+                output,
+                "**Reactions:** {}",
+                format_reactions(&self.reactions)
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown reactions: {e}")))?;
+        }
+
         // Body content
         writeln!(output, "\n---\n\n{}\n", self.body)
             .map_err(|e| CliError::General(format!("Failed to write markdown body: {e}")))?;
@@ -121,6 +252,10 @@ impl Formattable for Comment {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -169,10 +304,16 @@ impl Formattable for Comment {
     fn to_table(&self) -> Result<String, CliError> {
         generic_table_formatter(self)
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
 
 /// Wrapper type for a list of comments
 /// Provides different formatting for collections vs single items
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct CommentList(pub Vec<Comment>);
 
 impl Formattable for CommentList {
@@ -180,15 +321,28 @@ impl Formattable for CommentList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
         // Write header row
-        wtr.write_record(["id", "user_name", "body_preview", "created_at", "edited_at"])
-            .csv_err("Failed to write CSV header")?;
+        wtr.write_record([
+            "id",
+            "user_name",
+            "body_preview",
+            "created_at",
+            "edited_at",
+            "parent_id",
+            "resolved_at",
+            "reactions",
+        ])
+        .csv_err("Failed to write CSV header")?;
 
-        // Write each comment as a row
-        for comment in &self.0 {
+        // Write each comment as a row, replies following their parent
+        for (_, comment) in flatten_thread(&self.0) {
             // Truncate body for preview (first 100 chars)
             let body_preview = if comment.body.len() > 100 {
                 format!("{}...", &comment.body[..97])
@@ -196,12 +350,22 @@ impl Formattable for CommentList {
                 comment.body.clone()
             };
 
+            let reactions = comment
+                .reactions
+                .iter()
+                .map(|r| format!("{}:{}", r.emoji, r.count))
+                .collect::<Vec<_>>()
+                .join(" ");
+
             wtr.write_record([
                 &comment.id,
                 &comment.user_name,
                 &body_preview,
                 &comment.created_at,
                 comment.edited_at.as_deref().unwrap_or(""),
+                comment.parent_id.as_deref().unwrap_or(""),
+                comment.resolved_at.as_deref().unwrap_or(""),
+                &reactions,
             ])
             .csv_err("Failed to write CSV row")?;
         }
@@ -212,36 +376,54 @@ impl Formattable for CommentList {
     }
 
     fn to_markdown(&self) -> Result<String, CliError> {
+        let comments = flatten_thread(&self.0);
+
         // Pre-allocate: ~250 bytes per comment for structure + actual content
-        let capacity: usize = self
-            .0
+        let capacity: usize = comments
             .iter()
-            .map(|c| 250 + c.body.len() + c.user_name.len() + c.user_email.len())
+            .map(|(_, c)| 250 + c.body.len() + c.user_name.len() + c.user_email.len())
             .sum();
         let mut output = String::with_capacity(capacity);
 
         // Header with count
-        writeln!(output, "## Comments ({})\n", self.0.len())
+        writeln!(output, "## Comments ({})\n", comments.len())
             .map_err(|e| CliError::General(format!("Failed to write markdown header: {e}")))?;
 
-        // Output each comment as a summary card
-        for comment in &self.0 {
-            write!(output, "---\n\n### {}\n", comment.user_name)
+        // Output each thread as a summary card, replies quoted beneath their parent
+        for (depth, comment) in comments {
+            let mut card = String::new();
+
+            let resolved = if comment.resolved_at.is_some() {
+                " (resolved)"
+            } else {
+                ""
+            };
+            writeln!(card, "### {}{}", comment.user_name, resolved)
                 .map_err(|e| CliError::General(format!("Failed to write markdown title: {e}")))?;
 
             // Metadata on one line
-            let edited_str = if let Some(edited) = &comment.edited_at {
+            let mut edited_str = if let Some(edited) = &comment.edited_at {
                 format!(" | **Edited:** {}", edited)
             } else {
                 String::new()
             };
+            if !comment.reactions.is_empty() {
+                edited_str.push_str(" | ");
+                edited_str.push_str(&format_reactions(&comment.reactions));
+            }
 
-            writeln!(output, "**Created:** {}{}", comment.created_at, edited_str).map_err(|e| {
+            writeln!(card, "**Created:** {}{}", comment.created_at, edited_str).map_err(|e| {
                 CliError::General(format!("Failed to write markdown metadata: {e}"))
             })?;
 
             // Body content
-            writeln!(output, "\n{}\n", comment.body)
+            write!(card, "\n{}", comment.body)
+                .map_err(|e| CliError::General(format!("Failed to write markdown body: {e}")))?;
+
+            if depth == 0 {
+                output.push_str("---\n\n");
+            }
+            writeln!(output, "{}\n", quote_lines(&card, depth))
                 .map_err(|e| CliError::General(format!("Failed to write markdown body: {e}")))?;
         }
 
@@ -256,30 +438,55 @@ impl Formattable for CommentList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
-        let mut table = Table::new();
-        table.load_preset(UTF8_FULL);
-
-        // Horizontal layout: column headers
-        table.set_header(vec!["Author", "Body Preview", "Created", "Edited"]);
-
-        // Each comment is a row
-        for comment in &self.0 {
-            // Truncate body for preview (first 80 chars)
-            let body_preview = if comment.body.len() > 80 {
-                format!("{}...", &comment.body[..77])
-            } else {
-                comment.body.clone()
-            };
+        self.to_table_with_layout(&TableLayout::default())
+    }
 
-            table.add_row(vec![
-                &comment.user_name,
-                &body_preview,
-                &comment.created_at,
-                comment.edited_at.as_deref().unwrap_or(""),
-            ]);
-        }
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        let comments = flatten_thread(&self.0);
+
+        generic_table_list_formatter(
+            &comments,
+            &[
+                TableColumn::new("Author"),
+                TableColumn::new("Body Preview").flexible(),
+                TableColumn::new("Created"),
+                TableColumn::new("Edited"),
+                TableColumn::new("Reactions"),
+                TableColumn::new("Resolved").wide_only(),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
+            |(depth, comment)| {
+                // Truncate body for preview (first 80 chars) unless --wide
+                let body = if layout.wide || comment.body.chars().count() <= 80 {
+                    comment.body.clone()
+                } else {
+                    let preview: String = comment.body.chars().take(77).collect();
+                    format!("{preview}...")
+                };
+
+                // Indent replies under their parent
+                let body = if *depth == 0 {
+                    body
+                } else {
+                    format!("{}↳ {body}", "  ".repeat(depth - 1))
+                };
+
+                vec![
+                    comment.user_name.clone(),
+                    body,
+                    comment.created_at.clone(),
+                    comment.edited_at.clone().unwrap_or_default(),
+                    format_reactions(&comment.reactions),
+                    comment.resolved_at.clone().unwrap_or_default(),
+                    comment.id.clone(),
+                ]
+            },
+        )
+    }
 
-        Ok(table.to_string())
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
     }
 }
 
@@ -300,7 +507,11 @@ impl From<crate::client::queries::CommentNode> for Comment {
             created_at: node.created_at.0,
             updated_at: node.updated_at.0,
             edited_at: node.edited_at.map(|d| d.0),
+            resolved_at: node.resolved_at.map(|d| d.0),
             issue_identifier: node.issue.map(|i| i.identifier),
+            parent_id: node.parent.map(|p| p.id.inner().to_string()),
+            replies: Vec::new(),
+            reactions: summarize_reactions(&node.reactions),
         }
     }
 }
@@ -5,7 +5,9 @@ use crate::client::cycles::CycleClient;
 use crate::cycles::types::{Cycle, CycleList};
 use crate::error::CliError;
 use crate::io::Io;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use secrecy::ExposeSecret;
 
 /// Handle `cycle view <id>` command
@@ -50,8 +52,8 @@ pub fn handle_list(
     let cycles: Vec<Cycle> = client.list_cycles(token.expose_secret(), limit)?;
     let cycle_list = CycleList(cycles);
 
-    // Determine output format (CLI flag > env var > auto-detect)
-    let format = get_format_with_provider(format_flag, config);
+    // Determine output format (CLI flag > env var > auto-detect), sized to the terminal
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     // Format and output via streaming writer API
     let mut output = Vec::new();
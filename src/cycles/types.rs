@@ -1,8 +1,10 @@
 use crate::error::CliError;
 use crate::output::{
-    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
-    generic_json_formatter, generic_json_list_formatter, generic_table_formatter,
-    generic_table_list_formatter,
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    TimeDisplay, Timed, fast_markdown_formatter, generic_json_formatter,
+    generic_json_list_formatter, generic_jsonl_formatter, generic_jsonl_list_formatter,
+    generic_table_formatter, generic_table_list_formatter, generic_template_formatter,
+    generic_template_list_formatter, state_color,
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -73,86 +75,96 @@ pub struct Cycle {
 
 // Trait implementations for generic formatters
 
-impl TableFormatter for Cycle {
+impl TableFormatter for Timed<'_, Cycle> {
     fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
         let mut rows = vec![
-            (Cow::Borrowed("Name"), Cow::Borrowed(self.name.as_str())),
+            (
+                Cow::Borrowed("Name"),
+                Cow::Borrowed(self.item.name.as_str()),
+            ),
             (
                 Cow::Borrowed("Number"),
-                Cow::Owned(format!("#{}", self.number)),
+                Cow::Owned(format!("#{}", self.item.number)),
             ),
         ];
 
         let status = format_cycle_status(
-            self.is_active,
-            self.is_future,
-            self.is_past,
-            self.is_next,
-            self.is_previous,
+            self.item.is_active,
+            self.item.is_future,
+            self.item.is_past,
+            self.item.is_next,
+            self.item.is_previous,
         );
         rows.push((Cow::Borrowed("Status"), Cow::Borrowed(status)));
 
         rows.push((
             Cow::Borrowed("Progress"),
-            Cow::Owned(format_progress_percent(self.progress)),
+            Cow::Owned(format_progress_percent(self.item.progress)),
         ));
 
         rows.push((
             Cow::Borrowed("Team"),
-            Cow::Owned(format!("{} ({})", self.team_name, self.team_key)),
+            Cow::Owned(format!("{} ({})", self.item.team_name, self.item.team_key)),
         ));
 
-        if let Some(desc) = &self.description {
+        if let Some(desc) = &self.item.description {
             rows.push((Cow::Borrowed("Description"), Cow::Borrowed(desc.as_str())));
         }
 
         rows.push((
             Cow::Borrowed("Start Date"),
-            Cow::Borrowed(self.starts_at.as_str()),
+            Cow::Owned(
+                self.time
+                    .format_event(&self.item.starts_at, "starts", "started"),
+            ),
         ));
         rows.push((
             Cow::Borrowed("End Date"),
-            Cow::Borrowed(self.ends_at.as_str()),
+            Cow::Owned(self.time.format_event(&self.item.ends_at, "ends", "ended")),
         ));
 
-        if let Some(completed) = &self.completed_at {
+        if let Some(completed) = &self.item.completed_at {
             rows.push((
                 Cow::Borrowed("Completed"),
-                Cow::Borrowed(completed.as_str()),
+                Cow::Owned(self.time.format(completed)),
             ));
         }
 
-        rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.id.as_str())));
+        rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.item.id.as_str())));
         rows.push((
             Cow::Borrowed("Created"),
-            Cow::Borrowed(self.created_at.as_str()),
+            Cow::Owned(self.time.format(&self.item.created_at)),
         ));
 
         rows
     }
 }
 
-impl MarkdownFormatter for Cycle {
+impl MarkdownFormatter for Timed<'_, Cycle> {
     fn markdown_capacity_hint(&self) -> usize {
-        400 + self.name.len()
-            + self.id.len()
-            + self.description.as_ref().map_or(0, |d| d.len())
-            + self.team_name.len()
-            + self.team_key.len()
-            + self.starts_at.len()
-            + self.ends_at.len()
-            + self.completed_at.as_ref().map_or(0, |c| c.len())
-            + self.created_at.len()
+        400 + self.item.name.len()
+            + self.item.id.len()
+            + self.item.description.as_ref().map_or(0, |d| d.len())
+            + self.item.team_name.len()
+            + self.item.team_key.len()
+            + self.item.starts_at.len()
+            + self.item.ends_at.len()
+            + self.item.completed_at.as_ref().map_or(0, |c| c.len())
+            + self.item.created_at.len()
     }
 
     fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
         // Exact same logic as original custom implementation
         // H1 title with cycle name and number
-        writeln!(output, "# {} (Cycle #{})\n", self.name, self.number)
-            .map_err(|e| CliError::General(format!("Failed to write markdown title: {e}")))?;
+        writeln!(
+            output,
+            "# {} (Cycle #{})\n",
+            self.item.name, self.item.number
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown title: {e}")))?;
 
         // Description
-        if let Some(desc) = &self.description {
+        if let Some(desc) = &self.item.description {
             writeln!(output, "{}\n", desc).map_err(|e| {
                 CliError::General(format!("Failed to write markdown description: {e}"))
             })?;
@@ -160,41 +172,58 @@ impl MarkdownFormatter for Cycle {
 
         // Status and progress
         let status = format_cycle_status(
-            self.is_active,
-            self.is_future,
-            self.is_past,
-            self.is_next,
-            self.is_previous,
+            self.item.is_active,
+            self.item.is_future,
+            self.item.is_past,
+            self.item.is_next,
+            self.item.is_previous,
         );
         writeln!(
             output,
             "**Status:** {} | **Progress:** {:.0}%",
             status,
-            self.progress * 100.0
+            self.item.progress * 100.0
         )
         .map_err(|e| CliError::General(format!("Failed to write markdown status: {e}")))?;
 
         // Team
-        writeln!(output, "**Team:** {} ({})", self.team_name, self.team_key)
-            .map_err(|e| CliError::General(format!("Failed to write markdown team: {e}")))?;
+        writeln!(
+            output,
+            "**Team:** {} ({})",
+            self.item.team_name, self.item.team_key
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown team: {e}")))?;
 
         // Dates
-        writeln!(output, "\n**Start Date:** {}", self.starts_at)
-            .map_err(|e| CliError::General(format!("Failed to write markdown start date: {e}")))?;
-        writeln!(output, "**End Date:** {}", self.ends_at)
-            .map_err(|e| CliError::General(format!("Failed to write markdown end date: {e}")))?;
+        writeln!(
+            output,
+            "\n**Start Date:** {}",
+            self.time
+                .format_event(&self.item.starts_at, "starts", "started")
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown start date: {e}")))?;
+        writeln!(
+            output,
+            "**End Date:** {}",
+            self.time.format_event(&self.item.ends_at, "ends", "ended")
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown end date: {e}")))?;
 
-        if let Some(completed) = &self.completed_at {
-            writeln!(output, "**Completed:** {}", completed).map_err(|e| {
+        if let Some(completed) = &self.item.completed_at {
+            writeln!(output, "**Completed:** {}", self.time.format(completed)).map_err(|e| {
                 CliError::General(format!("Failed to write markdown completed: {e}"))
             })?;
         }
 
         // Metadata
-        writeln!(output, "\n**ID:** {}", self.id)
+        writeln!(output, "\n**ID:** {}", self.item.id)
             .map_err(|e| CliError::General(format!("Failed to write markdown ID: {e}")))?;
-        writeln!(output, "**Created:** {}", self.created_at)
-            .map_err(|e| CliError::General(format!("Failed to write markdown created: {e}")))?;
+        writeln!(
+            output,
+            "**Created:** {}",
+            self.time.format(&self.item.created_at)
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown created: {e}")))?;
 
         Ok(())
     }
@@ -205,6 +234,10 @@ impl Formattable for Cycle {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -253,16 +286,36 @@ impl Formattable for Cycle {
     }
 
     fn to_markdown(&self) -> Result<String, CliError> {
-        fast_markdown_formatter(self)
+        self.to_markdown_with_time(&TimeDisplay::default())
+    }
+
+    fn to_markdown_with_time(&self, time: &TimeDisplay) -> Result<String, CliError> {
+        fast_markdown_formatter(&Timed {
+            item: self,
+            time: *time,
+        })
     }
 
     fn to_table(&self) -> Result<String, CliError> {
-        generic_table_formatter(self)
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_formatter(&Timed {
+            item: self,
+            time: layout.time,
+        })
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
     }
 }
 
 /// Wrapper type for a list of cycles
 /// Provides different formatting for collections vs single items
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct CycleList(pub Vec<Cycle>);
 
 impl Formattable for CycleList {
@@ -270,6 +323,10 @@ impl Formattable for CycleList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -314,6 +371,10 @@ impl Formattable for CycleList {
     }
 
     fn to_markdown(&self) -> Result<String, CliError> {
+        self.to_markdown_with_time(&TimeDisplay::default())
+    }
+
+    fn to_markdown_with_time(&self, time: &TimeDisplay) -> Result<String, CliError> {
         // Pre-allocate: ~200 bytes per cycle for structure + actual content
         let capacity: usize = self
             .0
@@ -359,8 +420,13 @@ impl Formattable for CycleList {
             .map_err(|e| CliError::General(format!("Failed to write markdown metadata: {e}")))?;
 
             // Dates
-            writeln!(output, "**Dates:** {} → {}", cycle.starts_at, cycle.ends_at)
-                .map_err(|e| CliError::General(format!("Failed to write markdown dates: {e}")))?;
+            writeln!(
+                output,
+                "**Dates:** {} → {}",
+                time.format_event(&cycle.starts_at, "starts", "started"),
+                time.format_event(&cycle.ends_at, "ends", "ended")
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown dates: {e}")))?;
 
             // Description if available
             if let Some(desc) = &cycle.description {
@@ -385,9 +451,22 @@ impl Formattable for CycleList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
         generic_table_list_formatter(
             &self.0,
-            &["Name", "Number", "Status", "Progress", "Team", "Dates"],
+            &[
+                TableColumn::new("Name").flexible(),
+                TableColumn::new("Number"),
+                TableColumn::new("Status").colored(state_color),
+                TableColumn::new("Progress"),
+                TableColumn::new("Team"),
+                TableColumn::new("Dates"),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
             |cycle| {
                 let status = format_cycle_status(
                     cycle.is_active,
@@ -403,11 +482,22 @@ impl Formattable for CycleList {
                     status.to_string(),
                     format_progress_percent(cycle.progress),
                     format!("{} ({})", cycle.team_name, cycle.team_key),
-                    format!("{} → {}", cycle.starts_at, cycle.ends_at),
+                    format!(
+                        "{} → {}",
+                        layout
+                            .time
+                            .format_event(&cycle.starts_at, "starts", "started"),
+                        layout.time.format_event(&cycle.ends_at, "ends", "ended")
+                    ),
+                    cycle.id.clone(),
                 ]
             },
         )
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
 }
 
 // From trait implementation for converting Cynic types to domain types
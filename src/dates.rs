@@ -0,0 +1,197 @@
+//! Date arguments accepted on the command line
+//!
+//! Every command that takes a date or time goes through [`parse_date`] or
+//! [`parse_datetime`], which accept:
+//!
+//! - calendar dates: `2026-11-01`
+//! - RFC 3339 timestamps: `2026-11-01T09:00:00+02:00`
+//! - `now`, `today`, `tomorrow`, `yesterday`
+//! - weekday names: `friday` or `fri` (today if it is that day, else the
+//!   coming one), `next friday`, `last friday`
+//! - offsets from now in minutes, hours, days or weeks: `-7d`, `2w`, `+3d`, `30m`
+//!
+//! Days and weekdays are resolved in the local timezone of the [`Clock`].
+
+use crate::client::queries::{DateTime as DateTimeScalar, TimelessDate};
+use crate::error::CliError;
+use chrono::{
+    DateTime, Datelike, Days, Duration, FixedOffset, Local, NaiveDate, SecondsFormat, Utc, Weekday,
+};
+
+/// Source of the current time that relative dates are resolved against
+///
+/// Commands use [`SystemClock`]; tests pass a [`FixedClock`].
+pub trait Clock {
+    /// The current time, in the timezone that days are counted in
+    fn now(&self) -> DateTime<FixedOffset>;
+
+    /// The clock's UTC offset at `instant`, for showing times in local time
+    fn offset_at(&self, _instant: DateTime<Utc>) -> FixedOffset {
+        *self.now().offset()
+    }
+}
+
+/// The system clock in the local timezone
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        Local::now().fixed_offset()
+    }
+
+    fn offset_at(&self, instant: DateTime<Utc>) -> FixedOffset {
+        *instant.with_timezone(&Local).offset()
+    }
+}
+
+/// A clock stopped at a given time, for deterministic tests
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<FixedOffset>);
+
+impl FixedClock {
+    /// A clock stopped at `now`, counting days in UTC
+    #[must_use]
+    pub fn utc(now: DateTime<Utc>) -> Self {
+        Self(now.fixed_offset())
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        self.0
+    }
+}
+
+/// A parsed argument: either a whole day or an exact instant
+enum Moment {
+    Day(NaiveDate),
+    Instant(DateTime<FixedOffset>),
+}
+
+fn invalid(value: &str) -> CliError {
+    CliError::InvalidArgs(format!(
+        "Invalid date '{value}': expected YYYY-MM-DD, an RFC 3339 timestamp, today, tomorrow, \
+         yesterday, a weekday (friday, next fri, last mon), or an offset like +3d, -7d or 2w"
+    ))
+}
+
+fn parse_moment(value: &str, clock: &dyn Clock) -> Result<Moment, CliError> {
+    let value = value.trim();
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(Moment::Instant(timestamp));
+    }
+    if let Ok(day) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(Moment::Day(day));
+    }
+
+    let now = clock.now();
+    let today = now.date_naive();
+    let lower = value.to_ascii_lowercase();
+    let day = match lower.split_whitespace().collect::<Vec<_>>().as_slice() {
+        ["now"] => return Ok(Moment::Instant(now)),
+        ["today"] => Some(today),
+        ["tomorrow"] => today.succ_opt(),
+        ["yesterday"] => today.pred_opt(),
+        ["next", name] => weekday(name).and_then(|day| days_ahead(today, day, 1)),
+        ["last", name] => weekday(name).and_then(|day| days_back(today, day)),
+        [word] => match weekday(word) {
+            Some(day) => days_ahead(today, day, 0),
+            None => {
+                return offset(word, now)
+                    .map(Moment::Instant)
+                    .ok_or_else(|| invalid(value));
+            }
+        },
+        _ => None,
+    };
+    day.map(Moment::Day).ok_or_else(|| invalid(value))
+}
+
+fn weekday(name: &str) -> Option<Weekday> {
+    name.parse().ok()
+}
+
+/// The first `day` on or after `today + min_days`
+fn days_ahead(today: NaiveDate, day: Weekday, min_days: u32) -> Option<NaiveDate> {
+    let from = today.checked_add_days(Days::new(u64::from(min_days)))?;
+    let ahead = (7 + day.num_days_from_monday() - from.weekday().num_days_from_monday()) % 7;
+    from.checked_add_days(Days::new(u64::from(ahead)))
+}
+
+/// The last `day` strictly before `today`
+fn days_back(today: NaiveDate, day: Weekday) -> Option<NaiveDate> {
+    let back = (7 + today.weekday().num_days_from_monday() - day.num_days_from_monday()) % 7;
+    today.checked_sub_days(Days::new(u64::from(if back == 0 { 7 } else { back })))
+}
+
+/// `now` shifted by an offset such as `-7d`, `+3d`, `2w`, `4h` or `30m`
+fn offset(value: &str, now: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+    let (sign, rest) = match value.as_bytes().first()? {
+        b'-' => (-1, &value[1..]),
+        b'+' => (1, &value[1..]),
+        _ => (1, value),
+    };
+    let (idx, unit) = rest.char_indices().last()?;
+    let amount: i64 = rest[..idx].parse().ok()?;
+    let amount = amount.checked_mul(sign)?;
+    let duration = match unit {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }?;
+    now.checked_add_signed(duration)
+}
+
+/// Parse a date argument to a calendar day in the clock's timezone
+///
+/// # Errors
+/// Returns `InvalidArgs` if the value is not in one of the accepted forms.
+pub fn parse_date(value: &str, clock: &dyn Clock) -> Result<NaiveDate, CliError> {
+    Ok(match parse_moment(value, clock)? {
+        Moment::Day(day) => day,
+        Moment::Instant(instant) => instant.with_timezone(clock.now().offset()).date_naive(),
+    })
+}
+
+/// Parse a date argument to an instant; days start at midnight in the clock's timezone
+///
+/// # Errors
+/// Returns `InvalidArgs` if the value is not in one of the accepted forms.
+pub fn parse_datetime(value: &str, clock: &dyn Clock) -> Result<DateTime<Utc>, CliError> {
+    match parse_moment(value, clock)? {
+        Moment::Day(day) => day
+            .and_hms_opt(0, 0, 0)
+            .and_then(|midnight| midnight.and_local_timezone(*clock.now().offset()).single())
+            .map(|midnight| midnight.with_timezone(&Utc))
+            .ok_or_else(|| invalid(value)),
+        Moment::Instant(instant) => Ok(instant.with_timezone(&Utc)),
+    }
+}
+
+/// Resolve a date argument against the system clock to `YYYY-MM-DD`
+///
+/// # Errors
+/// Returns `InvalidArgs` if the value is not in one of the accepted forms.
+pub fn parse_calendar_date(value: &str) -> Result<String, CliError> {
+    timeless_date(value, &SystemClock).map(|date| date.0)
+}
+
+/// Parse a date argument to Linear's `TimelessDate` scalar (`YYYY-MM-DD`)
+///
+/// # Errors
+/// Returns `InvalidArgs` if the value is not in one of the accepted forms.
+pub fn timeless_date(value: &str, clock: &dyn Clock) -> Result<TimelessDate, CliError> {
+    parse_date(value, clock).map(|day| TimelessDate(day.format("%Y-%m-%d").to_string()))
+}
+
+/// Parse a date argument to Linear's `DateTime` scalar (UTC, millisecond precision)
+///
+/// # Errors
+/// Returns `InvalidArgs` if the value is not in one of the accepted forms.
+pub fn datetime(value: &str, clock: &dyn Clock) -> Result<DateTimeScalar, CliError> {
+    parse_datetime(value, clock)
+        .map(|instant| DateTimeScalar(instant.to_rfc3339_opts(SecondsFormat::Millis, true)))
+}
@@ -0,0 +1,155 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::documents::{CreateDocumentInput, DocumentClient, UpdateDocumentInput};
+use crate::documents::types::DocumentList;
+use crate::error::CliError;
+use crate::io::{Io, TextSource};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output, format_output_to_writer,
+    get_format_with_provider,
+};
+use secrecy::ExposeSecret;
+
+/// Handle `doc list` command
+pub fn handle_list(
+    project: Option<&str>,
+    limit: usize,
+    client: &dyn DocumentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let documents = client.list_documents(token.expose_secret(), project, limit)?;
+
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&DocumentList(documents), format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle `doc view <id|slug>` command
+pub fn handle_view(
+    reference: &str,
+    client: &dyn DocumentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let document = client.get_document(token.expose_secret(), reference)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&document, format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle `doc create` command
+#[allow(clippy::too_many_arguments)]
+pub fn handle_create(
+    title: &str,
+    project: Option<String>,
+    content: Option<String>,
+    client: &dyn DocumentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let document = client.create_document(
+        token.expose_secret(),
+        CreateDocumentInput {
+            title: title.to_string(),
+            content,
+            project,
+        },
+    )?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&document, format)?);
+
+    Ok(())
+}
+
+/// Handle `doc update <id>` command
+///
+/// With `--editor`, the current content is fetched to pre-fill the buffer.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_update(
+    reference: &str,
+    title: Option<String>,
+    content: Option<TextSource>,
+    client: &dyn DocumentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    if title.is_none() && content.is_none() {
+        return Err(CliError::InvalidArgs(
+            "doc update requires --title or new content".to_string(),
+        ));
+    }
+
+    let token = get_token_with_provider(config, storage)?;
+
+    let content = match content {
+        Some(source) if source.needs_initial() => {
+            let current = client.get_document(token.expose_secret(), reference)?;
+            Some(source.read(io, current.content.as_deref().unwrap_or(""))?)
+        }
+        Some(source) => Some(source.read(io, "")?),
+        None => None,
+    };
+
+    let document = client.update_document(
+        token.expose_secret(),
+        reference,
+        UpdateDocumentInput { title, content },
+    )?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&document, format)?);
+
+    Ok(())
+}
+
+/// Handle `doc delete <id>` command
+pub fn handle_delete(
+    id: &str,
+    client: &dyn DocumentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    client.delete_document(token.expose_secret(), id)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let msg = if matches!(format, OutputFormat::Json) {
+        serde_json::json!({
+            "deleted": true,
+            "id": id,
+        })
+        .to_string()
+    } else {
+        format!("Deleted document {id}")
+    };
+    io.print(&msg);
+
+    Ok(())
+}
@@ -0,0 +1,280 @@
+use crate::client::queries;
+use crate::error::CliError;
+use crate::output::{
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+
+/// Linear document (a project doc or workspace page)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    pub id: String,
+    pub title: String,
+    /// Short ID used in the document's URL
+    pub slug_id: String,
+    pub url: String,
+    pub icon: Option<String>,
+    /// Markdown content; omitted from listings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub creator_name: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl TableFormatter for Document {
+    fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        let mut rows = vec![(Cow::Borrowed("Title"), Cow::Borrowed(self.title.as_str()))];
+
+        if let Some(project) = &self.project_name {
+            rows.push((Cow::Borrowed("Project"), Cow::Borrowed(project.as_str())));
+        }
+        if let Some(creator) = &self.creator_name {
+            rows.push((Cow::Borrowed("Creator"), Cow::Borrowed(creator.as_str())));
+        }
+
+        rows.push((
+            Cow::Borrowed("Updated"),
+            Cow::Borrowed(self.updated_at.as_str()),
+        ));
+        rows.push((Cow::Borrowed("URL"), Cow::Borrowed(self.url.as_str())));
+        rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.id.as_str())));
+
+        if let Some(content) = &self.content {
+            rows.push((Cow::Borrowed("Content"), Cow::Borrowed(content.as_str())));
+        }
+
+        rows
+    }
+}
+
+impl MarkdownFormatter for Document {
+    fn markdown_capacity_hint(&self) -> usize {
+        150 + self.title.len()
+            + self.url.len()
+            + self.id.len()
+            + self.content.as_ref().map_or(0, |c| c.len())
+    }
+
+    fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
+        writeln!(output, "# {}\n", self.title)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        if let Some(project) = &self.project_name {
+            writeln!(output, "**Project:** {}", project)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        if let Some(creator) = &self.creator_name {
+            writeln!(output, "**Creator:** {}", creator)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        writeln!(output, "**Updated:** {}", self.updated_at)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        writeln!(output, "**URL:** {}", self.url)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        if let Some(content) = self.content.as_deref().filter(|c| !c.trim().is_empty()) {
+            writeln!(output, "\n---\n\n{}", content.trim_end())
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Formattable for Document {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        DocumentList(vec![self.clone()]).to_csv()
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        fast_markdown_formatter(self)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        generic_table_formatter(self)
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
+}
+
+/// Wrapper for a list of documents
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct DocumentList(pub Vec<Document>);
+
+impl Formattable for DocumentList {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_list_formatter(&self.0)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record([
+            "id",
+            "slug_id",
+            "title",
+            "project",
+            "creator",
+            "updated_at",
+            "url",
+        ])
+        .csv_err("Failed to write CSV header")?;
+
+        for document in &self.0 {
+            wtr.write_record([
+                document.id.as_str(),
+                document.slug_id.as_str(),
+                document.title.as_str(),
+                document.project_name.as_deref().unwrap_or(""),
+                document.creator_name.as_deref().unwrap_or(""),
+                document.updated_at.as_str(),
+                document.url.as_str(),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        let capacity = self
+            .0
+            .iter()
+            .map(|d| 40 + d.title.len() + d.url.len())
+            .sum();
+        let mut output = String::with_capacity(capacity);
+
+        writeln!(output, "## Documents ({})\n", self.0.len())
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        for document in &self.0 {
+            write!(output, "- [{}]({})", document.title, document.url)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            if let Some(project) = &document.project_name {
+                write!(output, " ({project})")
+                    .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            }
+            writeln!(output)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(output)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new("Title").flexible(),
+                TableColumn::new("Project"),
+                TableColumn::new("Updated"),
+                TableColumn::new("Slug"),
+                TableColumn::new("Creator").wide_only(),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
+            |document| {
+                vec![
+                    document.title.clone(),
+                    document.project_name.clone().unwrap_or_default(),
+                    document.updated_at.clone(),
+                    document.slug_id.clone(),
+                    document.creator_name.clone().unwrap_or_default(),
+                    document.id.clone(),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
+}
+
+impl From<queries::DocumentNode> for Document {
+    fn from(node: queries::DocumentNode) -> Self {
+        Document {
+            id: node.id.inner().to_string(),
+            title: node.title,
+            slug_id: node.slug_id,
+            url: node.url,
+            icon: node.icon,
+            content: Some(node.content.unwrap_or_default()),
+            project_id: node.project.as_ref().map(|p| p.id.inner().to_string()),
+            project_name: node.project.map(|p| p.name),
+            creator_name: node.creator.map(|u| u.name),
+            created_at: node.created_at.0,
+            updated_at: node.updated_at.0,
+        }
+    }
+}
+
+impl From<queries::DocumentSummaryNode> for Document {
+    fn from(node: queries::DocumentSummaryNode) -> Self {
+        Document {
+            id: node.id.inner().to_string(),
+            title: node.title,
+            slug_id: node.slug_id,
+            url: node.url,
+            icon: node.icon,
+            content: None,
+            project_id: node.project.as_ref().map(|p| p.id.inner().to_string()),
+            project_name: node.project.map(|p| p.name),
+            creator_name: node.creator.map(|u| u.name),
+            created_at: node.created_at.0,
+            updated_at: node.updated_at.0,
+        }
+    }
+}
+
+/// How a user-supplied document reference should be looked up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocumentRef {
+    /// Document UUID
+    Id(String),
+    /// URL slug ID (the hex suffix of the document URL)
+    Slug(String),
+}
+
+impl DocumentRef {
+    /// Parse a document UUID, slug ID, `title-slug` or full document URL
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim().trim_end_matches('/');
+        if crate::client::issues::is_uuid_like(value) {
+            return DocumentRef::Id(value.to_string());
+        }
+
+        let segment = value.rsplit('/').next().unwrap_or(value);
+        let slug = segment.rsplit('-').next().unwrap_or(segment);
+        DocumentRef::Slug(slug.to_string())
+    }
+}
@@ -0,0 +1,186 @@
+//! Git integration for issue commands that work from branches and commits
+//!
+//! Shells out to the `git` executable rather than linking a git library, so
+//! the user's configuration (hooks, credential helpers, worktrees) applies.
+
+use crate::error::CliError;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// A git working tree that commands run in
+#[derive(Debug, Clone)]
+pub struct GitRepo {
+    dir: PathBuf,
+}
+
+/// Result of [`GitRepo::checkout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Checkout {
+    /// The branch did not exist and was created from `HEAD`
+    Created,
+    /// An existing branch was checked out
+    Switched,
+    /// The branch was already checked out
+    AlreadyOn,
+}
+
+impl GitRepo {
+    /// The repository containing the current directory
+    ///
+    /// # Errors
+    /// Returns `General` if the current directory cannot be determined.
+    pub fn current_dir() -> Result<Self, CliError> {
+        std::env::current_dir()
+            .map(Self::at)
+            .map_err(|e| CliError::General(format!("Failed to read current directory: {e}")))
+    }
+
+    /// The repository containing `dir`
+    #[must_use]
+    pub fn at(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn run(&self, args: &[&str]) -> Result<Output, CliError> {
+        Command::new("git")
+            .arg("-C")
+            .arg(&self.dir)
+            .args(args)
+            .output()
+            .map_err(|e| CliError::General(format!("Failed to run git: {e}")))
+    }
+
+    /// Run git and return its trimmed stdout, failing on a non-zero exit
+    fn git(&self, args: &[&str]) -> Result<String, CliError> {
+        let output = self.run(args)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(CliError::General(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                stderr.trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Name of the checked-out branch
+    ///
+    /// # Errors
+    /// Returns `InvalidArgs` outside a git repository or with a detached `HEAD`.
+    pub fn current_branch(&self) -> Result<String, CliError> {
+        let output = self.run(&["symbolic-ref", "--quiet", "--short", "HEAD"])?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).trim().to_string());
+        }
+        if self.run(&["rev-parse", "--git-dir"])?.status.success() {
+            Err(CliError::InvalidArgs(
+                "Not on a branch (HEAD is detached)".to_string(),
+            ))
+        } else {
+            Err(CliError::InvalidArgs(format!(
+                "{} is not inside a git repository",
+                self.dir.display()
+            )))
+        }
+    }
+
+    /// Whether a local branch called `name` exists
+    ///
+    /// # Errors
+    /// Returns `General` if git cannot be run.
+    pub fn branch_exists(&self, name: &str) -> Result<bool, CliError> {
+        let reference = format!("refs/heads/{name}");
+        Ok(self
+            .run(&["rev-parse", "--verify", "--quiet", &reference])?
+            .status
+            .success())
+    }
+
+    /// Full messages of the commits in `range` (e.g. `v1.2.0..HEAD`), newest first
+    ///
+    /// # Errors
+    /// Returns `General` if git rejects the range or is not run in a repository.
+    pub fn commit_messages(&self, range: &str) -> Result<Vec<String>, CliError> {
+        if range.starts_with('-') {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid revision range '{range}'"
+            )));
+        }
+        let log = self.git(&["log", "--format=%B%x00", range, "--"])?;
+        Ok(log
+            .split('\0')
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Check out `name`, creating it from `HEAD` if it does not exist
+    ///
+    /// # Errors
+    /// Returns `InvalidArgs` outside a git repository and `General` if git
+    /// refuses the checkout (e.g. local changes would be overwritten).
+    pub fn checkout(&self, name: &str) -> Result<Checkout, CliError> {
+        if let Ok(current) = self.current_branch()
+            && current == name
+        {
+            return Ok(Checkout::AlreadyOn);
+        }
+        if self.branch_exists(name)? {
+            self.git(&["checkout", "--quiet", name])?;
+            return Ok(Checkout::Switched);
+        }
+        if !self.run(&["rev-parse", "--git-dir"])?.status.success() {
+            return Err(CliError::InvalidArgs(format!(
+                "{} is not inside a git repository",
+                self.dir.display()
+            )));
+        }
+        self.git(&["checkout", "--quiet", "-b", name])?;
+        Ok(Checkout::Created)
+    }
+}
+
+/// Find an issue identifier (`ENG-123`) in a branch name
+///
+/// Matches Linear's branch names (`alice/eng-123-fix-login`) as well as
+/// hand-made ones (`feature/ENG-123`, `eng-123`); the first match wins.
+#[must_use]
+pub fn issue_identifier_from_branch(branch: &str) -> Option<String> {
+    issue_identifiers(branch).next()
+}
+
+/// Every issue identifier in `text`, uppercased, in order of appearance
+///
+/// An identifier is a team key starting with a letter, a dash and a number,
+/// on word boundaries: `ENG-123`, `(eng-7)`, `Fixes OPS2-9.` all match, while
+/// `42ENG-1` and `ENG-12abc` do not. Text such as `UTF-8` also matches, so
+/// callers should check the key against the workspace's teams.
+pub fn issue_identifiers(text: &str) -> impl Iterator<Item = String> + '_ {
+    let bytes = text.as_bytes();
+    (0..bytes.len()).filter_map(move |start| {
+        if !bytes[start].is_ascii_alphabetic()
+            || (start > 0 && bytes[start - 1].is_ascii_alphanumeric())
+        {
+            return None;
+        }
+        let key_end = start
+            + bytes[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_alphanumeric())
+                .count();
+        if bytes.get(key_end) != Some(&b'-') {
+            return None;
+        }
+        let digits = bytes[key_end + 1..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        let end = key_end + 1 + digits;
+        if digits == 0 || bytes.get(end).is_some_and(u8::is_ascii_alphanumeric) {
+            return None;
+        }
+        Some(text[start..end].to_ascii_uppercase())
+    })
+}
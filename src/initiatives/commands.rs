@@ -0,0 +1,169 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::initiatives::{CreateInitiativeInput, InitiativeClient, UpdateInitiativeInput};
+use crate::dates::parse_calendar_date;
+use crate::error::CliError;
+use crate::initiatives::types::{InitiativeList, InitiativeStatus, InitiativeUpdateList};
+use crate::io::Io;
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output, format_output_to_writer,
+    get_format_with_provider,
+};
+use secrecy::ExposeSecret;
+
+/// Handle `initiative list` command
+pub fn handle_list(
+    status: Option<InitiativeStatus>,
+    limit: usize,
+    client: &dyn InitiativeClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let initiatives = client.list_initiatives(token.expose_secret(), status, limit)?;
+
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&InitiativeList(initiatives), format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle `initiative view <id|slug|name>` command
+pub fn handle_view(
+    reference: &str,
+    client: &dyn InitiativeClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let initiative = client.get_initiative(token.expose_secret(), reference)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&initiative, format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle `initiative create` command
+#[allow(clippy::too_many_arguments)]
+pub fn handle_create(
+    name: &str,
+    description: Option<String>,
+    status: Option<InitiativeStatus>,
+    target_date: Option<String>,
+    client: &dyn InitiativeClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let target_date = target_date
+        .as_deref()
+        .map(parse_calendar_date)
+        .transpose()?;
+    let token = get_token_with_provider(config, storage)?;
+
+    let initiative = client.create_initiative(
+        token.expose_secret(),
+        CreateInitiativeInput {
+            name: name.to_string(),
+            description,
+            status,
+            target_date,
+        },
+    )?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&initiative, format)?);
+
+    Ok(())
+}
+
+/// Handle `initiative update <id>` command
+pub fn handle_update(
+    reference: &str,
+    input: UpdateInitiativeInput,
+    client: &dyn InitiativeClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    if input == UpdateInitiativeInput::default() {
+        return Err(CliError::InvalidArgs(
+            "initiative update requires at least one field to change".to_string(),
+        ));
+    }
+    let input = UpdateInitiativeInput {
+        target_date: input
+            .target_date
+            .as_deref()
+            .map(parse_calendar_date)
+            .transpose()?,
+        ..input
+    };
+
+    let token = get_token_with_provider(config, storage)?;
+
+    let initiative = client.update_initiative(token.expose_secret(), reference, input)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&initiative, format)?);
+
+    Ok(())
+}
+
+/// Handle `initiative add-project <initiative> <project>` command
+///
+/// Prints the initiative with its updated project roll-up.
+pub fn handle_add_project(
+    initiative: &str,
+    project: &str,
+    client: &dyn InitiativeClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let initiative = client.add_project(token.expose_secret(), initiative, project)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&initiative, format)?);
+
+    Ok(())
+}
+
+/// Handle `initiative updates <id>` command
+pub fn handle_updates(
+    reference: &str,
+    limit: usize,
+    client: &dyn InitiativeClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let updates = client.list_updates(token.expose_secret(), reference, limit)?;
+
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&InitiativeUpdateList(updates), format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
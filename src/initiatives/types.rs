@@ -0,0 +1,569 @@
+use crate::client::queries;
+use crate::error::CliError;
+use crate::output::{
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+
+/// Project linked to an initiative
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitiativeProject {
+    pub id: String,
+    pub name: String,
+    pub slug_id: String,
+    pub url: String,
+    pub status_name: String,
+    pub status_type: String,
+    pub progress: f64, // 0.0 to 1.0
+    pub target_date: Option<String>,
+}
+
+/// Linear initiative with its projects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Initiative {
+    pub id: String,
+    pub name: String,
+    /// Short ID used in the initiative's URL
+    pub slug_id: String,
+    pub url: String,
+    pub description: Option<String>,
+    /// `Planned`, `Active` or `Completed`
+    pub status: String,
+    /// Health from the latest update: `onTrack`, `atRisk` or `offTrack`
+    pub health: Option<String>,
+    pub target_date: Option<String>,
+    pub owner_name: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub projects: Vec<InitiativeProject>,
+}
+
+impl Initiative {
+    /// Average progress of the linked projects, or `None` without projects
+    #[must_use]
+    pub fn progress(&self) -> Option<f64> {
+        if self.projects.is_empty() {
+            return None;
+        }
+        let total: f64 = self.projects.iter().map(|p| p.progress).sum();
+        Some(total / self.projects.len() as f64)
+    }
+
+    fn progress_label(&self) -> String {
+        self.progress()
+            .map(format_progress_percent)
+            .unwrap_or_default()
+    }
+}
+
+fn format_progress_percent(progress: f64) -> String {
+    format!("{:.0}%", progress * 100.0)
+}
+
+/// Human-readable label for an initiative health value
+#[must_use]
+pub fn health_label(health: &str) -> &str {
+    match health {
+        "onTrack" => "On track",
+        "atRisk" => "At risk",
+        "offTrack" => "Off track",
+        other => other,
+    }
+}
+
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+impl TableFormatter for Initiative {
+    fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        let mut rows = vec![
+            (Cow::Borrowed("Name"), Cow::Borrowed(self.name.as_str())),
+            (Cow::Borrowed("Status"), Cow::Borrowed(self.status.as_str())),
+        ];
+
+        if let Some(health) = &self.health {
+            rows.push((Cow::Borrowed("Health"), Cow::Borrowed(health_label(health))));
+        }
+        if let Some(owner) = &self.owner_name {
+            rows.push((Cow::Borrowed("Owner"), Cow::Borrowed(owner.as_str())));
+        }
+        if let Some(target) = &self.target_date {
+            rows.push((Cow::Borrowed("Target"), Cow::Borrowed(target.as_str())));
+        }
+        if let Some(progress) = self.progress() {
+            rows.push((
+                Cow::Borrowed("Progress"),
+                Cow::Owned(format_progress_percent(progress)),
+            ));
+        }
+        for project in &self.projects {
+            rows.push((
+                Cow::Borrowed("Project"),
+                Cow::Owned(format!(
+                    "{} ({}, {})",
+                    project.name,
+                    project.status_name,
+                    format_progress_percent(project.progress)
+                )),
+            ));
+        }
+
+        rows.push((Cow::Borrowed("URL"), Cow::Borrowed(self.url.as_str())));
+        rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.id.as_str())));
+
+        rows
+    }
+}
+
+impl MarkdownFormatter for Initiative {
+    fn markdown_capacity_hint(&self) -> usize {
+        200 + self.name.len()
+            + self.url.len()
+            + self.description.as_ref().map_or(0, |d| d.len())
+            + self
+                .projects
+                .iter()
+                .map(|p| 40 + p.name.len() + p.url.len() + p.status_name.len())
+                .sum::<usize>()
+    }
+
+    fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
+        writeln!(output, "# {}\n", self.name)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        writeln!(output, "**Status:** {}", self.status)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        if let Some(health) = &self.health {
+            writeln!(output, "**Health:** {}", health_label(health))
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        if let Some(owner) = &self.owner_name {
+            writeln!(output, "**Owner:** {}", owner)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        if let Some(target) = &self.target_date {
+            writeln!(output, "**Target:** {}", target)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        if let Some(progress) = self.progress() {
+            writeln!(
+                output,
+                "**Progress:** {} across {} project(s)",
+                format_progress_percent(progress),
+                self.projects.len()
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        writeln!(output, "**URL:** {}", self.url)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        if let Some(description) = self.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            writeln!(output, "\n{}", description.trim_end())
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        writeln!(output, "\n## Projects ({})\n", self.projects.len())
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        if self.projects.is_empty() {
+            writeln!(output, "No projects linked.")
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            return Ok(());
+        }
+
+        writeln!(
+            output,
+            "| Project | Status | Progress | Target |\n|---|---|---|---|"
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        for project in &self.projects {
+            writeln!(
+                output,
+                "| [{}]({}) | {} | {} | {} |",
+                escape_cell(&project.name),
+                project.url,
+                escape_cell(&project.status_name),
+                format_progress_percent(project.progress),
+                project.target_date.as_deref().unwrap_or("")
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Formattable for Initiative {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        InitiativeList(vec![self.clone()]).to_csv()
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        fast_markdown_formatter(self)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        generic_table_formatter(self)
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
+}
+
+/// Wrapper for a list of initiatives
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct InitiativeList(pub Vec<Initiative>);
+
+impl Formattable for InitiativeList {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_list_formatter(&self.0)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record([
+            "id",
+            "slug_id",
+            "name",
+            "status",
+            "health",
+            "progress",
+            "projects",
+            "target_date",
+            "owner",
+            "url",
+        ])
+        .csv_err("Failed to write CSV header")?;
+
+        for initiative in &self.0 {
+            let progress = initiative
+                .progress()
+                .map(|p| format!("{p:.2}"))
+                .unwrap_or_default();
+            let projects = initiative.projects.len().to_string();
+            wtr.write_record([
+                initiative.id.as_str(),
+                initiative.slug_id.as_str(),
+                initiative.name.as_str(),
+                initiative.status.as_str(),
+                initiative.health.as_deref().unwrap_or(""),
+                progress.as_str(),
+                projects.as_str(),
+                initiative.target_date.as_deref().unwrap_or(""),
+                initiative.owner_name.as_deref().unwrap_or(""),
+                initiative.url.as_str(),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        let capacity = self.0.iter().map(|i| 60 + i.name.len() + i.url.len()).sum();
+        let mut output = String::with_capacity(capacity);
+
+        writeln!(output, "## Initiatives ({})\n", self.0.len())
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        for initiative in &self.0 {
+            write!(
+                output,
+                "- [{}]({}) — {}",
+                initiative.name, initiative.url, initiative.status
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            if let Some(health) = &initiative.health {
+                write!(output, ", {}", health_label(health))
+                    .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            }
+            if let Some(progress) = initiative.progress() {
+                write!(
+                    output,
+                    ", {} across {} project(s)",
+                    format_progress_percent(progress),
+                    initiative.projects.len()
+                )
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            }
+            writeln!(output)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(output)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new("Name").flexible(),
+                TableColumn::new("Status"),
+                TableColumn::new("Health"),
+                TableColumn::new("Progress"),
+                TableColumn::new("Projects"),
+                TableColumn::new("Target"),
+                TableColumn::new("Owner").wide_only(),
+                TableColumn::new("Slug").wide_only(),
+            ],
+            layout,
+            |initiative| {
+                vec![
+                    initiative.name.clone(),
+                    initiative.status.clone(),
+                    initiative
+                        .health
+                        .as_deref()
+                        .map(health_label)
+                        .unwrap_or_default()
+                        .to_string(),
+                    initiative.progress_label(),
+                    initiative.projects.len().to_string(),
+                    initiative.target_date.clone().unwrap_or_default(),
+                    initiative.owner_name.clone().unwrap_or_default(),
+                    initiative.slug_id.clone(),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
+}
+
+/// Status update posted on an initiative
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitiativeUpdate {
+    pub id: String,
+    pub body: String,
+    /// `onTrack`, `atRisk` or `offTrack`
+    pub health: String,
+    pub author_name: String,
+    pub created_at: String,
+    pub url: String,
+}
+
+/// Wrapper for a list of initiative updates
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct InitiativeUpdateList(pub Vec<InitiativeUpdate>);
+
+impl Formattable for InitiativeUpdateList {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_list_formatter(&self.0)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record(["id", "created_at", "author", "health", "body", "url"])
+            .csv_err("Failed to write CSV header")?;
+
+        for update in &self.0 {
+            wtr.write_record([
+                update.id.as_str(),
+                update.created_at.as_str(),
+                update.author_name.as_str(),
+                update.health.as_str(),
+                update.body.as_str(),
+                update.url.as_str(),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        let capacity = self
+            .0
+            .iter()
+            .map(|u| 60 + u.author_name.len() + u.body.len())
+            .sum();
+        let mut output = String::with_capacity(capacity);
+
+        writeln!(output, "## Updates ({})\n", self.0.len())
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        for (i, update) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(output, "---\n")
+                    .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            }
+            writeln!(
+                output,
+                "### {} · {}\n**Created:** {}\n\n{}\n",
+                update.author_name,
+                health_label(&update.health),
+                update.created_at,
+                update.body.trim_end()
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(output)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new("Created"),
+                TableColumn::new("Author"),
+                TableColumn::new("Health"),
+                TableColumn::new("Update").flexible(),
+            ],
+            layout,
+            |update| {
+                vec![
+                    update.created_at.clone(),
+                    update.author_name.clone(),
+                    health_label(&update.health).to_string(),
+                    update.body.lines().next().unwrap_or_default().to_string(),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
+}
+
+fn status_str(status: queries::InitiativeStatus) -> &'static str {
+    match status {
+        queries::InitiativeStatus::Planned => "Planned",
+        queries::InitiativeStatus::Active => "Active",
+        queries::InitiativeStatus::Completed => "Completed",
+    }
+}
+
+fn health_str(health: queries::InitiativeUpdateHealthType) -> &'static str {
+    match health {
+        queries::InitiativeUpdateHealthType::OnTrack => "onTrack",
+        queries::InitiativeUpdateHealthType::AtRisk => "atRisk",
+        queries::InitiativeUpdateHealthType::OffTrack => "offTrack",
+    }
+}
+
+impl From<queries::InitiativeProjectNode> for InitiativeProject {
+    fn from(node: queries::InitiativeProjectNode) -> Self {
+        let status_type = match node.status.status_type {
+            queries::ProjectStatusType::Backlog => "backlog",
+            queries::ProjectStatusType::Planned => "planned",
+            queries::ProjectStatusType::Started => "started",
+            queries::ProjectStatusType::Paused => "paused",
+            queries::ProjectStatusType::Completed => "completed",
+            queries::ProjectStatusType::Canceled => "canceled",
+        };
+
+        InitiativeProject {
+            id: node.id.inner().to_string(),
+            name: node.name,
+            slug_id: node.slug_id,
+            url: node.url,
+            status_name: node.status.name,
+            status_type: status_type.to_string(),
+            progress: node.progress,
+            target_date: node.target_date.map(|d| d.0),
+        }
+    }
+}
+
+impl From<queries::InitiativeNode> for Initiative {
+    fn from(node: queries::InitiativeNode) -> Self {
+        Initiative {
+            id: node.id.inner().to_string(),
+            name: node.name,
+            slug_id: node.slug_id,
+            url: node.url,
+            description: node.description,
+            status: status_str(node.status).to_string(),
+            health: node.health.map(|h| health_str(h).to_string()),
+            target_date: node.target_date.map(|d| d.0),
+            owner_name: node.owner.map(|u| u.name),
+            created_at: node.created_at.0,
+            updated_at: node.updated_at.0,
+            projects: node.projects.nodes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<queries::InitiativeUpdateNode> for InitiativeUpdate {
+    fn from(node: queries::InitiativeUpdateNode) -> Self {
+        InitiativeUpdate {
+            id: node.id.inner().to_string(),
+            body: node.body,
+            health: health_str(node.health).to_string(),
+            author_name: node.user.name,
+            created_at: node.created_at.0,
+            url: node.url,
+        }
+    }
+}
+
+/// Initiative status accepted on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum InitiativeStatus {
+    Planned,
+    Active,
+    Completed,
+}
+
+impl InitiativeStatus {
+    /// Status name as the API spells it
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InitiativeStatus::Planned => "Planned",
+            InitiativeStatus::Active => "Active",
+            InitiativeStatus::Completed => "Completed",
+        }
+    }
+}
+
+impl From<InitiativeStatus> for queries::InitiativeStatus {
+    fn from(status: InitiativeStatus) -> Self {
+        match status {
+            InitiativeStatus::Planned => queries::InitiativeStatus::Planned,
+            InitiativeStatus::Active => queries::InitiativeStatus::Active,
+            InitiativeStatus::Completed => queries::InitiativeStatus::Completed,
+        }
+    }
+}
@@ -1,3 +1,5 @@
 pub mod terminal;
+pub mod text_source;
 
 pub use terminal::{Io, MockIo, RealIo};
+pub use text_source::TextSource;
@@ -1,6 +1,10 @@
 use crate::error::CliError;
-use std::io::{self, Write};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Trait for terminal I/O operations
 pub trait Io: Send + Sync {
@@ -20,6 +24,23 @@ pub trait Io: Send + Sync {
 
     /// Print an error message to stderr
     fn print_error(&self, message: &str);
+
+    /// Width of the terminal stdout is attached to, or `None` when stdout is
+    /// not a terminal (piped or redirected output).
+    fn terminal_width(&self) -> Option<u16> {
+        None
+    }
+
+    /// Read all of stdin as text
+    fn read_stdin(&self) -> Result<String, CliError> {
+        Err(CliError::InvalidArgs("stdin is not available".to_string()))
+    }
+
+    /// Let the user edit `initial` in their editor and return the result
+    fn edit_text(&self, initial: &str) -> Result<String, CliError> {
+        let _ = initial;
+        Err(CliError::InvalidArgs("no editor is available".to_string()))
+    }
 }
 
 /// Production implementation using real stdin/stdout/stderr
@@ -49,6 +70,88 @@ impl Io for RealIo {
     fn print_error(&self, message: &str) {
         eprintln!("{}", message);
     }
+
+    fn terminal_width(&self) -> Option<u16> {
+        terminal_size::terminal_size().map(|(terminal_size::Width(width), _)| width)
+    }
+
+    fn read_stdin(&self) -> Result<String, CliError> {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|e| CliError::InvalidArgs(format!("Failed to read stdin: {e}")))?;
+        Ok(contents)
+    }
+
+    fn edit_text(&self, initial: &str) -> Result<String, CliError> {
+        let editor = ["VISUAL", "EDITOR"]
+            .iter()
+            .filter_map(|key| std::env::var(key).ok())
+            .find(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| "vi".to_string());
+
+        let path = create_editor_file(initial)
+            .map_err(|e| CliError::General(format!("Failed to create temp file: {e}")))?;
+
+        // Editors are often configured with arguments, e.g. `code --wait`
+        let mut words = editor.split_whitespace();
+        let program = words.next().unwrap_or("vi");
+        let status = Command::new(program).args(words).arg(&path).status();
+
+        let result = match status {
+            Ok(status) if status.success() => std::fs::read_to_string(&path)
+                .map_err(|e| CliError::General(format!("Failed to read edited text: {e}"))),
+            Ok(status) => Err(CliError::InvalidArgs(format!(
+                "Editor '{editor}' exited with {status}"
+            ))),
+            Err(e) => Err(CliError::InvalidArgs(format!(
+                "Failed to launch editor '{editor}': {e}"
+            ))),
+        };
+        let _ = std::fs::remove_file(&path);
+        result
+    }
+}
+
+/// Create a Markdown file holding `initial` in the temp directory for an editor session
+///
+/// The buffer may hold a private issue's description, so the file is created
+/// exclusively (never following an existing file or symlink) and, on unix,
+/// readable only by the current user.
+pub fn create_editor_file(initial: &str) -> io::Result<PathBuf> {
+    let mut attempt = 0;
+    loop {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.subsec_nanos());
+        let path = std::env::temp_dir().join(format!(
+            "linear-cli-{}-{nanos}-{attempt}.md",
+            std::process::id()
+        ));
+        match open_private_file(&path) {
+            Ok(mut file) => {
+                file.write_all(initial.as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists && attempt < 16 => attempt += 1,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn open_private_file(path: &Path) -> io::Result<File> {
+    use std::os::unix::fs::OpenOptionsExt;
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)
+}
+
+#[cfg(not(unix))]
+fn open_private_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 /// Mock implementation for testing
@@ -56,6 +159,12 @@ pub struct MockIo {
     pub input: String,
     pub stdout: Arc<Mutex<Vec<String>>>,
     pub stderr: Arc<Mutex<Vec<String>>>,
+    /// Terminal width reported to callers; `None` simulates piped output
+    pub terminal_width: Option<u16>,
+    /// Text the simulated editor saves; `None` simulates an editor failure
+    pub editor_output: Option<String>,
+    /// Initial buffers the editor was opened with
+    pub editor_input: Arc<Mutex<Vec<String>>>,
 }
 
 impl Default for MockIo {
@@ -71,6 +180,9 @@ impl MockIo {
             input: String::new(),
             stdout: Arc::new(Mutex::new(Vec::new())),
             stderr: Arc::new(Mutex::new(Vec::new())),
+            terminal_width: None,
+            editor_output: None,
+            editor_input: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -80,9 +192,26 @@ impl MockIo {
             input,
             stdout: Arc::new(Mutex::new(Vec::new())),
             stderr: Arc::new(Mutex::new(Vec::new())),
+            terminal_width: None,
+            editor_output: None,
+            editor_input: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
+    /// Simulate stdout attached to a terminal of the given width
+    #[must_use]
+    pub fn with_terminal_width(mut self, width: u16) -> Self {
+        self.terminal_width = Some(width);
+        self
+    }
+
+    /// Simulate an editor session that saves `text`
+    #[must_use]
+    pub fn with_editor_output(mut self, text: &str) -> Self {
+        self.editor_output = Some(text.to_string());
+        self
+    }
+
     /// Get captured stdout lines for assertions
     #[must_use]
     pub fn stdout_lines(&self) -> Vec<String> {
@@ -115,4 +244,20 @@ impl Io for MockIo {
     fn print_error(&self, message: &str) {
         self.stderr.lock().unwrap().push(message.to_string());
     }
+
+    fn terminal_width(&self) -> Option<u16> {
+        self.terminal_width
+    }
+
+    /// Stdin content is the same `input` used for secrets
+    fn read_stdin(&self) -> Result<String, CliError> {
+        Ok(self.input.clone())
+    }
+
+    fn edit_text(&self, initial: &str) -> Result<String, CliError> {
+        self.editor_input.lock().unwrap().push(initial.to_string());
+        self.editor_output
+            .clone()
+            .ok_or_else(|| CliError::InvalidArgs("Editor exited with an error".to_string()))
+    }
 }
@@ -0,0 +1,56 @@
+use crate::error::CliError;
+use crate::io::Io;
+
+/// Where a long-form text argument (issue description, comment body) comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextSource {
+    /// Passed inline on the command line
+    Inline(String),
+    /// Read from a file, or stdin for `-`
+    File(String),
+    /// Written in `$VISUAL`/`$EDITOR`
+    Editor,
+}
+
+impl TextSource {
+    /// Build a source from the mutually exclusive `--x`, `--x-file` and `--editor` flags
+    #[must_use]
+    pub fn from_flags(inline: Option<String>, file: Option<String>, editor: bool) -> Option<Self> {
+        if editor {
+            Some(TextSource::Editor)
+        } else if let Some(path) = file {
+            Some(TextSource::File(path))
+        } else {
+            inline.map(TextSource::Inline)
+        }
+    }
+
+    /// Resolve the text; `initial` pre-fills the editor buffer
+    ///
+    /// # Errors
+    /// Returns `InvalidArgs` if the file or stdin cannot be read, or if the
+    /// editor fails or is closed with an empty buffer.
+    pub fn read(self, io: &dyn Io, initial: &str) -> Result<String, CliError> {
+        match self {
+            TextSource::Inline(text) => Ok(text),
+            TextSource::File(path) if path == "-" => io.read_stdin(),
+            TextSource::File(path) => std::fs::read_to_string(&path)
+                .map_err(|e| CliError::InvalidArgs(format!("Failed to read '{path}': {e}"))),
+            TextSource::Editor => {
+                let text = io.edit_text(initial)?;
+                if text.trim().is_empty() {
+                    return Err(CliError::InvalidArgs(
+                        "Aborting: editor text is empty".to_string(),
+                    ));
+                }
+                Ok(text.trim_end().to_string())
+            }
+        }
+    }
+
+    /// Whether resolving this source needs the current text to pre-fill the editor
+    #[must_use]
+    pub fn needs_initial(&self) -> bool {
+        matches!(self, TextSource::Editor)
+    }
+}
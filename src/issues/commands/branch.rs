@@ -0,0 +1,220 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::issues::IssueClient;
+use crate::client::teams::TeamClient;
+use crate::error::CliError;
+use crate::git::{Checkout, GitRepo, issue_identifier_from_branch, issue_identifiers};
+use crate::io::Io;
+use crate::issues::commands::view::{ViewDeps, handle_view};
+use crate::issues::types::{Issue, IssueList};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
+use secrecy::ExposeSecret;
+use std::collections::HashSet;
+
+/// Upper bound on teams fetched to recognise identifiers in commit messages
+const TEAM_LIMIT: usize = 250;
+
+/// Fetch an issue and its suggested branch name
+fn issue_branch(
+    identifier: &str,
+    client: &dyn IssueClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+) -> Result<(Issue, String), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+    let issue = client.get_issue(token.expose_secret(), identifier)?;
+    let branch = issue
+        .branch_name
+        .clone()
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| {
+            CliError::General(format!("Issue {} has no branch name", issue.identifier))
+        })?;
+    Ok((issue, branch))
+}
+
+/// Handle `issue branch <id>`: print Linear's suggested git branch name
+pub fn handle_branch(
+    identifier: &str,
+    client: &dyn IssueClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let (issue, branch) = issue_branch(identifier, client, config, storage)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let msg = if matches!(format, OutputFormat::Json) {
+        serde_json::json!({
+            "identifier": issue.identifier,
+            "branch": branch,
+        })
+        .to_string()
+    } else {
+        branch
+    };
+    io.print(&msg);
+
+    Ok(())
+}
+
+/// Handle `issue checkout <id>`: create or switch to the issue's branch
+pub fn handle_checkout(
+    identifier: &str,
+    repo: &GitRepo,
+    client: &dyn IssueClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let (issue, branch) = issue_branch(identifier, client, config, storage)?;
+    let checkout = repo.checkout(&branch)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let msg = if matches!(format, OutputFormat::Json) {
+        serde_json::json!({
+            "identifier": issue.identifier,
+            "branch": branch,
+            "created": checkout == Checkout::Created,
+        })
+        .to_string()
+    } else {
+        match checkout {
+            Checkout::Created => format!("Switched to a new branch '{branch}'"),
+            Checkout::Switched => format!("Switched to branch '{branch}'"),
+            Checkout::AlreadyOn => format!("Already on '{branch}'"),
+        }
+    };
+    io.print(&msg);
+
+    Ok(())
+}
+
+/// Handle `issue current`: view the issue named by the checked-out branch
+pub fn handle_current(
+    repo: &GitRepo,
+    with_comments: bool,
+    comment_limit: usize,
+    deps: &ViewDeps,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let branch = repo.current_branch()?;
+    let identifier = issue_identifier_from_branch(&branch).ok_or_else(|| {
+        CliError::InvalidArgs(format!(
+            "Branch '{branch}' does not contain an issue identifier like ENG-123"
+        ))
+    })?;
+
+    handle_view(&identifier, with_comments, comment_limit, deps, format_flag)
+}
+
+/// Handle `issue from-branch <name>`: show the issue linked to a git branch
+///
+/// Uses Linear's branch search, falling back to an identifier in the branch
+/// name (`feature/ENG-123`) for branches Linear does not know about.
+pub fn handle_from_branch(
+    branch: &str,
+    client: &dyn IssueClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let issue = match client.find_issue_by_branch(token.expose_secret(), branch) {
+        Err(CliError::NotFound(msg)) => match issue_identifier_from_branch(branch) {
+            Some(identifier) => client.get_issue(token.expose_secret(), &identifier)?,
+            None => return Err(CliError::NotFound(msg)),
+        },
+        result => result?,
+    };
+
+    let format = get_format_with_provider(format_flag, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&issue, format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Issue identifiers mentioned in the commit messages of `range`
+///
+/// Only identifiers whose key belongs to a workspace team count (or to one of
+/// `teams`, when given), so text like `UTF-8` is ignored. Each identifier is
+/// listed once, in order of its newest mention.
+pub(crate) fn commit_issue_identifiers(
+    range: &str,
+    teams: &[String],
+    repo: &GitRepo,
+    team_client: &dyn TeamClient,
+    token: &str,
+) -> Result<Vec<String>, CliError> {
+    let messages = repo.commit_messages(range)?;
+
+    let keys: HashSet<String> = if teams.is_empty() {
+        team_client
+            .list_teams(token, TEAM_LIMIT)?
+            .into_iter()
+            .map(|team| team.key.to_ascii_uppercase())
+            .collect()
+    } else {
+        teams.iter().map(|key| key.to_ascii_uppercase()).collect()
+    };
+
+    let mut seen = HashSet::new();
+    Ok(messages
+        .iter()
+        .flat_map(|message| issue_identifiers(message))
+        .filter(|identifier| {
+            identifier
+                .rsplit_once('-')
+                .is_some_and(|(key, _)| keys.contains(key))
+        })
+        .filter(|identifier| seen.insert(identifier.clone()))
+        .collect())
+}
+
+/// Handle `issue from-commits <rev-range>`: list issues referenced in commit messages
+///
+/// See [`commit_issue_identifiers`] for which identifiers count; those that
+/// no longer resolve are reported on stderr and skipped.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_from_commits(
+    range: &str,
+    teams: &[String],
+    repo: &GitRepo,
+    issue_client: &dyn IssueClient,
+    team_client: &dyn TeamClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+    let identifiers =
+        commit_issue_identifiers(range, teams, repo, team_client, token.expose_secret())?;
+
+    let mut issues = Vec::with_capacity(identifiers.len());
+    for identifier in identifiers {
+        match issue_client.get_issue(token.expose_secret(), &identifier) {
+            Ok(issue) => issues.push(issue),
+            Err(CliError::NotFound(_)) => {
+                io.print_error(&format!("Skipping {identifier}: issue not found"));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&IssueList(issues), format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
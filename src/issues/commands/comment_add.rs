@@ -1,11 +1,14 @@
+use crate::attachments::upload::upload_file;
 use crate::auth::config::ConfigProvider;
 use crate::auth::storage::TokenStorage;
 use crate::auth::token::get_token_with_provider;
 use crate::client::comments::{CommentClient, CreateCommentInput};
+use crate::client::uploads::UploadClient;
 use crate::error::CliError;
 use crate::io::Io;
 use crate::output::{OutputFormat, format_output, get_format_with_provider};
 use secrecy::ExposeSecret;
+use std::path::Path;
 
 pub fn handle_comment_add(
     identifier: &str,
@@ -18,11 +21,75 @@ pub fn handle_comment_add(
 ) -> Result<(), CliError> {
     let token = get_token_with_provider(config, storage)?;
 
-    let comment = client.create_comment(
+    create_comment(
+        identifier,
+        body.to_string(),
+        client,
+        token.expose_secret(),
+        config,
+        io,
+        format_flag,
+    )
+}
+
+/// Handle `issue comment add --attach <path>...`
+///
+/// Each file is uploaded first, then embedded in the comment body as Markdown:
+/// images inline, other files as links. Nothing is posted if an upload fails.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_comment_add_with_files(
+    identifier: &str,
+    body: Option<&str>,
+    files: &[String],
+    client: &dyn CommentClient,
+    uploads: &dyn UploadClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let embeds = files
+        .iter()
+        .map(|file| {
+            upload_file(uploads, token.expose_secret(), Path::new(file)).map(|f| f.to_markdown())
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n");
+
+    let body = match body.map(str::trim_end).filter(|b| !b.is_empty()) {
+        Some(text) if !embeds.is_empty() => format!("{text}\n\n{embeds}"),
+        Some(text) => text.to_string(),
+        None => embeds,
+    };
+
+    create_comment(
+        identifier,
+        body,
+        client,
         token.expose_secret(),
+        config,
+        io,
+        format_flag,
+    )
+}
+
+fn create_comment(
+    identifier: &str,
+    body: String,
+    client: &dyn CommentClient,
+    token: &str,
+    config: &dyn ConfigProvider,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let comment = client.create_comment(
+        token,
         CreateCommentInput {
             issue_id: identifier.to_string(),
-            body: body.to_string(),
+            body,
+            parent_id: None,
         },
     )?;
 
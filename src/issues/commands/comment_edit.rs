@@ -0,0 +1,28 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::comments::CommentClient;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::{OutputFormat, format_output, get_format_with_provider};
+use secrecy::ExposeSecret;
+
+/// Handle the comment edit command
+pub fn handle_comment_edit(
+    comment_id: &str,
+    body: &str,
+    client: &dyn CommentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let comment = client.update_comment(token.expose_secret(), comment_id, body)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&comment, format)?);
+
+    Ok(())
+}
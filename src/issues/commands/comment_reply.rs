@@ -0,0 +1,37 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::comments::{CommentClient, CreateCommentInput};
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::{OutputFormat, format_output, get_format_with_provider};
+use secrecy::ExposeSecret;
+
+/// Handle the comment reply command
+///
+/// The reply is created in the parent's thread and on the parent's issue.
+pub fn handle_comment_reply(
+    parent_id: &str,
+    body: &str,
+    client: &dyn CommentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let comment = client.create_comment(
+        token.expose_secret(),
+        CreateCommentInput {
+            issue_id: String::new(),
+            body: body.to_string(),
+            parent_id: Some(parent_id.to_string()),
+        },
+    )?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&comment, format)?);
+
+    Ok(())
+}
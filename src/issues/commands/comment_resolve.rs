@@ -0,0 +1,28 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::comments::CommentClient;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::{OutputFormat, format_output, get_format_with_provider};
+use secrecy::ExposeSecret;
+
+/// Handle the comment resolve and unresolve commands
+pub fn handle_comment_resolve(
+    comment_id: &str,
+    resolved: bool,
+    client: &dyn CommentClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let comment = client.set_comment_resolved(token.expose_secret(), comment_id, resolved)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&comment, format)?);
+
+    Ok(())
+}
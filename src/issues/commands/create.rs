@@ -4,6 +4,7 @@ use crate::auth::token::get_token_with_provider;
 use crate::client::issues::{CreateIssueInput, IssueClient};
 use crate::error::CliError;
 use crate::io::Io;
+use crate::issues::commands::fields::IssueFields;
 use crate::output::{
     JsonStyle, OutputFormat, format_output, get_format_with_provider,
     resolve_json_style_with_provider,
@@ -17,14 +18,18 @@ pub fn handle_create(
     description: Option<String>,
     assignee: Option<String>,
     project: Option<String>,
+    milestone: Option<String>,
     state: Option<String>,
     priority: Option<i32>,
+    fields: IssueFields,
     client: &dyn IssueClient,
     config: &dyn ConfigProvider,
     storage: &dyn TokenStorage,
     io: &dyn Io,
     format_flag: Option<OutputFormat>,
 ) -> Result<(), CliError> {
+    // `null` has nothing to clear on a new issue, so it just leaves the field out
+    let fields = fields.parse()?;
     let token = get_token_with_provider(config, storage)?;
 
     let created = client.create_issue(
@@ -35,8 +40,14 @@ pub fn handle_create(
             description,
             assignee_id: assignee,
             project_id: project,
+            milestone_id: milestone,
             state_id: state,
             priority,
+            estimate: fields.estimate.value(),
+            due_date: fields.due_date.value(),
+            cycle: fields.cycle.value(),
+            subscriber_ids: fields.subscriber_ids.unwrap_or_default(),
+            sort_order: fields.sort_order,
         },
     )?;
 
@@ -0,0 +1,103 @@
+use crate::cli::IssueFieldArgs;
+use crate::client::queries::Nullable;
+use crate::dates::parse_calendar_date;
+use crate::error::CliError;
+
+/// Planning fields shared by `issue create` and `issue update`
+///
+/// Values are as given on the command line. On update, `null` clears
+/// the estimate, due date or cycle, and a single `null` subscriber
+/// removes every subscriber.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueFields {
+    /// Estimate in points
+    pub estimate: Option<String>,
+    /// Due date in any form [`crate::dates`] accepts, e.g. `2026-11-01`, `friday`, `+3d`
+    pub due_date: Option<String>,
+    /// Cycle: `current`, `next`, a cycle number, or an ID
+    pub cycle: Option<String>,
+    /// Subscriber user IDs
+    pub subscribers: Vec<String>,
+    pub sort_order: Option<f64>,
+}
+
+/// Fields parsed and validated, ready for the issue client
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ParsedIssueFields {
+    pub estimate: Nullable<i32>,
+    pub due_date: Nullable<String>,
+    pub cycle: Nullable<String>,
+    pub subscriber_ids: Option<Vec<String>>,
+    pub sort_order: Option<f64>,
+}
+
+impl IssueFields {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == IssueFields::default()
+    }
+
+    /// Parse the fields, resolving relative due dates against the local date
+    ///
+    /// # Errors
+    /// Returns `InvalidArgs` for a malformed estimate or due date.
+    pub(crate) fn parse(self) -> Result<ParsedIssueFields, CliError> {
+        let estimate = match clearable(self.estimate) {
+            Nullable::Value(value) => {
+                Nullable::Value(value.trim().parse::<i32>().map_err(|_| {
+                    CliError::InvalidArgs(format!(
+                        "Invalid estimate '{value}': expected a whole number of points or null"
+                    ))
+                })?)
+            }
+            Nullable::Null => Nullable::Null,
+            Nullable::Unset => Nullable::Unset,
+        };
+        let due_date = match clearable(self.due_date) {
+            Nullable::Value(value) => Nullable::Value(parse_calendar_date(&value)?),
+            Nullable::Null => Nullable::Null,
+            Nullable::Unset => Nullable::Unset,
+        };
+        let subscriber_ids = match self.subscribers.as_slice() {
+            [] => None,
+            [only] if only == "null" => Some(Vec::new()),
+            ids if ids.iter().any(|id| id == "null") => {
+                return Err(CliError::InvalidArgs(
+                    "--subscriber null clears subscribers and cannot be combined with IDs"
+                        .to_string(),
+                ));
+            }
+            ids => Some(ids.to_vec()),
+        };
+
+        Ok(ParsedIssueFields {
+            estimate,
+            due_date,
+            cycle: clearable(self.cycle),
+            subscriber_ids,
+            sort_order: self.sort_order,
+        })
+    }
+}
+
+impl From<IssueFieldArgs> for IssueFields {
+    fn from(args: IssueFieldArgs) -> Self {
+        IssueFields {
+            estimate: args.estimate,
+            due_date: args.due_date,
+            cycle: args.cycle,
+            subscribers: args.subscribers,
+            sort_order: args.sort_order,
+        }
+    }
+}
+
+/// Read a command-line value where the literal `null` clears the field
+#[must_use]
+pub fn clearable(value: Option<String>) -> Nullable<String> {
+    match value {
+        None => Nullable::Unset,
+        Some(value) if value == "null" => Nullable::Null,
+        Some(value) => Nullable::Value(value),
+    }
+}
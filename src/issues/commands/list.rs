@@ -5,7 +5,9 @@ use crate::client::issues::IssueClient;
 use crate::error::CliError;
 use crate::io::Io;
 use crate::issues::types::IssueList;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use secrecy::ExposeSecret;
 
 /// Handle the issue list command
@@ -23,15 +25,35 @@ pub fn handle_list(
     // Get authentication token
     let token = get_token_with_provider(config, storage)?;
 
+    // Determine output format (CLI flag > env var > auto-detect), sized to the terminal
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+
+    // JSON Lines is written page by page as results arrive instead of
+    // buffering the whole list.
+    if matches!(format, OutputFormat::Jsonl) {
+        return client.list_issues_paged(
+            token.expose_secret(),
+            assignee,
+            project,
+            limit,
+            &mut |page| {
+                if page.is_empty() {
+                    return Ok(());
+                }
+                let mut output = Vec::new();
+                format_output_to_writer(&IssueList(page), OutputFormat::Jsonl, &mut output)?;
+                io.print_bytes(&output);
+                Ok(())
+            },
+        );
+    }
+
     // Fetch issues from API
     let issues = client.list_issues(token.expose_secret(), assignee, project, limit)?;
 
     // Wrap in IssueList for collection-specific formatting
     let issue_list = IssueList(issues);
 
-    // Determine output format (CLI flag > env var > auto-detect)
-    let format = get_format_with_provider(format_flag, config);
-
     // Format and output via streaming writer API
     let mut output = Vec::new();
     format_output_to_writer(&issue_list, format, &mut output)?;
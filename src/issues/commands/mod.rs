@@ -1,18 +1,32 @@
+pub mod branch;
 pub mod comment_add;
 pub mod comment_delete;
+pub mod comment_edit;
+pub mod comment_reply;
+pub mod comment_resolve;
 pub mod create;
 pub mod delete;
+pub mod fields;
 pub mod lifecycle;
 pub mod list;
 pub mod relation;
 pub mod search;
+pub mod subscribe;
 pub mod update;
 pub mod view;
 
-pub use comment_add::handle_comment_add;
+pub use branch::{
+    handle_branch, handle_checkout, handle_current, handle_from_branch, handle_from_commits,
+};
+pub use comment_add::{handle_comment_add, handle_comment_add_with_files};
+pub use comment_edit::handle_comment_edit;
+pub use comment_reply::handle_comment_reply;
+pub use comment_resolve::handle_comment_resolve;
 pub use create::handle_create;
+pub use fields::{IssueFields, clearable};
 pub use lifecycle::{handle_archive, handle_unarchive};
 pub use list::handle_list;
 pub use relation::{handle_block, handle_duplicate, handle_link};
+pub use subscribe::handle_subscribe;
 pub use update::handle_update;
 pub use view::handle_view;
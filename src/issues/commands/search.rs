@@ -5,7 +5,9 @@ use crate::client::search::SearchClient;
 use crate::error::CliError;
 use crate::io::Io;
 use crate::issues::types::IssueList;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use secrecy::ExposeSecret;
 
 /// Search parameters
@@ -38,7 +40,7 @@ pub fn handle_search(
         limit,
     )?;
     let issue_list = IssueList(issues);
-    let format = get_format_with_provider(format_flag, config);
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     let mut output = Vec::new();
     format_output_to_writer(&issue_list, format, &mut output)?;
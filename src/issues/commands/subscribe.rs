@@ -0,0 +1,39 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::issues::IssueClient;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::{OutputFormat, get_format_with_provider};
+use secrecy::ExposeSecret;
+
+/// Handle `issue subscribe` and `issue unsubscribe` commands
+pub fn handle_subscribe(
+    identifier: &str,
+    subscribed: bool,
+    client: &dyn IssueClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let issue = client.set_issue_subscribed(token.expose_secret(), identifier, subscribed)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let msg = if matches!(format, OutputFormat::Json) {
+        serde_json::json!({
+            "identifier": issue.identifier,
+            "subscribed": subscribed,
+        })
+        .to_string()
+    } else if subscribed {
+        format!("Subscribed to {}", issue.identifier)
+    } else {
+        format!("Unsubscribed from {}", issue.identifier)
+    };
+    io.print(&msg);
+
+    Ok(())
+}
@@ -3,7 +3,8 @@ use crate::auth::storage::TokenStorage;
 use crate::auth::token::get_token_with_provider;
 use crate::client::issues::{IssueClient, UpdateIssueInput};
 use crate::error::CliError;
-use crate::io::Io;
+use crate::io::{Io, TextSource};
+use crate::issues::commands::fields::{IssueFields, clearable};
 use crate::output::{OutputFormat, format_output, get_format_with_provider};
 use secrecy::ExposeSecret;
 
@@ -11,11 +12,14 @@ use secrecy::ExposeSecret;
 pub fn handle_update(
     identifier: &str,
     title: Option<String>,
-    description: Option<String>,
+    description: Option<TextSource>,
     assignee: Option<String>,
     project: Option<String>,
+    milestone: Option<String>,
     state: Option<String>,
     priority: Option<i32>,
+    team: Option<String>,
+    fields: IssueFields,
     client: &dyn IssueClient,
     config: &dyn ConfigProvider,
     storage: &dyn TokenStorage,
@@ -26,26 +30,52 @@ pub fn handle_update(
         && description.is_none()
         && assignee.is_none()
         && project.is_none()
+        && milestone.is_none()
         && state.is_none()
         && priority.is_none()
+        && team.is_none()
+        && fields.is_empty()
     {
         return Err(CliError::InvalidArgs(
             "issue update requires at least one patch field".to_string(),
         ));
     }
 
+    if team.as_deref() == Some("null") {
+        return Err(CliError::InvalidArgs(
+            "an issue always belongs to a team; --team cannot be cleared".to_string(),
+        ));
+    }
+    let fields = fields.parse()?;
+
     let token = get_token_with_provider(config, storage)?;
 
+    let description = match description {
+        Some(source) if source.needs_initial() => {
+            let current = client.get_issue(token.expose_secret(), identifier)?;
+            Some(source.read(io, current.description.as_deref().unwrap_or(""))?)
+        }
+        Some(source) => Some(source.read(io, "")?),
+        None => None,
+    };
+
     let updated = client.update_issue(
         token.expose_secret(),
         identifier,
         UpdateIssueInput {
             title,
             description,
-            assignee_id: assignee,
-            project_id: project,
+            assignee_id: clearable(assignee),
+            project_id: clearable(project),
+            milestone_id: clearable(milestone),
             state_id: state,
             priority,
+            estimate: fields.estimate,
+            due_date: fields.due_date,
+            cycle: fields.cycle,
+            team_id: team,
+            subscriber_ids: fields.subscriber_ids,
+            sort_order: fields.sort_order,
         },
     )?;
 
@@ -1,17 +1,24 @@
 use crate::auth::config::ConfigProvider;
 use crate::auth::storage::TokenStorage;
 use crate::auth::token::get_token_with_provider;
+use crate::client::attachments::AttachmentClient;
 use crate::client::comments::CommentClient;
 use crate::client::issues::IssueClient;
+use crate::comments::types::thread_comments;
 use crate::error::CliError;
 use crate::io::Io;
 use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
 use secrecy::ExposeSecret;
 
+/// Maximum number of attachments shown by `issue view`
+const ATTACHMENT_LIMIT: usize = 50;
+
 /// Dependencies for the issue view command
 pub struct ViewDeps<'a> {
     pub issue_client: &'a dyn IssueClient,
     pub comment_client: &'a dyn CommentClient,
+    /// `None` when attachments are unavailable (the offline mirror does not store them)
+    pub attachment_client: Option<&'a dyn AttachmentClient>,
     pub config: &'a dyn ConfigProvider,
     pub storage: &'a dyn TokenStorage,
     pub io: &'a dyn Io,
@@ -38,7 +45,16 @@ pub fn handle_view(
         let comments =
             deps.comment_client
                 .list_comments(token.expose_secret(), &issue.id, comment_limit)?;
-        issue.comments = Some(comments);
+        issue.comments = Some(thread_comments(comments));
+    }
+
+    if let Some(attachment_client) = deps.attachment_client {
+        let attachments = attachment_client.list_attachments(
+            token.expose_secret(),
+            &issue.id,
+            ATTACHMENT_LIMIT,
+        )?;
+        issue.attachments = Some(attachments);
     }
 
     // Determine output format (CLI flag > env var > auto-detect)
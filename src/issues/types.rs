@@ -1,11 +1,14 @@
+use crate::attachments::types::Attachment;
 use crate::client::queries;
-use crate::comments::types::Comment;
+use crate::comments::types::{Comment, count_comments, quote_lines};
 use crate::error::CliError;
 use crate::output::{
-    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
-    generic_json_formatter, generic_json_list_formatter, generic_table_formatter,
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    TimeDisplay, Timed, fast_markdown_formatter, generic_json_formatter,
+    generic_json_list_formatter, generic_jsonl_formatter, generic_jsonl_list_formatter,
+    generic_table_formatter, generic_table_list_formatter, generic_template_formatter,
+    generic_template_list_formatter, priority_color, state_color,
 };
-use comfy_table::{Table, presets::UTF8_FULL};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Write as FmtWrite;
@@ -103,6 +106,42 @@ pub struct IssueProject {
     pub slug_id: String,
 }
 
+/// Team an issue belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueTeam {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+/// Cycle an issue is scheduled in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueCycle {
+    pub id: String,
+    pub number: u32,
+    pub name: Option<String>,
+}
+
+impl IssueCycle {
+    /// Display label: the cycle name when set, else `Cycle <number>`
+    #[must_use]
+    pub fn label(&self) -> String {
+        match &self.name {
+            Some(name) if !name.is_empty() => format!("{name} (#{})", self.number),
+            _ => format!("Cycle {}", self.number),
+        }
+    }
+}
+
+/// Format an estimate without a trailing `.0` for whole points
+fn format_estimate(estimate: f64) -> String {
+    if estimate.fract() == 0.0 {
+        format!("{estimate:.0}")
+    } else {
+        estimate.to_string()
+    }
+}
+
 /// Issue details returned from Linear API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Issue {
@@ -115,15 +154,64 @@ pub struct Issue {
     pub assignee: Option<User>,
     pub creator: User,
     pub project: Option<IssueProject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<IssueTeam>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<IssueCycle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+    /// Due date as `YYYY-MM-DD`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<Vec<User>>,
+    /// Git branch name suggested by Linear
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_name: Option<String>,
     pub created_at: String,
     pub updated_at: String,
     pub url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<Vec<Comment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Attachment>>,
+}
+
+impl Issue {
+    /// CSV cells for team, cycle, estimate, due date, sort order and subscribers
+    fn planning_columns(&self) -> (String, String, String, String, String, String) {
+        (
+            self.team
+                .as_ref()
+                .map(|t| t.key.clone())
+                .unwrap_or_default(),
+            self.cycle
+                .as_ref()
+                .map(|c| c.number.to_string())
+                .unwrap_or_default(),
+            self.estimate.map(format_estimate).unwrap_or_default(),
+            self.due_date.clone().unwrap_or_default(),
+            self.sort_order.map(|o| o.to_string()).unwrap_or_default(),
+            self.subscribers
+                .as_ref()
+                .map(|users| {
+                    users
+                        .iter()
+                        .map(|u| u.email.as_str())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                })
+                .unwrap_or_default(),
+        )
+    }
 }
 
 /// Wrapper type for a list of issues
 /// Provides different formatting for collections vs single items
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct IssueList(pub Vec<Issue>);
 
 impl Formattable for IssueList {
@@ -131,6 +219,10 @@ impl Formattable for IssueList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -145,11 +237,19 @@ impl Formattable for IssueList {
             "created_at",
             "updated_at",
             "url",
+            "team",
+            "cycle",
+            "estimate",
+            "due_date",
+            "sort_order",
+            "subscribers",
         ])
         .csv_err("Failed to write CSV header")?;
 
         // Write each issue as a row
         for issue in &self.0 {
+            let (team, cycle, estimate, due_date, sort_order, subscribers) =
+                issue.planning_columns();
             wtr.write_record([
                 &issue.identifier,
                 &issue.title,
@@ -164,6 +264,12 @@ impl Formattable for IssueList {
                 &issue.created_at,
                 &issue.updated_at,
                 &issue.url,
+                &team,
+                &cycle,
+                &estimate,
+                &due_date,
+                &sort_order,
+                &subscribers,
             ])
             .csv_err("Failed to write CSV row")?;
         }
@@ -235,148 +341,243 @@ impl Formattable for IssueList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
-        let mut table = Table::new();
-        table.load_preset(UTF8_FULL);
-
-        // Horizontal layout: column headers
-        table.set_header(vec!["ID", "Title", "State", "Priority", "Assignee"]);
+        self.to_table_with_layout(&TableLayout::default())
+    }
 
-        // Each issue is a row
-        for issue in &self.0 {
-            let priority_str = format_priority(&issue.priority);
-            table.add_row(vec![
-                &issue.identifier,
-                &issue.title,
-                &issue.state.name,
-                &priority_str,
-                issue
-                    .assignee
-                    .as_ref()
-                    .map(|u| u.name.as_str())
-                    .unwrap_or("—"),
-            ]);
-        }
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new("ID"),
+                TableColumn::new("Title").flexible(),
+                TableColumn::new("State").colored(state_color),
+                TableColumn::new("Priority").colored(priority_color),
+                TableColumn::new("Assignee"),
+                TableColumn::new("Project").wide_only(),
+                TableColumn::new("Creator").wide_only(),
+                TableColumn::new("Updated").wide_only(),
+                TableColumn::new("URL").wide_only(),
+            ],
+            layout,
+            |issue| {
+                vec![
+                    issue.identifier.clone(),
+                    issue.title.clone(),
+                    issue.state.name.clone(),
+                    format_priority(&issue.priority),
+                    issue
+                        .assignee
+                        .as_ref()
+                        .map_or_else(|| "—".to_string(), |u| u.name.clone()),
+                    issue
+                        .project
+                        .as_ref()
+                        .map_or_else(|| "—".to_string(), |p| p.name.clone()),
+                    issue.creator.name.clone(),
+                    layout.time.format(&issue.updated_at),
+                    issue.url.clone(),
+                ]
+            },
+        )
+    }
 
-        Ok(table.to_string())
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
     }
 }
 
 // Trait implementations for generic formatters
-impl TableFormatter for Issue {
+impl TableFormatter for Timed<'_, Issue> {
     fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
         let mut rows = vec![
             (
                 Cow::Borrowed("Identifier"),
-                Cow::Borrowed(self.identifier.as_str()),
+                Cow::Borrowed(self.item.identifier.as_str()),
+            ),
+            (
+                Cow::Borrowed("Title"),
+                Cow::Borrowed(self.item.title.as_str()),
             ),
-            (Cow::Borrowed("Title"), Cow::Borrowed(self.title.as_str())),
             (
                 Cow::Borrowed("State"),
-                Cow::Borrowed(self.state.name.as_str()),
+                Cow::Borrowed(self.item.state.name.as_str()),
             ),
             (
                 Cow::Borrowed("Priority"),
                 Cow::Owned(format!(
                     "{} {}",
-                    self.priority.emoji(),
-                    self.priority.as_str()
+                    self.item.priority.emoji(),
+                    self.item.priority.as_str()
                 )),
             ),
             (
                 Cow::Borrowed("Assignee"),
-                self.assignee
+                self.item
+                    .assignee
                     .as_ref()
                     .map(|u| Cow::Borrowed(u.name.as_str()))
                     .unwrap_or(Cow::Borrowed("—")),
             ),
             (
                 Cow::Borrowed("Creator"),
-                Cow::Borrowed(self.creator.name.as_str()),
+                Cow::Borrowed(self.item.creator.name.as_str()),
             ),
         ];
 
-        if let Some(project) = &self.project {
+        if let Some(project) = &self.item.project {
             rows.push((
                 Cow::Borrowed("Project"),
                 Cow::Borrowed(project.name.as_str()),
             ));
         }
 
-        if let Some(desc) = &self.description {
+        if let Some(team) = &self.item.team {
+            rows.push((Cow::Borrowed("Team"), Cow::Borrowed(team.key.as_str())));
+        }
+
+        if let Some(cycle) = &self.item.cycle {
+            rows.push((Cow::Borrowed("Cycle"), Cow::Owned(cycle.label())));
+        }
+
+        if let Some(estimate) = self.item.estimate {
+            rows.push((
+                Cow::Borrowed("Estimate"),
+                Cow::Owned(format_estimate(estimate)),
+            ));
+        }
+
+        if let Some(due_date) = &self.item.due_date {
+            rows.push((Cow::Borrowed("Due"), Cow::Borrowed(due_date.as_str())));
+        }
+
+        if let Some(subscribers) = &self.item.subscribers
+            && !subscribers.is_empty()
+        {
+            let names: Vec<&str> = subscribers.iter().map(|u| u.name.as_str()).collect();
+            rows.push((Cow::Borrowed("Subscribers"), Cow::Owned(names.join(", "))));
+        }
+
+        if let Some(desc) = &self.item.description {
             rows.push((Cow::Borrowed("Description"), Cow::Borrowed(desc.as_str())));
         }
 
         rows.push((
             Cow::Borrowed("Created"),
-            Cow::Borrowed(self.created_at.as_str()),
+            Cow::Owned(self.time.format(&self.item.created_at)),
         ));
         rows.push((
             Cow::Borrowed("Updated"),
-            Cow::Borrowed(self.updated_at.as_str()),
+            Cow::Owned(self.time.format(&self.item.updated_at)),
         ));
-        rows.push((Cow::Borrowed("URL"), Cow::Borrowed(self.url.as_str())));
+        if let Some(branch) = &self.item.branch_name {
+            rows.push((Cow::Borrowed("Branch"), Cow::Borrowed(branch.as_str())));
+        }
+        rows.push((Cow::Borrowed("URL"), Cow::Borrowed(self.item.url.as_str())));
 
         // Add comment count if comments are present
-        if let Some(comments) = &self.comments {
+        if let Some(comments) = &self.item.comments {
             rows.push((
                 Cow::Borrowed("Comments"),
-                Cow::Owned(comments.len().to_string()),
+                Cow::Owned(count_comments(comments).to_string()),
             ));
         }
 
+        if let Some(attachments) = &self.item.attachments
+            && !attachments.is_empty()
+        {
+            let links: Vec<String> = attachments
+                .iter()
+                .map(|a| format!("{} ({}) {}", a.title, a.source(), a.url))
+                .collect();
+            rows.push((Cow::Borrowed("Attachments"), Cow::Owned(links.join("\n"))));
+        }
+
         rows
     }
 }
 
-impl MarkdownFormatter for Issue {
+impl MarkdownFormatter for Timed<'_, Issue> {
     fn markdown_capacity_hint(&self) -> usize {
         // Same pre-allocation logic as original custom implementation
-        300 + self.identifier.len()
-            + self.title.len()
-            + self.state.name.len()
-            + self.description.as_ref().map_or(0, |d| d.len())
-            + self.creator.name.len()
-            + self.url.len()
+        300 + self.item.identifier.len()
+            + self.item.title.len()
+            + self.item.state.name.len()
+            + self.item.description.as_ref().map_or(0, |d| d.len())
+            + self.item.creator.name.len()
+            + self.item.url.len()
+            + self.item.attachments.as_ref().map_or(0, |attachments| {
+                attachments
+                    .iter()
+                    .map(|a| 20 + a.title.len() + a.url.len())
+                    .sum()
+            })
     }
 
     fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
         // Exact same logic as original custom implementation
         // H1 title with identifier
-        writeln!(output, "# {}: {}\n", self.identifier, self.title)
+        writeln!(output, "# {}: {}\n", self.item.identifier, self.item.title)
             .map_err(|e| CliError::General(format!("Failed to write markdown title: {e}")))?;
 
         // Metadata section
-        writeln!(output, "**State:** {}", self.state.name)
+        writeln!(output, "**State:** {}", self.item.state.name)
             .map_err(|e| CliError::General(format!("Failed to write markdown state: {e}")))?;
         writeln!(
             output,
             "**Priority:** {} {}",
-            self.priority.emoji(),
-            self.priority.as_str()
+            self.item.priority.emoji(),
+            self.item.priority.as_str()
         )
         .map_err(|e| CliError::General(format!("Failed to write markdown priority: {e}")))?;
         writeln!(
             output,
             "**Assignee:** {}",
-            self.assignee
+            self.item
+                .assignee
                 .as_ref()
                 .map(|u| u.name.as_str())
                 .unwrap_or("Unassigned")
         )
         .map_err(|e| CliError::General(format!("Failed to write markdown assignee: {e}")))?;
-        writeln!(output, "**Creator:** {}", self.creator.name)
+        writeln!(output, "**Creator:** {}", self.item.creator.name)
             .map_err(|e| CliError::General(format!("Failed to write markdown creator: {e}")))?;
-        if let Some(project) = &self.project {
+        if let Some(project) = &self.item.project {
             writeln!(output, "**Project:** {}", project.name)
                 .map_err(|e| CliError::General(format!("Failed to write markdown project: {e}")))?;
         }
+        if let Some(team) = &self.item.team {
+            writeln!(output, "**Team:** {} ({})", team.name, team.key)
+                .map_err(|e| CliError::General(format!("Failed to write markdown team: {e}")))?;
+        }
+        if let Some(cycle) = &self.item.cycle {
+            writeln!(output, "**Cycle:** {}", cycle.label())
+                .map_err(|e| CliError::General(format!("Failed to write markdown cycle: {e}")))?;
+        }
+        if let Some(estimate) = self.item.estimate {
+            writeln!(output, "**Estimate:** {}", format_estimate(estimate)).map_err(|e| {
+                CliError::General(format!("Failed to write markdown estimate: {e}"))
+            })?;
+        }
+        if let Some(due_date) = &self.item.due_date {
+            writeln!(output, "**Due:** {due_date}").map_err(|e| {
+                CliError::General(format!("Failed to write markdown due date: {e}"))
+            })?;
+        }
+        if let Some(subscribers) = &self.item.subscribers
+            && !subscribers.is_empty()
+        {
+            let names: Vec<&str> = subscribers.iter().map(|u| u.name.as_str()).collect();
+            writeln!(output, "**Subscribers:** {}", names.join(", ")).map_err(|e| {
+                CliError::General(format!("Failed to write markdown subscribers: {e}"))
+            })?;
+        }
         writeln!(output).map_err(|e| CliError::General(format!("Failed to write newline: {e}")))?;
 
         // Description section
         write!(output, "## Description\n\n").map_err(|e| {
             CliError::General(format!("Failed to write markdown description header: {e}"))
         })?;
-        if let Some(desc) = &self.description {
+        if let Some(desc) = &self.item.description {
             writeln!(output, "{}\n", desc).map_err(|e| {
                 CliError::General(format!("Failed to write markdown description: {e}"))
             })?;
@@ -390,41 +591,98 @@ impl MarkdownFormatter for Issue {
         writeln!(output, "## Details\n").map_err(|e| {
             CliError::General(format!("Failed to write markdown details header: {e}"))
         })?;
-        writeln!(output, "- **Created:** {}", self.created_at).map_err(|e| {
-            CliError::General(format!("Failed to write markdown created date: {e}"))
-        })?;
-        writeln!(output, "- **Updated:** {}", self.updated_at).map_err(|e| {
-            CliError::General(format!("Failed to write markdown updated date: {e}"))
-        })?;
-        writeln!(output, "- **URL:** {}", self.url)
+        writeln!(
+            output,
+            "- **Created:** {}",
+            self.time.format(&self.item.created_at)
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown created date: {e}")))?;
+        writeln!(
+            output,
+            "- **Updated:** {}",
+            self.time.format(&self.item.updated_at)
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown updated date: {e}")))?;
+        if let Some(branch) = &self.item.branch_name {
+            writeln!(output, "- **Branch:** `{branch}`")
+                .map_err(|e| CliError::General(format!("Failed to write markdown branch: {e}")))?;
+        }
+        writeln!(output, "- **URL:** {}", self.item.url)
             .map_err(|e| CliError::General(format!("Failed to write markdown URL: {e}")))?;
 
+        // Attachments section (if present)
+        if let Some(attachments) = &self.item.attachments
+            && !attachments.is_empty()
+        {
+            writeln!(output, "\n## Attachments\n").map_err(|e| {
+                CliError::General(format!("Failed to write markdown attachments header: {e}"))
+            })?;
+
+            for attachment in attachments {
+                writeln!(
+                    output,
+                    "- [{}]({}) ({})",
+                    attachment.title,
+                    attachment.url,
+                    attachment.source()
+                )
+                .map_err(|e| CliError::General(format!("Failed to write attachment: {e}")))?;
+            }
+        }
+
         // Comments section (if present)
-        if let Some(comments) = &self.comments
+        if let Some(comments) = &self.item.comments
             && !comments.is_empty()
         {
             writeln!(output, "\n## Comments\n").map_err(|e| {
                 CliError::General(format!("Failed to write markdown comments header: {e}"))
             })?;
 
-            for comment in comments {
-                writeln!(output, "**{}** ({})", comment.user_name, comment.created_at).map_err(
-                    |e| CliError::General(format!("Failed to write comment author: {e}")),
-                )?;
-                writeln!(output, "{}\n", comment.body)
-                    .map_err(|e| CliError::General(format!("Failed to write comment body: {e}")))?;
-            }
+            write_comment_thread(output, comments, &self.time, 0)?;
         }
 
         Ok(())
     }
 }
 
+/// Write comments with their replies quoted beneath them
+fn write_comment_thread(
+    output: &mut String,
+    comments: &[Comment],
+    time: &TimeDisplay,
+    depth: usize,
+) -> Result<(), CliError> {
+    for comment in comments {
+        let resolved = if comment.resolved_at.is_some() {
+            " · resolved"
+        } else {
+            ""
+        };
+        let entry = format!(
+            "**{}** ({}){}\n{}",
+            comment.user_name,
+            time.format(&comment.created_at),
+            resolved,
+            comment.body
+        );
+        writeln!(output, "{}\n", quote_lines(&entry, depth))
+            .map_err(|e| CliError::General(format!("Failed to write comment body: {e}")))?;
+
+        write_comment_thread(output, &comment.replies, time, depth + 1)?;
+    }
+
+    Ok(())
+}
+
 impl Formattable for Issue {
     fn to_json(&self) -> Result<String, CliError> {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -440,6 +698,12 @@ impl Formattable for Issue {
             "updated_at",
             "url",
             "comment_count",
+            "team",
+            "cycle",
+            "estimate",
+            "due_date",
+            "sort_order",
+            "subscribers",
         ])
         .csv_err("Failed to write CSV header")?;
 
@@ -447,9 +711,11 @@ impl Formattable for Issue {
         let comment_count = self
             .comments
             .as_ref()
-            .map(|c| c.len().to_string())
+            .map(|c| count_comments(c).to_string())
             .unwrap_or_else(|| "0".to_string());
 
+        let (team, cycle, estimate, due_date, sort_order, subscribers) = self.planning_columns();
+
         // Write data row
         wtr.write_record([
             &self.identifier,
@@ -465,6 +731,12 @@ impl Formattable for Issue {
             &self.updated_at,
             &self.url,
             &comment_count,
+            &team,
+            &cycle,
+            &estimate,
+            &due_date,
+            &sort_order,
+            &subscribers,
         ])
         .csv_err("Failed to write CSV data")?;
 
@@ -474,12 +746,30 @@ impl Formattable for Issue {
     }
 
     fn to_markdown(&self) -> Result<String, CliError> {
+        self.to_markdown_with_time(&TimeDisplay::default())
+    }
+
+    fn to_markdown_with_time(&self, time: &TimeDisplay) -> Result<String, CliError> {
         // Use fast generic formatter with writer pattern (zero intermediate allocations)
-        fast_markdown_formatter(self)
+        fast_markdown_formatter(&Timed {
+            item: self,
+            time: *time,
+        })
     }
 
     fn to_table(&self) -> Result<String, CliError> {
-        generic_table_formatter(self)
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_formatter(&Timed {
+            item: self,
+            time: layout.time,
+        })
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
     }
 }
 
@@ -514,6 +804,26 @@ impl From<queries::IssueProject> for IssueProject {
     }
 }
 
+impl From<queries::IssueTeam> for IssueTeam {
+    fn from(team: queries::IssueTeam) -> Self {
+        IssueTeam {
+            id: team.id.inner().to_string(),
+            key: team.key,
+            name: team.name,
+        }
+    }
+}
+
+impl From<queries::IssueCycle> for IssueCycle {
+    fn from(cycle: queries::IssueCycle) -> Self {
+        IssueCycle {
+            id: cycle.id.inner().to_string(),
+            number: cycle.number as u32,
+            name: cycle.name,
+        }
+    }
+}
+
 impl TryFrom<queries::IssueNode> for Issue {
     type Error = CliError;
 
@@ -531,10 +841,18 @@ impl TryFrom<queries::IssueNode> for Issue {
                 .map(Into::into)
                 .ok_or_else(|| CliError::General("Issue creator not found".to_string()))?,
             project: node.project.map(Into::into),
+            team: Some(node.team.into()),
+            cycle: node.cycle.map(Into::into),
+            estimate: node.estimate,
+            due_date: node.due_date.map(|d| d.0),
+            sort_order: Some(node.sort_order),
+            subscribers: Some(node.subscribers.nodes.into_iter().map(Into::into).collect()),
+            branch_name: Some(node.branch_name),
             created_at: node.created_at.0,
             updated_at: node.updated_at.0,
             url: node.url,
             comments: None,
+            attachments: None,
         })
     }
 }
@@ -560,6 +878,14 @@ impl TryFrom<queries::SearchIssueNode> for Issue {
             updated_at: node.updated_at.0,
             url: node.url,
             comments: None,
+            attachments: None,
+            team: None,
+            cycle: None,
+            estimate: None,
+            due_date: None,
+            sort_order: None,
+            subscribers: None,
+            branch_name: None,
         })
     }
 }
@@ -5,7 +5,9 @@ use crate::client::labels::LabelClient;
 use crate::error::CliError;
 use crate::io::Io;
 use crate::labels::types::IssueLabelList;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use secrecy::ExposeSecret;
 
 /// Handle the label list command
@@ -21,7 +23,7 @@ pub fn handle_list(
     let token = get_token_with_provider(config, storage)?;
     let labels = client.list_labels(token.expose_secret(), team_key, limit)?;
     let label_list = IssueLabelList(labels);
-    let format = get_format_with_provider(format_flag, config);
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     let mut output = Vec::new();
     format_output_to_writer(&label_list, format, &mut output)?;
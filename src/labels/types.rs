@@ -1,9 +1,10 @@
 use crate::client::queries;
 use crate::error::CliError;
 use crate::output::{
-    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
-    generic_json_formatter, generic_json_list_formatter, generic_table_formatter,
-    generic_table_list_formatter,
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -84,6 +85,10 @@ impl Formattable for IssueLabel {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -112,9 +117,15 @@ impl Formattable for IssueLabel {
     fn to_table(&self) -> Result<String, CliError> {
         generic_table_formatter(self)
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
 
 /// Wrapper for a list of labels
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct IssueLabelList(pub Vec<IssueLabel>);
 
 impl Formattable for IssueLabelList {
@@ -122,6 +133,10 @@ impl Formattable for IssueLabelList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -183,19 +198,37 @@ impl Formattable for IssueLabelList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
         generic_table_list_formatter(
             &self.0,
-            &["Name", "Color", "Group", "Description"],
+            &[
+                TableColumn::new("Name"),
+                TableColumn::new("Color"),
+                TableColumn::new("Group"),
+                TableColumn::new("Description").flexible(),
+                TableColumn::new("Parent").wide_only(),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
             |label| {
                 vec![
                     label.name.clone(),
                     label.color.clone(),
                     if label.is_group { "Yes" } else { "No" }.to_string(),
                     label.description.clone().unwrap_or_else(|| "—".to_string()),
+                    label.parent_name.clone().unwrap_or_else(|| "—".to_string()),
+                    label.id.clone(),
                 ]
             },
         )
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
 }
 
 impl From<queries::IssueLabelNode> for IssueLabel {
@@ -1,15 +1,27 @@
+pub mod api;
+pub mod attachments;
 pub mod auth;
+pub mod cache;
+pub mod changelog;
 pub mod cli;
 pub mod client;
 pub mod comments;
 pub mod cycles;
+pub mod dates;
+pub mod documents;
 pub mod error;
+pub mod git;
+pub mod initiatives;
 pub mod io;
 pub mod issues;
 pub mod labels;
+pub mod milestones;
+pub mod mirror;
+pub mod notifications;
 pub mod output;
 pub mod perf;
 pub mod projects;
+pub mod reactions;
 pub mod search;
 pub mod states;
 pub mod teams;
@@ -1,45 +1,96 @@
 use clap::Parser;
+use linear_cli::api::commands::{handle_api, handle_api_validate};
+use linear_cli::api::{build_variables, read_source};
+use linear_cli::attachments::commands::{
+    handle_attach, handle_attach_file, handle_detach, handle_list as handle_attachment_list,
+};
 use linear_cli::auth::commands::{handle_login, handle_logout, handle_status, handle_token};
-use linear_cli::auth::config::EnvConfigProvider;
+use linear_cli::auth::config::{CliConfigProvider, EnvConfigProvider};
 use linear_cli::auth::storage::KeyringStorage;
+use linear_cli::cache::commands::{
+    handle_clear as handle_cache_clear, handle_stats as handle_cache_stats,
+};
+use linear_cli::cache::{CacheMode, ResponseCache};
+use linear_cli::changelog::commands::{ChangelogOptions, handle_changelog};
 use linear_cli::cli::{
-    AuthCommands, Cli, Commands, CycleCommands, IssueCommands, IssueCommentCommands,
-    IssueLifecycleCommands, IssueRelationCommands, LabelCommands, ProjectCommands, StateCommands,
-    TeamCommands, UserCommands,
+    ApiCommands, AuthCommands, CacheCommands, Cli, Commands, CycleCommands, DocCommands,
+    InitiativeCommands, IssueCommands, IssueCommentCommands, IssueLifecycleCommands,
+    IssueRelationCommands, LabelCommands, NotificationCommands, ProjectCommands,
+    ProjectMilestoneCommands, StateCommands, TeamCommands, UserCommands,
 };
 use linear_cli::client::LinearClient;
+use linear_cli::client::api::ApiClient;
+use linear_cli::client::attachments::{AttachmentClient, AttachmentLinkKind};
 use linear_cli::client::auth::AuthClient;
+use linear_cli::client::changelog::ChangelogClient;
 use linear_cli::client::comments::CommentClient;
 use linear_cli::client::cycles::CycleClient;
+use linear_cli::client::documents::DocumentClient;
+use linear_cli::client::graphql::GraphQLRequest;
+use linear_cli::client::initiatives::{InitiativeClient, UpdateInitiativeInput};
 use linear_cli::client::issues::IssueClient;
 use linear_cli::client::labels::LabelClient;
+use linear_cli::client::milestones::{CreateMilestoneInput, MilestoneClient, UpdateMilestoneInput};
+use linear_cli::client::notifications::NotificationClient;
 use linear_cli::client::projects::ProjectClient;
+use linear_cli::client::reactions::{ReactionClient, ReactionTarget};
 use linear_cli::client::search::SearchClient;
 use linear_cli::client::semantic_search::SemanticSearchClient;
 use linear_cli::client::states::StateClient;
+use linear_cli::client::sync::SyncClient;
 use linear_cli::client::teams::TeamClient;
+use linear_cli::client::uploads::UploadClient;
 use linear_cli::client::users::UserClient;
 use linear_cli::comments::commands::handle_list as handle_comment_list;
 use linear_cli::cycles::commands::{
     handle_current as handle_cycle_current, handle_list as handle_cycle_list,
     handle_view as handle_cycle_view,
 };
-use linear_cli::io::RealIo;
+use linear_cli::documents::commands::{
+    handle_create as handle_doc_create, handle_delete as handle_doc_delete,
+    handle_list as handle_doc_list, handle_update as handle_doc_update,
+    handle_view as handle_doc_view,
+};
+use linear_cli::error::CliError;
+use linear_cli::git::GitRepo;
+use linear_cli::initiatives::commands::{
+    handle_add_project as handle_initiative_add_project, handle_create as handle_initiative_create,
+    handle_list as handle_initiative_list, handle_update as handle_initiative_update,
+    handle_updates as handle_initiative_updates, handle_view as handle_initiative_view,
+};
+use linear_cli::io::{RealIo, TextSource};
 use linear_cli::issues::commands::{
     comment_delete::handle_comment_delete,
     delete::handle_delete as handle_issue_delete,
     handle_archive as handle_issue_archive, handle_block as handle_issue_relation_block,
-    handle_comment_add as handle_issue_comment_add, handle_create as handle_issue_create,
-    handle_duplicate as handle_issue_relation_duplicate, handle_link as handle_issue_relation_link,
-    handle_list as handle_issue_list, handle_unarchive as handle_issue_unarchive,
-    handle_update as handle_issue_update,
+    handle_branch as handle_issue_branch, handle_checkout as handle_issue_checkout,
+    handle_comment_add as handle_issue_comment_add,
+    handle_comment_add_with_files as handle_issue_comment_add_with_files, handle_comment_edit,
+    handle_comment_reply, handle_comment_resolve, handle_create as handle_issue_create,
+    handle_current as handle_issue_current, handle_duplicate as handle_issue_relation_duplicate,
+    handle_from_branch as handle_issue_from_branch,
+    handle_from_commits as handle_issue_from_commits, handle_link as handle_issue_relation_link,
+    handle_list as handle_issue_list, handle_subscribe as handle_issue_subscribe,
+    handle_unarchive as handle_issue_unarchive, handle_update as handle_issue_update,
     search::handle_search as handle_issue_search,
     view::{ViewDeps, handle_view as handle_issue_view},
 };
 use linear_cli::labels::commands::list::handle_list as handle_label_list;
+use linear_cli::milestones::commands::{
+    handle_create as handle_milestone_create, handle_delete as handle_milestone_delete,
+    handle_list as handle_milestone_list, handle_update as handle_milestone_update,
+};
+use linear_cli::mirror::client::MirrorClient;
+use linear_cli::mirror::commands::{handle_sql, handle_sync};
+use linear_cli::notifications::commands::{
+    handle_archive as handle_notification_archive, handle_list as handle_notification_list,
+    handle_read as handle_notification_read, handle_snooze as handle_notification_snooze,
+};
+use linear_cli::output::TimeDisplay;
 use linear_cli::projects::commands::{
-    handle_list as handle_project_list, handle_view as handle_project_view,
+    ProjectViewOptions, handle_list as handle_project_list, handle_view as handle_project_view,
 };
+use linear_cli::reactions::commands::handle_react;
 use linear_cli::search::commands::search::handle_semantic_search;
 use linear_cli::states::commands::list::handle_list as handle_state_list;
 use linear_cli::teams::commands::{
@@ -48,6 +99,7 @@ use linear_cli::teams::commands::{
 use linear_cli::users::commands::list::handle_list as handle_user_list;
 use secrecy::SecretString;
 use std::io::{self, Read};
+use std::path::Path;
 
 /// Read token from stdin if --with-token flag is set
 /// Returns SecretString to minimize exposure window in memory
@@ -64,8 +116,183 @@ fn read_token_from_stdin_if_needed(with_token: bool) -> Option<SecretString> {
     Some(SecretString::from(token.trim().to_string()))
 }
 
+/// Build the API client, attaching the response cache unless bypassed
+fn linear_client(cache_mode: CacheMode) -> LinearClient {
+    match ResponseCache::from_config(&EnvConfigProvider, cache_mode) {
+        Ok(cache) => LinearClient::with_cache(cache),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(e.exit_code());
+        }
+    }
+}
+
+/// Read clients backed by the Linear API, or by the local mirror with `--offline`
+enum Backend {
+    Online(LinearClient),
+    Offline(MirrorClient),
+}
+
+impl Backend {
+    fn new(offline: bool, cache_mode: CacheMode) -> Self {
+        if !offline {
+            return Backend::Online(linear_client(cache_mode));
+        }
+        match MirrorClient::open(&EnvConfigProvider) {
+            Ok(client) => Backend::Offline(client),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(e.exit_code());
+            }
+        }
+    }
+
+    fn issues(&self) -> &dyn IssueClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+
+    fn comments(&self) -> &dyn CommentClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+
+    /// Attachments are not mirrored, so they are unavailable with `--offline`
+    fn attachments(&self) -> Result<&dyn AttachmentClient, CliError> {
+        match self {
+            Backend::Online(client) => Ok(client),
+            Backend::Offline(_) => Err(CliError::InvalidArgs(
+                "issue attachments are not available with --offline".to_string(),
+            )),
+        }
+    }
+
+    /// File uploads need the API, so they are unavailable with `--offline`
+    fn uploads(&self) -> Result<&dyn UploadClient, CliError> {
+        match self {
+            Backend::Online(client) => Ok(client),
+            Backend::Offline(_) => Err(CliError::InvalidArgs(
+                "file uploads are not available with --offline".to_string(),
+            )),
+        }
+    }
+
+    /// Reactions are not mirrored, so they are unavailable with `--offline`
+    fn reactions(&self) -> Result<&dyn ReactionClient, CliError> {
+        match self {
+            Backend::Online(client) => Ok(client),
+            Backend::Offline(_) => Err(CliError::InvalidArgs(
+                "reactions are not available with --offline".to_string(),
+            )),
+        }
+    }
+
+    /// Documents are not mirrored, so they are unavailable with `--offline`
+    fn documents(&self) -> Result<&dyn DocumentClient, CliError> {
+        match self {
+            Backend::Online(client) => Ok(client),
+            Backend::Offline(_) => Err(CliError::InvalidArgs(
+                "documents are not available with --offline".to_string(),
+            )),
+        }
+    }
+
+    /// Initiatives are not mirrored, so they are unavailable with `--offline`
+    fn initiatives(&self) -> Result<&dyn InitiativeClient, CliError> {
+        match self {
+            Backend::Online(client) => Ok(client),
+            Backend::Offline(_) => Err(CliError::InvalidArgs(
+                "initiatives are not available with --offline".to_string(),
+            )),
+        }
+    }
+
+    /// Milestone writes need the API, so they are unavailable with `--offline`
+    fn milestones(&self) -> Result<&dyn MilestoneClient, CliError> {
+        match self {
+            Backend::Online(client) => Ok(client),
+            Backend::Offline(_) => Err(CliError::InvalidArgs(
+                "project milestones are not available with --offline".to_string(),
+            )),
+        }
+    }
+
+    /// The inbox is not mirrored, so notifications are unavailable with `--offline`
+    fn notifications(&self) -> Result<&dyn NotificationClient, CliError> {
+        match self {
+            Backend::Online(client) => Ok(client),
+            Backend::Offline(_) => Err(CliError::InvalidArgs(
+                "notifications are not available with --offline".to_string(),
+            )),
+        }
+    }
+
+    fn search(&self) -> &dyn SearchClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+
+    fn teams(&self) -> &dyn TeamClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+
+    fn projects(&self) -> &dyn ProjectClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+
+    fn cycles(&self) -> &dyn CycleClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+
+    fn labels(&self) -> &dyn LabelClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+
+    fn states(&self) -> &dyn StateClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+
+    fn users(&self) -> &dyn UserClient {
+        match self {
+            Backend::Online(client) => client,
+            Backend::Offline(client) => client,
+        }
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
+    let cache_mode = cli.cache_mode();
+    let offline = cli.offline;
+
+    let cli_config = CliConfigProvider { utc: cli.utc };
+
+    // Output formats fall back to ISO timestamps on a bad value; reject it up front
+    if let Err(e) = TimeDisplay::from_config(&cli_config) {
+        eprintln!("Error: {}", e);
+        std::process::exit(e.exit_code());
+    }
 
     let result = match cli.command {
         Commands::Auth { action } => {
@@ -76,10 +303,10 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
 
-            let client = LinearClient::new();
+            let client = linear_client(cache_mode);
 
             match action {
                 AuthCommands::Login {
@@ -114,9 +341,9 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
-            let client = LinearClient::new();
+            let client = Backend::new(offline, cache_mode);
 
             match action {
                 IssueCommands::View {
@@ -126,8 +353,9 @@ fn main() {
                     format,
                 } => {
                     let deps = ViewDeps {
-                        issue_client: &client as &dyn IssueClient,
-                        comment_client: &client as &dyn CommentClient,
+                        issue_client: client.issues(),
+                        comment_client: client.comments(),
+                        attachment_client: client.attachments().ok(),
                         config: &config,
                         storage: &storage,
                         io: &io,
@@ -140,6 +368,75 @@ fn main() {
                         format.to_format(),
                     )
                 }
+                IssueCommands::Current {
+                    with_comments,
+                    comment_limit,
+                    format,
+                } => {
+                    let deps = ViewDeps {
+                        issue_client: client.issues(),
+                        comment_client: client.comments(),
+                        attachment_client: client.attachments().ok(),
+                        config: &config,
+                        storage: &storage,
+                        io: &io,
+                    };
+                    GitRepo::current_dir().and_then(|repo| {
+                        handle_issue_current(
+                            &repo,
+                            with_comments,
+                            comment_limit,
+                            &deps,
+                            format.to_format(),
+                        )
+                    })
+                }
+                IssueCommands::Branch { identifier, format } => handle_issue_branch(
+                    &identifier,
+                    client.issues(),
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                IssueCommands::Checkout { identifier, format } => {
+                    GitRepo::current_dir().and_then(|repo| {
+                        handle_issue_checkout(
+                            &identifier,
+                            &repo,
+                            client.issues(),
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        )
+                    })
+                }
+                IssueCommands::FromBranch { branch, format } => handle_issue_from_branch(
+                    &branch,
+                    client.issues(),
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                IssueCommands::FromCommits {
+                    range,
+                    teams,
+                    format,
+                } => GitRepo::current_dir().and_then(|repo| {
+                    handle_issue_from_commits(
+                        &range,
+                        &teams,
+                        &repo,
+                        client.issues(),
+                        client.teams(),
+                        &config,
+                        &storage,
+                        &io,
+                        format.to_format(),
+                    )
+                }),
                 IssueCommands::List {
                     assignee,
                     project,
@@ -149,7 +446,7 @@ fn main() {
                     assignee,
                     project,
                     limit,
-                    &client as &dyn IssueClient,
+                    client.issues(),
                     &config,
                     &storage,
                     &io,
@@ -159,25 +456,36 @@ fn main() {
                     team,
                     title,
                     description,
+                    description_file,
+                    editor,
                     assignee,
                     project,
+                    milestone,
                     state,
                     priority,
+                    fields,
                     format,
-                } => handle_issue_create(
-                    &team,
-                    &title,
-                    description,
-                    assignee,
-                    project,
-                    state,
-                    priority.map(i32::from),
-                    &client as &dyn IssueClient,
-                    &config,
-                    &storage,
-                    &io,
-                    format.to_format(),
-                ),
+                } => TextSource::from_flags(description, description_file, editor)
+                    .map(|source| source.read(&io, ""))
+                    .transpose()
+                    .and_then(|description| {
+                        handle_issue_create(
+                            &team,
+                            &title,
+                            description,
+                            assignee,
+                            project,
+                            milestone,
+                            state,
+                            priority.map(i32::from),
+                            fields.into(),
+                            client.issues(),
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        )
+                    }),
                 IssueCommands::Update {
                     identifier,
                     patch,
@@ -185,12 +493,15 @@ fn main() {
                 } => handle_issue_update(
                     &identifier,
                     patch.title,
-                    patch.description,
+                    TextSource::from_flags(patch.description, patch.description_file, patch.editor),
                     patch.assignee,
                     patch.project,
+                    patch.milestone,
                     patch.state,
                     patch.priority.map(i32::from),
-                    &client as &dyn IssueClient,
+                    patch.team,
+                    patch.fields.into(),
+                    client.issues(),
                     &config,
                     &storage,
                     &io,
@@ -207,7 +518,7 @@ fn main() {
                     team.as_deref(),
                     include_comments,
                     limit,
-                    &client as &dyn SearchClient,
+                    client.search(),
                     &config,
                     &storage,
                     &io,
@@ -220,7 +531,7 @@ fn main() {
                 } => handle_issue_delete(
                     &identifier,
                     permanently,
-                    &client as &dyn IssueClient,
+                    client.issues(),
                     &config,
                     &storage,
                     &io,
@@ -230,7 +541,7 @@ fn main() {
                     IssueLifecycleCommands::Archive { identifier, format } => handle_issue_archive(
                         &identifier,
                         false,
-                        &client as &dyn IssueClient,
+                        client.issues(),
                         &config,
                         &storage,
                         &io,
@@ -239,7 +550,7 @@ fn main() {
                     IssueLifecycleCommands::Unarchive { identifier, format } => {
                         handle_issue_unarchive(
                             &identifier,
-                            &client as &dyn IssueClient,
+                            client.issues(),
                             &config,
                             &storage,
                             &io,
@@ -255,7 +566,7 @@ fn main() {
                     } => handle_issue_relation_link(
                         &identifier,
                         &related,
-                        &client as &dyn IssueClient,
+                        client.issues(),
                         &config,
                         &storage,
                         &io,
@@ -268,7 +579,7 @@ fn main() {
                     } => handle_issue_relation_block(
                         &identifier,
                         &related,
-                        &client as &dyn IssueClient,
+                        client.issues(),
                         &config,
                         &storage,
                         &io,
@@ -281,7 +592,7 @@ fn main() {
                     } => handle_issue_relation_duplicate(
                         &identifier,
                         &related,
-                        &client as &dyn IssueClient,
+                        client.issues(),
                         &config,
                         &storage,
                         &io,
@@ -291,7 +602,7 @@ fn main() {
                 IssueCommands::Comment { action } => match action {
                     IssueCommentCommands::Delete { id, format } => handle_comment_delete(
                         &id,
-                        &client as &dyn CommentClient,
+                        client.comments(),
                         &config,
                         &storage,
                         &io,
@@ -300,23 +611,97 @@ fn main() {
                     IssueCommentCommands::Add {
                         identifier,
                         body,
+                        body_file,
+                        editor,
+                        attach,
                         format,
-                    } => handle_issue_comment_add(
-                        &identifier,
+                    } => TextSource::from_flags(body, body_file, editor)
+                        .map(|source| source.read(&io, ""))
+                        .transpose()
+                        .and_then(|body| match body {
+                            Some(body) if attach.is_empty() => handle_issue_comment_add(
+                                &identifier,
+                                &body,
+                                client.comments(),
+                                &config,
+                                &storage,
+                                &io,
+                                format.to_format(),
+                            ),
+                            body => client.uploads().and_then(|uploads| {
+                                handle_issue_comment_add_with_files(
+                                    &identifier,
+                                    body.as_deref(),
+                                    &attach,
+                                    client.comments(),
+                                    uploads,
+                                    &config,
+                                    &storage,
+                                    &io,
+                                    format.to_format(),
+                                )
+                            }),
+                        }),
+                    IssueCommentCommands::Edit { id, body, format } => handle_comment_edit(
+                        &id,
+                        &body,
+                        client.comments(),
+                        &config,
+                        &storage,
+                        &io,
+                        format.to_format(),
+                    ),
+                    IssueCommentCommands::Reply { id, body, format } => handle_comment_reply(
+                        &id,
                         &body,
-                        &client as &dyn CommentClient,
+                        client.comments(),
+                        &config,
+                        &storage,
+                        &io,
+                        format.to_format(),
+                    ),
+                    IssueCommentCommands::Resolve { id, format } => handle_comment_resolve(
+                        &id,
+                        true,
+                        client.comments(),
+                        &config,
+                        &storage,
+                        &io,
+                        format.to_format(),
+                    ),
+                    IssueCommentCommands::Unresolve { id, format } => handle_comment_resolve(
+                        &id,
+                        false,
+                        client.comments(),
                         &config,
                         &storage,
                         &io,
                         format.to_format(),
                     ),
+                    IssueCommentCommands::React {
+                        id,
+                        emoji,
+                        remove,
+                        format,
+                    } => client.reactions().and_then(|reactions| {
+                        handle_react(
+                            ReactionTarget::Comment(id),
+                            &emoji,
+                            remove,
+                            reactions,
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        )
+                    }),
                 },
                 IssueCommands::Comments {
                     issue_id,
                     limit,
                     format,
                 } => handle_comment_list(
-                    &client as &dyn CommentClient,
+                    client.comments(),
                     &config,
                     &storage,
                     &io,
@@ -324,6 +709,108 @@ fn main() {
                     limit,
                     format.to_format(),
                 ),
+                IssueCommands::Attach {
+                    identifier,
+                    url,
+                    github_pr,
+                    file,
+                    title,
+                    format,
+                } => {
+                    if let Some(file) = file {
+                        client.attachments().and_then(|attachments| {
+                            client.uploads().and_then(|uploads| {
+                                handle_attach_file(
+                                    &identifier,
+                                    Path::new(&file),
+                                    title,
+                                    attachments,
+                                    uploads,
+                                    &config,
+                                    &storage,
+                                    &io,
+                                    format.to_format(),
+                                )
+                            })
+                        })
+                    } else {
+                        let (url, kind) = match (url, github_pr) {
+                            (_, Some(pr)) => (pr, AttachmentLinkKind::GitHubPr),
+                            (Some(url), None) => (url, AttachmentLinkKind::Url),
+                            (None, None) => {
+                                unreachable!("clap requires --url, --github-pr or --file")
+                            }
+                        };
+                        client.attachments().and_then(|attachments| {
+                            handle_attach(
+                                &identifier,
+                                &url,
+                                title,
+                                kind,
+                                attachments,
+                                &config,
+                                &storage,
+                                &io,
+                                format.to_format(),
+                            )
+                        })
+                    }
+                }
+                IssueCommands::Attachments {
+                    issue_id,
+                    limit,
+                    format,
+                } => client.attachments().and_then(|attachments| {
+                    handle_attachment_list(
+                        attachments,
+                        &config,
+                        &storage,
+                        &io,
+                        &issue_id,
+                        limit,
+                        format.to_format(),
+                    )
+                }),
+                IssueCommands::Detach { id, format } => {
+                    client.attachments().and_then(|attachments| {
+                        handle_detach(&id, attachments, &config, &storage, &io, format.to_format())
+                    })
+                }
+                IssueCommands::Subscribe { identifier, format } => handle_issue_subscribe(
+                    &identifier,
+                    true,
+                    client.issues(),
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                IssueCommands::Unsubscribe { identifier, format } => handle_issue_subscribe(
+                    &identifier,
+                    false,
+                    client.issues(),
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                IssueCommands::React {
+                    identifier,
+                    emoji,
+                    remove,
+                    format,
+                } => client.reactions().and_then(|reactions| {
+                    handle_react(
+                        ReactionTarget::Issue(identifier),
+                        &emoji,
+                        remove,
+                        reactions,
+                        &config,
+                        &storage,
+                        &io,
+                        format.to_format(),
+                    )
+                }),
             }
         }
         Commands::Team { action } => {
@@ -334,14 +821,14 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
-            let client = LinearClient::new();
+            let client = Backend::new(offline, cache_mode);
 
             match action {
                 TeamCommands::View { id, format } => handle_team_view(
                     &id,
-                    &client as &dyn TeamClient,
+                    client.teams(),
                     &config,
                     &storage,
                     &io,
@@ -349,7 +836,7 @@ fn main() {
                 ),
                 TeamCommands::List { limit, format } => handle_team_list(
                     limit,
-                    &client as &dyn TeamClient,
+                    client.teams(),
                     &config,
                     &storage,
                     &io,
@@ -365,14 +852,25 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
-            let client = LinearClient::new();
+            let client = Backend::new(offline, cache_mode);
 
             match action {
-                ProjectCommands::View { id, format } => handle_project_view(
+                ProjectCommands::View {
+                    id,
+                    issues,
+                    members,
+                    progress,
+                    format,
+                } => handle_project_view(
                     &id,
-                    &client as &dyn ProjectClient,
+                    &ProjectViewOptions {
+                        issues,
+                        members,
+                        progress,
+                    },
+                    client.projects(),
                     &config,
                     &storage,
                     &io,
@@ -380,12 +878,72 @@ fn main() {
                 ),
                 ProjectCommands::List { limit, format } => handle_project_list(
                     limit,
-                    &client as &dyn ProjectClient,
+                    client.projects(),
                     &config,
                     &storage,
                     &io,
                     format.to_format(),
                 ),
+                ProjectCommands::Milestone { action } => {
+                    client.milestones().and_then(|milestones| match action {
+                        ProjectMilestoneCommands::List { project, format } => {
+                            handle_milestone_list(
+                                &project,
+                                milestones,
+                                &config,
+                                &storage,
+                                &io,
+                                format.to_format(),
+                            )
+                        }
+                        ProjectMilestoneCommands::Create {
+                            project,
+                            name,
+                            target_date,
+                            description,
+                            format,
+                        } => handle_milestone_create(
+                            CreateMilestoneInput {
+                                project,
+                                name,
+                                description,
+                                target_date,
+                            },
+                            milestones,
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        ),
+                        ProjectMilestoneCommands::Update {
+                            id,
+                            name,
+                            target_date,
+                            description,
+                            format,
+                        } => handle_milestone_update(
+                            &id,
+                            UpdateMilestoneInput {
+                                name,
+                                description,
+                                target_date,
+                            },
+                            milestones,
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        ),
+                        ProjectMilestoneCommands::Delete { id, format } => handle_milestone_delete(
+                            &id,
+                            milestones,
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        ),
+                    })
+                }
             }
         }
         Commands::Cycle { action } => {
@@ -396,14 +954,14 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
-            let client = LinearClient::new();
+            let client = Backend::new(offline, cache_mode);
 
             match action {
                 CycleCommands::View { id, format } => handle_cycle_view(
                     &id,
-                    &client as &dyn CycleClient,
+                    client.cycles(),
                     &config,
                     &storage,
                     &io,
@@ -411,14 +969,14 @@ fn main() {
                 ),
                 CycleCommands::List { limit, format } => handle_cycle_list(
                     limit,
-                    &client as &dyn CycleClient,
+                    client.cycles(),
                     &config,
                     &storage,
                     &io,
                     format.to_format(),
                 ),
                 CycleCommands::Current { format } => handle_cycle_current(
-                    &client as &dyn CycleClient,
+                    client.cycles(),
                     &config,
                     &storage,
                     &io,
@@ -426,6 +984,246 @@ fn main() {
                 ),
             }
         }
+        Commands::Doc { action } => {
+            let storage = match KeyringStorage::new() {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(e.exit_code());
+                }
+            };
+            let config = cli_config;
+            let io = RealIo;
+            let client = Backend::new(offline, cache_mode);
+
+            client.documents().and_then(|documents| match action {
+                DocCommands::List {
+                    project,
+                    limit,
+                    format,
+                } => handle_doc_list(
+                    project.as_deref(),
+                    limit,
+                    documents,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                DocCommands::View { id, format } => {
+                    handle_doc_view(&id, documents, &config, &storage, &io, format.to_format())
+                }
+                DocCommands::Create {
+                    title,
+                    project,
+                    content,
+                    file,
+                    editor,
+                    format,
+                } => TextSource::from_flags(content, file, editor)
+                    .map(|source| source.read(&io, ""))
+                    .transpose()
+                    .and_then(|content| {
+                        handle_doc_create(
+                            &title,
+                            project,
+                            content,
+                            documents,
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        )
+                    }),
+                DocCommands::Update {
+                    id,
+                    title,
+                    content,
+                    file,
+                    editor,
+                    format,
+                } => handle_doc_update(
+                    &id,
+                    title,
+                    TextSource::from_flags(content, file, editor),
+                    documents,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                DocCommands::Delete { id, format } => {
+                    handle_doc_delete(&id, documents, &config, &storage, &io, format.to_format())
+                }
+            })
+        }
+        Commands::Initiative { action } => {
+            let storage = match KeyringStorage::new() {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(e.exit_code());
+                }
+            };
+            let config = cli_config;
+            let io = RealIo;
+            let client = Backend::new(offline, cache_mode);
+
+            client.initiatives().and_then(|initiatives| match action {
+                InitiativeCommands::List {
+                    status,
+                    limit,
+                    format,
+                } => handle_initiative_list(
+                    status,
+                    limit,
+                    initiatives,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                InitiativeCommands::View { id, format } => handle_initiative_view(
+                    &id,
+                    initiatives,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                InitiativeCommands::Create {
+                    name,
+                    description,
+                    status,
+                    target_date,
+                    format,
+                } => handle_initiative_create(
+                    &name,
+                    description,
+                    status,
+                    target_date,
+                    initiatives,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                InitiativeCommands::Update {
+                    id,
+                    name,
+                    description,
+                    status,
+                    target_date,
+                    format,
+                } => handle_initiative_update(
+                    &id,
+                    UpdateInitiativeInput {
+                        name,
+                        description,
+                        status,
+                        target_date,
+                    },
+                    initiatives,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                InitiativeCommands::AddProject {
+                    initiative,
+                    project,
+                    format,
+                } => handle_initiative_add_project(
+                    &initiative,
+                    &project,
+                    initiatives,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+                InitiativeCommands::Updates { id, limit, format } => handle_initiative_updates(
+                    &id,
+                    limit,
+                    initiatives,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                ),
+            })
+        }
+        Commands::Notification { action } => {
+            let storage = match KeyringStorage::new() {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(e.exit_code());
+                }
+            };
+            let config = cli_config;
+            let io = RealIo;
+            let client = Backend::new(offline, cache_mode);
+
+            client
+                .notifications()
+                .and_then(|notifications| match action {
+                    NotificationCommands::List {
+                        unread,
+                        limit,
+                        format,
+                    } => handle_notification_list(
+                        unread,
+                        limit,
+                        notifications,
+                        &config,
+                        &storage,
+                        &io,
+                        format.to_format(),
+                    ),
+                    NotificationCommands::Read { id, format, .. } => handle_notification_read(
+                        id.as_deref(),
+                        notifications,
+                        &config,
+                        &storage,
+                        &io,
+                        format.to_format(),
+                    ),
+                    NotificationCommands::Archive { id, format, .. } => {
+                        handle_notification_archive(
+                            id.as_deref(),
+                            notifications,
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        )
+                    }
+                    NotificationCommands::Snooze { id, until, format } => {
+                        handle_notification_snooze(
+                            &id,
+                            &until,
+                            notifications,
+                            &config,
+                            &storage,
+                            &io,
+                            format.to_format(),
+                        )
+                    }
+                })
+        }
+        Commands::Search { .. } if offline => Err(CliError::InvalidArgs(
+            "search is not available with --offline; use `issue search`".to_string(),
+        )),
+        Commands::Sync { .. } if offline => Err(CliError::InvalidArgs(
+            "sync cannot be combined with --offline".to_string(),
+        )),
+        Commands::Changelog { .. } if offline => Err(CliError::InvalidArgs(
+            "changelog cannot be combined with --offline".to_string(),
+        )),
+        Commands::Api { action: None, .. } if offline => Err(CliError::InvalidArgs(
+            "api cannot be combined with --offline".to_string(),
+        )),
         Commands::Search {
             query,
             r#type,
@@ -439,9 +1237,9 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
-            let client = LinearClient::new();
+            let client = linear_client(cache_mode);
 
             handle_semantic_search(
                 &query,
@@ -462,9 +1260,9 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
-            let client = LinearClient::new();
+            let client = Backend::new(offline, cache_mode);
 
             match action {
                 StateCommands::List {
@@ -474,7 +1272,7 @@ fn main() {
                 } => handle_state_list(
                     limit,
                     team.as_deref(),
-                    &client as &dyn StateClient,
+                    client.states(),
                     &config,
                     &storage,
                     &io,
@@ -490,9 +1288,9 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
-            let client = LinearClient::new();
+            let client = Backend::new(offline, cache_mode);
 
             match action {
                 LabelCommands::List {
@@ -502,7 +1300,7 @@ fn main() {
                 } => handle_label_list(
                     limit,
                     team.as_deref(),
-                    &client as &dyn LabelClient,
+                    client.labels(),
                     &config,
                     &storage,
                     &io,
@@ -518,14 +1316,14 @@ fn main() {
                     std::process::exit(e.exit_code());
                 }
             };
-            let config = EnvConfigProvider;
+            let config = cli_config;
             let io = RealIo;
-            let client = LinearClient::new();
+            let client = Backend::new(offline, cache_mode);
 
             match action {
                 UserCommands::List { limit, format } => handle_user_list(
                     limit,
-                    &client as &dyn UserClient,
+                    client.users(),
                     &config,
                     &storage,
                     &io,
@@ -533,6 +1331,146 @@ fn main() {
                 ),
             }
         }
+        Commands::Sync { full, only, format } => {
+            let storage = match KeyringStorage::new() {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(e.exit_code());
+                }
+            };
+            let config = cli_config;
+            let io = RealIo;
+            let client = linear_client(cache_mode);
+
+            handle_sync(
+                &only,
+                full,
+                &client as &dyn SyncClient,
+                &config,
+                &storage,
+                &io,
+                format.to_format(),
+            )
+        }
+        Commands::Changelog {
+            since,
+            until,
+            group_by,
+            teams,
+            format,
+        } => {
+            let storage = match KeyringStorage::new() {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(e.exit_code());
+                }
+            };
+            let config = cli_config;
+            let io = RealIo;
+            let client = linear_client(cache_mode);
+            let options = ChangelogOptions {
+                since,
+                until,
+                group_by,
+                teams,
+            };
+
+            GitRepo::current_dir().and_then(|repo| {
+                handle_changelog(
+                    &options,
+                    &repo,
+                    &client as &dyn ChangelogClient,
+                    &client as &dyn TeamClient,
+                    &config,
+                    &storage,
+                    &io,
+                    format.to_format(),
+                )
+            })
+        }
+        Commands::Api {
+            action: Some(ApiCommands::Validate { files }),
+            ..
+        } => {
+            // Validation only reads the bundled schema (no keyring or client)
+            let io = RealIo;
+            handle_api_validate(&files, &io)
+        }
+        Commands::Api {
+            action: None,
+            query,
+            query_file,
+            fields,
+            raw_fields,
+            operation_name,
+            paginate,
+            validate,
+        } => {
+            let storage = match KeyringStorage::new() {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(e.exit_code());
+                }
+            };
+            let config = cli_config;
+            let io = RealIo;
+            let client = linear_client(cache_mode);
+
+            let query = match (query, query_file) {
+                (Some(query), _) => Ok(query),
+                (None, Some(path)) => read_source(&path),
+                (None, None) => Err(CliError::InvalidArgs(
+                    "Pass --query or --query-file".to_string(),
+                )),
+            };
+            query
+                .and_then(|query| {
+                    Ok(GraphQLRequest {
+                        query,
+                        variables: build_variables(&fields, &raw_fields)?,
+                        operation_name,
+                    })
+                })
+                .and_then(|request| {
+                    handle_api(
+                        request,
+                        paginate,
+                        validate,
+                        &client as &dyn ApiClient,
+                        &config,
+                        &storage,
+                        &io,
+                    )
+                })
+        }
+        Commands::Sql {
+            query,
+            schema,
+            format,
+        } => {
+            // Queries only read the local mirror (no keyring or client)
+            let config = cli_config;
+            let io = RealIo;
+
+            handle_sql(query.as_deref(), schema, &config, &io, format.to_format())
+        }
+        Commands::Cache { action } => {
+            // Cache commands only touch the local filesystem (no keyring or client)
+            let config = cli_config;
+            let io = RealIo;
+
+            match action {
+                CacheCommands::Clear { format } => {
+                    handle_cache_clear(&config, &io, format.to_format())
+                }
+                CacheCommands::Stats { format } => {
+                    handle_cache_stats(&config, &io, format.to_format())
+                }
+            }
+        }
     };
 
     match result {
@@ -0,0 +1,123 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::milestones::{CreateMilestoneInput, MilestoneClient, UpdateMilestoneInput};
+use crate::dates::parse_calendar_date;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::milestones::types::MilestoneList;
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output, format_output_to_writer,
+    get_format_with_provider,
+};
+use secrecy::ExposeSecret;
+
+/// Handle `project milestone list <project>` command
+pub fn handle_list(
+    project: &str,
+    client: &dyn MilestoneClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let milestones = client.list_milestones(token.expose_secret(), project)?;
+
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&MilestoneList(milestones), format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle `project milestone create` command
+pub fn handle_create(
+    input: CreateMilestoneInput,
+    client: &dyn MilestoneClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let input = CreateMilestoneInput {
+        target_date: input
+            .target_date
+            .as_deref()
+            .map(parse_calendar_date)
+            .transpose()?,
+        ..input
+    };
+    let token = get_token_with_provider(config, storage)?;
+
+    let milestone = client.create_milestone(token.expose_secret(), input)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&milestone, format)?);
+
+    Ok(())
+}
+
+/// Handle `project milestone update <id>` command
+pub fn handle_update(
+    id: &str,
+    input: UpdateMilestoneInput,
+    client: &dyn MilestoneClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    if input == UpdateMilestoneInput::default() {
+        return Err(CliError::InvalidArgs(
+            "milestone update requires at least one field to change".to_string(),
+        ));
+    }
+    let input = UpdateMilestoneInput {
+        target_date: input
+            .target_date
+            .as_deref()
+            .map(parse_calendar_date)
+            .transpose()?,
+        ..input
+    };
+
+    let token = get_token_with_provider(config, storage)?;
+
+    let milestone = client.update_milestone(token.expose_secret(), id, input)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&milestone, format)?);
+
+    Ok(())
+}
+
+/// Handle `project milestone delete <id>` command
+pub fn handle_delete(
+    id: &str,
+    client: &dyn MilestoneClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    client.delete_milestone(token.expose_secret(), id)?;
+
+    let format = get_format_with_provider(format_flag, config);
+    let msg = if matches!(format, OutputFormat::Json) {
+        serde_json::json!({
+            "deleted": true,
+            "id": id,
+        })
+        .to_string()
+    } else {
+        format!("Deleted milestone {id}")
+    };
+    io.print(&msg);
+
+    Ok(())
+}
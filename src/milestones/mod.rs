@@ -0,0 +1,4 @@
+pub mod commands;
+pub mod types;
+
+pub use types::{MilestoneList, ProjectMilestone};
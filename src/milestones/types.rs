@@ -0,0 +1,247 @@
+use crate::client::queries;
+use crate::error::CliError;
+use crate::output::{
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+
+/// Milestone within a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMilestone {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub target_date: Option<String>,
+    /// `unstarted`, `next`, `overdue` or `done`
+    pub status: String,
+    /// Share of the milestone's issues that are completed, 0.0 to 1.0
+    pub progress: f64,
+}
+
+fn format_progress_percent(progress: f64) -> String {
+    format!("{:.0}%", progress * 100.0)
+}
+
+/// Write a markdown table of milestones, as used by `project view`
+pub(crate) fn write_milestone_table(
+    output: &mut String,
+    milestones: &[ProjectMilestone],
+) -> Result<(), CliError> {
+    writeln!(
+        output,
+        "| Milestone | Status | Progress | Target |\n|---|---|---|---|"
+    )
+    .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+    for milestone in milestones {
+        writeln!(
+            output,
+            "| {} | {} | {} | {} |",
+            milestone.name.replace('|', "\\|"),
+            milestone.status,
+            format_progress_percent(milestone.progress),
+            milestone.target_date.as_deref().unwrap_or("")
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+    }
+    Ok(())
+}
+
+impl TableFormatter for ProjectMilestone {
+    fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        let mut rows = vec![
+            (Cow::Borrowed("Name"), Cow::Borrowed(self.name.as_str())),
+            (Cow::Borrowed("Status"), Cow::Borrowed(self.status.as_str())),
+            (
+                Cow::Borrowed("Progress"),
+                Cow::Owned(format_progress_percent(self.progress)),
+            ),
+        ];
+
+        if let Some(target) = &self.target_date {
+            rows.push((Cow::Borrowed("Target"), Cow::Borrowed(target.as_str())));
+        }
+        if let Some(description) = &self.description {
+            rows.push((
+                Cow::Borrowed("Description"),
+                Cow::Borrowed(description.as_str()),
+            ));
+        }
+        rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.id.as_str())));
+
+        rows
+    }
+}
+
+impl MarkdownFormatter for ProjectMilestone {
+    fn markdown_capacity_hint(&self) -> usize {
+        100 + self.name.len() + self.id.len() + self.description.as_ref().map_or(0, |d| d.len())
+    }
+
+    fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
+        writeln!(output, "# {}\n", self.name)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        writeln!(output, "**Status:** {}", self.status)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        writeln!(
+            output,
+            "**Progress:** {}",
+            format_progress_percent(self.progress)
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        if let Some(target) = &self.target_date {
+            writeln!(output, "**Target:** {}", target)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        writeln!(output, "**ID:** {}", self.id)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        if let Some(description) = self.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            writeln!(output, "\n{}", description.trim_end())
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Formattable for ProjectMilestone {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        MilestoneList(vec![self.clone()]).to_csv()
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        fast_markdown_formatter(self)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        generic_table_formatter(self)
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
+}
+
+/// Wrapper for a list of milestones
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct MilestoneList(pub Vec<ProjectMilestone>);
+
+impl Formattable for MilestoneList {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_list_formatter(&self.0)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record(["id", "name", "status", "progress", "target_date"])
+            .csv_err("Failed to write CSV header")?;
+
+        for milestone in &self.0 {
+            let progress = format!("{:.2}", milestone.progress);
+            wtr.write_record([
+                milestone.id.as_str(),
+                milestone.name.as_str(),
+                milestone.status.as_str(),
+                progress.as_str(),
+                milestone.target_date.as_deref().unwrap_or(""),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        let mut output =
+            String::with_capacity(60 + self.0.iter().map(|m| 30 + m.name.len()).sum::<usize>());
+
+        writeln!(output, "## Milestones ({})\n", self.0.len())
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        if !self.0.is_empty() {
+            write_milestone_table(&mut output, &self.0)?;
+        }
+
+        Ok(output)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new("Name").flexible(),
+                TableColumn::new("Status"),
+                TableColumn::new("Progress"),
+                TableColumn::new("Target"),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
+            |milestone| {
+                vec![
+                    milestone.name.clone(),
+                    milestone.status.clone(),
+                    format_progress_percent(milestone.progress),
+                    milestone.target_date.clone().unwrap_or_default(),
+                    milestone.id.clone(),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
+}
+
+impl From<queries::ProjectMilestoneNode> for ProjectMilestone {
+    fn from(node: queries::ProjectMilestoneNode) -> Self {
+        let status = match node.status {
+            queries::ProjectMilestoneStatus::Unstarted => "unstarted",
+            queries::ProjectMilestoneStatus::Next => "next",
+            queries::ProjectMilestoneStatus::Overdue => "overdue",
+            queries::ProjectMilestoneStatus::Done => "done",
+        };
+
+        ProjectMilestone {
+            id: node.id.inner().to_string(),
+            name: node.name,
+            description: node.description,
+            target_date: node.target_date.map(|d| d.0),
+            status: status.to_string(),
+            progress: node.progress,
+        }
+    }
+}
+
+/// Convert a milestone connection in the project's sort order
+#[must_use]
+pub fn milestones_from_connection(
+    connection: queries::ProjectMilestoneConnection,
+) -> Vec<ProjectMilestone> {
+    let mut nodes = connection.nodes;
+    nodes.sort_by(|a, b| a.sort_order.total_cmp(&b.sort_order));
+    nodes.into_iter().map(Into::into).collect()
+}
@@ -0,0 +1,445 @@
+use crate::auth::config::ConfigProvider;
+use crate::client::comments::CommentClient;
+use crate::client::cycles::CycleClient;
+use crate::client::issues::{
+    CreateIssueInput, CreateIssueRelationInput, IssueClient, UpdateIssueInput,
+};
+use crate::client::labels::LabelClient;
+use crate::client::projects::ProjectClient;
+use crate::client::search::SearchClient;
+use crate::client::states::StateClient;
+use crate::client::teams::TeamClient;
+use crate::client::users::UserClient;
+use crate::comments::types::Comment;
+use crate::cycles::types::Cycle;
+use crate::error::CliError;
+use crate::issues::types::Issue;
+use crate::labels::types::IssueLabel;
+use crate::mirror::sync::VIEWER_ID_KEY;
+use crate::mirror::{Mirror, db_error, require_mirror_path};
+use crate::projects::types::{Project, ProjectIssue, ProjectMember};
+use crate::states::types::WorkflowState;
+use crate::teams::types::Team;
+use crate::users::types::User;
+use chrono::{DateTime, Utc};
+use rusqlite::OptionalExtension;
+use rusqlite::types::Value;
+use serde::de::DeserializeOwned;
+use std::sync::{Mutex, MutexGuard};
+
+/// Read-only client serving `--offline` commands from the local mirror
+///
+/// Tokens are accepted but ignored; write operations fail with `InvalidArgs`.
+pub struct MirrorClient {
+    mirror: Mutex<Mirror>,
+    now: DateTime<Utc>,
+}
+
+impl MirrorClient {
+    #[must_use]
+    pub fn new(mirror: Mirror) -> Self {
+        Self {
+            mirror: Mutex::new(mirror),
+            now: Utc::now(),
+        }
+    }
+
+    /// Open the mirror configured for this environment (see [`crate::mirror::mirror_path`])
+    pub fn open(config: &dyn ConfigProvider) -> Result<Self, CliError> {
+        let path = require_mirror_path(config)?;
+        Ok(Self::new(Mirror::open_read_only(&path)?))
+    }
+
+    /// Evaluate cycle timing flags against `now` instead of the sync time
+    #[must_use]
+    pub fn with_now(mut self, now: DateTime<Utc>) -> Self {
+        self.now = now;
+        self
+    }
+
+    fn mirror(&self) -> MutexGuard<'_, Mirror> {
+        self.mirror
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn records<T: DeserializeOwned>(
+        &self,
+        sql: &str,
+        params: Vec<Value>,
+    ) -> Result<Vec<T>, CliError> {
+        self.mirror()
+            .query_records(sql, rusqlite::params_from_iter(params))
+    }
+
+    fn record<T: DeserializeOwned>(
+        &self,
+        sql: &str,
+        params: Vec<Value>,
+        not_found: impl FnOnce() -> String,
+    ) -> Result<T, CliError> {
+        self.records(sql, params)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| CliError::NotFound(not_found()))
+    }
+
+    /// Resolve an issue ID or identifier to its identifier
+    fn issue_identifier(&self, issue_id: &str) -> Result<Option<String>, CliError> {
+        self.mirror()
+            .connection()
+            .query_row(
+                "SELECT identifier FROM issues WHERE id = ?1 OR identifier = ?1 COLLATE NOCASE",
+                [issue_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(db_error)
+    }
+
+    /// Recompute time-relative cycle flags, which go stale once mirrored
+    fn refresh_cycle_timing(&self, mut cycle: Cycle) -> Cycle {
+        let parse = |value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .ok()
+                .map(|date| date.with_timezone(&Utc))
+        };
+        if let (Some(starts_at), Some(ends_at)) = (parse(&cycle.starts_at), parse(&cycle.ends_at)) {
+            cycle.is_active = starts_at <= self.now && self.now < ends_at;
+            cycle.is_future = starts_at > self.now;
+            cycle.is_past = ends_at <= self.now;
+        }
+        cycle
+    }
+}
+
+fn offline_write(operation: &str) -> CliError {
+    CliError::InvalidArgs(format!("{operation} is not available with --offline"))
+}
+
+fn text(value: &str) -> Value {
+    Value::Text(value.to_string())
+}
+
+fn limit(value: usize) -> Value {
+    Value::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+}
+
+fn not_synced(what: &str) -> String {
+    format!("{what} not found in offline mirror (run `linear sync` to update it)")
+}
+
+impl IssueClient for MirrorClient {
+    fn get_issue(&self, _token: &str, identifier: &str) -> Result<Issue, CliError> {
+        self.record(
+            "SELECT data FROM issues WHERE id = ?1 OR identifier = ?1 COLLATE NOCASE",
+            vec![text(identifier)],
+            || not_synced(&format!("Issue {identifier}")),
+        )
+    }
+
+    fn list_issues(
+        &self,
+        _token: &str,
+        assignee: Option<String>,
+        project: Option<String>,
+        limit_value: usize,
+    ) -> Result<Vec<Issue>, CliError> {
+        let mut sql = String::from("SELECT data FROM issues WHERE 1 = 1");
+        let mut params = Vec::new();
+
+        if let Some(assignee) = assignee {
+            if assignee == "@me" {
+                let viewer_id = self.mirror().meta(VIEWER_ID_KEY)?.ok_or_else(|| {
+                    CliError::InvalidArgs(
+                        "The offline mirror does not know the current user; run `linear sync`"
+                            .to_string(),
+                    )
+                })?;
+                sql.push_str(" AND assignee_id = ?");
+                params.push(text(&viewer_id));
+            } else if assignee.contains('@') {
+                sql.push_str(" AND assignee_email = ? COLLATE NOCASE");
+                params.push(text(&assignee));
+            } else {
+                sql.push_str(" AND assignee_id = ?");
+                params.push(text(&assignee));
+            }
+        }
+
+        if let Some(project) = project {
+            sql.push_str(" AND (project_id = ? OR project_slug_id = ? OR project_name = ?)");
+            params.extend([text(&project), text(&project), text(&project)]);
+        }
+
+        sql.push_str(" ORDER BY created_at DESC LIMIT ?");
+        params.push(limit(limit_value));
+
+        self.records(&sql, params)
+    }
+
+    fn create_issue(&self, _token: &str, _input: CreateIssueInput) -> Result<Issue, CliError> {
+        Err(offline_write("issue create"))
+    }
+
+    fn update_issue(
+        &self,
+        _token: &str,
+        _id: &str,
+        _input: UpdateIssueInput,
+    ) -> Result<Issue, CliError> {
+        Err(offline_write("issue update"))
+    }
+
+    fn archive_issue(&self, _token: &str, _id: &str, _trash: bool) -> Result<Issue, CliError> {
+        Err(offline_write("issue archive"))
+    }
+
+    fn unarchive_issue(&self, _token: &str, _id: &str) -> Result<Issue, CliError> {
+        Err(offline_write("issue unarchive"))
+    }
+
+    fn set_issue_subscribed(
+        &self,
+        _token: &str,
+        _id: &str,
+        _subscribed: bool,
+    ) -> Result<Issue, CliError> {
+        Err(offline_write("issue subscribe"))
+    }
+
+    fn delete_issue(&self, _token: &str, _id: &str, _permanently: bool) -> Result<(), CliError> {
+        Err(offline_write("issue delete"))
+    }
+
+    fn create_issue_relation(
+        &self,
+        _token: &str,
+        _input: CreateIssueRelationInput,
+    ) -> Result<Issue, CliError> {
+        Err(offline_write("issue relation"))
+    }
+}
+
+impl CommentClient for MirrorClient {
+    fn list_comments(
+        &self,
+        _token: &str,
+        issue_id: &str,
+        limit_value: usize,
+    ) -> Result<Vec<Comment>, CliError> {
+        let identifier = self
+            .issue_identifier(issue_id)?
+            .ok_or_else(|| CliError::NotFound(not_synced(&format!("Issue {issue_id}"))))?;
+
+        self.records(
+            "SELECT data FROM comments WHERE issue_identifier = ?1 ORDER BY created_at LIMIT ?2",
+            vec![text(&identifier), limit(limit_value)],
+        )
+    }
+
+    fn delete_comment(&self, _token: &str, _id: &str) -> Result<(), CliError> {
+        Err(offline_write("comment delete"))
+    }
+
+    fn create_comment(
+        &self,
+        _token: &str,
+        _input: crate::client::comments::CreateCommentInput,
+    ) -> Result<Comment, CliError> {
+        Err(offline_write("comment create"))
+    }
+
+    fn update_comment(&self, _token: &str, _id: &str, _body: &str) -> Result<Comment, CliError> {
+        Err(offline_write("comment edit"))
+    }
+
+    fn set_comment_resolved(
+        &self,
+        _token: &str,
+        _id: &str,
+        _resolved: bool,
+    ) -> Result<Comment, CliError> {
+        Err(offline_write("comment resolve"))
+    }
+}
+
+impl SearchClient for MirrorClient {
+    fn search_issues(
+        &self,
+        _token: &str,
+        term: &str,
+        team_id: Option<&str>,
+        include_comments: bool,
+        limit_value: usize,
+    ) -> Result<Vec<Issue>, CliError> {
+        let pattern = format!(
+            "%{}%",
+            term.replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+        let mut sql = String::from(
+            "SELECT data FROM issues WHERE (title LIKE ?1 ESCAPE '\\' \
+             OR description LIKE ?1 ESCAPE '\\' OR identifier LIKE ?1 ESCAPE '\\'",
+        );
+        if include_comments {
+            sql.push_str(
+                " OR identifier IN (SELECT issue_identifier FROM comments \
+                 WHERE body LIKE ?1 ESCAPE '\\')",
+            );
+        }
+        sql.push(')');
+
+        let mut params = vec![text(&pattern)];
+        if let Some(team) = team_id {
+            // Issue identifiers are prefixed with the team key
+            sql.push_str(
+                " AND identifier LIKE (SELECT key FROM teams WHERE id = ?2 OR key = ?2 COLLATE NOCASE) || '-%'",
+            );
+            params.push(text(team));
+        }
+        sql.push_str(&format!(
+            " ORDER BY updated_at DESC LIMIT ?{}",
+            params.len() + 1
+        ));
+        params.push(limit(limit_value));
+
+        self.records(&sql, params)
+    }
+}
+
+impl TeamClient for MirrorClient {
+    fn get_team(&self, _token: &str, id: &str) -> Result<Team, CliError> {
+        self.record(
+            "SELECT data FROM teams WHERE id = ?1 OR key = ?1 COLLATE NOCASE",
+            vec![text(id)],
+            || not_synced(&format!("Team {id}")),
+        )
+    }
+
+    fn list_teams(&self, _token: &str, limit_value: usize) -> Result<Vec<Team>, CliError> {
+        self.records(
+            "SELECT data FROM teams ORDER BY name LIMIT ?1",
+            vec![limit(limit_value)],
+        )
+    }
+}
+
+impl ProjectClient for MirrorClient {
+    fn get_project(&self, _token: &str, reference: &str) -> Result<Project, CliError> {
+        self.record(
+            "SELECT data FROM projects WHERE id = ?1 OR slug_id = ?1 OR name = ?1 COLLATE NOCASE",
+            vec![text(reference)],
+            || not_synced(&format!("Project {reference}")),
+        )
+    }
+
+    fn list_projects(&self, _token: &str, limit_value: usize) -> Result<Vec<Project>, CliError> {
+        self.records(
+            "SELECT data FROM projects ORDER BY created_at DESC LIMIT ?1",
+            vec![limit(limit_value)],
+        )
+    }
+
+    fn list_project_issues(
+        &self,
+        _token: &str,
+        project_id: &str,
+    ) -> Result<Vec<ProjectIssue>, CliError> {
+        // State types live on the mirrored states; estimates are not mirrored
+        self.records(
+            "SELECT json_object(
+                'id', issues.id, 'identifier', issues.identifier, 'title', issues.title,
+                'url', issues.url, 'state_name', issues.state_name,
+                'state_type', COALESCE(states.type, 'unstarted'),
+                'assignee_name', issues.assignee_name, 'estimate', NULL)
+             FROM issues LEFT JOIN states ON states.id = issues.state_id
+             WHERE issues.project_id = ?1
+             ORDER BY issues.created_at LIMIT 250",
+            vec![text(project_id)],
+        )
+    }
+
+    fn list_project_members(
+        &self,
+        _token: &str,
+        _project_id: &str,
+    ) -> Result<Vec<ProjectMember>, CliError> {
+        Err(CliError::InvalidArgs(
+            "project members are not available with --offline".to_string(),
+        ))
+    }
+}
+
+impl CycleClient for MirrorClient {
+    fn get_cycle(&self, _token: &str, id: &str) -> Result<Cycle, CliError> {
+        let cycle = self.record(
+            "SELECT data FROM cycles WHERE id = ?1",
+            vec![text(id)],
+            || not_synced(&format!("Cycle {id}")),
+        )?;
+        Ok(self.refresh_cycle_timing(cycle))
+    }
+
+    fn list_cycles(&self, _token: &str, limit_value: usize) -> Result<Vec<Cycle>, CliError> {
+        let cycles: Vec<Cycle> = self.records(
+            "SELECT data FROM cycles ORDER BY starts_at DESC LIMIT ?1",
+            vec![limit(limit_value)],
+        )?;
+        Ok(cycles
+            .into_iter()
+            .map(|cycle| self.refresh_cycle_timing(cycle))
+            .collect())
+    }
+}
+
+impl LabelClient for MirrorClient {
+    fn list_labels(
+        &self,
+        _token: &str,
+        team_key: Option<&str>,
+        limit_value: usize,
+    ) -> Result<Vec<IssueLabel>, CliError> {
+        if team_key.is_some() {
+            return Err(CliError::InvalidArgs(
+                "label list --team is not available with --offline".to_string(),
+            ));
+        }
+        self.records(
+            "SELECT data FROM labels ORDER BY name LIMIT ?1",
+            vec![limit(limit_value)],
+        )
+    }
+}
+
+impl StateClient for MirrorClient {
+    fn list_states(
+        &self,
+        _token: &str,
+        team_key: Option<&str>,
+        limit_value: usize,
+    ) -> Result<Vec<WorkflowState>, CliError> {
+        match team_key {
+            Some(team_key) => self.records(
+                "SELECT data FROM states WHERE team_name = \
+                 (SELECT name FROM teams WHERE key = ?1 COLLATE NOCASE) \
+                 ORDER BY position LIMIT ?2",
+                vec![text(team_key), limit(limit_value)],
+            ),
+            None => self.records(
+                "SELECT data FROM states ORDER BY team_name, position LIMIT ?1",
+                vec![limit(limit_value)],
+            ),
+        }
+    }
+}
+
+impl UserClient for MirrorClient {
+    fn list_users(&self, _token: &str, limit_value: usize) -> Result<Vec<User>, CliError> {
+        self.records(
+            "SELECT data FROM users ORDER BY name LIMIT ?1",
+            vec![limit(limit_value)],
+        )
+    }
+}
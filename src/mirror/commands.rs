@@ -0,0 +1,172 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::sync::SyncClient;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::mirror::sync::run_sync;
+use crate::mirror::types::{SqlResult, SyncReport};
+use crate::mirror::{Mirror, SyncResource, db_error, require_mirror_path};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
+use rusqlite::limits::Limit;
+use rusqlite::types::ValueRef;
+use secrecy::ExposeSecret;
+use serde_json::Value;
+use std::fmt::Write as FmtWrite;
+
+/// Columns of every mirror table, for `linear sql --schema`
+const SCHEMA_QUERY: &str = "
+SELECT m.name AS \"table\",
+       c.name AS \"column\",
+       c.type AS \"type\",
+       CASE WHEN c.\"notnull\" THEN 'no' ELSE 'yes' END AS nullable,
+       CASE WHEN c.hidden IN (2, 3) THEN 'generated' ELSE '' END AS note
+FROM sqlite_master AS m
+JOIN pragma_table_xinfo(m.name) AS c
+WHERE m.type = 'table' AND m.name NOT LIKE 'sqlite_%'
+ORDER BY m.name, c.cid
+";
+
+/// Handle the sync command
+///
+/// Syncs every resource when `only` is empty.
+pub fn handle_sync(
+    only: &[SyncResource],
+    full: bool,
+    client: &dyn SyncClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+    let path = require_mirror_path(config)?;
+    let mut mirror = Mirror::open(&path)?;
+
+    // Keep the canonical order regardless of how `--only` was spelled
+    let resources: Vec<SyncResource> = SyncResource::ALL
+        .into_iter()
+        .filter(|resource| only.is_empty() || only.contains(resource))
+        .collect();
+
+    let reports = run_sync(
+        client,
+        token.expose_secret(),
+        &mut mirror,
+        &resources,
+        full,
+        chrono::Utc::now(),
+    )?;
+
+    let report = SyncReport {
+        database: path.display().to_string(),
+        resources: reports,
+    };
+
+    let format = get_format_with_provider(format_flag, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&report, format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle the sql command
+///
+/// Runs a single read-only statement against the offline mirror, or lists the
+/// mirror's tables and columns with `--schema`.
+pub fn handle_sql(
+    query: Option<&str>,
+    schema: bool,
+    config: &dyn ConfigProvider,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let path = require_mirror_path(config)?;
+    let mirror = Mirror::open_read_only(&path)?;
+
+    let sql = match query {
+        Some(_) if schema => {
+            return Err(CliError::InvalidArgs(
+                "Pass either a query or --schema, not both".to_string(),
+            ));
+        }
+        Some(query) => query,
+        None if schema => SCHEMA_QUERY,
+        None => {
+            return Err(CliError::InvalidArgs(
+                "A SQL query is required (or pass --schema)".to_string(),
+            ));
+        }
+    };
+
+    let result = run_query(&mirror, sql)?;
+
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&result, format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Run one read-only statement and collect its rows.
+///
+/// The connection is already read-only; statements SQLite reports as writes are
+/// refused up front for a clearer error, and `ATTACH` is disabled so queries
+/// cannot reach other database files.
+pub fn run_query(mirror: &Mirror, sql: &str) -> Result<SqlResult, CliError> {
+    let conn = mirror.connection();
+    conn.set_limit(Limit::SQLITE_LIMIT_ATTACHED, 0)
+        .map_err(db_error)?;
+
+    let mut stmt = conn.prepare(sql).map_err(sql_error)?;
+    if !stmt.readonly() {
+        return Err(CliError::InvalidArgs(
+            "linear sql is read-only; only SELECT-style statements are allowed".to_string(),
+        ));
+    }
+
+    let columns: Vec<String> = stmt
+        .column_names()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let width = columns.len();
+
+    let rows = stmt
+        .query_map([], |row| {
+            (0..width)
+                .map(|idx| row.get_ref(idx).map(sql_value))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(sql_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(sql_error)?;
+
+    Ok(SqlResult { columns, rows })
+}
+
+/// SQL mistakes are user errors rather than database failures
+fn sql_error(e: rusqlite::Error) -> CliError {
+    CliError::InvalidArgs(format!("SQL error: {e}"))
+}
+
+fn sql_value(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(n) => Value::from(n),
+        // NaN and infinities have no JSON representation
+        ValueRef::Real(n) => serde_json::Number::from_f64(n).map_or(Value::Null, Value::Number),
+        ValueRef::Text(text) => Value::String(String::from_utf8_lossy(text).into_owned()),
+        ValueRef::Blob(bytes) => Value::String(bytes.iter().fold(
+            String::with_capacity(bytes.len() * 2),
+            |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            },
+        )),
+    }
+}
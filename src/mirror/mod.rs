@@ -0,0 +1,602 @@
+//! Local SQLite mirror of workspace data for offline reads and analytics.
+//!
+//! `linear sync` incrementally copies teams, users, workflow states, labels,
+//! projects, cycles, issues and comments into a SQLite file. Each resource keeps
+//! an `updatedAt` watermark so later syncs only fetch records changed since the
+//! previous run; `--full` ignores the watermarks and refetches everything.
+//!
+//! With the global `--offline` flag, read commands are served by
+//! [`client::MirrorClient`] instead of the Linear API.
+//!
+//! The database lives at `LINEAR_CLI_MIRROR_PATH` when set, otherwise at
+//! `$XDG_DATA_HOME/linear-cli/mirror.sqlite3` (default `~/.local/share`).
+//! Every table stores typed columns for querying plus the full record as JSON
+//! in a `data` column.
+
+pub mod client;
+pub mod commands;
+pub mod sync;
+pub mod types;
+
+use crate::auth::config::ConfigProvider;
+use crate::cache::create_private_dir;
+use crate::client::sync::SyncRecords;
+use crate::error::CliError;
+use rusqlite::{Connection, OpenFlags, OptionalExtension, params};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+
+/// Directory name under the platform data directory
+const DATA_DIR_NAME: &str = "linear-cli";
+
+/// Mirror database file name
+const MIRROR_FILE_NAME: &str = "mirror.sqlite3";
+
+/// Current schema version, stored in `PRAGMA user_version`
+const SCHEMA_VERSION: i32 = 1;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS teams (
+    id TEXT PRIMARY KEY,
+    key TEXT NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    private INTEGER NOT NULL,
+    created_at TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS users (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    email TEXT NOT NULL,
+    active INTEGER NOT NULL,
+    admin INTEGER NOT NULL,
+    guest INTEGER NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS states (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    type TEXT NOT NULL,
+    color TEXT NOT NULL,
+    position REAL NOT NULL,
+    description TEXT,
+    team_name TEXT,
+    data TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS labels (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    color TEXT NOT NULL,
+    description TEXT,
+    is_group INTEGER NOT NULL,
+    parent_name TEXT,
+    data TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS projects (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    slug_id TEXT NOT NULL,
+    status TEXT NOT NULL,
+    status_type TEXT NOT NULL,
+    progress REAL NOT NULL,
+    priority INTEGER NOT NULL,
+    lead_name TEXT,
+    start_date TEXT,
+    target_date TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    url TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS cycles (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    number REAL NOT NULL,
+    team_key TEXT NOT NULL,
+    team_name TEXT NOT NULL,
+    starts_at TEXT NOT NULL,
+    ends_at TEXT NOT NULL,
+    completed_at TEXT,
+    progress REAL NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS issues (
+    id TEXT PRIMARY KEY,
+    identifier TEXT NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT,
+    state_id TEXT NOT NULL,
+    state_name TEXT NOT NULL,
+    priority INTEGER NOT NULL,
+    assignee_id TEXT,
+    assignee_name TEXT,
+    assignee_email TEXT,
+    creator_id TEXT NOT NULL,
+    creator_name TEXT NOT NULL,
+    project_id TEXT,
+    project_name TEXT,
+    project_slug_id TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    url TEXT NOT NULL,
+    data TEXT NOT NULL,
+    -- Generated for ad-hoc SQL: team key from the identifier (`ENG-123` -> `ENG`)
+    -- and `state` as an alias of `state_name`
+    team TEXT GENERATED ALWAYS AS (substr(identifier, 1, instr(identifier, '-') - 1)) VIRTUAL,
+    state TEXT GENERATED ALWAYS AS (state_name) VIRTUAL
+);
+CREATE INDEX IF NOT EXISTS issues_identifier ON issues (identifier);
+CREATE INDEX IF NOT EXISTS issues_created_at ON issues (created_at);
+CREATE TABLE IF NOT EXISTS comments (
+    id TEXT PRIMARY KEY,
+    issue_identifier TEXT,
+    body TEXT NOT NULL,
+    user_name TEXT NOT NULL,
+    user_email TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    edited_at TEXT,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS comments_issue_identifier ON comments (issue_identifier);
+CREATE TABLE IF NOT EXISTS sync_state (
+    resource TEXT PRIMARY KEY,
+    watermark TEXT NOT NULL,
+    synced_at TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS meta (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+";
+
+/// Mirrored resources, in the order `linear sync` fetches them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum SyncResource {
+    Teams,
+    Users,
+    States,
+    Labels,
+    Projects,
+    Cycles,
+    Issues,
+    Comments,
+}
+
+impl SyncResource {
+    pub const ALL: [SyncResource; 8] = [
+        SyncResource::Teams,
+        SyncResource::Users,
+        SyncResource::States,
+        SyncResource::Labels,
+        SyncResource::Projects,
+        SyncResource::Cycles,
+        SyncResource::Issues,
+        SyncResource::Comments,
+    ];
+
+    /// Table (and sync state key) for this resource
+    #[must_use]
+    pub fn table(self) -> &'static str {
+        match self {
+            SyncResource::Teams => "teams",
+            SyncResource::Users => "users",
+            SyncResource::States => "states",
+            SyncResource::Labels => "labels",
+            SyncResource::Projects => "projects",
+            SyncResource::Cycles => "cycles",
+            SyncResource::Issues => "issues",
+            SyncResource::Comments => "comments",
+        }
+    }
+}
+
+/// Resolve the mirror database path from `LINEAR_CLI_MIRROR_PATH` or the data directory.
+#[must_use]
+pub fn mirror_path(config: &dyn ConfigProvider) -> Option<PathBuf> {
+    if let Some(path) = config
+        .get_var("LINEAR_CLI_MIRROR_PATH")
+        .filter(|path| !path.is_empty())
+    {
+        return Some(PathBuf::from(path));
+    }
+
+    let base = config
+        .get_var("XDG_DATA_HOME")
+        .filter(|dir| Path::new(dir).is_absolute())
+        .map(PathBuf::from)
+        .or_else(|| {
+            config
+                .get_var("HOME")
+                .filter(|home| !home.is_empty())
+                .map(|home| PathBuf::from(home).join(".local").join("share"))
+        })
+        .or_else(|| {
+            config
+                .get_var("LOCALAPPDATA")
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from)
+        })?;
+    Some(base.join(DATA_DIR_NAME).join(MIRROR_FILE_NAME))
+}
+
+/// Resolve the mirror path, failing when no location can be determined
+pub(crate) fn require_mirror_path(config: &dyn ConfigProvider) -> Result<PathBuf, CliError> {
+    mirror_path(config).ok_or_else(|| {
+        CliError::General(
+            "Cannot determine mirror location: set LINEAR_CLI_MIRROR_PATH, XDG_DATA_HOME or HOME"
+                .to_string(),
+        )
+    })
+}
+
+/// Create an empty owner-only file at `path` unless one exists; SQLite's
+/// journal files take their permissions from it
+#[cfg(unix)]
+fn create_private_file(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::OpenOptionsExt;
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)
+    {
+        Err(e) if e.kind() != std::io::ErrorKind::AlreadyExists => Err(e),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(not(unix))]
+fn create_private_file(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+pub(crate) fn db_error(e: rusqlite::Error) -> CliError {
+    CliError::General(format!("Mirror database error: {e}"))
+}
+
+fn json_error(e: serde_json::Error) -> CliError {
+    CliError::General(format!("Corrupt mirror record: {e}"))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, CliError> {
+    serde_json::to_string(value).map_err(json_error)
+}
+
+/// SQLite-backed mirror of workspace data
+pub struct Mirror {
+    conn: Connection,
+}
+
+impl Mirror {
+    /// Open (creating if needed) the mirror database for writing.
+    ///
+    /// The mirror holds issue text and user emails, so a new directory and
+    /// database file are created readable only by the current user, like the
+    /// response cache.
+    pub fn open(path: &Path) -> Result<Self, CliError> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            create_private_dir(parent).map_err(|e| {
+                CliError::General(format!(
+                    "Failed to create mirror directory {}: {e}",
+                    parent.display()
+                ))
+            })?;
+        }
+        create_private_file(path).map_err(|e| {
+            CliError::General(format!(
+                "Failed to create mirror database {}: {e}",
+                path.display()
+            ))
+        })?;
+        let conn = Connection::open(path).map_err(db_error)?;
+        Self::initialize(conn)
+    }
+
+    /// Open an existing mirror without write access.
+    pub fn open_read_only(path: &Path) -> Result<Self, CliError> {
+        if !path.exists() {
+            return Err(CliError::NotFound(format!(
+                "No offline mirror at {}; run `linear sync` first",
+                path.display()
+            )));
+        }
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .map_err(db_error)?;
+        Ok(Self { conn })
+    }
+
+    /// Open a throwaway in-memory mirror (used by tests).
+    pub fn open_in_memory() -> Result<Self, CliError> {
+        Self::initialize(Connection::open_in_memory().map_err(db_error)?)
+    }
+
+    fn initialize(conn: Connection) -> Result<Self, CliError> {
+        conn.execute_batch(SCHEMA).map_err(db_error)?;
+        conn.pragma_update(None, "user_version", SCHEMA_VERSION)
+            .map_err(db_error)?;
+        Ok(Self { conn })
+    }
+
+    /// Underlying SQLite connection
+    #[must_use]
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Watermark recorded by the last successful sync of `resource`
+    pub fn watermark(&self, resource: SyncResource) -> Result<Option<String>, CliError> {
+        self.conn
+            .query_row(
+                "SELECT watermark FROM sync_state WHERE resource = ?1",
+                [resource.table()],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(db_error)
+    }
+
+    /// Number of mirrored records for `resource`
+    pub fn count(&self, resource: SyncResource) -> Result<usize, CliError> {
+        let sql = format!("SELECT COUNT(*) FROM {}", resource.table());
+        self.conn
+            .query_row(&sql, [], |row| row.get::<_, i64>(0))
+            .map(|count| count as usize)
+            .map_err(db_error)
+    }
+
+    pub fn meta(&self, key: &str) -> Result<Option<String>, CliError> {
+        self.conn
+            .query_row("SELECT value FROM meta WHERE key = ?1", [key], |row| {
+                row.get(0)
+            })
+            .optional()
+            .map_err(db_error)
+    }
+
+    pub fn set_meta(&self, key: &str, value: &str) -> Result<(), CliError> {
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO meta (key, value) VALUES (?1, ?2)",
+                params![key, value],
+            )
+            .map(|_| ())
+            .map_err(db_error)
+    }
+
+    /// Run `f` inside a transaction, committing only when it succeeds.
+    pub(crate) fn transaction<T>(
+        &mut self,
+        f: impl FnOnce(&Connection) -> Result<T, CliError>,
+    ) -> Result<T, CliError> {
+        let tx = self.conn.transaction().map_err(db_error)?;
+        let value = f(&tx)?;
+        tx.commit().map_err(db_error)?;
+        Ok(value)
+    }
+
+    /// Deserialize the `data` column of every row returned by `sql`
+    pub(crate) fn query_records<T: DeserializeOwned>(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> Result<Vec<T>, CliError> {
+        let mut stmt = self.conn.prepare(sql).map_err(db_error)?;
+        let rows = stmt
+            .query_map(params, |row| row.get::<_, String>(0))
+            .map_err(db_error)?;
+
+        rows.map(|data| serde_json::from_str(&data.map_err(db_error)?).map_err(json_error))
+            .collect()
+    }
+}
+
+/// Record the watermark for `resource` after a successful sync
+pub(crate) fn set_watermark(
+    conn: &Connection,
+    resource: SyncResource,
+    watermark: &str,
+    synced_at: &str,
+) -> Result<(), CliError> {
+    conn.execute(
+        "INSERT OR REPLACE INTO sync_state (resource, watermark, synced_at) VALUES (?1, ?2, ?3)",
+        params![resource.table(), watermark, synced_at],
+    )
+    .map(|_| ())
+    .map_err(db_error)
+}
+
+/// Insert or replace a page of synced records, returning how many were written
+pub(crate) fn upsert_records(conn: &Connection, records: &SyncRecords) -> Result<usize, CliError> {
+    match records {
+        SyncRecords::Teams(teams) => {
+            for team in teams {
+                conn.execute(
+                    "INSERT OR REPLACE INTO teams (id, key, name, description, private, created_at, data)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        team.id,
+                        team.key,
+                        team.name,
+                        team.description,
+                        team.private,
+                        team.created_at,
+                        to_json(team)?
+                    ],
+                )
+                .map_err(db_error)?;
+            }
+            Ok(teams.len())
+        }
+        SyncRecords::Users(users) => {
+            for user in users {
+                conn.execute(
+                    "INSERT OR REPLACE INTO users (id, name, display_name, email, active, admin, guest, data)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    params![
+                        user.id,
+                        user.name,
+                        user.display_name,
+                        user.email,
+                        user.active,
+                        user.admin,
+                        user.guest,
+                        to_json(user)?
+                    ],
+                )
+                .map_err(db_error)?;
+            }
+            Ok(users.len())
+        }
+        SyncRecords::States(states) => {
+            for state in states {
+                conn.execute(
+                    "INSERT OR REPLACE INTO states (id, name, type, color, position, description, team_name, data)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    params![
+                        state.id,
+                        state.name,
+                        state.state_type,
+                        state.color,
+                        state.position,
+                        state.description,
+                        state.team_name,
+                        to_json(state)?
+                    ],
+                )
+                .map_err(db_error)?;
+            }
+            Ok(states.len())
+        }
+        SyncRecords::Labels(labels) => {
+            for label in labels {
+                conn.execute(
+                    "INSERT OR REPLACE INTO labels (id, name, color, description, is_group, parent_name, data)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        label.id,
+                        label.name,
+                        label.color,
+                        label.description,
+                        label.is_group,
+                        label.parent_name,
+                        to_json(label)?
+                    ],
+                )
+                .map_err(db_error)?;
+            }
+            Ok(labels.len())
+        }
+        SyncRecords::Projects(projects) => {
+            for project in projects {
+                conn.execute(
+                    "INSERT OR REPLACE INTO projects (id, name, slug_id, status, status_type, progress, priority,
+                        lead_name, start_date, target_date, created_at, updated_at, url, data)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+                    params![
+                        project.id,
+                        project.name,
+                        project.slug_id,
+                        project.status_name,
+                        project.status_type,
+                        project.progress,
+                        project.priority,
+                        project.lead_name,
+                        project.start_date,
+                        project.target_date,
+                        project.created_at,
+                        project.updated_at,
+                        project.url,
+                        to_json(project)?
+                    ],
+                )
+                .map_err(db_error)?;
+            }
+            Ok(projects.len())
+        }
+        SyncRecords::Cycles(cycles) => {
+            for cycle in cycles {
+                conn.execute(
+                    "INSERT OR REPLACE INTO cycles (id, name, number, team_key, team_name, starts_at, ends_at,
+                        completed_at, progress, data)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                    params![
+                        cycle.id,
+                        cycle.name,
+                        cycle.number,
+                        cycle.team_key,
+                        cycle.team_name,
+                        cycle.starts_at,
+                        cycle.ends_at,
+                        cycle.completed_at,
+                        cycle.progress,
+                        to_json(cycle)?
+                    ],
+                )
+                .map_err(db_error)?;
+            }
+            Ok(cycles.len())
+        }
+        SyncRecords::Issues(issues) => {
+            for issue in issues {
+                conn.execute(
+                    "INSERT OR REPLACE INTO issues (id, identifier, title, description, state_id, state_name,
+                        priority, assignee_id, assignee_name, assignee_email, creator_id, creator_name,
+                        project_id, project_name, project_slug_id, created_at, updated_at, url, data)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
+                    params![
+                        issue.id,
+                        issue.identifier,
+                        issue.title,
+                        issue.description,
+                        issue.state.id,
+                        issue.state.name,
+                        issue.priority.as_i32(),
+                        issue.assignee.as_ref().map(|u| &u.id),
+                        issue.assignee.as_ref().map(|u| &u.name),
+                        issue.assignee.as_ref().map(|u| &u.email),
+                        issue.creator.id,
+                        issue.creator.name,
+                        issue.project.as_ref().map(|p| &p.id),
+                        issue.project.as_ref().map(|p| &p.name),
+                        issue.project.as_ref().map(|p| &p.slug_id),
+                        issue.created_at,
+                        issue.updated_at,
+                        issue.url,
+                        to_json(issue)?
+                    ],
+                )
+                .map_err(db_error)?;
+            }
+            Ok(issues.len())
+        }
+        SyncRecords::Comments(comments) => {
+            for comment in comments {
+                conn.execute(
+                    "INSERT OR REPLACE INTO comments (id, issue_identifier, body, user_name, user_email,
+                        created_at, updated_at, edited_at, data)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                    params![
+                        comment.id,
+                        comment.issue_identifier,
+                        comment.body,
+                        comment.user_name,
+                        comment.user_email,
+                        comment.created_at,
+                        comment.updated_at,
+                        comment.edited_at,
+                        to_json(comment)?
+                    ],
+                )
+                .map_err(db_error)?;
+            }
+            Ok(comments.len())
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use crate::error::CliError;
+use crate::output::{
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_jsonl_formatter,
+    generic_table_formatter, generic_table_list_formatter, generic_template_formatter,
+    generic_template_list_formatter,
+};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+
+/// Sync result for a single resource
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncResourceReport {
+    pub resource: String,
+    /// Records fetched (new or updated) during this sync
+    pub fetched: usize,
+    /// Records now stored in the mirror
+    pub total: usize,
+    /// Watermark the sync started from (`None` for a full sync)
+    pub since: Option<String>,
+    /// Watermark recorded for the next sync
+    pub watermark: String,
+}
+
+/// Output of `linear sync`
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncReport {
+    pub database: String,
+    pub resources: Vec<SyncResourceReport>,
+}
+
+impl TableFormatter for SyncReport {
+    fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        let mut rows = vec![(
+            Cow::Borrowed("Database"),
+            Cow::Borrowed(self.database.as_str()),
+        )];
+
+        for report in &self.resources {
+            rows.push((
+                Cow::Borrowed(report.resource.as_str()),
+                Cow::Owned(format!(
+                    "{} fetched, {} total ({})",
+                    report.fetched,
+                    report.total,
+                    report
+                        .since
+                        .as_deref()
+                        .map_or_else(|| "full".to_string(), |since| format!("since {since}"))
+                )),
+            ));
+        }
+
+        rows
+    }
+}
+
+impl MarkdownFormatter for SyncReport {
+    fn markdown_capacity_hint(&self) -> usize {
+        120 + self.database.len() + self.resources.len() * 80
+    }
+
+    fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
+        writeln!(output, "# Sync\n")
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        writeln!(output, "**Database:** {}\n", self.database)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        writeln!(
+            output,
+            "| Resource | Fetched | Total | Since |\n|---|---|---|---|"
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        for report in &self.resources {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} |",
+                report.resource,
+                report.fetched,
+                report.total,
+                report.since.as_deref().unwrap_or("full")
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Formattable for SyncReport {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record(["resource", "fetched", "total", "since", "watermark"])
+            .csv_err("Failed to write CSV header")?;
+
+        for report in &self.resources {
+            wtr.write_record([
+                report.resource.clone(),
+                report.fetched.to_string(),
+                report.total.to_string(),
+                report.since.clone().unwrap_or_default(),
+                report.watermark.clone(),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        fast_markdown_formatter(self)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        generic_table_formatter(self)
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
+}
+
+/// Result set of `linear sql` (and `linear sql --schema`)
+///
+/// Serializes as an array of objects keyed by column name, in column order.
+#[derive(Debug, Clone, Default)]
+pub struct SqlResult {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+/// One result row borrowed as a column-name keyed map
+struct SqlRow<'a> {
+    columns: &'a [String],
+    values: &'a [Value],
+}
+
+impl Serialize for SqlRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for (column, value) in self.columns.iter().zip(self.values) {
+            map.serialize_entry(column, value)?;
+        }
+        map.end()
+    }
+}
+
+impl SqlResult {
+    fn row_maps(&self) -> Vec<SqlRow<'_>> {
+        self.rows
+            .iter()
+            .map(|values| SqlRow {
+                columns: &self.columns,
+                values,
+            })
+            .collect()
+    }
+}
+
+impl Serialize for SqlResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.rows.len()))?;
+        for row in self.row_maps() {
+            seq.serialize_element(&row)?;
+        }
+        seq.end()
+    }
+}
+
+/// Render a cell as plain text (`NULL` becomes an empty string)
+fn sql_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl Formattable for SqlResult {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        let lines = self
+            .row_maps()
+            .iter()
+            .map(serde_json::to_string)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| CliError::General(format!("Failed to serialize to JSON Lines: {e}")))?;
+        Ok(lines.join("\n"))
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record(&self.columns)
+            .csv_err("Failed to write CSV header")?;
+
+        for row in &self.rows {
+            wtr.write_record(row.iter().map(sql_cell))
+                .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        let escape = |text: String| text.replace('|', "\\|").replace(['\n', '\r'], " ");
+
+        let mut output = String::new();
+        writeln!(
+            output,
+            "| {} |\n|{}",
+            self.columns
+                .iter()
+                .map(|column| escape(column.clone()))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            "---|".repeat(self.columns.len())
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        for row in &self.rows {
+            writeln!(
+                output,
+                "| {} |",
+                row.iter()
+                    .map(|value| escape(sql_cell(value)))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(output)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        // Result columns are arbitrary, so any of them may be truncated to fit
+        let columns: Vec<TableColumn> = self
+            .columns
+            .iter()
+            .map(|column| TableColumn::owned(column.clone()).flexible())
+            .collect();
+
+        generic_table_list_formatter(&self.rows, &columns, layout, |row| {
+            row.iter().map(sql_cell).collect()
+        })
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.row_maps(), template)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::notifications::{NotificationClient, UpdateNotificationInput};
+use crate::dates::SystemClock;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::notifications::types::{NotificationList, parse_snooze_until};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output, format_output_to_writer,
+    get_format_with_provider,
+};
+use chrono::{SecondsFormat, Utc};
+use secrecy::ExposeSecret;
+
+/// Handle `notification list` command
+pub fn handle_list(
+    unread: bool,
+    limit: usize,
+    client: &dyn NotificationClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+
+    let notifications = client.list_notifications(token.expose_secret(), limit, unread)?;
+
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
+    let mut output = Vec::new();
+    format_output_to_writer(&NotificationList(notifications), format, &mut output)?;
+    io.print_bytes(&output);
+
+    Ok(())
+}
+
+/// Handle `notification read <id>` and `notification read --all` commands
+///
+/// Without an ID, pages through the whole inbox and marks every unread
+/// notification as read.
+pub fn handle_read(
+    id: Option<&str>,
+    client: &dyn NotificationClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+    let format = get_format_with_provider(format_flag, config);
+    let read_at = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+    let input = UpdateNotificationInput {
+        read_at: Some(read_at),
+        ..Default::default()
+    };
+
+    if let Some(id) = id {
+        let notification = client.update_notification(token.expose_secret(), id, input)?;
+        io.print(&format_output(&notification, format)?);
+        return Ok(());
+    }
+
+    let unread: Vec<String> = client
+        .list_notifications(token.expose_secret(), usize::MAX, true)?
+        .into_iter()
+        .map(|n| n.id)
+        .collect();
+    for id in &unread {
+        client.update_notification(token.expose_secret(), id, input.clone())?;
+    }
+
+    let msg = if matches!(format, OutputFormat::Json) {
+        serde_json::json!({ "read": unread.len() }).to_string()
+    } else {
+        format!("Marked {} notification(s) as read", unread.len())
+    };
+    io.print(&msg);
+
+    Ok(())
+}
+
+/// Handle `notification archive <id>` and `notification archive --all` commands
+///
+/// Without an ID, pages through the whole inbox and archives every notification.
+pub fn handle_archive(
+    id: Option<&str>,
+    client: &dyn NotificationClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let token = get_token_with_provider(config, storage)?;
+    let format = get_format_with_provider(format_flag, config);
+
+    let ids = match id {
+        Some(id) => vec![id.to_string()],
+        None => client
+            .list_notifications(token.expose_secret(), usize::MAX, false)?
+            .into_iter()
+            .map(|n| n.id)
+            .collect(),
+    };
+    for id in &ids {
+        client.archive_notification(token.expose_secret(), id)?;
+    }
+
+    let msg = match (id, matches!(format, OutputFormat::Json)) {
+        (Some(id), true) => serde_json::json!({ "archived": true, "id": id }).to_string(),
+        (None, true) => serde_json::json!({ "archived": ids.len() }).to_string(),
+        (Some(id), false) => format!("Archived notification {id}"),
+        (None, false) => format!("Archived {} notification(s)", ids.len()),
+    };
+    io.print(&msg);
+
+    Ok(())
+}
+
+/// Handle `notification snooze <id> --until <when>` command
+pub fn handle_snooze(
+    id: &str,
+    until: &str,
+    client: &dyn NotificationClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let snoozed_until_at = parse_snooze_until(until, &SystemClock)?;
+    let token = get_token_with_provider(config, storage)?;
+
+    let notification = client.update_notification(
+        token.expose_secret(),
+        id,
+        UpdateNotificationInput {
+            snoozed_until_at: Some(snoozed_until_at),
+            ..Default::default()
+        },
+    )?;
+
+    let format = get_format_with_provider(format_flag, config);
+    io.print(&format_output(&notification, format)?);
+
+    Ok(())
+}
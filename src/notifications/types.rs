@@ -0,0 +1,297 @@
+use crate::client::queries;
+use crate::dates::{Clock, parse_datetime};
+use crate::error::CliError;
+use crate::output::{
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
+};
+use chrono::SecondsFormat;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+
+/// Notification in the current user's inbox
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: String,
+    /// Notification type (e.g. `issueAssignedToYou`, `issueNewComment`)
+    pub kind: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub url: String,
+    pub actor_name: Option<String>,
+    pub created_at: String,
+    pub read_at: Option<String>,
+    pub snoozed_until_at: Option<String>,
+}
+
+impl Notification {
+    /// Whether the notification has not been read yet
+    #[must_use]
+    pub fn is_unread(&self) -> bool {
+        self.read_at.is_none()
+    }
+
+    /// Marker shown next to unread notifications in lists
+    fn unread_marker(&self) -> &'static str {
+        if self.is_unread() { "●" } else { "" }
+    }
+}
+
+impl TableFormatter for Notification {
+    fn table_rows(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        let mut rows = vec![(Cow::Borrowed("Title"), Cow::Borrowed(self.title.as_str()))];
+
+        if let Some(subtitle) = &self.subtitle {
+            rows.push((Cow::Borrowed("Subtitle"), Cow::Borrowed(subtitle.as_str())));
+        }
+
+        rows.push((Cow::Borrowed("Type"), Cow::Borrowed(self.kind.as_str())));
+
+        if let Some(actor) = &self.actor_name {
+            rows.push((Cow::Borrowed("From"), Cow::Borrowed(actor.as_str())));
+        }
+
+        rows.push((
+            Cow::Borrowed("Created"),
+            Cow::Borrowed(self.created_at.as_str()),
+        ));
+        rows.push((
+            Cow::Borrowed("Read"),
+            Cow::Borrowed(self.read_at.as_deref().unwrap_or("no")),
+        ));
+
+        if let Some(until) = &self.snoozed_until_at {
+            rows.push((
+                Cow::Borrowed("Snoozed Until"),
+                Cow::Borrowed(until.as_str()),
+            ));
+        }
+
+        rows.push((Cow::Borrowed("URL"), Cow::Borrowed(self.url.as_str())));
+        rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.id.as_str())));
+
+        rows
+    }
+}
+
+impl MarkdownFormatter for Notification {
+    fn markdown_capacity_hint(&self) -> usize {
+        150 + self.title.len()
+            + self.url.len()
+            + self.id.len()
+            + self.subtitle.as_ref().map_or(0, |s| s.len())
+    }
+
+    fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
+        writeln!(output, "# [{}]({})\n", self.title, self.url)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        if let Some(subtitle) = &self.subtitle {
+            writeln!(output, "{}\n", subtitle)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        writeln!(output, "**Type:** {}", self.kind)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        if let Some(actor) = &self.actor_name {
+            writeln!(output, "**From:** {}", actor)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        writeln!(output, "**Created:** {}", self.created_at)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        if let Some(read_at) = &self.read_at {
+            writeln!(output, "**Read:** {}", read_at)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        if let Some(until) = &self.snoozed_until_at {
+            writeln!(output, "**Snoozed Until:** {}", until)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+        writeln!(output, "**ID:** {}", self.id)
+            .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        Ok(())
+    }
+}
+
+impl Formattable for Notification {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_formatter(self)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        NotificationList(vec![self.clone()]).to_csv()
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        fast_markdown_formatter(self)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        generic_table_formatter(self)
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
+}
+
+/// Wrapper for a list of notifications
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct NotificationList(pub Vec<Notification>);
+
+impl Formattable for NotificationList {
+    fn to_json(&self) -> Result<String, CliError> {
+        generic_json_list_formatter(&self.0)
+    }
+
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
+    fn to_csv(&self) -> Result<String, CliError> {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+
+        wtr.write_record([
+            "id",
+            "type",
+            "title",
+            "subtitle",
+            "actor",
+            "created_at",
+            "read_at",
+            "snoozed_until_at",
+            "url",
+        ])
+        .csv_err("Failed to write CSV header")?;
+
+        for notification in &self.0 {
+            wtr.write_record([
+                notification.id.as_str(),
+                notification.kind.as_str(),
+                notification.title.as_str(),
+                notification.subtitle.as_deref().unwrap_or(""),
+                notification.actor_name.as_deref().unwrap_or(""),
+                notification.created_at.as_str(),
+                notification.read_at.as_deref().unwrap_or(""),
+                notification.snoozed_until_at.as_deref().unwrap_or(""),
+                notification.url.as_str(),
+            ])
+            .csv_err("Failed to write CSV row")?;
+        }
+
+        let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
+        String::from_utf8(data).csv_err("Failed to convert CSV to UTF-8")
+    }
+
+    fn to_markdown(&self) -> Result<String, CliError> {
+        let capacity = self
+            .0
+            .iter()
+            .map(|n| 60 + n.title.len() + n.url.len() + n.id.len())
+            .sum();
+        let mut output = String::with_capacity(capacity);
+
+        let unread = self.0.iter().filter(|n| n.is_unread()).count();
+        writeln!(
+            output,
+            "## Notifications ({}, {} unread)\n",
+            self.0.len(),
+            unread
+        )
+        .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+
+        for notification in &self.0 {
+            let title = if notification.is_unread() {
+                format!("**[{}]({})**", notification.title, notification.url)
+            } else {
+                format!("[{}]({})", notification.title, notification.url)
+            };
+            write!(output, "- {title}")
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            if let Some(subtitle) = &notification.subtitle {
+                write!(output, " — {subtitle}")
+                    .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+            }
+            writeln!(output, " (`{}`)", notification.id)
+                .map_err(|e| CliError::General(format!("Failed to write markdown: {e}")))?;
+        }
+
+        Ok(output)
+    }
+
+    fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new(""),
+                TableColumn::new("Title").flexible(),
+                TableColumn::new("From"),
+                TableColumn::new("Type").wide_only(),
+                TableColumn::new("Created").wide_only(),
+                TableColumn::new("ID"),
+            ],
+            layout,
+            |notification| {
+                vec![
+                    notification.unread_marker().to_string(),
+                    notification.title.clone(),
+                    notification.actor_name.clone().unwrap_or_default(),
+                    notification.kind.clone(),
+                    notification.created_at.clone(),
+                    notification.id.clone(),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
+}
+
+impl From<queries::NotificationNode> for Notification {
+    fn from(node: queries::NotificationNode) -> Self {
+        Notification {
+            id: node.id.inner().to_string(),
+            kind: node.kind,
+            title: node.title,
+            subtitle: Some(node.subtitle).filter(|s| !s.is_empty()),
+            url: node.url,
+            actor_name: node.actor.map(|a| a.name),
+            created_at: node.created_at.0,
+            read_at: node.read_at.map(|d| d.0),
+            snoozed_until_at: node.snoozed_until_at.map(|d| d.0),
+        }
+    }
+}
+
+/// Resolve a `--until` value to an RFC 3339 timestamp
+///
+/// Accepts anything [`crate::dates`] does, such as `30m`, `2d`, `friday`,
+/// `2026-03-01` (snoozed until local midnight) or a full RFC 3339 timestamp.
+///
+/// # Errors
+/// Returns `InvalidArgs` if the value is not a date or time after now.
+pub fn parse_snooze_until(value: &str, clock: &dyn Clock) -> Result<String, CliError> {
+    let until = parse_datetime(value, clock)?;
+    if until <= clock.now() {
+        return Err(CliError::InvalidArgs(format!(
+            "Invalid snooze time '{}': must be in the future",
+            value.trim()
+        )));
+    }
+    Ok(until.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
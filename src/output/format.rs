@@ -1,12 +1,21 @@
 use crate::auth::config::ConfigProvider;
+use crate::output::table::TableLayout;
+use crate::output::time::TimeDisplay;
 use std::io::IsTerminal;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OutputFormat {
     Json,
+    Jsonl,
     Csv,
-    Markdown,
-    Table,
+    /// Markdown output; timestamps follow the carried display
+    Markdown(TimeDisplay),
+    /// Table output; list layout is controlled by `--wide`/`--no-headers` and the terminal
+    Table(TableLayout),
+    Yaml,
+    Toml,
+    /// Handlebars-style template rendered once per item
+    Template(String),
     Auto,
 }
 
@@ -22,9 +31,12 @@ pub fn detect_format_with_provider(config: &dyn ConfigProvider) -> OutputFormat
     if let Some(format_str) = config.get_var("LINEAR_CLI_FORMAT") {
         return match format_str.to_lowercase().as_str() {
             "json" => OutputFormat::Json,
+            "jsonl" | "ndjson" => OutputFormat::Jsonl,
             "csv" => OutputFormat::Csv,
-            "markdown" | "md" => OutputFormat::Markdown,
-            "table" => OutputFormat::Table,
+            "markdown" | "md" => OutputFormat::Markdown(TimeDisplay::default()),
+            "table" => OutputFormat::Table(TableLayout::default()),
+            "yaml" | "yml" => OutputFormat::Yaml,
+            "toml" => OutputFormat::Toml,
             "auto" => OutputFormat::Auto,
             _ => OutputFormat::Auto,
         };
@@ -82,6 +94,10 @@ pub fn detect_format() -> OutputFormat {
 /// 1. Explicit format from CLI flags (highest priority)
 /// 2. Environment variable (LINEAR_CLI_FORMAT)
 /// 3. Auto-detection based on TTY (lowest priority)
+///
+/// Table and Markdown formats carry the timestamp display from
+/// `LINEAR_CLI_TIME_FORMAT`/`LINEAR_CLI_UTC`. The CLI rejects an invalid
+/// `LINEAR_CLI_TIME_FORMAT` at startup; here it falls back to ISO timestamps.
 pub fn get_format_with_provider(
     cli_format: Option<OutputFormat>,
     config: &dyn ConfigProvider,
@@ -89,6 +105,7 @@ pub fn get_format_with_provider(
     cli_format
         .unwrap_or_else(|| detect_format_with_provider(config))
         .resolve()
+        .with_time_display(TimeDisplay::from_config(config).unwrap_or_default())
 }
 
 /// Legacy function for backward compatibility (uses real env vars)
@@ -106,7 +123,7 @@ impl OutputFormat {
         match self {
             OutputFormat::Auto => {
                 if std::io::stdout().is_terminal() {
-                    OutputFormat::Table
+                    OutputFormat::Table(TableLayout::default())
                 } else {
                     OutputFormat::Json
                 }
@@ -114,4 +131,14 @@ impl OutputFormat {
             other => other,
         }
     }
+
+    /// Render table and Markdown timestamps with `time`
+    #[must_use]
+    pub fn with_time_display(self, time: TimeDisplay) -> OutputFormat {
+        match self {
+            OutputFormat::Table(layout) => OutputFormat::Table(TableLayout { time, ..layout }),
+            OutputFormat::Markdown(_) => OutputFormat::Markdown(time),
+            other => other,
+        }
+    }
 }
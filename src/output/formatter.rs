@@ -1,22 +1,50 @@
 use crate::error::CliError;
 use crate::output::OutputFormat;
+use crate::output::generic_formatters::{generic_toml_formatter, generic_yaml_formatter};
+use crate::output::table::TableLayout;
+use crate::output::time::TimeDisplay;
+use crate::output::traits::StructuredData;
 
 /// Trait that enforces all data types to implement formatters for all supported output formats.
 ///
 /// This trait uses Rust's type system to ensure compile-time enforcement:
-/// if a type doesn't implement all four methods, the code will not compile.
-pub trait Formattable {
+/// if a type doesn't implement all six methods, the code will not compile.
+///
+/// Formats that can be derived mechanically from the serialized data (YAML, TOML)
+/// are implemented once on top of `StructuredData` and need no per-type code.
+pub trait Formattable: StructuredData {
     /// Format as JSON (pretty-printed)
     fn to_json(&self) -> Result<String, CliError>;
 
+    /// Format as JSON Lines (one compact JSON object per line, no trailing newline)
+    fn to_jsonl(&self) -> Result<String, CliError>;
+
     /// Format as CSV (with headers)
     fn to_csv(&self) -> Result<String, CliError>;
 
     /// Format as Markdown (GitHub Flavored Markdown)
     fn to_markdown(&self) -> Result<String, CliError>;
 
+    /// Format as Markdown, rendering timestamps with `time`.
+    ///
+    /// Types without timestamps ignore the display.
+    fn to_markdown_with_time(&self, _time: &TimeDisplay) -> Result<String, CliError> {
+        self.to_markdown()
+    }
+
     /// Format as Table (UTF-8 box drawing for terminal display)
     fn to_table(&self) -> Result<String, CliError>;
+
+    /// Format as Table honouring list layout (`--wide`, `--no-headers`, width, color).
+    ///
+    /// Single-item tables are vertical field/value tables and only take the
+    /// timestamp display from the layout.
+    fn to_table_with_layout(&self, _layout: &TableLayout) -> Result<String, CliError> {
+        self.to_table()
+    }
+
+    /// Render a Handlebars-style template per item (one rendered item per line)
+    fn to_template(&self, template: &str) -> Result<String, CliError>;
 }
 
 /// Format output using the specified format.
@@ -32,10 +60,14 @@ pub trait Formattable {
 /// ```
 pub fn format_output<T: Formattable>(data: &T, format: OutputFormat) -> Result<String, CliError> {
     match format {
+        OutputFormat::Template(template) => data.to_template(&template),
         OutputFormat::Json => data.to_json(),
+        OutputFormat::Jsonl => data.to_jsonl(),
         OutputFormat::Csv => data.to_csv(),
-        OutputFormat::Markdown => data.to_markdown(),
-        OutputFormat::Table => data.to_table(),
+        OutputFormat::Markdown(time) => data.to_markdown_with_time(&time),
+        OutputFormat::Table(layout) => data.to_table_with_layout(&layout),
+        OutputFormat::Yaml => generic_yaml_formatter(data),
+        OutputFormat::Toml => generic_toml_formatter(data),
         OutputFormat::Auto => {
             // This should never happen if get_format() is used properly,
             // but provide a fallback just in case
@@ -47,8 +79,10 @@ pub fn format_output<T: Formattable>(data: &T, format: OutputFormat) -> Result<S
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde::Serialize;
 
     // Test implementation of Formattable
+    #[derive(Serialize)]
     struct TestData {
         value: String,
     }
@@ -58,6 +92,10 @@ mod tests {
             Ok(format!(r#"{{"value":"{}"}}"#, self.value))
         }
 
+        fn to_jsonl(&self) -> Result<String, CliError> {
+            Ok(format!(r#"{{"value":"{}"}}"#, self.value))
+        }
+
         fn to_csv(&self) -> Result<String, CliError> {
             Ok(format!("value\n{}", self.value))
         }
@@ -69,6 +107,10 @@ mod tests {
         fn to_table(&self) -> Result<String, CliError> {
             Ok(format!("┌─────┐\n│ {} │\n└─────┘", self.value))
         }
+
+        fn to_template(&self, template: &str) -> Result<String, CliError> {
+            Ok(template.replace("{{value}}", &self.value))
+        }
     }
 
     #[test]
@@ -80,6 +122,15 @@ mod tests {
         assert_eq!(output, r#"{"value":"test"}"#);
     }
 
+    #[test]
+    fn test_format_output_routes_to_jsonl() {
+        let data = TestData {
+            value: "test".to_string(),
+        };
+        let output = format_output(&data, OutputFormat::Jsonl).unwrap();
+        assert_eq!(output, r#"{"value":"test"}"#);
+    }
+
     #[test]
     fn test_format_output_routes_to_csv() {
         let data = TestData {
@@ -94,7 +145,7 @@ mod tests {
         let data = TestData {
             value: "test".to_string(),
         };
-        let output = format_output(&data, OutputFormat::Markdown).unwrap();
+        let output = format_output(&data, OutputFormat::Markdown(TimeDisplay::default())).unwrap();
         assert_eq!(output, "# test");
     }
 
@@ -103,10 +154,35 @@ mod tests {
         let data = TestData {
             value: "test".to_string(),
         };
-        let output = format_output(&data, OutputFormat::Table).unwrap();
+        let output = format_output(&data, OutputFormat::Table(TableLayout::default())).unwrap();
         assert_eq!(output, "┌─────┐\n│ test │\n└─────┘");
     }
 
+    #[test]
+    fn test_format_output_routes_to_template() {
+        let data = TestData {
+            value: "test".to_string(),
+        };
+        let output =
+            format_output(&data, OutputFormat::Template("<{{value}}>".to_string())).unwrap();
+        assert_eq!(output, "<test>");
+    }
+
+    #[test]
+    fn test_format_output_routes_yaml_and_toml_through_structured_data() {
+        let data = TestData {
+            value: "test".to_string(),
+        };
+        assert_eq!(
+            format_output(&data, OutputFormat::Yaml).unwrap(),
+            "value: test"
+        );
+        assert_eq!(
+            format_output(&data, OutputFormat::Toml).unwrap(),
+            "value = \"test\""
+        );
+    }
+
     #[test]
     fn test_format_output_auto_falls_back_to_json() {
         let data = TestData {
@@ -57,28 +57,6 @@ pub fn generic_table_formatter<T: TableFormatter>(data: &T) -> Result<String, Cl
     Ok(table.to_string())
 }
 
-/// Generic table formatter for list/collection layouts with explicit headers.
-///
-/// The row builder closure enables low-overhead row projection for each item.
-pub fn generic_table_list_formatter<T, F>(
-    items: &[T],
-    headers: &[&str],
-    mut row_builder: F,
-) -> Result<String, CliError>
-where
-    F: FnMut(&T) -> Vec<String>,
-{
-    let mut table = Table::new();
-    table.load_preset(UTF8_FULL);
-    table.set_header(headers.iter().copied().map(Cell::new));
-
-    for item in items {
-        table.add_row(row_builder(item));
-    }
-
-    Ok(table.to_string())
-}
-
 /// Fast generic markdown formatter using writer pattern (zero intermediate allocations)
 ///
 /// This formatter achieves identical performance to hand-written formatters
@@ -124,6 +102,78 @@ pub fn generic_json_list_formatter<T: serde::Serialize>(items: &[T]) -> Result<S
     }
 }
 
+/// Generic JSON Lines formatter for a single item
+///
+/// Always compact (one line), regardless of `LINEAR_CLI_JSON_STYLE`.
+pub fn generic_jsonl_formatter<T: StructuredData>(data: &T) -> Result<String, CliError> {
+    serde_json::to_string(data)
+        .map_err(|e| CliError::General(format!("Failed to serialize to JSON Lines: {e}")))
+}
+
+/// Generic JSON Lines formatter for list types
+///
+/// Emits one compact object per line. Lines are separated by `\n` without a
+/// trailing newline, matching the other formatters (the `Io` layer terminates output).
+pub fn generic_jsonl_list_formatter<T: serde::Serialize>(items: &[T]) -> Result<String, CliError> {
+    let mut output = Vec::with_capacity(items.len() * 256);
+    crate::output::streaming::write_jsonl_records(items, &mut output)?;
+    String::from_utf8(output)
+        .map_err(|e| CliError::General(format!("Failed to convert JSON Lines to UTF-8: {e}")))
+}
+
+/// Generic YAML formatter for any type that implements StructuredData
+///
+/// Goes through the same JSON value as `--json`, so field names and shapes match.
+pub fn generic_yaml_formatter<T: StructuredData + ?Sized>(data: &T) -> Result<String, CliError> {
+    let value = data.to_json_value()?;
+    let yaml = serde_norway::to_string(&value)
+        .map_err(|e| CliError::General(format!("Failed to serialize to YAML: {e}")))?;
+    Ok(yaml.trim_end_matches('\n').to_string())
+}
+
+/// Generic TOML formatter for any type that implements StructuredData
+///
+/// TOML has no null and requires a table at the top level, so null fields are
+/// omitted, lists are emitted as an `items` array of tables and bare scalars
+/// are wrapped in a `value` key.
+pub fn generic_toml_formatter<T: StructuredData + ?Sized>(data: &T) -> Result<String, CliError> {
+    let table = match json_to_toml(data.to_json_value()?) {
+        Some(toml::Value::Table(table)) => table,
+        Some(toml::Value::Array(items)) => {
+            toml::Table::from_iter([("items".to_string(), items.into())])
+        }
+        Some(other) => toml::Table::from_iter([("value".to_string(), other)]),
+        None => toml::Table::new(),
+    };
+
+    let output = toml::to_string(&table)
+        .map_err(|e| CliError::General(format!("Failed to serialize to TOML: {e}")))?;
+    Ok(output.trim_end_matches('\n').to_string())
+}
+
+/// Convert a JSON value to TOML, dropping nulls (TOML has no null type).
+fn json_to_toml(value: serde_json::Value) -> Option<toml::Value> {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => None,
+        Value::Bool(b) => Some(toml::Value::Boolean(b)),
+        Value::Number(n) => n
+            .as_i64()
+            .map(toml::Value::Integer)
+            .or_else(|| n.as_f64().map(toml::Value::Float)),
+        Value::String(s) => Some(toml::Value::String(s)),
+        Value::Array(items) => Some(toml::Value::Array(
+            items.into_iter().filter_map(json_to_toml).collect(),
+        )),
+        Value::Object(map) => Some(toml::Value::Table(
+            map.into_iter()
+                .filter_map(|(key, value)| json_to_toml(value).map(|value| (key, value)))
+                .collect(),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +227,61 @@ mod tests {
         assert!(json.contains("\"value\":20"));
     }
 
+    #[test]
+    fn test_generic_jsonl_list_formatter_emits_one_object_per_line() {
+        let items = vec![
+            TestData {
+                id: "item-1".to_string(),
+                value: 10,
+            },
+            TestData {
+                id: "item-2".to_string(),
+                value: 20,
+            },
+        ];
+
+        let jsonl = generic_jsonl_list_formatter(&items).unwrap();
+        let lines: Vec<&str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], r#"{"id":"item-1","value":10}"#);
+        assert_eq!(lines[1], r#"{"id":"item-2","value":20}"#);
+        assert!(!jsonl.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_generic_jsonl_list_formatter_empty_list_is_empty_string() {
+        let items: Vec<TestData> = vec![];
+        assert_eq!(generic_jsonl_list_formatter(&items).unwrap(), "");
+    }
+
+    #[test]
+    fn test_generic_yaml_formatter() {
+        let data = TestData {
+            id: "test-123".to_string(),
+            value: 42,
+        };
+
+        let yaml = generic_yaml_formatter(&data).unwrap();
+        assert_eq!(yaml, "id: test-123\nvalue: 42");
+    }
+
+    #[test]
+    fn test_generic_toml_formatter_wraps_lists_and_drops_nulls() {
+        #[derive(Serialize)]
+        struct Optional {
+            id: String,
+            parent: Option<String>,
+        }
+
+        let items = vec![Optional {
+            id: "a".to_string(),
+            parent: None,
+        }];
+
+        let toml = generic_toml_formatter(&items).unwrap();
+        assert_eq!(toml, "[[items]]\nid = \"a\"");
+    }
+
     #[test]
     fn test_generic_table_formatter() {
         let data = TestData {
@@ -3,6 +3,9 @@ pub mod format;
 pub mod formatter;
 pub mod generic_formatters;
 pub mod streaming;
+pub mod table;
+pub mod template;
+pub mod time;
 pub mod traits;
 
 pub use csv_ext::CsvResultExt;
@@ -14,7 +17,14 @@ pub use format::{
 pub use formatter::{Formattable, format_output};
 pub use generic_formatters::{
     fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
-    generic_table_formatter, generic_table_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_toml_formatter, generic_yaml_formatter,
 };
-pub use streaming::format_output_to_writer;
+pub use streaming::{format_output_to_writer, write_jsonl_records};
+pub use table::{
+    TableColumn, TableLayout, apply_terminal_layout, generic_table_list_formatter, priority_color,
+    state_color,
+};
+pub use template::{generic_template_formatter, generic_template_list_formatter};
+pub use time::{TimeDisplay, TimeFormat, Timed};
 pub use traits::{MarkdownFormatter, MarkdownSection, StructuredData, TableFormatter};
@@ -1,5 +1,6 @@
 use crate::error::CliError;
 use crate::output::{Formattable, OutputFormat, format_output};
+use serde::Serialize;
 use std::io::Write;
 
 /// Writer-based output API for parity with string-based formatting.
@@ -17,3 +18,24 @@ pub fn format_output_to_writer<T: Formattable, W: Write>(
         .write_all(output.as_bytes())
         .map_err(|e| CliError::General(format!("Failed to write formatted output: {e}")))
 }
+
+/// Write items as JSON Lines directly into `writer`, one compact object per line.
+///
+/// Records are separated by `\n` with no trailing newline, so callers that
+/// emit several batches (e.g. one per fetched page) can terminate each batch
+/// through `Io::print_bytes` without producing blank lines.
+pub fn write_jsonl_records<T: Serialize, W: Write>(
+    items: &[T],
+    writer: &mut W,
+) -> Result<(), CliError> {
+    for (idx, item) in items.iter().enumerate() {
+        if idx > 0 {
+            writer
+                .write_all(b"\n")
+                .map_err(|e| CliError::General(format!("Failed to write JSON Lines: {e}")))?;
+        }
+        serde_json::to_writer(&mut *writer, item)
+            .map_err(|e| CliError::General(format!("Failed to serialize to JSON Lines: {e}")))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,366 @@
+//! List table layout: one row per item, sized to the terminal.
+//!
+//! List tables are borderless so they stay readable in narrow terminals and
+//! pipe cleanly into `awk`/`cut` (combine with `--no-headers`). When the
+//! terminal width is known, the column marked [`TableColumn::flexible`] is
+//! truncated with an ellipsis so rows never wrap; `--wide` shows every column
+//! and disables truncation.
+
+use crate::auth::config::ConfigProvider;
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::OutputFormat;
+use crate::output::time::TimeDisplay;
+use comfy_table::{
+    Attribute, Cell, Color, ColumnConstraint, ContentArrangement, Row, Table, presets::NOTHING,
+};
+use std::borrow::Cow;
+
+/// Space between columns in list tables
+const COLUMN_GAP: u16 = 2;
+
+/// Layout options for list tables (`--wide`, `--no-headers`, terminal width, color)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableLayout {
+    /// Show wide-only columns and never truncate
+    pub wide: bool,
+    /// Omit the header row
+    pub no_headers: bool,
+    /// Terminal width to fit rows into; `None` when not writing to a terminal
+    pub width: Option<u16>,
+    /// Colorize state and priority columns
+    pub color: bool,
+    /// How timestamp columns are rendered
+    pub time: TimeDisplay,
+}
+
+/// Column definition for [`generic_table_list_formatter`]
+#[derive(Debug, Clone)]
+pub struct TableColumn {
+    pub header: Cow<'static, str>,
+    wide_only: bool,
+    flexible: bool,
+    color: Option<fn(&str) -> Option<Color>>,
+}
+
+impl TableColumn {
+    #[must_use]
+    pub const fn new(header: &'static str) -> Self {
+        Self {
+            header: Cow::Borrowed(header),
+            wide_only: false,
+            flexible: false,
+            color: None,
+        }
+    }
+
+    /// Column whose header is only known at runtime (e.g. SQL result columns)
+    #[must_use]
+    pub fn owned(header: String) -> Self {
+        Self {
+            header: Cow::Owned(header),
+            wide_only: false,
+            flexible: false,
+            color: None,
+        }
+    }
+
+    /// Only shown with `--wide`
+    #[must_use]
+    pub const fn wide_only(mut self) -> Self {
+        self.wide_only = true;
+        self
+    }
+
+    /// Truncated to fit the terminal width (typically the title column)
+    #[must_use]
+    pub const fn flexible(mut self) -> Self {
+        self.flexible = true;
+        self
+    }
+
+    /// Color cells by value when color output is enabled
+    #[must_use]
+    pub const fn colored(mut self, color: fn(&str) -> Option<Color>) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Generic table formatter for list/collection layouts.
+///
+/// `row_builder` returns one value per entry in `columns` (including wide-only
+/// columns); hidden columns are dropped according to `layout`.
+pub fn generic_table_list_formatter<T, F>(
+    items: &[T],
+    columns: &[TableColumn],
+    layout: &TableLayout,
+    mut row_builder: F,
+) -> Result<String, CliError>
+where
+    F: FnMut(&T) -> Vec<String>,
+{
+    let visible: Vec<usize> = (0..columns.len())
+        .filter(|&idx| layout.wide || !columns[idx].wide_only)
+        .collect();
+
+    let mut table = Table::new();
+    table.load_preset(NOTHING).force_no_tty();
+    if layout.color {
+        table.enforce_styling();
+    }
+
+    if !layout.no_headers {
+        table.set_header(visible.iter().map(|&idx| {
+            let cell = Cell::new(columns[idx].header.as_ref());
+            if layout.color {
+                cell.add_attribute(Attribute::Bold)
+            } else {
+                cell
+            }
+        }));
+    }
+
+    for item in items {
+        let values = row_builder(item);
+        if values.len() != columns.len() {
+            return Err(CliError::General(format!(
+                "Table row has {} values but {} columns are defined",
+                values.len(),
+                columns.len()
+            )));
+        }
+
+        let mut row = Row::from(visible.iter().map(|&idx| {
+            let value = single_line(&values[idx]);
+            let color = columns[idx]
+                .color
+                .filter(|_| layout.color)
+                .and_then(|color| color(&value));
+            match color {
+                Some(color) => Cell::new(value).fg(color),
+                None => Cell::new(value),
+            }
+        }));
+        row.max_height(1);
+        table.add_row(row);
+    }
+
+    match layout.width.filter(|_| !layout.wide) {
+        Some(width) => {
+            table
+                .set_content_arrangement(ContentArrangement::Dynamic)
+                .set_width(width)
+                .set_truncation_indicator("…");
+        }
+        None => {
+            table.set_content_arrangement(ContentArrangement::Disabled);
+        }
+    }
+
+    let last = visible.len().saturating_sub(1);
+    for (position, column) in table.column_iter_mut().enumerate() {
+        column.set_padding((0, if position == last { 0 } else { COLUMN_GAP }));
+        if !columns[visible[position]].flexible {
+            column.set_constraint(ColumnConstraint::ContentWidth);
+        }
+    }
+
+    Ok(table.trim_fmt())
+}
+
+/// Collapse line breaks so every item occupies exactly one row.
+fn single_line(value: &str) -> String {
+    if value.contains(['\n', '\r']) {
+        value.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Color for workflow state names and types (`started`, `completed`, ...)
+#[must_use]
+pub fn state_color(value: &str) -> Option<Color> {
+    let value = value.to_ascii_lowercase();
+    if ["done", "complete", "merged", "released"]
+        .iter()
+        .any(|s| value.contains(s))
+    {
+        Some(Color::Green)
+    } else if ["cancel", "duplicate", "won't", "wont"]
+        .iter()
+        .any(|s| value.contains(s))
+    {
+        Some(Color::DarkGrey)
+    } else if ["progress", "started", "review", "active"]
+        .iter()
+        .any(|s| value.contains(s))
+    {
+        Some(Color::Yellow)
+    } else if value.contains("triage") {
+        Some(Color::Magenta)
+    } else {
+        None
+    }
+}
+
+/// Color for priority labels (`🔴 Urgent`, `High`, ...)
+#[must_use]
+pub fn priority_color(value: &str) -> Option<Color> {
+    let value = value.to_ascii_lowercase();
+    if value.contains("urgent") {
+        Some(Color::Red)
+    } else if value.contains("high") {
+        Some(Color::DarkYellow)
+    } else if value.contains("medium") {
+        Some(Color::Yellow)
+    } else if value.contains("low") {
+        Some(Color::Blue)
+    } else {
+        None
+    }
+}
+
+/// Fill in terminal-dependent table layout for a resolved output format.
+///
+/// Width comes from the `Io` layer, which only reports one when stdout is a
+/// terminal. Color is enabled for terminals unless `NO_COLOR` is set to a
+/// non-empty value (<https://no-color.org>).
+#[must_use]
+pub fn apply_terminal_layout(
+    format: OutputFormat,
+    io: &dyn Io,
+    config: &dyn ConfigProvider,
+) -> OutputFormat {
+    match format {
+        OutputFormat::Table(mut layout) => {
+            layout.width = io.terminal_width();
+            layout.color =
+                layout.width.is_some() && config.get_var("NO_COLOR").is_none_or(|v| v.is_empty());
+            OutputFormat::Table(layout)
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLUMNS: [TableColumn; 3] = [
+        TableColumn::new("ID"),
+        TableColumn::new("Title").flexible(),
+        TableColumn::new("URL").wide_only(),
+    ];
+
+    fn rows() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("ENG-1", "A very long title that keeps going and going"),
+            ("ENG-22", "Short"),
+        ]
+    }
+
+    fn render(layout: &TableLayout) -> String {
+        generic_table_list_formatter(&rows(), &COLUMNS, layout, |(id, title)| {
+            vec![
+                id.to_string(),
+                title.to_string(),
+                format!("https://linear.app/{id}"),
+            ]
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_compact_layout_hides_wide_columns() {
+        let output = render(&TableLayout::default());
+        assert_eq!(
+            output,
+            "ID      Title\n\
+             ENG-1   A very long title that keeps going and going\n\
+             ENG-22  Short"
+        );
+    }
+
+    #[test]
+    fn test_terminal_width_truncates_flexible_column() {
+        let output = render(&TableLayout {
+            width: Some(30),
+            ..TableLayout::default()
+        });
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3, "rows must not wrap: {output}");
+        assert!(lines[1].ends_with('…'));
+        assert!(lines.iter().all(|line| line.chars().count() <= 30));
+    }
+
+    #[test]
+    fn test_wide_layout_shows_all_columns_untruncated() {
+        let output = render(&TableLayout {
+            wide: true,
+            width: Some(30),
+            ..TableLayout::default()
+        });
+        assert!(output.starts_with("ID      Title"));
+        assert!(output.contains("keeps going and going  https://linear.app/ENG-1"));
+    }
+
+    #[test]
+    fn test_no_headers_omits_header_row() {
+        let output = render(&TableLayout {
+            no_headers: true,
+            ..TableLayout::default()
+        });
+        assert!(output.starts_with("ENG-1 "));
+        assert_eq!(output.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_color_only_applied_when_enabled() {
+        let columns = [TableColumn::new("Priority").colored(priority_color)];
+        let plain =
+            generic_table_list_formatter(&["Urgent"], &columns, &TableLayout::default(), |p| {
+                vec![p.to_string()]
+            })
+            .unwrap();
+        assert!(!plain.contains('\u{1b}'));
+
+        let colored = generic_table_list_formatter(
+            &["Urgent"],
+            &columns,
+            &TableLayout {
+                color: true,
+                ..TableLayout::default()
+            },
+            |p| vec![p.to_string()],
+        )
+        .unwrap();
+        assert!(colored.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn test_multiline_values_stay_on_one_row() {
+        let columns = [TableColumn::new("Body")];
+        let output = generic_table_list_formatter(
+            &["first line\nsecond line"],
+            &columns,
+            &TableLayout {
+                no_headers: true,
+                ..TableLayout::default()
+            },
+            |body| vec![body.to_string()],
+        )
+        .unwrap();
+        assert_eq!(output, "first line second line");
+    }
+
+    #[test]
+    fn test_state_and_priority_colors() {
+        assert_eq!(state_color("In Progress"), Some(Color::Yellow));
+        assert_eq!(state_color("completed"), Some(Color::Green));
+        assert_eq!(state_color("Canceled"), Some(Color::DarkGrey));
+        assert_eq!(state_color("Todo"), None);
+        assert_eq!(priority_color("🔴 Urgent"), Some(Color::Red));
+        assert_eq!(priority_color("—"), None);
+    }
+}
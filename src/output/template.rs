@@ -0,0 +1,263 @@
+//! Handlebars-style output templates rendered per item against its JSON value.
+//!
+//! Templates use `{{field}}` / `{{nested.field}}` lookups plus a small set of
+//! helpers suited to shell prompts and status bars:
+//!
+//! - `{{pad title 30}}` / `{{lpad estimate 4}}` — left/right aligned padding
+//! - `{{truncate title 40}}` — truncate to a width with an ellipsis
+//! - `{{date created_at "%Y-%m-%d"}}` — reformat ISO-8601 timestamps and dates
+//! - `{{priority_emoji priority}}` / `{{priority_label priority}}` — issue priority display
+//!
+//! Output is never HTML-escaped.
+
+use crate::error::CliError;
+use crate::issues::types::Priority;
+use chrono::format::{Item, StrftimeItems};
+use handlebars::{
+    Context, Handlebars, Helper, HelperResult, Output, RenderContext, RenderErrorReason,
+    handlebars_helper, no_escape,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Write as FmtWrite;
+
+const ITEM_TEMPLATE: &str = "item";
+
+/// Render a single item through `template`.
+pub fn generic_template_formatter<T: Serialize>(
+    data: &T,
+    template: &str,
+) -> Result<String, CliError> {
+    let registry = build_registry(template)?;
+    render_item(&registry, data)
+}
+
+/// Render each item through `template`, one rendered item per line.
+///
+/// The template is compiled once and reused for every item.
+pub fn generic_template_list_formatter<T: Serialize>(
+    items: &[T],
+    template: &str,
+) -> Result<String, CliError> {
+    let registry = build_registry(template)?;
+
+    let rendered = items
+        .iter()
+        .map(|item| render_item(&registry, item))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(rendered.join("\n"))
+}
+
+/// Expand `\t`, `\n` and `\\` escapes in a template passed on the command line.
+///
+/// Shells pass `'{{identifier}}\t{{title}}'` through literally, so the CLI
+/// interprets the common escapes itself. Unknown escapes are left untouched.
+#[must_use]
+pub fn unescape_template(template: &str) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.peek() {
+            Some('t') => {
+                output.push('\t');
+                chars.next();
+            }
+            Some('n') => {
+                output.push('\n');
+                chars.next();
+            }
+            Some('\\') => {
+                output.push('\\');
+                chars.next();
+            }
+            _ => output.push('\\'),
+        }
+    }
+
+    output
+}
+
+fn build_registry(template: &str) -> Result<Handlebars<'static>, CliError> {
+    let mut registry = Handlebars::new();
+    registry.register_escape_fn(no_escape);
+    registry.register_helper("pad", Box::new(pad));
+    registry.register_helper("lpad", Box::new(lpad));
+    registry.register_helper("truncate", Box::new(truncate));
+    registry.register_helper("date", Box::new(date));
+    registry.register_helper("priority_emoji", Box::new(priority_emoji));
+    registry.register_helper("priority_label", Box::new(priority_label));
+    registry
+        .register_template_string(ITEM_TEMPLATE, template)
+        .map_err(|e| CliError::InvalidArgs(format!("Invalid output template: {e}")))?;
+    Ok(registry)
+}
+
+fn render_item<T: Serialize>(registry: &Handlebars<'_>, data: &T) -> Result<String, CliError> {
+    registry
+        .render(ITEM_TEMPLATE, data)
+        .map_err(|e| CliError::General(format!("Failed to render output template: {e}")))
+}
+
+/// Display form of a JSON value: strings unquoted, null as empty.
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn priority_from_value(value: &Value) -> Option<Priority> {
+    match value {
+        Value::Number(n) => n.as_f64().map(|n| Priority::from_i32(n as i32)),
+        Value::String(_) => serde_json::from_value(value.clone()).ok(),
+        _ => None,
+    }
+}
+
+handlebars_helper!(pad: |value: Json, width: u64| {
+    let text = display_value(value);
+    format!("{text:<width$}", width = width as usize)
+});
+
+handlebars_helper!(lpad: |value: Json, width: u64| {
+    let text = display_value(value);
+    format!("{text:>width$}", width = width as usize)
+});
+
+handlebars_helper!(truncate: |value: Json, width: u64| {
+    let text = display_value(value);
+    let width = width as usize;
+    if text.chars().count() <= width {
+        text
+    } else if width == 0 {
+        String::new()
+    } else {
+        let mut truncated: String = text.chars().take(width - 1).collect();
+        truncated.push('…');
+        truncated
+    }
+});
+
+/// `{{date value "fmt"}}`: reformat a timestamp or date with a strftime string
+///
+/// Written out rather than with `handlebars_helper!` so an invalid format is a
+/// render error instead of a panic inside chrono's `Display`.
+fn date(
+    helper: &Helper<'_>,
+    _: &Handlebars<'_>,
+    _: &Context,
+    _: &mut RenderContext<'_, '_>,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let text = display_value(helper.param(0).map_or(&Value::Null, |p| p.value()));
+    let fmt = helper
+        .param(1)
+        .and_then(|p| p.value().as_str())
+        .ok_or(RenderErrorReason::ParamNotFoundForIndex("date", 1))?;
+    if StrftimeItems::new(fmt).any(|item| matches!(item, Item::Error)) {
+        return Err(RenderErrorReason::Other(format!("Invalid date format '{fmt}'")).into());
+    }
+
+    let mut rendered = String::new();
+    let written = if let Ok(timestamp) = chrono::DateTime::parse_from_rfc3339(&text) {
+        write!(rendered, "{}", timestamp.format(fmt))
+    } else if let Ok(day) = chrono::NaiveDate::parse_from_str(&text, "%Y-%m-%d") {
+        write!(rendered, "{}", day.format(fmt))
+    } else {
+        rendered = text;
+        Ok(())
+    };
+    // Valid specifiers can still fail, e.g. `%H` on a date without a time
+    written.map_err(|_| {
+        RenderErrorReason::Other(format!("Date format '{fmt}' does not apply to this value"))
+    })?;
+
+    out.write(&rendered)?;
+    Ok(())
+}
+
+handlebars_helper!(priority_emoji: |value: Json| {
+    priority_from_value(value).map_or("", |p| p.emoji())
+});
+
+handlebars_helper!(priority_label: |value: Json| {
+    priority_from_value(value).map_or("", |p| p.as_str())
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unescape_template_expands_tabs_and_newlines() {
+        assert_eq!(unescape_template(r"{{a}}\t{{b}}\n"), "{{a}}\t{{b}}\n");
+        assert_eq!(unescape_template(r"C:\\path"), r"C:\path");
+        assert_eq!(unescape_template(r"\x"), r"\x");
+    }
+
+    #[test]
+    fn test_template_does_not_html_escape() {
+        let data = json!({"title": "Fix <b> & \"quotes\""});
+        let output = generic_template_formatter(&data, "{{title}}").unwrap();
+        assert_eq!(output, "Fix <b> & \"quotes\"");
+    }
+
+    #[test]
+    fn test_pad_and_truncate_helpers() {
+        let data = json!({"id": "ENG-1", "title": "A very long title"});
+        let output =
+            generic_template_formatter(&data, "{{pad id 7}}|{{lpad id 7}}|{{truncate title 6}}")
+                .unwrap();
+        assert_eq!(output, "ENG-1  |  ENG-1|A ver…");
+    }
+
+    #[test]
+    fn test_date_helper_formats_timestamps_and_dates() {
+        let data = json!({"created_at": "2025-03-04T10:20:30.000Z", "target": "2025-12-31"});
+        let output = generic_template_formatter(
+            &data,
+            r#"{{date created_at "%d/%m"}} {{date target "%b %Y"}}"#,
+        )
+        .unwrap();
+        assert_eq!(output, "04/03 Dec 2025");
+    }
+
+    #[test]
+    fn test_date_helper_rejects_invalid_formats() {
+        let data = json!({"created_at": "2025-03-04T10:20:30.000Z", "target": "2025-12-31"});
+        for template in [
+            r#"{{date created_at "%Q"}}"#,
+            r#"{{date target "%H:%M"}}"#,
+            "{{date created_at}}",
+        ] {
+            let err = generic_template_formatter(&data, template).unwrap_err();
+            assert!(matches!(err, CliError::General(_)), "{template}");
+        }
+    }
+
+    #[test]
+    fn test_priority_helpers_accept_names_and_numbers() {
+        let data = json!({"priority": "urgent", "numeric": 3});
+        let output = generic_template_formatter(
+            &data,
+            "{{priority_emoji priority}} {{priority_label priority}} {{priority_label numeric}}",
+        )
+        .unwrap();
+        assert_eq!(output, "🔴 Urgent Medium");
+    }
+
+    #[test]
+    fn test_invalid_template_is_invalid_args() {
+        let data = json!({});
+        let err = generic_template_formatter(&data, "{{#if}}").unwrap_err();
+        assert!(matches!(err, CliError::InvalidArgs(_)));
+    }
+}
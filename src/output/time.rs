@@ -0,0 +1,176 @@
+//! Timestamp display for table and Markdown output.
+//!
+//! Table and Markdown formatters render API timestamps with the [`TimeDisplay`]
+//! carried by the output format (`TableLayout::time`, `OutputFormat::Markdown`):
+//!
+//! - `relative` (default): "3 days ago", "in 2 hours"
+//! - `local`: "2026-10-19 14:30" in the local timezone
+//! - `iso`: the raw RFC 3339 value
+//!
+//! The style comes from `LINEAR_CLI_TIME_FORMAT`; `--utc` (`LINEAR_CLI_UTC`)
+//! shows local times in UTC instead. JSON, JSONL, CSV, YAML, TOML and templates
+//! always keep the ISO-8601 values. Formats that were not given a display show
+//! timestamps unchanged.
+
+use crate::auth::config::ConfigProvider;
+use crate::dates::{Clock, SystemClock};
+use crate::error::CliError;
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// How timestamps are rendered in table and Markdown output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeFormat {
+    /// Relative to now ("3 days ago")
+    Relative,
+    /// Date and time in the local timezone
+    Local,
+    /// Unchanged RFC 3339
+    Iso,
+}
+
+/// Timestamp display settings (`LINEAR_CLI_TIME_FORMAT`, `--utc`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeDisplay {
+    pub format: TimeFormat,
+    /// Show times in UTC rather than the local timezone
+    pub utc: bool,
+}
+
+impl TimeDisplay {
+    /// Timestamps exactly as returned by the API
+    pub const ISO: Self = Self {
+        format: TimeFormat::Iso,
+        utc: false,
+    };
+
+    /// Read the style from `LINEAR_CLI_TIME_FORMAT` (`relative`, `local` or `iso`)
+    ///
+    /// Times are shown in UTC when `LINEAR_CLI_UTC` is set to a non-empty
+    /// value, which is how the CLI passes on `--utc`.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgs` if `LINEAR_CLI_TIME_FORMAT` holds any other value.
+    pub fn from_config(config: &dyn ConfigProvider) -> Result<Self, CliError> {
+        let format = match config
+            .get_var("LINEAR_CLI_TIME_FORMAT")
+            .as_deref()
+            .map(str::trim)
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            None | Some("" | "relative") => TimeFormat::Relative,
+            Some("local") => TimeFormat::Local,
+            Some("iso") => TimeFormat::Iso,
+            Some(other) => {
+                return Err(CliError::InvalidArgs(format!(
+                    "Invalid LINEAR_CLI_TIME_FORMAT '{other}': expected relative, local or iso"
+                )));
+            }
+        };
+        let utc = config
+            .get_var("LINEAR_CLI_UTC")
+            .is_some_and(|v| !v.is_empty());
+        Ok(Self { format, utc })
+    }
+
+    /// Render a timestamp for table or Markdown output
+    #[must_use]
+    pub fn format(&self, value: &str) -> String {
+        self.render(value, &SystemClock)
+    }
+
+    /// Render an event timestamp for table or Markdown output ("ends in 2 days")
+    #[must_use]
+    pub fn format_event(&self, value: &str, upcoming: &str, past: &str) -> String {
+        self.render_event(value, upcoming, past, &SystemClock)
+    }
+
+    /// Render an RFC 3339 timestamp; other values are returned unchanged
+    #[must_use]
+    pub fn render(&self, value: &str, clock: &dyn Clock) -> String {
+        let Ok(instant) = DateTime::parse_from_rfc3339(value) else {
+            return value.to_string();
+        };
+        let instant = instant.with_timezone(&Utc);
+
+        match self.format {
+            TimeFormat::Relative => relative(instant, clock),
+            TimeFormat::Local if self.utc => instant.format("%Y-%m-%d %H:%M UTC").to_string(),
+            TimeFormat::Local => instant
+                .with_timezone(&clock.offset_at(instant))
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            TimeFormat::Iso if self.utc => instant.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            TimeFormat::Iso => value.to_string(),
+        }
+    }
+
+    /// Render a timestamp for an event, e.g. "ends in 2 days" / "ended 3 days ago"
+    ///
+    /// Only the relative style adds the verb; the others render the time alone.
+    #[must_use]
+    pub fn render_event(
+        &self,
+        value: &str,
+        upcoming: &str,
+        past: &str,
+        clock: &dyn Clock,
+    ) -> String {
+        let rendered = self.render(value, clock);
+        match DateTime::parse_from_rfc3339(value) {
+            Ok(instant) if self.format == TimeFormat::Relative => {
+                if instant > clock.now() {
+                    format!("{upcoming} {rendered}")
+                } else {
+                    format!("{past} {rendered}")
+                }
+            }
+            _ => rendered,
+        }
+    }
+}
+
+/// Timestamps unchanged, for output that was not given a display
+impl Default for TimeDisplay {
+    fn default() -> Self {
+        Self::ISO
+    }
+}
+
+/// "in 2 days", "3 hours ago", "just now"
+fn relative(instant: DateTime<Utc>, clock: &dyn Clock) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let seconds = instant.signed_duration_since(clock.now()).num_seconds();
+    let abs = seconds.abs();
+    if abs < MINUTE {
+        return "just now".to_string();
+    }
+
+    let (amount, unit) = match abs {
+        _ if abs < HOUR => (abs / MINUTE, "minute"),
+        _ if abs < DAY => (abs / HOUR, "hour"),
+        _ if abs < 30 * DAY => (abs / DAY, "day"),
+        _ if abs < 365 * DAY => (abs / (30 * DAY), "month"),
+        _ => (abs / (365 * DAY), "year"),
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+
+    if seconds > 0 {
+        format!("in {amount} {unit}{plural}")
+    } else {
+        format!("{amount} {unit}{plural} ago")
+    }
+}
+
+/// An item paired with the timestamp display it is rendered with
+///
+/// Types whose table or Markdown output shows timestamps implement
+/// `TableFormatter`/`MarkdownFormatter` for `Timed<'_, Self>`.
+#[derive(Debug, Clone, Copy)]
+pub struct Timed<'a, T> {
+    pub item: &'a T,
+    pub time: TimeDisplay,
+}
@@ -5,8 +5,8 @@ use crate::client::projects::ProjectClient;
 use crate::error::CliError;
 use crate::io::Io;
 use crate::output::{
-    JsonStyle, OutputFormat, format_output_to_writer, get_format_with_provider,
-    resolve_json_style_with_provider,
+    JsonStyle, OutputFormat, apply_terminal_layout, format_output_to_writer,
+    get_format_with_provider, resolve_json_style_with_provider,
 };
 use crate::projects::types::ProjectList;
 use secrecy::ExposeSecret;
@@ -26,8 +26,8 @@ pub fn handle_list(
     // Fetch projects from API
     let projects = client.list_projects(token.expose_secret(), limit)?;
 
-    // Determine output format (CLI flag > env var > auto-detect)
-    let format = get_format_with_provider(format_flag, config);
+    // Determine output format (CLI flag > env var > auto-detect), sized to the terminal
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     // Hotspot optimization #1: project list JSON fast-path.
     // Resolve JSON style once from injected config, then serialize directly to bytes
@@ -2,4 +2,4 @@ pub mod list;
 pub mod view;
 
 pub use list::handle_list;
-pub use view::handle_view;
+pub use view::{ProjectViewOptions, handle_view};
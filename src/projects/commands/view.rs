@@ -8,11 +8,24 @@ use crate::output::{
     JsonStyle, OutputFormat, format_output_to_writer, get_format_with_provider,
     resolve_json_style_with_provider,
 };
+use crate::projects::types::{ProjectIssueProgress, open_issues_by_assignee};
 use secrecy::ExposeSecret;
 
+/// Optional sections for `project view`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProjectViewOptions {
+    /// Open issues grouped by assignee
+    pub issues: bool,
+    /// Project members
+    pub members: bool,
+    /// Issue breakdown by state type with scope/completed counts
+    pub progress: bool,
+}
+
 /// Handle the project view command
 pub fn handle_view(
-    id: &str,
+    reference: &str,
+    options: &ProjectViewOptions,
     client: &dyn ProjectClient,
     config: &dyn ConfigProvider,
     storage: &dyn TokenStorage,
@@ -23,7 +36,22 @@ pub fn handle_view(
     let token = get_token_with_provider(config, storage)?;
 
     // Fetch project from API
-    let project = client.get_project(token.expose_secret(), id)?;
+    let mut project = client.get_project(token.expose_secret(), reference)?;
+
+    // Issues are fetched once for both the breakdown and the assignee grouping
+    if options.issues || options.progress {
+        let issues = client.list_project_issues(token.expose_secret(), &project.id)?;
+        if options.progress {
+            project.issue_progress = Some(ProjectIssueProgress::from_issues(&issues));
+        }
+        if options.issues {
+            project.open_issues = Some(open_issues_by_assignee(issues));
+        }
+    }
+
+    if options.members {
+        project.members = Some(client.list_project_members(token.expose_secret(), &project.id)?);
+    }
 
     // Determine output format (CLI flag > env var > auto-detect)
     let format = get_format_with_provider(format_flag, config);
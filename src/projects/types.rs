@@ -1,8 +1,12 @@
 use crate::error::CliError;
+use crate::milestones::ProjectMilestone;
+use crate::milestones::types::{milestones_from_connection, write_milestone_table};
 use crate::output::{
-    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
-    generic_json_formatter, generic_json_list_formatter, generic_table_formatter,
-    generic_table_list_formatter,
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
+    priority_color, state_color,
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -48,6 +52,199 @@ pub struct Project {
 
     // Relationships
     pub lead_name: Option<String>, // From lead.name
+
+    /// Milestones in the project's sort order
+    #[serde(default)]
+    pub milestones: Vec<ProjectMilestone>,
+
+    /// Issue breakdown by state type, filled in by `project view --progress`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_progress: Option<ProjectIssueProgress>,
+    /// Open issues grouped by assignee, filled in by `project view --issues`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_issues: Option<Vec<AssigneeIssues>>,
+    /// Project members, filled in by `project view --members`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<ProjectMember>>,
+}
+
+/// Issue in a project, as used for `project view` breakdowns
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectIssue {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub state_name: String,
+    /// `triage`, `backlog`, `unstarted`, `started`, `completed` or `canceled`
+    pub state_type: String,
+    pub assignee_name: Option<String>,
+    pub estimate: Option<f64>,
+}
+
+impl ProjectIssue {
+    /// Whether the issue is still open (neither completed nor canceled)
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        !matches!(self.state_type.as_str(), "completed" | "canceled")
+    }
+}
+
+/// Member of a project
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectMember {
+    pub id: String,
+    pub name: String,
+    pub display_name: String,
+    pub email: String,
+}
+
+/// Number of issues in one workflow state type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateTypeCount {
+    pub state_type: String,
+    pub count: usize,
+}
+
+/// Workflow state types in the order Linear moves issues through them
+const STATE_TYPES: [&str; 6] = [
+    "triage",
+    "backlog",
+    "unstarted",
+    "started",
+    "completed",
+    "canceled",
+];
+
+/// Issue counts for a project, broken down by workflow state type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectIssueProgress {
+    /// Issues in scope, i.e. everything except canceled issues
+    pub scope: usize,
+    pub completed: usize,
+    /// Sum of estimates of the issues in scope
+    pub scope_points: f64,
+    pub completed_points: f64,
+    /// Counts for every state type, in workflow order
+    pub by_state_type: Vec<StateTypeCount>,
+}
+
+impl ProjectIssueProgress {
+    #[must_use]
+    pub fn from_issues(issues: &[ProjectIssue]) -> Self {
+        let mut by_state_type: Vec<StateTypeCount> = STATE_TYPES
+            .iter()
+            .map(|state_type| StateTypeCount {
+                state_type: (*state_type).to_string(),
+                count: 0,
+            })
+            .collect();
+        let mut progress = ProjectIssueProgress {
+            scope: 0,
+            completed: 0,
+            scope_points: 0.0,
+            completed_points: 0.0,
+            by_state_type: Vec::new(),
+        };
+
+        for issue in issues {
+            match by_state_type
+                .iter_mut()
+                .find(|c| c.state_type == issue.state_type)
+            {
+                Some(entry) => entry.count += 1,
+                None => by_state_type.push(StateTypeCount {
+                    state_type: issue.state_type.clone(),
+                    count: 1,
+                }),
+            }
+
+            if issue.state_type == "canceled" {
+                continue;
+            }
+            let points = issue.estimate.unwrap_or(0.0);
+            progress.scope += 1;
+            progress.scope_points += points;
+            if issue.state_type == "completed" {
+                progress.completed += 1;
+                progress.completed_points += points;
+            }
+        }
+
+        progress.by_state_type = by_state_type;
+        progress
+    }
+
+    /// Share of in-scope issues that are completed, 0.0 to 1.0
+    #[must_use]
+    pub fn completion(&self) -> f64 {
+        if self.scope == 0 {
+            0.0
+        } else {
+            self.completed as f64 / self.scope as f64
+        }
+    }
+}
+
+/// Open issues assigned to one person, or unassigned when `assignee` is `None`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssigneeIssues {
+    pub assignee: Option<String>,
+    pub issues: Vec<ProjectIssue>,
+}
+
+impl AssigneeIssues {
+    fn label(&self) -> &str {
+        self.assignee.as_deref().unwrap_or("Unassigned")
+    }
+}
+
+/// Group a project's open issues by assignee
+///
+/// Assignees are sorted by name with unassigned issues last; issues keep their order.
+#[must_use]
+pub fn open_issues_by_assignee(issues: Vec<ProjectIssue>) -> Vec<AssigneeIssues> {
+    let mut assigned: std::collections::BTreeMap<String, Vec<ProjectIssue>> =
+        std::collections::BTreeMap::new();
+    let mut unassigned = Vec::new();
+
+    for issue in issues.into_iter().filter(ProjectIssue::is_open) {
+        match issue.assignee_name.clone() {
+            Some(name) => assigned.entry(name).or_default().push(issue),
+            None => unassigned.push(issue),
+        }
+    }
+
+    let mut groups: Vec<AssigneeIssues> = assigned
+        .into_iter()
+        .map(|(assignee, issues)| AssigneeIssues {
+            assignee: Some(assignee),
+            issues,
+        })
+        .collect();
+    if !unassigned.is_empty() {
+        groups.push(AssigneeIssues {
+            assignee: None,
+            issues: unassigned,
+        });
+    }
+    groups
+}
+
+fn state_type_label(state_type: &str) -> String {
+    let mut chars = state_type.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+fn format_issue_count(count: usize, points: f64) -> String {
+    let noun = if count == 1 { "issue" } else { "issues" };
+    if points > 0.0 {
+        format!("{count} {noun} ({points} points)")
+    } else {
+        format!("{count} {noun}")
+    }
 }
 
 // Trait implementations for generic formatters
@@ -91,6 +288,68 @@ impl TableFormatter for Project {
             rows.push((Cow::Borrowed("Lead"), Cow::Borrowed(lead.as_str())));
         }
 
+        for milestone in &self.milestones {
+            rows.push((
+                Cow::Borrowed("Milestone"),
+                Cow::Owned(format!(
+                    "{} ({}, {})",
+                    milestone.name,
+                    milestone.status,
+                    format_progress_percent(milestone.progress)
+                )),
+            ));
+        }
+
+        if let Some(progress) = &self.issue_progress {
+            rows.push((
+                Cow::Borrowed("Scope"),
+                Cow::Owned(format_issue_count(progress.scope, progress.scope_points)),
+            ));
+            rows.push((
+                Cow::Borrowed("Completed"),
+                Cow::Owned(format!(
+                    "{} ({})",
+                    format_issue_count(progress.completed, progress.completed_points),
+                    format_progress_percent(progress.completion())
+                )),
+            ));
+            for count in progress.by_state_type.iter().filter(|c| c.count > 0) {
+                rows.push((
+                    Cow::Owned(state_type_label(&count.state_type)),
+                    Cow::Owned(count.count.to_string()),
+                ));
+            }
+        }
+
+        if let Some(members) = &self.members {
+            rows.push((
+                Cow::Borrowed("Members"),
+                Cow::Owned(
+                    members
+                        .iter()
+                        .map(|m| m.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+            ));
+        }
+
+        if let Some(groups) = &self.open_issues {
+            for group in groups {
+                rows.push((
+                    Cow::Owned(format!("Open ({})", group.label())),
+                    Cow::Owned(
+                        group
+                            .issues
+                            .iter()
+                            .map(|i| i.identifier.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                ));
+            }
+        }
+
         // Metadata fields
         rows.push((Cow::Borrowed("ID"), Cow::Borrowed(self.id.as_str())));
         rows.push((Cow::Borrowed("Slug"), Cow::Borrowed(self.slug_id.as_str())));
@@ -131,6 +390,22 @@ impl MarkdownFormatter for Project {
             + self.lead_name.as_ref().map_or(0, |l| l.len())
             + self.created_at.len()
             + self.updated_at.len()
+            + self
+                .milestones
+                .iter()
+                .map(|m| 40 + m.name.len())
+                .sum::<usize>()
+            + self.issue_progress.as_ref().map_or(0, |_| 200)
+            + self.members.as_ref().map_or(0, |m| {
+                m.iter().map(|m| 8 + m.name.len() + m.email.len()).sum()
+            })
+            + self.open_issues.as_ref().map_or(0, |groups| {
+                groups
+                    .iter()
+                    .flat_map(|g| &g.issues)
+                    .map(|i| 16 + i.identifier.len() + i.title.len() + i.url.len())
+                    .sum()
+            })
     }
 
     fn write_markdown(&self, output: &mut String) -> Result<(), CliError> {
@@ -204,6 +479,70 @@ impl MarkdownFormatter for Project {
         writeln!(output, "**Updated:** {}", self.updated_at)
             .map_err(|e| CliError::General(format!("Failed to write markdown updated: {e}")))?;
 
+        if !self.milestones.is_empty() {
+            writeln!(output, "\n## Milestones ({})\n", self.milestones.len()).map_err(|e| {
+                CliError::General(format!("Failed to write markdown milestones: {e}"))
+            })?;
+            write_milestone_table(output, &self.milestones)?;
+        }
+
+        if let Some(progress) = &self.issue_progress {
+            writeln!(
+                output,
+                "\n## Progress\n\n**Scope:** {}\n**Completed:** {} ({})\n",
+                format_issue_count(progress.scope, progress.scope_points),
+                format_issue_count(progress.completed, progress.completed_points),
+                format_progress_percent(progress.completion())
+            )
+            .map_err(|e| CliError::General(format!("Failed to write markdown progress: {e}")))?;
+            writeln!(output, "| State | Issues |\n|---|---|").map_err(|e| {
+                CliError::General(format!("Failed to write markdown progress: {e}"))
+            })?;
+            for count in &progress.by_state_type {
+                writeln!(
+                    output,
+                    "| {} | {} |",
+                    state_type_label(&count.state_type),
+                    count.count
+                )
+                .map_err(|e| {
+                    CliError::General(format!("Failed to write markdown progress: {e}"))
+                })?;
+            }
+        }
+
+        if let Some(members) = &self.members {
+            writeln!(output, "\n## Members ({})\n", members.len())
+                .map_err(|e| CliError::General(format!("Failed to write markdown members: {e}")))?;
+            for member in members {
+                writeln!(output, "- {} ({})", member.name, member.email).map_err(|e| {
+                    CliError::General(format!("Failed to write markdown members: {e}"))
+                })?;
+            }
+        }
+
+        if let Some(groups) = &self.open_issues {
+            let total: usize = groups.iter().map(|g| g.issues.len()).sum();
+            writeln!(output, "\n## Open issues ({total})").map_err(|e| {
+                CliError::General(format!("Failed to write markdown open issues: {e}"))
+            })?;
+            for group in groups {
+                writeln!(output, "\n### {} ({})\n", group.label(), group.issues.len()).map_err(
+                    |e| CliError::General(format!("Failed to write markdown open issues: {e}")),
+                )?;
+                for issue in &group.issues {
+                    writeln!(
+                        output,
+                        "- [{}]({}) {} — {}",
+                        issue.identifier, issue.url, issue.title, issue.state_name
+                    )
+                    .map_err(|e| {
+                        CliError::General(format!("Failed to write markdown open issues: {e}"))
+                    })?;
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -213,11 +552,14 @@ impl Formattable for Project {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
-        // Write header row
-        wtr.write_record([
+        let mut header: Vec<Cow<'_, str>> = [
             "name",
             "slug_id",
             "id",
@@ -234,30 +576,75 @@ impl Formattable for Project {
             "url",
             "created_at",
             "updated_at",
-        ])
-        .csv_err("Failed to write CSV header")?;
-
-        // Write data row
+        ]
+        .into_iter()
+        .map(Cow::Borrowed)
+        .collect();
         let progress_str = format_progress_percent(self.progress);
-        wtr.write_record([
-            &self.name,
-            &self.slug_id,
-            &self.id,
-            &self.description,
-            &self.status_name,
-            &progress_str,
-            &self.priority.to_string(),
-            &self.priority_label,
-            self.start_date.as_deref().unwrap_or(""),
-            self.target_date.as_deref().unwrap_or(""),
-            self.lead_name.as_deref().unwrap_or(""),
-            &self.color,
-            self.icon.as_deref().unwrap_or(""),
-            &self.url,
-            &self.created_at,
-            &self.updated_at,
-        ])
-        .csv_err("Failed to write CSV data")?;
+        let mut row: Vec<Cow<'_, str>> = vec![
+            Cow::Borrowed(&self.name),
+            Cow::Borrowed(&self.slug_id),
+            Cow::Borrowed(&self.id),
+            Cow::Borrowed(&self.description),
+            Cow::Borrowed(&self.status_name),
+            Cow::Owned(progress_str),
+            Cow::Owned(self.priority.to_string()),
+            Cow::Borrowed(&self.priority_label),
+            Cow::Borrowed(self.start_date.as_deref().unwrap_or("")),
+            Cow::Borrowed(self.target_date.as_deref().unwrap_or("")),
+            Cow::Borrowed(self.lead_name.as_deref().unwrap_or("")),
+            Cow::Borrowed(&self.color),
+            Cow::Borrowed(self.icon.as_deref().unwrap_or("")),
+            Cow::Borrowed(&self.url),
+            Cow::Borrowed(&self.created_at),
+            Cow::Borrowed(&self.updated_at),
+        ];
+
+        // Optional `project view` sections become extra columns
+        if let Some(progress) = &self.issue_progress {
+            header.extend([
+                Cow::Borrowed("scope_issues"),
+                Cow::Borrowed("completed_issues"),
+                Cow::Borrowed("scope_points"),
+                Cow::Borrowed("completed_points"),
+            ]);
+            row.extend([
+                Cow::Owned(progress.scope.to_string()),
+                Cow::Owned(progress.completed.to_string()),
+                Cow::Owned(progress.scope_points.to_string()),
+                Cow::Owned(progress.completed_points.to_string()),
+            ]);
+            for count in &progress.by_state_type {
+                header.push(Cow::Owned(format!("issues_{}", count.state_type)));
+                row.push(Cow::Owned(count.count.to_string()));
+            }
+        }
+        if let Some(members) = &self.members {
+            header.push(Cow::Borrowed("members"));
+            row.push(Cow::Owned(
+                members
+                    .iter()
+                    .map(|m| m.email.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            ));
+        }
+        if let Some(groups) = &self.open_issues {
+            header.push(Cow::Borrowed("open_issues"));
+            row.push(Cow::Owned(
+                groups
+                    .iter()
+                    .flat_map(|g| &g.issues)
+                    .map(|i| i.identifier.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            ));
+        }
+
+        wtr.write_record(header.iter().map(|c| c.as_ref()))
+            .csv_err("Failed to write CSV header")?;
+        wtr.write_record(row.iter().map(|c| c.as_ref()))
+            .csv_err("Failed to write CSV data")?;
 
         let data = wtr.into_inner().csv_err("Failed to finalize CSV")?;
 
@@ -271,10 +658,16 @@ impl Formattable for Project {
     fn to_table(&self) -> Result<String, CliError> {
         generic_table_formatter(self)
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
 
 /// Wrapper type for a list of projects
 /// Provides different formatting for collections vs single items
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct ProjectList(pub Vec<Project>);
 
 impl Formattable for ProjectList {
@@ -282,6 +675,10 @@ impl Formattable for ProjectList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -420,9 +817,23 @@ impl Formattable for ProjectList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
         generic_table_list_formatter(
             &self.0,
-            &["ID", "Name", "Status", "Progress", "Priority", "Lead"],
+            &[
+                TableColumn::new("ID").wide_only(),
+                TableColumn::new("Name").flexible(),
+                TableColumn::new("Status").colored(state_color),
+                TableColumn::new("Progress"),
+                TableColumn::new("Priority").colored(priority_color),
+                TableColumn::new("Lead"),
+                TableColumn::new("Target").wide_only(),
+                TableColumn::new("URL").wide_only(),
+            ],
+            layout,
             |project| {
                 vec![
                     project.id.clone(),
@@ -431,10 +842,19 @@ impl Formattable for ProjectList {
                     format_progress_percent(project.progress),
                     project.priority_label.clone(),
                     project.lead_name.clone().unwrap_or_else(|| "—".to_string()),
+                    project
+                        .target_date
+                        .clone()
+                        .unwrap_or_else(|| "—".to_string()),
+                    project.url.clone(),
                 ]
             },
         )
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
 }
 
 // From trait implementation for converting Cynic types to domain types
@@ -471,6 +891,36 @@ impl From<crate::client::queries::ProjectNode> for Project {
             lead_name: node.lead.map(|l| l.name),
             created_at: node.created_at.0,
             updated_at: node.updated_at.0,
+            milestones: milestones_from_connection(node.project_milestones),
+            issue_progress: None,
+            open_issues: None,
+            members: None,
+        }
+    }
+}
+
+impl From<crate::client::queries::ProjectIssueNode> for ProjectIssue {
+    fn from(node: crate::client::queries::ProjectIssueNode) -> Self {
+        ProjectIssue {
+            id: node.id.inner().to_string(),
+            identifier: node.identifier,
+            title: node.title,
+            url: node.url,
+            state_name: node.state.name,
+            state_type: node.state.state_type,
+            assignee_name: node.assignee.map(|a| a.name),
+            estimate: node.estimate,
+        }
+    }
+}
+
+impl From<crate::client::queries::ProjectMemberNode> for ProjectMember {
+    fn from(node: crate::client::queries::ProjectMemberNode) -> Self {
+        ProjectMember {
+            id: node.id.inner().to_string(),
+            name: node.name,
+            display_name: node.display_name,
+            email: node.email,
         }
     }
 }
@@ -0,0 +1,64 @@
+use crate::auth::config::ConfigProvider;
+use crate::auth::storage::TokenStorage;
+use crate::auth::token::get_token_with_provider;
+use crate::client::reactions::{ReactionClient, ReactionTarget};
+use crate::error::CliError;
+use crate::io::Io;
+use crate::output::{OutputFormat, get_format_with_provider};
+use crate::reactions::emoji::{display_emoji, normalize_emoji};
+use secrecy::ExposeSecret;
+
+/// Handle `issue react` and `issue comment react` commands
+///
+/// With `remove`, deletes the current user's reactions with that emoji.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_react(
+    target: ReactionTarget,
+    emoji: &str,
+    remove: bool,
+    client: &dyn ReactionClient,
+    config: &dyn ConfigProvider,
+    storage: &dyn TokenStorage,
+    io: &dyn Io,
+    format_flag: Option<OutputFormat>,
+) -> Result<(), CliError> {
+    let emoji = normalize_emoji(emoji)?;
+    let token = get_token_with_provider(config, storage)?;
+    let format = get_format_with_provider(format_flag, config);
+
+    let msg = if remove {
+        let removed = client.remove_reaction(token.expose_secret(), &target, &emoji)?;
+        if removed == 0 {
+            return Err(CliError::NotFound(format!(
+                "You have no {} reaction on {}",
+                display_emoji(&emoji),
+                target.id()
+            )));
+        }
+        if matches!(format, OutputFormat::Json) {
+            serde_json::json!({
+                "removed": removed,
+                "emoji": emoji,
+                "target": target.id(),
+            })
+            .to_string()
+        } else {
+            format!("Removed {} from {}", display_emoji(&emoji), target.id())
+        }
+    } else {
+        let id = client.add_reaction(token.expose_secret(), &target, &emoji)?;
+        if matches!(format, OutputFormat::Json) {
+            serde_json::json!({
+                "id": id,
+                "emoji": emoji,
+                "target": target.id(),
+            })
+            .to_string()
+        } else {
+            format!("Reacted {} to {}", display_emoji(&emoji), target.id())
+        }
+    };
+    io.print(&msg);
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::error::CliError;
+
+/// Common emoji and the shortcode names Linear stores reactions under
+const EMOJI: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("eyes", "👀"),
+    ("heart", "❤️"),
+    ("tada", "🎉"),
+    ("rocket", "🚀"),
+    ("smile", "😄"),
+    ("laughing", "😆"),
+    ("confused", "😕"),
+    ("white_check_mark", "✅"),
+    ("fire", "🔥"),
+    ("pray", "🙏"),
+    ("raised_hands", "🙌"),
+    ("100", "💯"),
+];
+
+/// Normalize `:eyes:`, `eyes` or `👀` to the shortcode name Linear expects
+///
+/// Unknown glyphs are passed through unchanged.
+///
+/// # Errors
+/// Returns `InvalidArgs` for an empty emoji.
+pub fn normalize_emoji(input: &str) -> Result<String, CliError> {
+    let trimmed = input.trim();
+    let name = trimmed
+        .strip_prefix(':')
+        .and_then(|s| s.strip_suffix(':'))
+        .unwrap_or(trimmed);
+
+    if name.is_empty() {
+        return Err(CliError::InvalidArgs("Emoji cannot be empty".to_string()));
+    }
+
+    let name = match name {
+        "thumbsup" => "+1",
+        "thumbsdown" => "-1",
+        _ => name,
+    };
+
+    let by_glyph = EMOJI
+        .iter()
+        .find(|(_, glyph)| glyph.trim_end_matches('\u{fe0f}') == name.trim_end_matches('\u{fe0f}'))
+        .map(|(shortcode, _)| *shortcode);
+
+    Ok(by_glyph.unwrap_or(name).to_string())
+}
+
+/// Emoji glyph for a shortcode name, falling back to `:name:`
+#[must_use]
+pub fn display_emoji(name: &str) -> String {
+    EMOJI
+        .iter()
+        .find(|(shortcode, _)| *shortcode == name)
+        .map_or_else(|| format!(":{name}:"), |(_, glyph)| (*glyph).to_string())
+}
@@ -0,0 +1,5 @@
+pub mod commands;
+pub mod emoji;
+pub mod types;
+
+pub use types::ReactionSummary;
@@ -0,0 +1,36 @@
+use crate::client::queries::ReactionNode;
+use crate::reactions::emoji::display_emoji;
+use serde::{Deserialize, Serialize};
+
+/// Number of reactions with one emoji
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReactionSummary {
+    pub emoji: String,
+    pub count: usize,
+}
+
+/// Count reactions per emoji, in the order each emoji was first used
+#[must_use]
+pub fn summarize_reactions(reactions: &[ReactionNode]) -> Vec<ReactionSummary> {
+    let mut summaries: Vec<ReactionSummary> = Vec::new();
+    for reaction in reactions {
+        match summaries.iter_mut().find(|s| s.emoji == reaction.emoji) {
+            Some(summary) => summary.count += 1,
+            None => summaries.push(ReactionSummary {
+                emoji: reaction.emoji.clone(),
+                count: 1,
+            }),
+        }
+    }
+    summaries
+}
+
+/// Compact display such as `👍 2  👀 1`
+#[must_use]
+pub fn format_reactions(reactions: &[ReactionSummary]) -> String {
+    reactions
+        .iter()
+        .map(|r| format!("{} {}", display_emoji(&r.emoji), r.count))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
@@ -5,7 +5,9 @@ use crate::client::queries::SemanticSearchResultType;
 use crate::client::semantic_search::SemanticSearchClient;
 use crate::error::CliError;
 use crate::io::Io;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use crate::search::types::SemanticSearchResultList;
 use secrecy::ExposeSecret;
 
@@ -47,7 +49,7 @@ pub fn handle_semantic_search(
     let results = client.semantic_search(token.expose_secret(), query, types, max_results)?;
 
     let result_list = SemanticSearchResultList(results);
-    let format = get_format_with_provider(format_flag, config);
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     let mut output = Vec::new();
     format_output_to_writer(&result_list, format, &mut output)?;
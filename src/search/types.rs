@@ -1,8 +1,10 @@
 use crate::error::CliError;
 use crate::output::CsvResultExt;
 use crate::output::{
-    Formattable, TableFormatter, generic_json_formatter, generic_json_list_formatter,
-    generic_table_formatter, generic_table_list_formatter,
+    Formattable, TableColumn, TableFormatter, TableLayout, generic_json_formatter,
+    generic_json_list_formatter, generic_jsonl_formatter, generic_jsonl_list_formatter,
+    generic_table_formatter, generic_table_list_formatter, generic_template_formatter,
+    generic_template_list_formatter,
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -41,6 +43,10 @@ impl Formattable for SemanticSearchResult {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -86,9 +92,15 @@ impl Formattable for SemanticSearchResult {
     fn to_table(&self) -> Result<String, CliError> {
         generic_table_formatter(self)
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
 
 /// Wrapper for a list of semantic search results
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct SemanticSearchResultList(pub Vec<SemanticSearchResult>);
 
 impl Formattable for SemanticSearchResultList {
@@ -96,6 +108,10 @@ impl Formattable for SemanticSearchResultList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -140,12 +156,31 @@ impl Formattable for SemanticSearchResultList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
-        generic_table_list_formatter(&self.0, &["Type", "Identifier", "Title"], |r| {
-            vec![
-                r.result_type.clone(),
-                r.identifier.clone().unwrap_or_else(|| "—".to_string()),
-                r.title.clone(),
-            ]
-        })
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new("Type"),
+                TableColumn::new("Identifier"),
+                TableColumn::new("Title").flexible(),
+                TableColumn::new("URL").wide_only(),
+            ],
+            layout,
+            |r| {
+                vec![
+                    r.result_type.clone(),
+                    r.identifier.clone().unwrap_or_else(|| "—".to_string()),
+                    r.title.clone(),
+                    r.url.clone().unwrap_or_else(|| "—".to_string()),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
     }
 }
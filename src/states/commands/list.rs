@@ -4,7 +4,9 @@ use crate::auth::token::get_token_with_provider;
 use crate::client::states::StateClient;
 use crate::error::CliError;
 use crate::io::Io;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use crate::states::types::WorkflowStateList;
 use secrecy::ExposeSecret;
 
@@ -21,7 +23,7 @@ pub fn handle_list(
     let token = get_token_with_provider(config, storage)?;
     let states = client.list_states(token.expose_secret(), team_key, limit)?;
     let state_list = WorkflowStateList(states);
-    let format = get_format_with_provider(format_flag, config);
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     let mut output = Vec::new();
     format_output_to_writer(&state_list, format, &mut output)?;
@@ -1,9 +1,11 @@
 use crate::client::queries;
 use crate::error::CliError;
 use crate::output::{
-    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
-    generic_json_formatter, generic_json_list_formatter, generic_table_formatter,
-    generic_table_list_formatter,
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
+    state_color,
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -88,6 +90,10 @@ impl Formattable for WorkflowState {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -124,9 +130,15 @@ impl Formattable for WorkflowState {
     fn to_table(&self) -> Result<String, CliError> {
         generic_table_formatter(self)
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
 
 /// Wrapper for a list of workflow states
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct WorkflowStateList(pub Vec<WorkflowState>);
 
 impl Formattable for WorkflowStateList {
@@ -134,6 +146,10 @@ impl Formattable for WorkflowStateList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -194,19 +210,37 @@ impl Formattable for WorkflowStateList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
         generic_table_list_formatter(
             &self.0,
-            &["Name", "Type", "Color", "Description"],
+            &[
+                TableColumn::new("Name"),
+                TableColumn::new("Type").colored(state_color),
+                TableColumn::new("Color"),
+                TableColumn::new("Description").flexible(),
+                TableColumn::new("Team").wide_only(),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
             |state| {
                 vec![
                     state.name.clone(),
                     state.state_type.clone(),
                     state.color.clone(),
                     state.description.clone().unwrap_or_else(|| "—".to_string()),
+                    state.team_name.clone().unwrap_or_else(|| "—".to_string()),
+                    state.id.clone(),
                 ]
             },
         )
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
 }
 
 impl From<queries::WorkflowStateNode> for WorkflowState {
@@ -4,7 +4,9 @@ use crate::auth::token::get_token_with_provider;
 use crate::client::teams::TeamClient;
 use crate::error::CliError;
 use crate::io::Io;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use crate::teams::types::TeamList;
 use secrecy::ExposeSecret;
 
@@ -26,8 +28,8 @@ pub fn handle_list(
     // Wrap in TeamList for collection-specific formatting
     let team_list = TeamList(teams);
 
-    // Determine output format (CLI flag > env var > auto-detect)
-    let format = get_format_with_provider(format_flag, config);
+    // Determine output format (CLI flag > env var > auto-detect), sized to the terminal
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     // Format and output via streaming writer API
     let mut output = Vec::new();
@@ -1,9 +1,10 @@
 use crate::client::queries;
 use crate::error::CliError;
 use crate::output::{
-    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
-    generic_json_formatter, generic_json_list_formatter, generic_table_formatter,
-    generic_table_list_formatter,
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -112,6 +113,10 @@ impl Formattable for Team {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -154,10 +159,16 @@ impl Formattable for Team {
     fn to_table(&self) -> Result<String, CliError> {
         generic_table_formatter(self)
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
 
 /// Wrapper type for a list of teams
 /// Provides different formatting for collections vs single items
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct TeamList(pub Vec<Team>);
 
 impl Formattable for TeamList {
@@ -165,6 +176,10 @@ impl Formattable for TeamList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -246,19 +261,35 @@ impl Formattable for TeamList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
         generic_table_list_formatter(
             &self.0,
-            &["Key", "Name", "Private", "Description"],
+            &[
+                TableColumn::new("Key"),
+                TableColumn::new("Name"),
+                TableColumn::new("Private"),
+                TableColumn::new("Description").flexible(),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
             |team| {
                 vec![
                     team.key.clone(),
                     team.name.clone(),
                     if team.private { "Yes" } else { "No" }.to_string(),
                     team.description.clone().unwrap_or_else(|| "—".to_string()),
+                    team.id.clone(),
                 ]
             },
         )
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
+    }
 }
 
 // From trait implementation for converting Cynic types to domain types
@@ -4,7 +4,9 @@ use crate::auth::token::get_token_with_provider;
 use crate::client::users::UserClient;
 use crate::error::CliError;
 use crate::io::Io;
-use crate::output::{OutputFormat, format_output_to_writer, get_format_with_provider};
+use crate::output::{
+    OutputFormat, apply_terminal_layout, format_output_to_writer, get_format_with_provider,
+};
 use crate::users::types::UserList;
 use secrecy::ExposeSecret;
 
@@ -20,7 +22,7 @@ pub fn handle_list(
     let token = get_token_with_provider(config, storage)?;
     let users = client.list_users(token.expose_secret(), limit)?;
     let user_list = UserList(users);
-    let format = get_format_with_provider(format_flag, config);
+    let format = apply_terminal_layout(get_format_with_provider(format_flag, config), io, config);
 
     let mut output = Vec::new();
     format_output_to_writer(&user_list, format, &mut output)?;
@@ -1,9 +1,10 @@
 use crate::client::queries;
 use crate::error::CliError;
 use crate::output::{
-    CsvResultExt, Formattable, MarkdownFormatter, TableFormatter, fast_markdown_formatter,
-    generic_json_formatter, generic_json_list_formatter, generic_table_formatter,
-    generic_table_list_formatter,
+    CsvResultExt, Formattable, MarkdownFormatter, TableColumn, TableFormatter, TableLayout,
+    fast_markdown_formatter, generic_json_formatter, generic_json_list_formatter,
+    generic_jsonl_formatter, generic_jsonl_list_formatter, generic_table_formatter,
+    generic_table_list_formatter, generic_template_formatter, generic_template_list_formatter,
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -85,6 +86,10 @@ impl Formattable for User {
         generic_json_formatter(self)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_formatter(self)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -112,9 +117,15 @@ impl Formattable for User {
     fn to_table(&self) -> Result<String, CliError> {
         generic_table_formatter(self)
     }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_formatter(self, template)
+    }
 }
 
 /// Wrapper for a list of users
+#[derive(Serialize)]
+#[serde(transparent)]
 pub struct UserList(pub Vec<User>);
 
 impl Formattable for UserList {
@@ -122,6 +133,10 @@ impl Formattable for UserList {
         generic_json_list_formatter(&self.0)
     }
 
+    fn to_jsonl(&self) -> Result<String, CliError> {
+        generic_jsonl_list_formatter(&self.0)
+    }
+
     fn to_csv(&self) -> Result<String, CliError> {
         let mut wtr = csv::Writer::from_writer(vec![]);
 
@@ -178,14 +193,36 @@ impl Formattable for UserList {
     }
 
     fn to_table(&self) -> Result<String, CliError> {
-        generic_table_list_formatter(&self.0, &["Name", "Email", "Role", "Active"], |user| {
-            vec![
-                user.name.clone(),
-                user.email.clone(),
-                user.role().to_string(),
-                if user.active { "Yes" } else { "No" }.to_string(),
-            ]
-        })
+        self.to_table_with_layout(&TableLayout::default())
+    }
+
+    fn to_table_with_layout(&self, layout: &TableLayout) -> Result<String, CliError> {
+        generic_table_list_formatter(
+            &self.0,
+            &[
+                TableColumn::new("Name").flexible(),
+                TableColumn::new("Email"),
+                TableColumn::new("Role"),
+                TableColumn::new("Active"),
+                TableColumn::new("Display Name").wide_only(),
+                TableColumn::new("ID").wide_only(),
+            ],
+            layout,
+            |user| {
+                vec![
+                    user.name.clone(),
+                    user.email.clone(),
+                    user.role().to_string(),
+                    if user.active { "Yes" } else { "No" }.to_string(),
+                    user.display_name.clone(),
+                    user.id.clone(),
+                ]
+            },
+        )
+    }
+
+    fn to_template(&self, template: &str) -> Result<String, CliError> {
+        generic_template_list_formatter(&self.0, template)
     }
 }
 
@@ -0,0 +1,520 @@
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use clap::Parser;
+use linear_cli::api::build_variables;
+use linear_cli::api::commands::{handle_api, handle_api_validate};
+use linear_cli::api::schema::Schema;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{ApiCommands, Cli, Commands};
+use linear_cli::client::api::MockApiClient;
+use linear_cli::client::graphql::GraphQLRequest;
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use predicates::prelude::*;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn request(query: &str) -> GraphQLRequest {
+    GraphQLRequest {
+        query: query.to_string(),
+        ..GraphQLRequest::default()
+    }
+}
+
+fn issues_page(identifiers: &[&str], end_cursor: Option<&str>) -> Value {
+    json!({
+        "issues": {
+            "nodes": identifiers.iter().map(|id| json!({ "identifier": id })).collect::<Vec<_>>(),
+            "pageInfo": { "hasNextPage": end_cursor.is_some(), "endCursor": end_cursor },
+        }
+    })
+}
+
+const PAGINATED_QUERY: &str = "query($endCursor: String) {
+  issues(first: 2, after: $endCursor) {
+    nodes { identifier }
+    pageInfo { hasNextPage endCursor }
+  }
+}";
+
+#[test]
+fn test_build_variables_types_fields_like_gh() {
+    let variables = build_variables(
+        &[
+            "first=50".to_string(),
+            "includeArchived=true".to_string(),
+            "estimate=1.5".to_string(),
+            "parentId=null".to_string(),
+            "input[title]=Fix login".to_string(),
+            "input[labelIds][]=a".to_string(),
+            "input[labelIds][]=b".to_string(),
+        ],
+        &["teamId=123".to_string(), "input[priority]=2".to_string()],
+    )
+    .unwrap();
+
+    assert_eq!(
+        Value::Object(variables),
+        json!({
+            "first": 50,
+            "includeArchived": true,
+            "estimate": 1.5,
+            "parentId": null,
+            "teamId": "123",
+            "input": { "title": "Fix login", "labelIds": ["a", "b"], "priority": "2" },
+        })
+    );
+}
+
+#[test]
+fn test_build_variables_reads_files_and_rejects_bad_fields() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("body.md");
+    std::fs::write(&path, "# Notes\n").unwrap();
+
+    let variables = build_variables(&[format!("body=@{}", path.display())], &[]).unwrap();
+    assert_eq!(variables["body"], json!("# Notes\n"));
+
+    for field in ["novalue", "=x", "a[b=1", "a[][b]=1"] {
+        let err = build_variables(&[field.to_string()], &[]).unwrap_err();
+        assert!(matches!(err, CliError::InvalidArgs(_)), "{field}");
+    }
+    let err = build_variables(&["a=1".to_string(), "a[b]=2".to_string()], &[]).unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("conflicts")));
+}
+
+#[test]
+fn test_handle_api_sends_request_and_prints_data() {
+    let client = MockApiClient {
+        responses: vec![Ok(json!({ "viewer": { "name": "Alice" } }))],
+        ..MockApiClient::default()
+    };
+    let io = MockIo::new();
+    let mut sent = request("query Me { viewer { name } }");
+    sent.variables = build_variables(&["first=1".to_string()], &[]).unwrap();
+    sent.operation_name = Some("Me".to_string());
+
+    handle_api(
+        sent.clone(),
+        false,
+        false,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+    )
+    .unwrap();
+
+    let data: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(data, json!({ "viewer": { "name": "Alice" } }));
+    assert_eq!(*client.requests.lock().unwrap(), vec![sent]);
+}
+
+#[test]
+fn test_handle_api_keeps_response_key_order() {
+    let response: Value =
+        serde_json::from_str(r#"{"viewer":{"name":"Alice","id":"user-1"}}"#).unwrap();
+    let client = MockApiClient {
+        responses: vec![Ok(response)],
+        ..MockApiClient::default()
+    };
+    let io = MockIo::new();
+
+    handle_api(
+        request("{ viewer { name id } }"),
+        false,
+        false,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+    )
+    .unwrap();
+
+    let output = io.stdout_lines().concat();
+    assert!(output.find("\"name\"").unwrap() < output.find("\"id\"").unwrap());
+}
+
+#[test]
+fn test_request_serializes_like_a_graphql_post_body() {
+    let mut sent = request("query Me { viewer { name } }");
+    assert_eq!(
+        serde_json::to_value(&sent).unwrap(),
+        json!({ "query": "query Me { viewer { name } }" })
+    );
+
+    sent.variables = build_variables(&["first=1".to_string()], &[]).unwrap();
+    sent.operation_name = Some("Me".to_string());
+    assert_eq!(
+        serde_json::to_value(&sent).unwrap(),
+        json!({
+            "query": "query Me { viewer { name } }",
+            "variables": { "first": 1 },
+            "operationName": "Me",
+        })
+    );
+}
+
+#[test]
+fn test_handle_api_paginates_and_merges_nodes() {
+    let client = MockApiClient {
+        responses: vec![
+            Ok(issues_page(&["ENG-1", "ENG-2"], Some("c1"))),
+            Ok(issues_page(&["ENG-3"], None)),
+        ],
+        ..MockApiClient::default()
+    };
+    let io = MockIo::new();
+
+    handle_api(
+        request(PAGINATED_QUERY),
+        true,
+        true,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+    )
+    .unwrap();
+
+    let data: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(
+        data,
+        json!({
+            "issues": {
+                "nodes": [
+                    { "identifier": "ENG-1" },
+                    { "identifier": "ENG-2" },
+                    { "identifier": "ENG-3" },
+                ],
+                "pageInfo": { "hasNextPage": false, "endCursor": null },
+            }
+        })
+    );
+
+    let requests = client.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].variables.get("endCursor").is_none());
+    assert_eq!(requests[1].variables["endCursor"], json!("c1"));
+}
+
+#[test]
+fn test_handle_api_paginate_requires_end_cursor_variable() {
+    let client = MockApiClient::default();
+
+    let err = handle_api(
+        request("{ issues { nodes { id } pageInfo { hasNextPage endCursor } } }"),
+        true,
+        false,
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("$endCursor")));
+    assert!(client.requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_handle_api_validation_fails_before_sending() {
+    let client = MockApiClient::default();
+
+    let err = handle_api(
+        request("query {\n  viewer {\n    nmae\n  }\n}"),
+        false,
+        true,
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+    )
+    .unwrap_err();
+
+    assert!(
+        matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("3:5: Cannot query field `nmae` on type `User`")),
+        "{err}"
+    );
+    assert!(client.requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_handle_api_surfaces_client_errors() {
+    let client = MockApiClient {
+        responses: vec![Err(CliError::General("GraphQL error: boom".to_string()))],
+        ..MockApiClient::default()
+    };
+
+    let err = handle_api(
+        request("{ viewer { id } }"),
+        false,
+        false,
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::General(ref msg) if msg.contains("boom")));
+}
+
+#[test]
+fn test_schema_reports_unknown_fields_arguments_and_selections() {
+    let query = r#"
+query {
+  issue(id: "ENG-1", bogus: 1) {
+    title
+    state
+    identifier { id }
+    ... on Issue { nope }
+  }
+}
+fragment F on NotAType { id }
+"#;
+
+    let messages: Vec<String> = Schema::linear()
+        .validate(query)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "3:22: Unknown argument `bogus` on field `Query.issue`",
+            "5:5: Field `Issue.state` of type `WorkflowState` must have a selection of subfields",
+            "6:16: Field `Issue.identifier` of type `String` cannot have a selection of subfields",
+            "7:20: Cannot query field `nope` on type `Issue`",
+            "10:10: Fragment `F` is never used",
+            "10:15: Unknown type `NotAType`",
+        ]
+    );
+}
+
+#[test]
+fn test_schema_accepts_valid_documents_and_reports_syntax_errors() {
+    let schema = Schema::linear();
+    assert!(
+        schema
+            .validate("mutation { issueCreate(input: { title: \"x\", teamId: \"t\" }) { success issue { id __typename } } }")
+            .is_empty()
+    );
+
+    let diagnostics = schema.validate("query { viewer { id }");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 1);
+    assert!(diagnostics[0].message.starts_with("Syntax error"));
+}
+
+fn messages(query: &str) -> Vec<String> {
+    Schema::linear()
+        .validate(query)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn test_schema_checks_variable_definitions_and_usages() {
+    let query = r#"
+query Issues($team: String, $first: Int!, $unused: Boolean, $filter: IssueFilter, $user: User) {
+  issue(id: $team) { id }
+  issues(first: $first, filter: $filter, after: $cursor) { nodes { id } }
+}
+"#;
+
+    assert_eq!(
+        messages(query),
+        vec![
+            "2:43: Variable `$unused` is never used",
+            "2:83: Variable `$user` is never used",
+            "2:90: Variable `$user` cannot be of non-input type `User`",
+            "3:13: Variable `$team` of type `String` cannot be used where `String!` is expected",
+            "4:49: Variable `$cursor` is not defined",
+        ]
+    );
+
+    // A default value makes a nullable variable acceptable for a required argument
+    assert!(messages("query($id: String = \"ENG-1\") { issue(id: $id) { id } }").is_empty());
+    // Variables used only inside fragments count as used
+    assert!(
+        messages(
+            "query($id: String!) { ...Lookup }\nfragment Lookup on Query { issue(id: $id) { id } }"
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn test_schema_checks_arguments_and_input_values() {
+    let query = r#"
+{
+  issue { id }
+  issues(first: "ten", orderBy: sideways, filter: { nope: 1 }) { nodes { id } }
+}
+mutation {
+  issueCreate(input: { title: null }) { success }
+}
+"#;
+
+    assert_eq!(
+        messages(query),
+        vec![
+            "2:1: Anonymous operations must be the only operation in the document",
+            "3:3: Field `Query.issue` requires argument `id` of type `String!`",
+            "4:17: Expected a value of type `Int`, found a string",
+            "4:33: Value `sideways` does not exist in enum `PaginationOrderBy`",
+            "4:53: Unknown field `nope` on input type `IssueFilter`",
+            "6:1: Anonymous operations must be the only operation in the document",
+            "7:22: Input `IssueCreateInput` requires field `teamId` of type `String!`",
+        ]
+    );
+}
+
+#[test]
+fn test_schema_checks_fragments_and_directives() {
+    let query = r#"
+query Q($skip: Boolean!) {
+  viewer @skip(if: $skip) { ...Missing ...OnIssue }
+  issues @cached { nodes { ...A } }
+}
+fragment OnIssue on Issue { id }
+fragment A on Issue { ...B }
+fragment B on Issue { ...A }
+"#;
+
+    assert_eq!(
+        messages(query),
+        vec![
+            "3:32: Unknown fragment `Missing`",
+            "3:43: Fragment `OnIssue` cannot be spread here: `User` can never be of type `Issue`",
+            "4:10: Unknown directive `@cached`",
+            "8:26: Cannot spread fragment `A` within itself",
+        ]
+    );
+}
+
+#[test]
+fn test_handle_api_validate_reports_file_positions() {
+    let dir = tempfile::tempdir().unwrap();
+    let good = dir.path().join("good.graphql");
+    let bad = dir.path().join("bad.graphql");
+    std::fs::write(&good, "query Me { viewer { id name } }").unwrap();
+    std::fs::write(&bad, "{\n  viewer { nope }\n}").unwrap();
+    let good = good.display().to_string();
+    let bad = bad.display().to_string();
+    let io = MockIo::new();
+
+    let err = handle_api_validate(&[good.clone(), bad.clone()], &io).unwrap_err();
+
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg == "1 problem found"));
+    assert_eq!(io.stdout_lines(), vec![format!("{good}: valid")]);
+    assert_eq!(
+        io.stderr_lines(),
+        vec![format!(
+            "{bad}:2:12: Cannot query field `nope` on type `User`"
+        )]
+    );
+
+    let io = MockIo::new();
+    handle_api_validate(std::slice::from_ref(&good), &io).unwrap();
+    assert_eq!(io.stdout_lines(), vec![format!("{good}: valid")]);
+}
+
+#[test]
+fn test_api_validate_subcommand_parses_and_runs_offline() {
+    let cli = Cli::parse_from(["linear", "api", "validate", "a.graphql", "b.graphql"]);
+    let Commands::Api {
+        action: Some(ApiCommands::Validate { files }),
+        ..
+    } = cli.command
+    else {
+        panic!("expected api validate command");
+    };
+    assert_eq!(files, vec!["a.graphql", "b.graphql"]);
+    assert!(Cli::try_parse_from(["linear", "api", "validate"]).is_err());
+
+    Command::cargo_bin("linear-cli")
+        .unwrap()
+        .env_remove("LINEAR_TOKEN")
+        .args(["--offline", "api", "validate", "-"])
+        .write_stdin("query { viewer { id } issue { id } }")
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains(
+            "<stdin>:1:23: Field `Query.issue` requires argument `id` of type `String!`",
+        ));
+}
+
+#[test]
+fn test_api_cli_parses_gh_style_fields() {
+    let cli = Cli::parse_from([
+        "linear",
+        "api",
+        "--query-file",
+        "q.graphql",
+        "-F",
+        "first=10",
+        "-f",
+        "teamId=abc",
+        "--paginate",
+    ]);
+    let Commands::Api {
+        query_file,
+        fields,
+        raw_fields,
+        paginate,
+        ..
+    } = cli.command
+    else {
+        panic!("expected api command");
+    };
+    assert_eq!(query_file.as_deref(), Some("q.graphql"));
+    assert_eq!(fields, vec!["first=10"]);
+    assert_eq!(raw_fields, vec!["teamId=abc"]);
+    assert!(paginate);
+
+    assert!(Cli::try_parse_from(["linear", "api"]).is_err());
+}
+
+#[test]
+fn test_api_validate_flag_runs_without_network() {
+    Command::cargo_bin("linear-cli")
+        .unwrap()
+        .env("LINEAR_TOKEN", "token")
+        .args(["api", "--validate", "--query", "{ viewer { nope } }"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("Cannot query field `nope`"));
+}
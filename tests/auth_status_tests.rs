@@ -3,7 +3,7 @@ use linear_cli::auth::config::TestConfigProvider;
 use linear_cli::auth::storage::TokenStorage;
 use linear_cli::client::auth::{AuthClient, MockAuthClient, UserInfo};
 use linear_cli::error::CliError;
-use linear_cli::output::OutputFormat;
+use linear_cli::output::{OutputFormat, TableLayout};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
@@ -165,7 +165,7 @@ fn test_status_shows_token_source_env() {
         &storage,
         &api_client,
         &io,
-        Some(OutputFormat::Table),
+        Some(OutputFormat::Table(TableLayout::default())),
     );
 
     assert!(result.is_ok());
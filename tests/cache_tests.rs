@@ -0,0 +1,126 @@
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use clap::Parser;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::cache::commands::{handle_clear, handle_stats};
+use linear_cli::cache::{CacheMode, CacheResource, CacheTtls, ResponseCache, cache_dir};
+use linear_cli::cli::{CacheCommands, Cli, Commands};
+use linear_cli::io::MockIo;
+use linear_cli::output::OutputFormat;
+use predicates::prelude::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+fn config(cache_home: &Path) -> TestConfigProvider {
+    let mut values = HashMap::new();
+    values.insert(
+        "XDG_CACHE_HOME".to_string(),
+        cache_home.display().to_string(),
+    );
+    TestConfigProvider { values }
+}
+
+fn populate(config: &TestConfigProvider) {
+    let cache = ResponseCache::from_config(config, CacheMode::Use)
+        .unwrap()
+        .expect("cache dir should resolve");
+    cache.put("token", CacheResource::Teams, b"teams-1", r#"{"data":{}}"#);
+    cache.put("token", CacheResource::Teams, b"teams-2", r#"{"data":{}}"#);
+    cache.put("token", CacheResource::Labels, b"labels", r#"{"data":{}}"#);
+}
+
+#[test]
+fn test_cache_stats_reports_entries_per_resource() {
+    let home = tempfile::tempdir().unwrap();
+    let config = config(home.path());
+    populate(&config);
+
+    let io = MockIo::new();
+    handle_stats(&config, &io, Some(OutputFormat::Json)).unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(json["entries"], 3);
+    assert_eq!(json["expired"], 0);
+    assert_eq!(
+        json["directory"],
+        home.path().join("linear-cli").display().to_string()
+    );
+
+    let teams = json["resources"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["resource"] == "teams")
+        .expect("teams resource");
+    assert_eq!(teams["entries"], 2);
+    assert_eq!(teams["ttl_seconds"], 3600);
+}
+
+#[test]
+fn test_cache_clear_removes_all_entries() {
+    let home = tempfile::tempdir().unwrap();
+    let config = config(home.path());
+    populate(&config);
+
+    let io = MockIo::new();
+    handle_clear(&config, &io, Some(OutputFormat::Json)).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(json["removed"], 3);
+
+    let ttls = CacheTtls::default();
+    let stats = linear_cli::cache::stats(&cache_dir(&config).unwrap(), &ttls).unwrap();
+    assert_eq!(stats.entries, 0);
+}
+
+#[test]
+fn test_cache_clear_on_missing_directory_removes_nothing() {
+    let home = tempfile::tempdir().unwrap();
+    let io = MockIo::new();
+
+    handle_clear(&config(home.path()), &io, Some(OutputFormat::Json)).unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(json["removed"], 0);
+}
+
+#[test]
+fn test_cache_flags_are_global_and_map_to_cache_mode() {
+    let cli = Cli::parse_from(["linear", "team", "list", "--no-cache"]);
+    assert_eq!(cli.cache_mode(), CacheMode::Bypass);
+
+    let cli = Cli::parse_from(["linear", "--refresh", "label", "list"]);
+    assert_eq!(cli.cache_mode(), CacheMode::Refresh);
+
+    let cli = Cli::parse_from(["linear", "cache", "stats", "--json"]);
+    assert_eq!(cli.cache_mode(), CacheMode::Use);
+    assert!(matches!(
+        cli.command,
+        Commands::Cache {
+            action: CacheCommands::Stats { .. }
+        }
+    ));
+}
+
+#[test]
+fn test_no_cache_conflicts_with_refresh() {
+    Command::cargo_bin("linear-cli")
+        .unwrap()
+        .args(["team", "list", "--no-cache", "--refresh"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_cache_stats_command_uses_xdg_cache_home() {
+    let home = tempfile::tempdir().unwrap();
+
+    Command::cargo_bin("linear-cli")
+        .unwrap()
+        .env("XDG_CACHE_HOME", home.path())
+        .args(["cache", "stats", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"entries\""));
+}
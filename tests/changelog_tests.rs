@@ -0,0 +1,360 @@
+use clap::Parser;
+use cynic::QueryBuilder;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::changelog::commands::{BATCH_SIZE, ChangelogOptions, handle_changelog};
+use linear_cli::changelog::{Changelog, ChangelogGrouping, ChangelogIssue};
+use linear_cli::cli::{Cli, Commands};
+use linear_cli::client::changelog::{MockChangelogClient, changelog_filter};
+use linear_cli::client::queries::{ChangelogIssuesQuery, ChangelogIssuesQueryVariables};
+use linear_cli::client::teams::MockTeamClient;
+use linear_cli::error::CliError;
+use linear_cli::git::GitRepo;
+use linear_cli::io::MockIo;
+use linear_cli::output::{Formattable, OutputFormat};
+use linear_cli::teams::types::Team;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn issue(identifier: &str, labels: &[&str], project: Option<&str>) -> ChangelogIssue {
+    ChangelogIssue {
+        identifier: identifier.to_string(),
+        title: format!("Issue {identifier}"),
+        url: format!("https://linear.app/acme/issue/{identifier}"),
+        project: project.map(str::to_string),
+        labels: labels.iter().map(|label| label.to_string()).collect(),
+    }
+}
+
+fn team(key: &str) -> Team {
+    Team {
+        id: format!("team-{key}"),
+        key: key.to_string(),
+        name: key.to_string(),
+        description: None,
+        color: None,
+        icon: None,
+        private: false,
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+    }
+}
+
+fn teams() -> MockTeamClient {
+    MockTeamClient {
+        result: Err(CliError::General("not used".to_string())),
+        list_result: Ok(vec![team("ENG"), team("OPS")]),
+    }
+}
+
+fn client() -> MockChangelogClient {
+    MockChangelogClient::new(vec![
+        issue("ENG-1", &["Feature"], Some("Auth")),
+        issue("ENG-12", &["Bug"], Some("Auth")),
+        issue("ENG-40", &["New feature", "Backend"], None),
+        issue("OPS-3", &["chore"], Some("Infra")),
+        issue("OPS-8", &["Docs"], None),
+    ])
+}
+
+fn options(since: &str) -> ChangelogOptions {
+    ChangelogOptions {
+        since: since.to_string(),
+        until: "HEAD".to_string(),
+        ..Default::default()
+    }
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+fn commit(dir: &Path, message: &str) {
+    git(dir, &["commit", "--quiet", "--allow-empty", "-m", message]);
+}
+
+/// A repository tagged `v1.2.0` after its first commit, with release commits on top
+fn repo() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    git(dir.path(), &["init", "--quiet", "--initial-branch=main"]);
+    commit(dir.path(), "Initial import (ENG-1)");
+    git(dir.path(), &["tag", "v1.2.0"]);
+    commit(dir.path(), "Fix login redirect\n\nFixes ENG-12, refs ops-3");
+    commit(dir.path(), "Bump UTF-8 decoder");
+    commit(dir.path(), "[ENG-40] Add audit log (OPS-8)");
+    commit(dir.path(), "Remove ENG-404 leftovers");
+    dir
+}
+
+fn run(
+    options: &ChangelogOptions,
+    client: &MockChangelogClient,
+    format: Option<OutputFormat>,
+) -> MockIo {
+    let dir = repo();
+    let io = MockIo::new();
+    handle_changelog(
+        options,
+        &GitRepo::at(dir.path()),
+        client,
+        &teams(),
+        &config(),
+        &TestStorage,
+        &io,
+        format,
+    )
+    .unwrap();
+    io
+}
+
+#[test]
+fn test_changelog_renders_markdown_by_label() {
+    let client = client();
+    let io = run(&options("v1.2.0"), &client, None);
+
+    assert_eq!(
+        io.stdout_lines().join("\n"),
+        "# Release notes (v1.2.0..HEAD)\n\
+         \n\
+         ## Features\n\
+         \n\
+         - Issue ENG-40 ([ENG-40](https://linear.app/acme/issue/ENG-40))\n\
+         \n\
+         ## Bug fixes\n\
+         \n\
+         - Issue ENG-12 ([ENG-12](https://linear.app/acme/issue/ENG-12))\n\
+         \n\
+         ## Chores\n\
+         \n\
+         - Issue OPS-3 ([OPS-3](https://linear.app/acme/issue/OPS-3))\n\
+         \n\
+         ## Other changes\n\
+         \n\
+         - Issue OPS-8 ([OPS-8](https://linear.app/acme/issue/OPS-8))\n"
+    );
+    assert_eq!(io.stderr_lines(), vec!["Skipping ENG-404: issue not found"]);
+    // One batch, newest mention first; UTF-8 is not a team key
+    assert_eq!(
+        *client.batches.lock().unwrap(),
+        vec![vec!["ENG-404", "ENG-40", "OPS-8", "ENG-12", "OPS-3"]]
+    );
+}
+
+#[test]
+fn test_changelog_json_groups_by_project() {
+    let options = ChangelogOptions {
+        group_by: ChangelogGrouping::Project,
+        ..options("v1.2.0")
+    };
+    let io = run(&options, &client(), Some(OutputFormat::Json));
+
+    let json: Value = serde_json::from_str(&io.stdout_lines().join("\n")).unwrap();
+    assert_eq!(json["range"], "v1.2.0..HEAD");
+    assert_eq!(json["missing"], json!(["ENG-404"]));
+    let sections: Vec<(&str, Vec<&str>)> = json["sections"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|section| {
+            (
+                section["title"].as_str().unwrap(),
+                section["issues"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|issue| issue["identifier"].as_str().unwrap())
+                    .collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        sections,
+        vec![
+            ("Auth", vec!["ENG-12"]),
+            ("Infra", vec!["OPS-3"]),
+            ("No project", vec!["ENG-40", "OPS-8"]),
+        ]
+    );
+    assert_eq!(
+        json["sections"][0]["issues"][0]["url"],
+        "https://linear.app/acme/issue/ENG-12"
+    );
+}
+
+#[test]
+fn test_changelog_fetches_in_batches() {
+    let dir = TempDir::new().unwrap();
+    git(dir.path(), &["init", "--quiet", "--initial-branch=main"]);
+    commit(dir.path(), "Initial import");
+    git(dir.path(), &["tag", "v1"]);
+    for number in 1..=BATCH_SIZE + 2 {
+        commit(dir.path(), &format!("Change ENG-{number}"));
+    }
+    let client = MockChangelogClient::new(vec![issue("ENG-1", &[], None)]);
+    let io = MockIo::new();
+
+    handle_changelog(
+        &ChangelogOptions {
+            teams: vec!["eng".to_string()],
+            ..options("v1")
+        },
+        &GitRepo::at(dir.path()),
+        &client,
+        &teams(),
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Csv),
+    )
+    .unwrap();
+
+    let batches = client.batches.lock().unwrap();
+    let sizes: Vec<usize> = batches.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![BATCH_SIZE, 2]);
+    assert_eq!(batches[1], vec!["ENG-2", "ENG-1"]);
+    assert_eq!(
+        io.stdout_lines().join("\n"),
+        "section,identifier,title,project,labels,url\n\
+         Other changes,ENG-1,Issue ENG-1,,,https://linear.app/acme/issue/ENG-1\n"
+    );
+    assert_eq!(io.stderr_lines().len(), BATCH_SIZE + 1);
+}
+
+#[test]
+fn test_changelog_matches_whole_label_words() {
+    let changelog = Changelog::new(
+        "v1..v2",
+        vec![
+            issue("ENG-1", &["Debug tooling"], None),
+            issue("ENG-2", &["Prefix"], None),
+            issue("ENG-3", &["Suffix"], None),
+            issue("ENG-4", &["type: bugs"], None),
+            issue("ENG-5", &["Quick-fix"], None),
+            issue("ENG-6", &["Features"], None),
+        ],
+        Vec::new(),
+        ChangelogGrouping::Label,
+    );
+
+    let sections: Vec<(&str, Vec<&str>)> = changelog
+        .sections
+        .iter()
+        .map(|section| {
+            (
+                section.title.as_str(),
+                section
+                    .issues
+                    .iter()
+                    .map(|issue| issue.identifier.as_str())
+                    .collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        sections,
+        vec![
+            ("Features", vec!["ENG-6"]),
+            ("Bug fixes", vec!["ENG-4", "ENG-5"]),
+            ("Other changes", vec!["ENG-1", "ENG-2", "ENG-3"]),
+        ]
+    );
+}
+
+#[test]
+fn test_changelog_without_issues() {
+    let changelog = Changelog::new("v1..v2", Vec::new(), Vec::new(), ChangelogGrouping::Label);
+    assert_eq!(
+        changelog.to_markdown().unwrap(),
+        "# Release notes (v1..v2)\n\nNo issues referenced.\n"
+    );
+    assert!(changelog.to_jsonl().unwrap().is_empty());
+}
+
+#[test]
+fn test_changelog_filter_groups_numbers_by_team() {
+    let identifiers = ["OPS-3", "ENG-40", "ENG-12", "not-an-id"].map(str::to_string);
+    let operation = ChangelogIssuesQuery::build(ChangelogIssuesQueryVariables {
+        first: Some(4),
+        filter: Some(changelog_filter(&identifiers)),
+    });
+    // Linear archives completed issues; release notes still need them
+    assert!(operation.query.contains("includeArchived: true"));
+    let json = serde_json::to_value(&operation).unwrap();
+    assert_eq!(
+        json["variables"]["filter"],
+        json!({
+            "or": [
+                {"team": {"key": {"eq": "ENG"}}, "number": {"in": [40.0, 12.0]}},
+                {"team": {"key": {"eq": "OPS"}}, "number": {"in": [3.0]}},
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_changelog_command_parses() {
+    let cli = Cli::parse_from([
+        "linear",
+        "changelog",
+        "--since",
+        "v1.2.0",
+        "--group-by",
+        "project",
+        "--team",
+        "ENG",
+        "--json",
+    ]);
+    let Commands::Changelog {
+        since,
+        until,
+        group_by,
+        teams,
+        ..
+    } = cli.command
+    else {
+        panic!("expected changelog command");
+    };
+    assert_eq!(since, "v1.2.0");
+    assert_eq!(until, "HEAD");
+    assert_eq!(group_by, ChangelogGrouping::Project);
+    assert_eq!(teams, vec!["ENG"]);
+
+    assert!(Cli::try_parse_from(["linear", "changelog"]).is_err());
+}
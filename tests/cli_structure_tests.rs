@@ -77,7 +77,7 @@ fn test_no_subcommand_shows_help() {
         .arg("auth")
         .assert()
         .failure()
-        .stderr(predicate::str::contains("auth <COMMAND>"));
+        .stderr(predicate::str::contains("auth [OPTIONS] <COMMAND>"));
 }
 
 #[test]
@@ -72,6 +72,10 @@ fn dummy_comment() -> Comment {
         updated_at: "2025-01-01T00:00:00Z".to_string(),
         edited_at: None,
         issue_identifier: None,
+        resolved_at: None,
+        parent_id: None,
+        replies: Vec::new(),
+        reactions: Vec::new(),
     }
 }
 
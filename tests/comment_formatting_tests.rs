@@ -11,6 +11,10 @@ fn create_test_comment() -> Comment {
         updated_at: "2024-01-15T10:30:00Z".to_string(),
         edited_at: None,
         issue_identifier: Some("ENG-123".to_string()),
+        resolved_at: None,
+        parent_id: None,
+        replies: Vec::new(),
+        reactions: Vec::new(),
     }
 }
 
@@ -16,6 +16,10 @@ fn create_test_comments(count: usize) -> Vec<Comment> {
                 None
             },
             issue_identifier: Some(format!("ENG-{}", i + 100)),
+            resolved_at: None,
+            parent_id: None,
+            replies: Vec::new(),
+            reactions: Vec::new(),
         })
         .collect()
 }
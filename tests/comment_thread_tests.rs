@@ -0,0 +1,341 @@
+use clap::Parser;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands, IssueCommentCommands};
+use linear_cli::client::comments::{CommentClient, CreateCommentInput};
+use linear_cli::comments::commands::handle_list;
+use linear_cli::comments::types::{Comment, CommentList, count_comments, thread_comments};
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::{
+    handle_comment_edit, handle_comment_reply, handle_comment_resolve,
+};
+use linear_cli::output::{Formattable, OutputFormat, TableLayout};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn comment(id: &str, user: &str, body: &str, parent_id: Option<&str>) -> Comment {
+    Comment {
+        id: id.to_string(),
+        body: body.to_string(),
+        user_name: user.to_string(),
+        user_email: format!("{}@example.com", user.to_lowercase()),
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-01T00:00:00Z".to_string(),
+        edited_at: None,
+        resolved_at: None,
+        issue_identifier: Some("ENG-1".to_string()),
+        parent_id: parent_id.map(str::to_string),
+        replies: Vec::new(),
+        reactions: Vec::new(),
+    }
+}
+
+/// Flat list as returned by the API: a thread with one reply, then a standalone comment
+fn flat_comments() -> Vec<Comment> {
+    vec![
+        comment("c1", "Alice", "Should we ship?", None),
+        comment("c2", "Carol", "Separate topic", None),
+        comment("c3", "Bob", "Yes, after QA", Some("c1")),
+    ]
+}
+
+/// Records every mutation the handlers issue
+#[derive(Default)]
+struct RecordingCommentClient {
+    creates: Mutex<Vec<CreateCommentInput>>,
+    updates: Mutex<Vec<(String, String)>>,
+    resolves: Mutex<Vec<(String, bool)>>,
+    list: Vec<Comment>,
+}
+
+impl CommentClient for RecordingCommentClient {
+    fn list_comments(
+        &self,
+        _token: &str,
+        _issue_id: &str,
+        _limit: usize,
+    ) -> Result<Vec<Comment>, CliError> {
+        Ok(self.list.clone())
+    }
+
+    fn create_comment(&self, _token: &str, input: CreateCommentInput) -> Result<Comment, CliError> {
+        let created = comment("c9", "Bob", &input.body, input.parent_id.as_deref());
+        self.creates.lock().unwrap().push(input);
+        Ok(created)
+    }
+
+    fn update_comment(&self, _token: &str, id: &str, body: &str) -> Result<Comment, CliError> {
+        self.updates
+            .lock()
+            .unwrap()
+            .push((id.to_string(), body.to_string()));
+        Ok(Comment {
+            edited_at: Some("2026-03-02T00:00:00Z".to_string()),
+            ..comment(id, "Alice", body, None)
+        })
+    }
+
+    fn set_comment_resolved(
+        &self,
+        _token: &str,
+        id: &str,
+        resolved: bool,
+    ) -> Result<Comment, CliError> {
+        self.resolves
+            .lock()
+            .unwrap()
+            .push((id.to_string(), resolved));
+        Ok(Comment {
+            resolved_at: resolved.then(|| "2026-03-03T00:00:00Z".to_string()),
+            ..comment(id, "Alice", "Should we ship?", None)
+        })
+    }
+}
+
+#[test]
+fn test_thread_comments_nests_replies_under_parent() {
+    let threads = thread_comments(flat_comments());
+
+    let ids: Vec<&str> = threads.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["c1", "c2"]);
+    assert_eq!(threads[0].replies.len(), 1);
+    assert_eq!(threads[0].replies[0].id, "c3");
+    assert_eq!(count_comments(&threads), 3);
+}
+
+#[test]
+fn test_thread_comments_keeps_orphaned_replies() {
+    let threads = thread_comments(vec![comment("c3", "Bob", "Yes", Some("missing"))]);
+
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].id, "c3");
+}
+
+#[test]
+fn test_comment_list_json_nests_replies() {
+    let json = CommentList(thread_comments(flat_comments()))
+        .to_json()
+        .unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(value.as_array().unwrap().len(), 2);
+    assert_eq!(value[0]["replies"][0]["id"], "c3");
+    assert_eq!(value[0]["replies"][0]["parent_id"], "c1");
+    // Comments without replies omit the field
+    assert!(value[1].get("replies").is_none());
+}
+
+#[test]
+fn test_comment_list_markdown_quotes_replies() {
+    let markdown = CommentList(thread_comments(flat_comments()))
+        .to_markdown()
+        .unwrap();
+
+    assert!(markdown.starts_with("## Comments (3)\n"));
+    assert!(markdown.contains(
+        "### Alice\n**Created:** 2026-03-01T00:00:00Z\n\nShould we ship?\n\n\
+         > ### Bob\n> **Created:** 2026-03-01T00:00:00Z\n>\n> Yes, after QA\n\n---\n\n### Carol"
+    ));
+}
+
+#[test]
+fn test_comment_list_table_indents_replies() {
+    let table = CommentList(thread_comments(flat_comments()))
+        .to_table_with_layout(&TableLayout::default())
+        .unwrap();
+
+    let lines: Vec<&str> = table.lines().collect();
+    let alice = lines
+        .iter()
+        .position(|l| l.contains("Should we ship?"))
+        .unwrap();
+    assert!(lines[alice + 1].contains("↳ Yes, after QA"));
+}
+
+#[test]
+fn test_comment_list_csv_includes_thread_columns() {
+    let csv = CommentList(thread_comments(flat_comments()))
+        .to_csv()
+        .unwrap();
+
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "id,user_name,body_preview,created_at,edited_at,parent_id,resolved_at,reactions"
+    );
+    assert!(lines[2].starts_with("c3,Bob,"));
+    assert!(lines[2].ends_with(",c1,,"));
+}
+
+#[test]
+fn test_comments_command_threads_replies() {
+    let client = RecordingCommentClient {
+        list: flat_comments(),
+        ..Default::default()
+    };
+    let io = MockIo::new();
+
+    handle_list(
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        "ENG-1",
+        50,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(value[0]["replies"][0]["body"], "Yes, after QA");
+}
+
+#[test]
+fn test_comment_reply_sets_parent() {
+    let client = RecordingCommentClient::default();
+    let io = MockIo::new();
+
+    handle_comment_reply(
+        "c1",
+        "Agreed",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let creates = client.creates.lock().unwrap();
+    assert_eq!(creates.len(), 1);
+    assert_eq!(creates[0].parent_id.as_deref(), Some("c1"));
+    assert_eq!(creates[0].body, "Agreed");
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(value["parent_id"], "c1");
+}
+
+#[test]
+fn test_comment_edit_updates_body() {
+    let client = RecordingCommentClient::default();
+    let io = MockIo::new();
+
+    handle_comment_edit(
+        "c1",
+        "Ship it",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *client.updates.lock().unwrap(),
+        vec![("c1".to_string(), "Ship it".to_string())]
+    );
+    assert!(
+        io.stdout_lines()
+            .concat()
+            .contains("**Edited:** 2026-03-02T00:00:00Z")
+    );
+}
+
+#[test]
+fn test_comment_resolve_and_unresolve() {
+    let client = RecordingCommentClient::default();
+    let io = MockIo::new();
+
+    for resolved in [true, false] {
+        handle_comment_resolve(
+            "c1",
+            resolved,
+            &client,
+            &config(),
+            &TestStorage,
+            &io,
+            Some(OutputFormat::Json),
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        *client.resolves.lock().unwrap(),
+        vec![("c1".to_string(), true), ("c1".to_string(), false)]
+    );
+    let lines = io.stdout_lines();
+    let resolved: Value = serde_json::from_str(&lines[0]).unwrap();
+    assert_eq!(resolved["resolved_at"], "2026-03-03T00:00:00Z");
+}
+
+#[test]
+fn test_comment_edit_requires_token() {
+    let client = RecordingCommentClient::default();
+
+    let err = handle_comment_edit(
+        "c1",
+        "Ship it",
+        &client,
+        &TestConfigProvider {
+            values: HashMap::new(),
+        },
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::AuthError(_)));
+    assert!(client.updates.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_comment_thread_cli_commands_parse() {
+    let cli = Cli::parse_from([
+        "linear", "issue", "comment", "reply", "c1", "--body", "Agreed",
+    ]);
+    let Commands::Issue {
+        action:
+            IssueCommands::Comment {
+                action: IssueCommentCommands::Reply { id, body, .. },
+            },
+    } = cli.command
+    else {
+        panic!("expected issue comment reply command");
+    };
+    assert_eq!((id.as_str(), body.as_str()), ("c1", "Agreed"));
+
+    for action in ["resolve", "unresolve"] {
+        assert!(Cli::try_parse_from(["linear", "issue", "comment", action, "c1"]).is_ok());
+    }
+    assert!(Cli::try_parse_from(["linear", "issue", "comment", "edit", "c1"]).is_err());
+}
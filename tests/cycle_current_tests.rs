@@ -5,7 +5,7 @@ use linear_cli::cycles::commands::handle_current;
 use linear_cli::cycles::types::Cycle;
 use linear_cli::error::CliError;
 use linear_cli::io::MockIo;
-use linear_cli::output::OutputFormat;
+use linear_cli::output::{OutputFormat, TableLayout};
 use std::collections::HashMap;
 
 /// Mock client for cycle current tests
@@ -211,7 +211,7 @@ fn test_current_markdown_format() {
         &config,
         &storage,
         &io,
-        Some(OutputFormat::Markdown),
+        Some(OutputFormat::Markdown(Default::default())),
     );
 
     // Assert: Success and valid markdown
@@ -239,7 +239,13 @@ fn test_current_table_format() {
     let io = MockIo::new();
 
     // Execute with Table format
-    let result = handle_current(&client, &config, &storage, &io, Some(OutputFormat::Table));
+    let result = handle_current(
+        &client,
+        &config,
+        &storage,
+        &io,
+        Some(OutputFormat::Table(TableLayout::default())),
+    );
 
     // Assert: Success and valid table
     assert!(result.is_ok());
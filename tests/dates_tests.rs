@@ -0,0 +1,105 @@
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use linear_cli::dates::{
+    FixedClock, datetime, parse_calendar_date, parse_date, parse_datetime, timeless_date,
+};
+use linear_cli::error::CliError;
+
+/// Monday 2026-10-19, late evening in UTC-7 (already Tuesday in UTC)
+fn clock() -> FixedClock {
+    FixedClock(DateTime::parse_from_rfc3339("2026-10-19T23:30:00-07:00").unwrap())
+}
+
+fn day(value: &str) -> String {
+    parse_date(value, &clock()).unwrap().to_string()
+}
+
+fn instant(value: &str) -> String {
+    parse_datetime(value, &clock()).unwrap().to_rfc3339()
+}
+
+#[test]
+fn test_calendar_dates_and_timestamps() {
+    assert_eq!(day("2026-11-01"), "2026-11-01");
+    assert_eq!(instant("2026-11-01"), "2026-11-01T07:00:00+00:00");
+    assert_eq!(
+        instant("2026-11-01T09:00:00+02:00"),
+        "2026-11-01T07:00:00+00:00"
+    );
+    // A timestamp's day is the day in the clock's timezone
+    assert_eq!(day("2026-11-01T03:00:00Z"), "2026-10-31");
+}
+
+#[test]
+fn test_named_days_use_the_local_date() {
+    assert_eq!(day("today"), "2026-10-19");
+    assert_eq!(day(" Tomorrow "), "2026-10-20");
+    assert_eq!(day("yesterday"), "2026-10-18");
+    assert_eq!(instant("today"), "2026-10-19T07:00:00+00:00");
+    assert_eq!(instant("now"), "2026-10-20T06:30:00+00:00");
+}
+
+#[test]
+fn test_weekdays_resolve_relative_to_today() {
+    assert_eq!(day("monday"), "2026-10-19");
+    assert_eq!(day("fri"), "2026-10-23");
+    assert_eq!(day("Sunday"), "2026-10-25");
+    assert_eq!(day("next monday"), "2026-10-26");
+    assert_eq!(day("next wed"), "2026-10-21");
+    assert_eq!(day("last monday"), "2026-10-12");
+    assert_eq!(day("last friday"), "2026-10-16");
+}
+
+#[test]
+fn test_relative_offsets_from_now() {
+    assert_eq!(day("+3d"), "2026-10-22");
+    assert_eq!(day("-7d"), "2026-10-12");
+    assert_eq!(day("2w"), "2026-11-02");
+    assert_eq!(instant("-7d"), "2026-10-13T06:30:00+00:00");
+    assert_eq!(instant("30m"), "2026-10-20T07:00:00+00:00");
+    assert_eq!(instant("+4h"), "2026-10-20T10:30:00+00:00");
+    // Crossing local midnight moves to the next local day
+    assert_eq!(day("1h"), "2026-10-20");
+}
+
+#[test]
+fn test_invalid_values_are_rejected() {
+    for bad in [
+        "",
+        "someday",
+        "3x",
+        "+d",
+        "d",
+        "2026-13-01",
+        "30/06/2026",
+        "next",
+        "last week",
+        "2日",
+    ] {
+        let err = parse_date(bad, &clock()).unwrap_err();
+        assert!(
+            matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("YYYY-MM-DD")),
+            "{bad:?} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_converts_to_linear_scalars() {
+    assert_eq!(timeless_date("friday", &clock()).unwrap().0, "2026-10-23");
+    assert_eq!(
+        datetime("2026-11-01", &clock()).unwrap().0,
+        "2026-11-01T07:00:00.000Z"
+    );
+
+    let utc = FixedClock::utc(Utc.with_ymd_and_hms(2026, 10, 19, 23, 30, 0).unwrap());
+    assert_eq!(
+        parse_date("tomorrow", &utc).unwrap(),
+        NaiveDate::from_ymd_opt(2026, 10, 20).unwrap()
+    );
+}
+
+#[test]
+fn test_calendar_date_uses_system_clock_for_absolute_dates() {
+    assert_eq!(parse_calendar_date("2026-06-30").unwrap(), "2026-06-30");
+    assert!(parse_calendar_date("30/06/2026").is_err());
+}
@@ -0,0 +1,315 @@
+use clap::Parser;
+use cynic::QueryBuilder;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, DocCommands};
+use linear_cli::client::documents::{CreateDocumentInput, MockDocumentClient, UpdateDocumentInput};
+use linear_cli::client::queries::{
+    DocumentFilterInput, DocumentsQuery, DocumentsQueryVariables, StringComparatorInput,
+};
+use linear_cli::documents::commands::{
+    handle_create, handle_delete, handle_list, handle_update, handle_view,
+};
+use linear_cli::documents::types::DocumentRef;
+use linear_cli::documents::{Document, DocumentList};
+use linear_cli::error::CliError;
+use linear_cli::io::{MockIo, TextSource};
+use linear_cli::output::{Formattable, OutputFormat};
+use serde_json::Value;
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn document(content: Option<&str>) -> Document {
+    Document {
+        id: "doc-1".to_string(),
+        title: "Onboarding guide".to_string(),
+        slug_id: "3f1c2a9b8d7e".to_string(),
+        url: "https://linear.app/acme/document/onboarding-guide-3f1c2a9b8d7e".to_string(),
+        icon: None,
+        content: content.map(str::to_string),
+        project_id: Some("project-1".to_string()),
+        project_name: Some("Platform".to_string()),
+        creator_name: Some("Alice".to_string()),
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-02T00:00:00Z".to_string(),
+    }
+}
+
+#[test]
+fn test_document_ref_accepts_ids_slugs_and_urls() {
+    assert_eq!(
+        DocumentRef::parse("0b7a4f3e-1c2d-4e5f-8a9b-0c1d2e3f4a5b"),
+        DocumentRef::Id("0b7a4f3e-1c2d-4e5f-8a9b-0c1d2e3f4a5b".to_string())
+    );
+    for value in [
+        "3f1c2a9b8d7e",
+        "onboarding-guide-3f1c2a9b8d7e",
+        "https://linear.app/acme/document/onboarding-guide-3f1c2a9b8d7e/",
+    ] {
+        assert_eq!(
+            DocumentRef::parse(value),
+            DocumentRef::Slug("3f1c2a9b8d7e".to_string()),
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn test_view_renders_content_as_markdown() {
+    let client = MockDocumentClient::new(document(Some("## Setup\n\nRun `make`.\n")));
+    let io = MockIo::new();
+
+    handle_view(
+        "3f1c2a9b8d7e",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    let output = io.stdout_lines().concat();
+    assert!(output.starts_with("# Onboarding guide\n\n**Project:** Platform\n"));
+    assert!(output.ends_with("\n---\n\n## Setup\n\nRun `make`.\n"));
+}
+
+#[test]
+fn test_list_json_omits_content() {
+    let client = MockDocumentClient::new(document(None));
+    let io = MockIo::new();
+
+    handle_list(
+        Some("platform"),
+        50,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(value[0]["slug_id"], "3f1c2a9b8d7e");
+    assert!(value[0].get("content").is_none());
+}
+
+#[test]
+fn test_document_list_formats() {
+    let list = DocumentList(vec![document(None)]);
+
+    assert_eq!(
+        list.to_markdown().unwrap(),
+        "## Documents (1)\n\n\
+         - [Onboarding guide](https://linear.app/acme/document/onboarding-guide-3f1c2a9b8d7e) (Platform)\n"
+    );
+    let csv = list.to_csv().unwrap();
+    assert!(csv.starts_with("id,slug_id,title,project,creator,updated_at,url\n"));
+    assert!(list.to_table().unwrap().contains("3f1c2a9b8d7e"));
+}
+
+#[test]
+fn test_create_sends_title_project_and_content() {
+    let client = MockDocumentClient::new(document(Some("Body")));
+
+    handle_create(
+        "Onboarding guide",
+        Some("project-1".to_string()),
+        Some("Body".to_string()),
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *client.creates.lock().unwrap(),
+        vec![CreateDocumentInput {
+            title: "Onboarding guide".to_string(),
+            content: Some("Body".to_string()),
+            project: Some("project-1".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn test_create_leaves_project_slug_for_the_client_to_resolve() {
+    let client = MockDocumentClient::new(document(None));
+
+    handle_create(
+        "Release checklist",
+        Some("mobile-app".to_string()),
+        None,
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let creates = client.creates.lock().unwrap();
+    assert_eq!(creates[0].project.as_deref(), Some("mobile-app"));
+}
+
+#[test]
+fn test_update_editor_prefills_current_content() {
+    let client = MockDocumentClient::new(document(Some("Old body")));
+    let io = MockIo::new().with_editor_output("New body\n");
+
+    handle_update(
+        "doc-1",
+        None,
+        Some(TextSource::Editor),
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(*io.editor_input.lock().unwrap(), vec!["Old body"]);
+    assert_eq!(
+        *client.updates.lock().unwrap(),
+        vec![(
+            "doc-1".to_string(),
+            UpdateDocumentInput {
+                title: None,
+                content: Some("New body".to_string()),
+            }
+        )]
+    );
+}
+
+#[test]
+fn test_update_requires_a_change() {
+    let client = MockDocumentClient::new(document(None));
+
+    let err = handle_update(
+        "doc-1",
+        None,
+        None,
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::InvalidArgs(_)));
+    assert!(client.updates.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_delete_reports_deleted_document() {
+    let client = MockDocumentClient::new(document(None));
+    let io = MockIo::new();
+
+    handle_delete(
+        "doc-1",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    assert_eq!(*client.deletes.lock().unwrap(), vec!["doc-1"]);
+    assert_eq!(io.stdout_lines(), vec!["Deleted document doc-1"]);
+}
+
+#[test]
+fn test_documents_query_filters_by_slug() {
+    let operation = DocumentsQuery::build(DocumentsQueryVariables {
+        first: Some(1),
+        filter: Some(DocumentFilterInput {
+            project: None,
+            slug_id: Some(StringComparatorInput {
+                eq: Some("3f1c2a9b8d7e".to_string()),
+            }),
+        }),
+    });
+
+    let json = serde_json::to_value(&operation).unwrap();
+    assert_eq!(json["variables"]["filter"]["slugId"]["eq"], "3f1c2a9b8d7e");
+    assert!(json["variables"]["filter"].get("project").is_none());
+}
+
+#[test]
+fn test_doc_cli_commands_parse() {
+    let cli = Cli::parse_from([
+        "linear",
+        "doc",
+        "create",
+        "--project",
+        "platform",
+        "--title",
+        "Guide",
+        "--file",
+        "content.md",
+    ]);
+    let Commands::Doc {
+        action:
+            DocCommands::Create {
+                title,
+                project,
+                file,
+                ..
+            },
+    } = cli.command
+    else {
+        panic!("expected doc create command");
+    };
+    assert_eq!(title, "Guide");
+    assert_eq!(project.as_deref(), Some("platform"));
+    assert_eq!(file.as_deref(), Some("content.md"));
+
+    assert!(Cli::try_parse_from(["linear", "doc", "update", "doc-1", "--file", "-"]).is_ok());
+    assert!(
+        Cli::try_parse_from([
+            "linear",
+            "doc",
+            "update",
+            "doc-1",
+            "--file",
+            "-",
+            "--content",
+            "x"
+        ])
+        .is_err()
+    );
+    assert!(Cli::try_parse_from(["linear", "doc", "view", "3f1c2a9b8d7e"]).is_ok());
+    assert!(Cli::try_parse_from(["linear", "doc", "delete", "doc-1"]).is_ok());
+}
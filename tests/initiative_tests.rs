@@ -0,0 +1,367 @@
+use clap::Parser;
+use cynic::QueryBuilder;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, InitiativeCommands};
+use linear_cli::client::initiatives::{
+    CreateInitiativeInput, MockInitiativeClient, UpdateInitiativeInput, initiative_filter,
+};
+use linear_cli::client::queries::{InitiativesQuery, InitiativesQueryVariables};
+use linear_cli::error::CliError;
+use linear_cli::initiatives::commands::{
+    handle_add_project, handle_create, handle_update, handle_updates, handle_view,
+};
+use linear_cli::initiatives::types::{InitiativeProject, InitiativeStatus};
+use linear_cli::initiatives::{Initiative, InitiativeList, InitiativeUpdate};
+use linear_cli::io::MockIo;
+use linear_cli::output::{Formattable, OutputFormat};
+use serde_json::Value;
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn project(name: &str, status: &str, progress: f64) -> InitiativeProject {
+    InitiativeProject {
+        id: format!("project-{}", name.to_lowercase()),
+        name: name.to_string(),
+        slug_id: "a1b2c3".to_string(),
+        url: format!("https://linear.app/acme/project/{}", name.to_lowercase()),
+        status_name: status.to_string(),
+        status_type: "started".to_string(),
+        progress,
+        target_date: Some("2026-06-30".to_string()),
+    }
+}
+
+fn initiative(projects: Vec<InitiativeProject>) -> Initiative {
+    Initiative {
+        id: "init-1".to_string(),
+        name: "Q3 Growth".to_string(),
+        slug_id: "9e8d7c6b5a4f".to_string(),
+        url: "https://linear.app/acme/initiative/q3-growth-9e8d7c6b5a4f".to_string(),
+        description: Some("Grow self-serve signups".to_string()),
+        status: "Active".to_string(),
+        health: Some("atRisk".to_string()),
+        target_date: Some("2026-09-30".to_string()),
+        owner_name: Some("Alice".to_string()),
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-02T00:00:00Z".to_string(),
+        projects,
+    }
+}
+
+fn two_projects() -> Vec<InitiativeProject> {
+    vec![
+        project("Platform", "In Progress", 0.4),
+        project("Billing", "Planned", 0.1),
+    ]
+}
+
+#[test]
+fn test_initiative_progress_averages_projects() {
+    assert_eq!(initiative(two_projects()).progress(), Some(0.25));
+    assert_eq!(initiative(Vec::new()).progress(), None);
+}
+
+#[test]
+fn test_view_renders_project_rollup_as_markdown() {
+    let client = MockInitiativeClient::new(initiative(two_projects()));
+    let io = MockIo::new();
+
+    handle_view(
+        "q3-growth-9e8d7c6b5a4f",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    let output = io.stdout_lines().concat();
+    assert!(output.starts_with("# Q3 Growth\n"));
+    assert!(output.contains("**Status:** Active\n**Health:** At risk\n"));
+    assert!(output.contains("**Progress:** 25% across 2 project(s)"));
+    assert!(output.contains("## Projects (2)"));
+    assert!(output.contains(
+        "| [Platform](https://linear.app/acme/project/platform) | In Progress | 40% | 2026-06-30 |"
+    ));
+    assert!(
+        output.contains("| [Billing](https://linear.app/acme/project/billing) | Planned | 10% |")
+    );
+}
+
+#[test]
+fn test_view_without_projects_says_so() {
+    let markdown = initiative(Vec::new()).to_markdown().unwrap();
+
+    assert!(markdown.contains("## Projects (0)\n\nNo projects linked."));
+    assert!(!markdown.contains("**Progress:**"));
+}
+
+#[test]
+fn test_initiative_list_formats() {
+    let list = InitiativeList(vec![initiative(two_projects())]);
+
+    let csv = list.to_csv().unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "id,slug_id,name,status,health,progress,projects,target_date,owner,url"
+    );
+    assert!(lines[1].starts_with("init-1,9e8d7c6b5a4f,Q3 Growth,Active,atRisk,0.25,2,"));
+
+    assert!(
+        list.to_markdown()
+            .unwrap()
+            .contains("- [Q3 Growth](https://linear.app/acme/initiative/q3-growth-9e8d7c6b5a4f) — Active, At risk, 25% across 2 project(s)")
+    );
+
+    let json: Value = serde_json::from_str(&list.to_json().unwrap()).unwrap();
+    assert_eq!(json[0]["projects"][1]["name"], "Billing");
+}
+
+#[test]
+fn test_create_validates_and_sends_fields() {
+    let client = MockInitiativeClient::new(initiative(Vec::new()));
+    let io = MockIo::new();
+
+    handle_create(
+        "Q3 Growth",
+        Some("Grow signups".to_string()),
+        Some(InitiativeStatus::Planned),
+        Some("2026-09-30".to_string()),
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *client.creates.lock().unwrap(),
+        vec![CreateInitiativeInput {
+            name: "Q3 Growth".to_string(),
+            description: Some("Grow signups".to_string()),
+            status: Some(InitiativeStatus::Planned),
+            target_date: Some("2026-09-30".to_string()),
+        }]
+    );
+
+    let err = handle_create(
+        "Q3 Growth",
+        None,
+        None,
+        Some("next quarter".to_string()),
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("YYYY-MM-DD")));
+    assert_eq!(client.creates.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_update_requires_a_change() {
+    let client = MockInitiativeClient::new(initiative(Vec::new()));
+
+    let err = handle_update(
+        "q3-growth",
+        UpdateInitiativeInput::default(),
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::InvalidArgs(_)));
+    assert!(client.edits.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_update_sends_status() {
+    let client = MockInitiativeClient::new(initiative(Vec::new()));
+
+    handle_update(
+        "Q3 Growth",
+        UpdateInitiativeInput {
+            status: Some(InitiativeStatus::Completed),
+            ..Default::default()
+        },
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let edits = client.edits.lock().unwrap();
+    assert_eq!(edits[0].0, "Q3 Growth");
+    assert_eq!(edits[0].1.status, Some(InitiativeStatus::Completed));
+}
+
+#[test]
+fn test_add_project_prints_updated_rollup() {
+    let client = MockInitiativeClient::new(initiative(two_projects()));
+    let io = MockIo::new();
+
+    handle_add_project(
+        "q3-growth",
+        "billing",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *client.links.lock().unwrap(),
+        vec![("q3-growth".to_string(), "billing".to_string())]
+    );
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(value["projects"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_updates_markdown_shows_author_and_health() {
+    let mut client = MockInitiativeClient::new(initiative(Vec::new()));
+    client.updates_result = Ok(vec![
+        InitiativeUpdate {
+            id: "u2".to_string(),
+            body: "Billing slipped a week".to_string(),
+            health: "atRisk".to_string(),
+            author_name: "Alice".to_string(),
+            created_at: "2026-03-09T00:00:00Z".to_string(),
+            url: "https://linear.app/acme/initiative/q3/updates/u2".to_string(),
+        },
+        InitiativeUpdate {
+            id: "u1".to_string(),
+            body: "Kicked off".to_string(),
+            health: "onTrack".to_string(),
+            author_name: "Bob".to_string(),
+            created_at: "2026-03-02T00:00:00Z".to_string(),
+            url: "https://linear.app/acme/initiative/q3/updates/u1".to_string(),
+        },
+    ]);
+    let io = MockIo::new();
+
+    handle_updates(
+        "q3-growth",
+        20,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    let output = io.stdout_lines().concat();
+    assert!(output.starts_with("## Updates (2)\n"));
+    assert!(output.contains(
+        "### Alice · At risk\n**Created:** 2026-03-09T00:00:00Z\n\nBilling slipped a week\n\n---\n\n### Bob · On track"
+    ));
+}
+
+#[test]
+fn test_initiative_filter_matches_id_slug_or_name() {
+    let by_id =
+        serde_json::to_value(initiative_filter("0b7a4f3e-1c2d-4e5f-8a9b-0c1d2e3f4a5b")).unwrap();
+    assert_eq!(by_id["id"]["eq"], "0b7a4f3e-1c2d-4e5f-8a9b-0c1d2e3f4a5b");
+    assert!(by_id.get("or").is_none());
+
+    let operation = InitiativesQuery::build(InitiativesQueryVariables {
+        first: Some(1),
+        filter: Some(initiative_filter(
+            "https://linear.app/acme/initiative/q3-growth-9e8d7c6b5a4f/overview",
+        )),
+    });
+    let json = serde_json::to_value(&operation).unwrap();
+    let or = &json["variables"]["filter"]["or"];
+    assert_eq!(or[0]["slugId"]["eq"], "9e8d7c6b5a4f");
+    assert!(or[0].get("name").is_none());
+
+    let by_name = serde_json::to_value(initiative_filter("Q3 Growth")).unwrap();
+    assert_eq!(by_name["or"][1]["name"]["eq"], "Q3 Growth");
+}
+
+#[test]
+fn test_initiative_cli_commands_parse() {
+    let cli = Cli::parse_from([
+        "linear",
+        "initiative",
+        "create",
+        "--name",
+        "Q3 Growth",
+        "--status",
+        "active",
+        "--target-date",
+        "2026-09-30",
+    ]);
+    let Commands::Initiative {
+        action:
+            InitiativeCommands::Create {
+                name,
+                status,
+                target_date,
+                ..
+            },
+    } = cli.command
+    else {
+        panic!("expected initiative create command");
+    };
+    assert_eq!(name, "Q3 Growth");
+    assert_eq!(status, Some(InitiativeStatus::Active));
+    assert_eq!(target_date.as_deref(), Some("2026-09-30"));
+
+    let cli = Cli::parse_from(["linear", "initiative", "add-project", "q3", "platform"]);
+    let Commands::Initiative {
+        action:
+            InitiativeCommands::AddProject {
+                initiative,
+                project,
+                ..
+            },
+    } = cli.command
+    else {
+        panic!("expected initiative add-project command");
+    };
+    assert_eq!((initiative.as_str(), project.as_str()), ("q3", "platform"));
+
+    assert!(Cli::try_parse_from(["linear", "initiative", "updates", "q3", "--limit", "5"]).is_ok());
+    assert!(Cli::try_parse_from(["linear", "initiative", "list", "--status", "paused"]).is_err());
+}
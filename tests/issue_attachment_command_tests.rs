@@ -0,0 +1,307 @@
+use clap::Parser;
+use linear_cli::attachments::commands::{handle_attach, handle_detach, handle_list};
+use linear_cli::attachments::types::{Attachment, AttachmentList};
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands};
+use linear_cli::client::attachments::{
+    AttachmentLinkKind, LinkAttachmentInput, MockAttachmentClient,
+};
+use linear_cli::client::comments::MockCommentClient;
+use linear_cli::client::issues::MockIssueClient;
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::view::{ViewDeps, handle_view};
+use linear_cli::issues::types::{Issue, IssueState, Priority, User};
+use linear_cli::output::{Formattable, OutputFormat};
+use serde_json::Value;
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn attachment(id: &str, title: &str, url: &str, source_type: Option<&str>) -> Attachment {
+    Attachment {
+        id: id.to_string(),
+        title: title.to_string(),
+        subtitle: None,
+        url: url.to_string(),
+        source_type: source_type.map(str::to_string),
+        creator_name: Some("Alice".to_string()),
+        created_at: "2025-01-01T00:00:00Z".to_string(),
+        updated_at: "2025-01-01T00:00:00Z".to_string(),
+    }
+}
+
+fn pull_request() -> Attachment {
+    attachment(
+        "att-1",
+        "Fix login #42",
+        "https://github.com/acme/app/pull/42",
+        Some("github"),
+    )
+}
+
+fn design_doc() -> Attachment {
+    attachment(
+        "att-2",
+        "Design doc",
+        "https://docs.example.com/login",
+        None,
+    )
+}
+
+fn issue() -> Issue {
+    Issue {
+        id: "issue-uuid".to_string(),
+        identifier: "ENG-1".to_string(),
+        title: "Fix login".to_string(),
+        description: None,
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "In Progress".to_string(),
+        },
+        priority: Priority::High,
+        assignee: None,
+        creator: User {
+            id: "user-1".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        },
+        project: None,
+        created_at: "2025-01-01T00:00:00Z".to_string(),
+        updated_at: "2025-01-02T00:00:00Z".to_string(),
+        url: "https://linear.app/acme/issue/ENG-1".to_string(),
+        comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+    }
+}
+
+#[test]
+fn test_attach_links_url_with_title() {
+    let client = MockAttachmentClient::new(vec![design_doc()]);
+    let io = MockIo::new();
+
+    handle_attach(
+        "ENG-1",
+        "https://docs.example.com/login",
+        Some("Design doc".to_string()),
+        AttachmentLinkKind::Url,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *client.links.lock().unwrap(),
+        vec![LinkAttachmentInput {
+            issue_id: "ENG-1".to_string(),
+            url: "https://docs.example.com/login".to_string(),
+            title: Some("Design doc".to_string()),
+            kind: AttachmentLinkKind::Url,
+        }]
+    );
+    let printed: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(printed["id"], "att-2");
+    assert_eq!(printed["url"], "https://docs.example.com/login");
+}
+
+#[test]
+fn test_attach_requires_token() {
+    let client = MockAttachmentClient::new(vec![pull_request()]);
+
+    let err = handle_attach(
+        "ENG-1",
+        "https://github.com/acme/app/pull/42",
+        None,
+        AttachmentLinkKind::GitHubPr,
+        &client,
+        &TestConfigProvider {
+            values: HashMap::new(),
+        },
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::AuthError(_)));
+    assert!(client.links.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_attachments_lists_source_types() {
+    let client = MockAttachmentClient::new(vec![pull_request(), design_doc()]);
+    let io = MockIo::new();
+
+    handle_list(
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        "ENG-1",
+        50,
+        Some(OutputFormat::Csv),
+    )
+    .unwrap();
+
+    let output = io.stdout_lines().concat();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "id,title,source,url,creator,created_at");
+    assert!(lines[1].starts_with("att-1,Fix login #42,github,"));
+    assert!(lines[2].starts_with("att-2,Design doc,url,"));
+}
+
+#[test]
+fn test_detach_deletes_attachment() {
+    let client = MockAttachmentClient::new(vec![]);
+    let io = MockIo::new();
+
+    handle_detach(
+        "att-1",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    assert_eq!(*client.deletes.lock().unwrap(), vec!["att-1"]);
+    assert_eq!(io.stdout_lines(), vec!["Deleted attachment att-1"]);
+}
+
+#[test]
+fn test_view_includes_attachments() {
+    let issue_client = MockIssueClient {
+        result: Ok(issue()),
+        list_result: Ok(vec![]),
+    };
+    let comment_client = MockCommentClient {
+        list_result: Ok(vec![]),
+        create_result: Err(CliError::General("not used".to_string())),
+        delete_result: Ok(()),
+    };
+    let attachment_client = MockAttachmentClient::new(vec![pull_request()]);
+    let io = MockIo::new();
+    let config = config();
+
+    let deps = ViewDeps {
+        issue_client: &issue_client,
+        comment_client: &comment_client,
+        attachment_client: Some(&attachment_client),
+        config: &config,
+        storage: &TestStorage,
+        io: &io,
+    };
+    handle_view(
+        "ENG-1",
+        false,
+        50,
+        &deps,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    let output = io.stdout_lines().concat();
+    assert!(output.contains(
+        "## Attachments\n\n- [Fix login #42](https://github.com/acme/app/pull/42) (github)"
+    ));
+
+    // JSON omits attachments when they were not fetched (offline)
+    let io = MockIo::new();
+    let deps = ViewDeps {
+        attachment_client: None,
+        io: &io,
+        ..deps
+    };
+    handle_view("ENG-1", false, 50, &deps, Some(OutputFormat::Json)).unwrap();
+    let printed: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert!(printed.get("attachments").is_none());
+}
+
+#[test]
+fn test_attachment_list_markdown() {
+    let markdown = AttachmentList(vec![pull_request(), design_doc()])
+        .to_markdown()
+        .unwrap();
+
+    assert_eq!(
+        markdown,
+        "## Attachments (2)\n\n\
+         - [Fix login #42](https://github.com/acme/app/pull/42) (github)\n\
+         - [Design doc](https://docs.example.com/login) (url)\n"
+    );
+}
+
+#[test]
+fn test_attach_cli_requires_exactly_one_link() {
+    let cli = Cli::parse_from([
+        "linear",
+        "issue",
+        "attach",
+        "ENG-1",
+        "--github-pr",
+        "https://github.com/acme/app/pull/42",
+    ]);
+    let Commands::Issue {
+        action: IssueCommands::Attach { url, github_pr, .. },
+    } = cli.command
+    else {
+        panic!("expected issue attach command");
+    };
+    assert_eq!(url, None);
+    assert_eq!(
+        github_pr.as_deref(),
+        Some("https://github.com/acme/app/pull/42")
+    );
+
+    assert!(Cli::try_parse_from(["linear", "issue", "attach", "ENG-1"]).is_err());
+    assert!(
+        Cli::try_parse_from([
+            "linear",
+            "issue",
+            "attach",
+            "ENG-1",
+            "--url",
+            "https://a",
+            "--github-pr",
+            "https://b",
+        ])
+        .is_err()
+    );
+}
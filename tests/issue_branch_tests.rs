@@ -0,0 +1,342 @@
+use clap::Parser;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands};
+use linear_cli::client::comments::MockCommentClient;
+use linear_cli::client::issues::{CreateIssueInput, IssueClient, UpdateIssueInput};
+use linear_cli::error::CliError;
+use linear_cli::git::{Checkout, GitRepo, issue_identifier_from_branch};
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::view::ViewDeps;
+use linear_cli::issues::commands::{handle_branch, handle_checkout, handle_current};
+use linear_cli::issues::types::{Issue, IssueState, Priority, User};
+use linear_cli::output::OutputFormat;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+use tempfile::TempDir;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn issue(identifier: &str, branch_name: Option<&str>) -> Issue {
+    let alice = User {
+        id: "user-1".to_string(),
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+    };
+    Issue {
+        id: "issue-1".to_string(),
+        identifier: identifier.to_string(),
+        title: "Fix login".to_string(),
+        description: None,
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "Todo".to_string(),
+        },
+        priority: Priority::Medium,
+        assignee: None,
+        creator: alice,
+        project: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: branch_name.map(str::to_string),
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-01T00:00:00Z".to_string(),
+        url: format!("https://linear.app/acme/issue/{identifier}"),
+        comments: None,
+        attachments: None,
+    }
+}
+
+/// Returns an issue with the given branch name and records requested identifiers
+struct BranchIssueClient {
+    branch_name: Option<&'static str>,
+    requested: Mutex<Vec<String>>,
+}
+
+impl BranchIssueClient {
+    fn new(branch_name: Option<&'static str>) -> Self {
+        Self {
+            branch_name,
+            requested: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl IssueClient for BranchIssueClient {
+    fn get_issue(&self, _token: &str, identifier: &str) -> Result<Issue, CliError> {
+        self.requested.lock().unwrap().push(identifier.to_string());
+        Ok(issue(identifier, self.branch_name))
+    }
+
+    fn list_issues(
+        &self,
+        _token: &str,
+        _assignee: Option<String>,
+        _project: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<Issue>, CliError> {
+        unreachable!("not used")
+    }
+
+    fn create_issue(&self, _token: &str, _input: CreateIssueInput) -> Result<Issue, CliError> {
+        unreachable!("not used")
+    }
+
+    fn update_issue(
+        &self,
+        _token: &str,
+        _id: &str,
+        _input: UpdateIssueInput,
+    ) -> Result<Issue, CliError> {
+        unreachable!("not used")
+    }
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// A repository with one commit on `main`
+fn repo() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    git(dir.path(), &["init", "--quiet", "--initial-branch=main"]);
+    git(
+        dir.path(),
+        &["commit", "--quiet", "--allow-empty", "-m", "init"],
+    );
+    dir
+}
+
+#[test]
+fn test_identifier_from_branch_names() {
+    let cases = [
+        ("alice/eng-123-fix-login", Some("ENG-123")),
+        ("eng-7", Some("ENG-7")),
+        ("feature/ENG-42", Some("ENG-42")),
+        ("fix-login-ops2-9-retry", Some("OPS2-9")),
+        ("bob/web-1_hotfix", Some("WEB-1")),
+        ("main", None),
+        ("release/v2", None),
+        ("eng-12abc", None),
+        ("xeng/123", None),
+        ("", None),
+    ];
+    for (branch, expected) in cases {
+        assert_eq!(
+            issue_identifier_from_branch(branch).as_deref(),
+            expected,
+            "{branch}"
+        );
+    }
+}
+
+#[test]
+fn test_branch_prints_suggested_name() {
+    let client = BranchIssueClient::new(Some("alice/eng-1-fix-login"));
+
+    let io = MockIo::new();
+    handle_branch(
+        "ENG-1",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+    assert_eq!(io.stdout_lines(), vec!["alice/eng-1-fix-login"]);
+
+    let io = MockIo::new();
+    handle_branch("ENG-1", &client, &config(), &TestStorage, &io, None).unwrap();
+    let json: Value = serde_json::from_str(&io.stdout_lines()[0]).unwrap();
+    assert_eq!(json["identifier"], "ENG-1");
+    assert_eq!(json["branch"], "alice/eng-1-fix-login");
+
+    let err = handle_branch(
+        "ENG-1",
+        &BranchIssueClient::new(None),
+        &config(),
+        &TestStorage,
+        &io,
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, CliError::General(ref msg) if msg.contains("no branch name")));
+}
+
+#[test]
+fn test_checkout_creates_then_switches_to_issue_branch() {
+    let dir = repo();
+    let repo = GitRepo::at(dir.path());
+    let client = BranchIssueClient::new(Some("alice/eng-1-fix-login"));
+    let checkout = |io: &MockIo| {
+        handle_checkout(
+            "ENG-1",
+            &repo,
+            &client,
+            &config(),
+            &TestStorage,
+            io,
+            Some(OutputFormat::Markdown(Default::default())),
+        )
+    };
+
+    let io = MockIo::new();
+    checkout(&io).unwrap();
+    assert_eq!(
+        io.stdout_lines(),
+        vec!["Switched to a new branch 'alice/eng-1-fix-login'"]
+    );
+    assert_eq!(repo.current_branch().unwrap(), "alice/eng-1-fix-login");
+
+    let io = MockIo::new();
+    checkout(&io).unwrap();
+    assert_eq!(
+        io.stdout_lines(),
+        vec!["Already on 'alice/eng-1-fix-login'"]
+    );
+
+    git(dir.path(), &["checkout", "--quiet", "main"]);
+    let io = MockIo::new();
+    checkout(&io).unwrap();
+    assert_eq!(
+        io.stdout_lines(),
+        vec!["Switched to branch 'alice/eng-1-fix-login'"]
+    );
+    assert_eq!(repo.current_branch().unwrap(), "alice/eng-1-fix-login");
+}
+
+#[test]
+fn test_git_repo_errors() {
+    let outside = TempDir::new().unwrap();
+    let repo = GitRepo::at(outside.path());
+    let err = repo.current_branch().unwrap_err();
+    assert!(
+        matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("not inside a git repository"))
+    );
+    let err = repo.checkout("eng-1").unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(_)));
+
+    let dir = repo_with_detached_head();
+    let err = GitRepo::at(dir.path()).current_branch().unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("detached")));
+
+    let dir = self::repo();
+    let repo = GitRepo::at(dir.path());
+    assert!(!repo.branch_exists("eng-1").unwrap());
+    assert_eq!(repo.checkout("eng-1").unwrap(), Checkout::Created);
+    assert!(repo.branch_exists("eng-1").unwrap());
+}
+
+fn repo_with_detached_head() -> TempDir {
+    let dir = repo();
+    git(dir.path(), &["checkout", "--quiet", "--detach"]);
+    dir
+}
+
+#[test]
+fn test_current_views_issue_from_branch() {
+    let dir = repo();
+    git(
+        dir.path(),
+        &["checkout", "--quiet", "-b", "alice/eng-77-fix-login"],
+    );
+    let repo = GitRepo::at(dir.path());
+
+    let client = BranchIssueClient::new(Some("alice/eng-77-fix-login"));
+    let comments = MockCommentClient {
+        list_result: Ok(vec![]),
+        create_result: Err(CliError::General("not used".to_string())),
+        delete_result: Ok(()),
+    };
+    let config = config();
+    let io = MockIo::new();
+    let deps = ViewDeps {
+        issue_client: &client,
+        comment_client: &comments,
+        attachment_client: None,
+        config: &config,
+        storage: &TestStorage,
+        io: &io,
+    };
+
+    handle_current(&repo, false, 50, &deps, Some(OutputFormat::Json)).unwrap();
+    assert_eq!(*client.requested.lock().unwrap(), vec!["ENG-77"]);
+    let json: Value = serde_json::from_str(&io.stdout_lines().join("\n")).unwrap();
+    assert_eq!(json["identifier"], "ENG-77");
+    assert_eq!(json["branch_name"], "alice/eng-77-fix-login");
+
+    git(dir.path(), &["checkout", "--quiet", "main"]);
+    let err = handle_current(&repo, false, 50, &deps, None).unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("'main'")));
+}
+
+#[test]
+fn test_branch_commands_parse() {
+    let cli = Cli::parse_from(["linear", "issue", "checkout", "ENG-1"]);
+    assert!(matches!(
+        cli.command,
+        Commands::Issue {
+            action: IssueCommands::Checkout { ref identifier, .. }
+        } if identifier == "ENG-1"
+    ));
+
+    let cli = Cli::parse_from(["linear", "issue", "branch", "ENG-1", "--json"]);
+    assert!(matches!(
+        cli.command,
+        Commands::Issue {
+            action: IssueCommands::Branch { .. }
+        }
+    ));
+
+    let cli = Cli::parse_from(["linear", "issue", "current", "--with-comments"]);
+    assert!(matches!(
+        cli.command,
+        Commands::Issue {
+            action: IssueCommands::Current {
+                with_comments: true,
+                ..
+            }
+        }
+    ));
+}
@@ -103,6 +103,10 @@ fn sample_comment() -> Comment {
         updated_at: "2026-02-24T00:00:00Z".to_string(),
         edited_at: None,
         issue_identifier: Some("ENG-123".to_string()),
+        resolved_at: None,
+        parent_id: None,
+        replies: Vec::new(),
+        reactions: Vec::new(),
     }
 }
 
@@ -9,6 +9,7 @@ fn test_comment_create_mutation_serializes_required_fields() {
         input: CommentCreateInput {
             issue_id: Some("issue-123".to_string()),
             body: Some("Looks good to me".to_string()),
+            parent_id: None,
         },
     });
 
@@ -25,6 +26,7 @@ fn test_comment_create_mutation_omits_unset_optional_fields() {
         input: CommentCreateInput {
             issue_id: Some("issue-123".to_string()),
             body: Some("Looks good to me".to_string()),
+            parent_id: None,
         },
     });
 
@@ -35,3 +37,20 @@ fn test_comment_create_mutation_omits_unset_optional_fields() {
     assert!(input.get("postId").is_none());
     assert!(input.get("initiativeUpdateId").is_none());
 }
+
+#[test]
+fn test_comment_create_mutation_serializes_parent_for_replies() {
+    let operation = CommentCreateMutation::build(CommentCreateMutationVariables {
+        input: CommentCreateInput {
+            issue_id: None,
+            body: Some("Agreed".to_string()),
+            parent_id: Some("comment-1".to_string()),
+        },
+    });
+
+    let json = serde_json::to_value(&operation).expect("operation should serialize to JSON");
+    let input = &json["variables"]["input"];
+
+    assert_eq!(input["parentId"], "comment-1");
+    assert!(input.get("issueId").is_none());
+}
@@ -4,6 +4,7 @@ use linear_cli::auth::storage::TokenStorage;
 use linear_cli::client::issues::{CreateIssueInput, IssueClient};
 use linear_cli::error::CliError;
 use linear_cli::io::Io;
+use linear_cli::issues::commands::IssueFields;
 use linear_cli::issues::commands::create::handle_create;
 use linear_cli::issues::types::{Issue, IssueState, Priority, User};
 use linear_cli::output::OutputFormat;
@@ -121,6 +122,14 @@ fn sample_issue() -> Issue {
         updated_at: "2026-02-23T00:00:00Z".to_string(),
         url: "https://linear.app/company/issue/ENG-123".to_string(),
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -143,7 +152,9 @@ fn test_create_outputs_full_issue_object_on_success() {
         Some("@me".to_string()),
         None,
         None,
+        None,
         Some(2),
+        IssueFields::default(),
         &client,
         &config,
         &storage,
@@ -176,6 +187,8 @@ fn test_create_returns_auth_error_when_no_token() {
         None,
         None,
         None,
+        None,
+        IssueFields::default(),
         &client,
         &config,
         &storage,
@@ -212,6 +225,8 @@ fn test_create_propagates_not_found_for_unresolved_reference() {
         Some("unknown-project".to_string()),
         None,
         None,
+        None,
+        IssueFields::default(),
         &client,
         &config,
         &storage,
@@ -246,7 +261,9 @@ fn test_create_uses_config_provider_json_style_override() {
         Some("@me".to_string()),
         None,
         None,
+        None,
         Some(2),
+        IssueFields::default(),
         &client,
         &config,
         &storage,
@@ -12,8 +12,10 @@ fn test_issue_create_mutation_serializes_required_fields() {
             description: None,
             assignee_id: None,
             project_id: None,
+            project_milestone_id: None,
             state_id: None,
             priority: None,
+            ..Default::default()
         },
     });
 
@@ -33,8 +35,10 @@ fn test_issue_create_mutation_omits_unset_optional_fields() {
             description: None,
             assignee_id: None,
             project_id: Some("project-456".to_string()),
+            project_milestone_id: None,
             state_id: None,
             priority: Some(2),
+            ..Default::default()
         },
     });
 
@@ -110,6 +110,14 @@ fn sample_issue() -> Issue {
         url: "https://linear.app/issue/ENG-123".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -0,0 +1,395 @@
+use clap::Parser;
+use cynic::QueryBuilder;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands, IssueFieldArgs, IssueUpdatePatchArgs};
+use linear_cli::client::issues::{CreateIssueInput, IssueClient, UpdateIssueInput};
+use linear_cli::client::queries::{IssueQuery, IssueQueryVariables, Nullable};
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::{IssueFields, handle_create, handle_update};
+use linear_cli::issues::types::{Issue, IssueCycle, IssueState, IssueTeam, Priority, User};
+use linear_cli::output::{Formattable, OutputFormat};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn user(name: &str) -> User {
+    User {
+        id: format!("user-{}", name.to_lowercase()),
+        name: name.to_string(),
+        email: format!("{}@example.com", name.to_lowercase()),
+    }
+}
+
+fn issue() -> Issue {
+    Issue {
+        id: "issue-1".to_string(),
+        identifier: "ENG-1".to_string(),
+        title: "Fix login".to_string(),
+        description: None,
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "Todo".to_string(),
+        },
+        priority: Priority::Medium,
+        assignee: None,
+        creator: user("Alice"),
+        project: None,
+        team: Some(IssueTeam {
+            id: "team-1".to_string(),
+            key: "ENG".to_string(),
+            name: "Engineering".to_string(),
+        }),
+        cycle: Some(IssueCycle {
+            id: "cycle-12".to_string(),
+            number: 12,
+            name: None,
+        }),
+        estimate: Some(3.0),
+        due_date: Some("2026-11-01".to_string()),
+        sort_order: Some(-42.5),
+        subscribers: Some(vec![user("Alice"), user("Bob")]),
+        branch_name: None,
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-01T00:00:00Z".to_string(),
+        url: "https://linear.app/acme/issue/ENG-1".to_string(),
+        comments: None,
+        attachments: None,
+    }
+}
+
+/// Records create and update payloads
+#[derive(Default)]
+struct RecordingIssueClient {
+    creates: Mutex<Vec<CreateIssueInput>>,
+    updates: Mutex<Vec<UpdateIssueInput>>,
+}
+
+impl IssueClient for RecordingIssueClient {
+    fn get_issue(&self, _token: &str, _identifier: &str) -> Result<Issue, CliError> {
+        Ok(issue())
+    }
+
+    fn list_issues(
+        &self,
+        _token: &str,
+        _assignee: Option<String>,
+        _project: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<Issue>, CliError> {
+        unreachable!("not used")
+    }
+
+    fn create_issue(&self, _token: &str, input: CreateIssueInput) -> Result<Issue, CliError> {
+        self.creates.lock().unwrap().push(input);
+        Ok(issue())
+    }
+
+    fn update_issue(
+        &self,
+        _token: &str,
+        _id: &str,
+        input: UpdateIssueInput,
+    ) -> Result<Issue, CliError> {
+        self.updates.lock().unwrap().push(input);
+        Ok(issue())
+    }
+}
+
+fn update(
+    client: &RecordingIssueClient,
+    assignee: Option<&str>,
+    team: Option<&str>,
+    fields: IssueFields,
+) -> Result<(), CliError> {
+    handle_update(
+        "ENG-1",
+        None,
+        None,
+        assignee.map(str::to_string),
+        None,
+        None,
+        None,
+        None,
+        team.map(str::to_string),
+        fields,
+        client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+}
+
+#[test]
+fn test_update_maps_null_to_cleared_fields() {
+    let client = RecordingIssueClient::default();
+
+    update(
+        &client,
+        Some("null"),
+        None,
+        IssueFields {
+            estimate: Some("null".to_string()),
+            due_date: Some("null".to_string()),
+            cycle: Some("null".to_string()),
+            subscribers: vec!["null".to_string()],
+            sort_order: None,
+        },
+    )
+    .unwrap();
+
+    let updates = client.updates.lock().unwrap();
+    let input = &updates[0];
+    assert_eq!(input.assignee_id, Nullable::Null);
+    assert_eq!(input.project_id, Nullable::Unset);
+    assert_eq!(input.estimate, Nullable::Null);
+    assert_eq!(input.due_date, Nullable::Null);
+    assert_eq!(input.cycle, Nullable::Null);
+    assert_eq!(input.subscriber_ids, Some(Vec::new()));
+}
+
+#[test]
+fn test_update_sets_planning_fields() {
+    let client = RecordingIssueClient::default();
+
+    update(
+        &client,
+        None,
+        Some("OPS"),
+        IssueFields {
+            estimate: Some("5".to_string()),
+            due_date: Some("2026-11-01".to_string()),
+            cycle: Some("next".to_string()),
+            subscribers: vec!["user-a".to_string(), "user-b".to_string()],
+            sort_order: Some(10.0),
+        },
+    )
+    .unwrap();
+
+    let updates = client.updates.lock().unwrap();
+    let input = &updates[0];
+    assert_eq!(input.estimate, Nullable::Value(5));
+    assert_eq!(input.due_date, Nullable::Value("2026-11-01".to_string()));
+    assert_eq!(input.cycle, Nullable::Value("next".to_string()));
+    assert_eq!(input.team_id.as_deref(), Some("OPS"));
+    assert_eq!(
+        input.subscriber_ids,
+        Some(vec!["user-a".to_string(), "user-b".to_string()])
+    );
+    assert_eq!(input.sort_order, Some(10.0));
+}
+
+#[test]
+fn test_update_rejects_invalid_planning_values() {
+    let client = RecordingIssueClient::default();
+
+    let err = update(&client, None, Some("null"), IssueFields::default()).unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("team")));
+
+    let err = update(
+        &client,
+        None,
+        None,
+        IssueFields {
+            estimate: Some("three".to_string()),
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("estimate")));
+
+    let err = update(
+        &client,
+        None,
+        None,
+        IssueFields {
+            subscribers: vec!["user-a".to_string(), "null".to_string()],
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(_)));
+
+    assert!(client.updates.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_create_passes_planning_fields_and_ignores_null() {
+    let client = RecordingIssueClient::default();
+
+    handle_create(
+        "ENG",
+        "Fix login",
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        IssueFields {
+            estimate: Some("2".to_string()),
+            due_date: Some("null".to_string()),
+            cycle: Some("current".to_string()),
+            subscribers: vec!["user-a".to_string()],
+            sort_order: None,
+        },
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let creates = client.creates.lock().unwrap();
+    let input = &creates[0];
+    assert_eq!(input.estimate, Some(2));
+    assert_eq!(input.due_date, None);
+    assert_eq!(input.cycle.as_deref(), Some("current"));
+    assert_eq!(input.subscriber_ids, vec!["user-a".to_string()]);
+}
+
+#[test]
+fn test_issue_formatters_show_planning_fields() {
+    let issue = issue();
+
+    let table = issue.to_table().unwrap();
+    assert!(table.contains("ENG"));
+    assert!(table.contains("Cycle 12"));
+    assert!(table.contains("2026-11-01"));
+    assert!(table.contains("Alice, Bob"));
+
+    let markdown = issue.to_markdown().unwrap();
+    assert!(markdown.contains("**Team:** Engineering (ENG)\n"));
+    assert!(markdown.contains("**Cycle:** Cycle 12\n"));
+    assert!(markdown.contains("**Estimate:** 3\n"));
+    assert!(markdown.contains("**Due:** 2026-11-01\n"));
+
+    let csv = issue.to_csv().unwrap();
+    let mut lines = csv.lines();
+    assert!(
+        lines
+            .next()
+            .unwrap()
+            .ends_with("team,cycle,estimate,due_date,sort_order,subscribers")
+    );
+    assert!(
+        lines
+            .next()
+            .unwrap()
+            .ends_with(",ENG,12,3,2026-11-01,-42.5,alice@example.com; bob@example.com")
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&issue.to_json().unwrap()).unwrap();
+    assert_eq!(json["team"]["key"], "ENG");
+    assert_eq!(json["cycle"]["number"], 12);
+    assert_eq!(json["due_date"], "2026-11-01");
+}
+
+#[test]
+fn test_issue_query_requests_planning_fields() {
+    let operation = IssueQuery::build(IssueQueryVariables {
+        id: "ENG-1".to_string(),
+    });
+    let query = operation.query;
+    assert!(query.contains("dueDate"));
+    assert!(query.contains("sortOrder"));
+    assert!(query.contains("estimate"));
+    assert!(query.contains("subscribers(first: 25)"));
+}
+
+#[test]
+fn test_planning_flags_parse() {
+    let cli = Cli::parse_from([
+        "linear",
+        "issue",
+        "update",
+        "ENG-1",
+        "--team",
+        "OPS",
+        "--cycle",
+        "current",
+        "--due-date",
+        "friday",
+        "--estimate",
+        "null",
+        "--subscriber",
+        "user-a",
+        "--subscriber",
+        "user-b",
+        "--sort-order",
+        "-3.5",
+    ]);
+    let Commands::Issue {
+        action: IssueCommands::Update { patch, .. },
+    } = cli.command
+    else {
+        panic!("expected issue update command");
+    };
+    assert!(patch.has_any_field());
+    let IssueUpdatePatchArgs {
+        team,
+        fields:
+            IssueFieldArgs {
+                estimate,
+                due_date,
+                cycle,
+                subscribers,
+                sort_order,
+            },
+        ..
+    } = patch;
+    assert_eq!(team.as_deref(), Some("OPS"));
+    assert_eq!(cycle.as_deref(), Some("current"));
+    assert_eq!(due_date.as_deref(), Some("friday"));
+    assert_eq!(estimate.as_deref(), Some("null"));
+    assert_eq!(subscribers, vec!["user-a", "user-b"]);
+    assert_eq!(sort_order, Some(-3.5));
+
+    let cli = Cli::parse_from([
+        "linear",
+        "issue",
+        "create",
+        "--team",
+        "ENG",
+        "--title",
+        "Fix",
+        "--due-date",
+        "+3d",
+    ]);
+    let Commands::Issue {
+        action: IssueCommands::Create { fields, .. },
+    } = cli.command
+    else {
+        panic!("expected issue create command");
+    };
+    assert_eq!(fields.due_date.as_deref(), Some("+3d"));
+}
@@ -28,6 +28,14 @@ fn create_test_issue_full() -> Issue {
         updated_at: "2025-11-13T09:30:00Z".to_string(),
         url: "https://linear.app/team/issue/ENG-123".to_string(),
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -53,6 +61,14 @@ fn create_test_issue_minimal() -> Issue {
         updated_at: "2025-11-02T14:00:00Z".to_string(),
         url: "https://linear.app/team/issue/ENG-124".to_string(),
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -94,6 +94,14 @@ fn dummy_issue() -> Issue {
         url: "https://linear.app/issue/DUMMY-1".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -146,6 +154,14 @@ fn test_list_returns_multiple_issues() {
             url: "https://linear.app/issue/ENG-123".to_string(),
             project: None,
             comments: None,
+            attachments: None,
+            team: None,
+            cycle: None,
+            estimate: None,
+            due_date: None,
+            sort_order: None,
+            subscribers: None,
+            branch_name: None,
         },
         Issue {
             id: "issue-2".to_string(),
@@ -172,6 +188,14 @@ fn test_list_returns_multiple_issues() {
             url: "https://linear.app/issue/ENG-124".to_string(),
             project: None,
             comments: None,
+            attachments: None,
+            team: None,
+            cycle: None,
+            estimate: None,
+            due_date: None,
+            sort_order: None,
+            subscribers: None,
+            branch_name: None,
         },
     ];
 
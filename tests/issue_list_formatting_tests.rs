@@ -28,6 +28,14 @@ fn create_test_issue_list() -> IssueList {
         url: "https://linear.app/team/issue/ENG-123".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     let issue2 = Issue {
@@ -51,6 +59,14 @@ fn create_test_issue_list() -> IssueList {
         url: "https://linear.app/team/issue/ENG-124".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     let issue3 = Issue {
@@ -78,6 +94,14 @@ fn create_test_issue_list() -> IssueList {
         url: "https://linear.app/team/issue/ENG-125".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     IssueList(vec![issue1, issue2, issue3])
@@ -140,6 +164,14 @@ fn test_issue_list_to_json_with_single_issue() {
         url: "https://linear.app/test".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     let list = IssueList(vec![issue]);
@@ -17,7 +17,9 @@ fn test_issues_query_omits_unset_assignee_filter_when_project_filter_is_set() {
                 name: None,
                 slug_id: None,
             }),
+            ..Default::default()
         }),
+        after: None,
     });
 
     let json = serde_json::to_value(&operation).expect("operation should serialize to JSON");
@@ -43,7 +45,9 @@ fn test_issues_query_omits_unset_project_filter_when_assignee_filter_is_set() {
                 email: None,
             }),
             project: None,
+            ..Default::default()
         }),
+        after: None,
     });
 
     let json = serde_json::to_value(&operation).expect("operation should serialize to JSON");
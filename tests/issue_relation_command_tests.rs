@@ -130,6 +130,14 @@ fn sample_issue() -> Issue {
         updated_at: "2026-02-24T00:00:00Z".to_string(),
         url: "https://linear.app/company/issue/ENG-123".to_string(),
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
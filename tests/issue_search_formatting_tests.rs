@@ -25,6 +25,14 @@ fn make_search_result(identifier: &str, title: &str) -> Issue {
         url: format!("https://linear.app/issue/{identifier}"),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
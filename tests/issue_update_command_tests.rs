@@ -4,6 +4,7 @@ use linear_cli::auth::storage::TokenStorage;
 use linear_cli::client::issues::{IssueClient, UpdateIssueInput};
 use linear_cli::error::CliError;
 use linear_cli::io::Io;
+use linear_cli::issues::commands::IssueFields;
 use linear_cli::issues::commands::update::handle_update;
 use linear_cli::issues::types::{Issue, IssueState, Priority, User};
 use std::collections::HashMap;
@@ -125,6 +126,14 @@ fn sample_issue() -> Issue {
         updated_at: "2026-02-24T00:00:00Z".to_string(),
         url: "https://linear.app/company/issue/ENG-123".to_string(),
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -148,6 +157,9 @@ fn test_update_outputs_full_issue_object_on_success() {
         None,
         None,
         None,
+        None,
+        None,
+        IssueFields::default(),
         &client,
         &config,
         &storage,
@@ -174,7 +186,21 @@ fn test_update_returns_invalid_args_when_no_patch_fields() {
     };
 
     let result = handle_update(
-        "ENG-123", None, None, None, None, None, None, &client, &config, &storage, &io, None,
+        "ENG-123",
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        IssueFields::default(),
+        &client,
+        &config,
+        &storage,
+        &io,
+        None,
     );
 
     assert!(result.is_err());
@@ -206,6 +232,9 @@ fn test_update_propagates_not_found_for_unresolved_reference() {
         Some("unknown-project".to_string()),
         None,
         None,
+        None,
+        None,
+        IssueFields::default(),
         &client,
         &config,
         &storage,
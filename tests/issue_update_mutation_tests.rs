@@ -1,7 +1,8 @@
 use cynic::MutationBuilder;
 use linear_cli::client::queries::{
-    IssueUpdateInput, IssueUpdateMutation, IssueUpdateMutationVariables,
+    IssueUpdateInput, IssueUpdateMutation, IssueUpdateMutationVariables, Nullable, TimelessDate,
 };
+use serde_json::Value;
 
 #[test]
 fn test_issue_update_mutation_serializes_patch_fields() {
@@ -10,10 +11,12 @@ fn test_issue_update_mutation_serializes_patch_fields() {
         input: IssueUpdateInput {
             title: Some("New issue title".to_string()),
             description: Some("Updated markdown description".to_string()),
-            assignee_id: Some("user-456".to_string()),
-            project_id: Some("project-789".to_string()),
+            assignee_id: Nullable::Value("user-456".to_string()),
+            project_id: Nullable::Value("project-789".to_string()),
+            project_milestone_id: Nullable::Unset,
             state_id: Some("state-111".to_string()),
             priority: Some(2),
+            ..Default::default()
         },
     });
 
@@ -36,10 +39,12 @@ fn test_issue_update_mutation_omits_unset_optional_patch_fields() {
         input: IssueUpdateInput {
             title: None,
             description: None,
-            assignee_id: None,
-            project_id: Some("project-789".to_string()),
+            assignee_id: Nullable::Unset,
+            project_id: Nullable::Value("project-789".to_string()),
+            project_milestone_id: Nullable::Unset,
             state_id: None,
             priority: None,
+            ..Default::default()
         },
     });
 
@@ -62,3 +67,35 @@ fn test_issue_update_mutation_omits_unset_optional_patch_fields() {
         "priority should be omitted"
     );
 }
+
+#[test]
+fn test_issue_update_mutation_sends_null_for_cleared_fields() {
+    let operation = IssueUpdateMutation::build(IssueUpdateMutationVariables {
+        id: "issue-123".to_string(),
+        input: IssueUpdateInput {
+            assignee_id: Nullable::Null,
+            estimate: Nullable::Null,
+            due_date: Nullable::Value(TimelessDate("2026-11-01".to_string())),
+            cycle_id: Nullable::Null,
+            team_id: Some("team-2".to_string()),
+            subscriber_ids: Some(Vec::new()),
+            sort_order: Some(-12.5),
+            ..Default::default()
+        },
+    });
+
+    let json = serde_json::to_value(&operation).expect("operation should serialize to JSON");
+    let input = json["variables"]["input"]
+        .as_object()
+        .expect("input should be an object");
+
+    assert_eq!(input["assigneeId"], Value::Null);
+    assert_eq!(input["estimate"], Value::Null);
+    assert_eq!(input["cycleId"], Value::Null);
+    assert_eq!(input["dueDate"], "2026-11-01");
+    assert_eq!(input["teamId"], "team-2");
+    assert_eq!(input["subscriberIds"], serde_json::json!([]));
+    assert_eq!(input["sortOrder"], -12.5);
+    assert!(!input.contains_key("projectId"), "unset fields are omitted");
+    assert!(!input.contains_key("projectMilestoneId"));
+}
@@ -0,0 +1,323 @@
+use clap::Parser;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands};
+use linear_cli::client::issues::IssueClient;
+use linear_cli::client::teams::MockTeamClient;
+use linear_cli::error::CliError;
+use linear_cli::git::{GitRepo, issue_identifiers};
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::{handle_from_branch, handle_from_commits};
+use linear_cli::issues::types::{Issue, IssueState, Priority, User};
+use linear_cli::output::OutputFormat;
+use linear_cli::teams::types::Team;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+use tempfile::TempDir;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn issue(identifier: &str) -> Issue {
+    let alice = User {
+        id: "user-1".to_string(),
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+    };
+    Issue {
+        id: format!("id-{identifier}"),
+        identifier: identifier.to_string(),
+        title: format!("Issue {identifier}"),
+        description: None,
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "Done".to_string(),
+        },
+        priority: Priority::Medium,
+        assignee: None,
+        creator: alice,
+        project: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-01T00:00:00Z".to_string(),
+        url: format!("https://linear.app/acme/issue/{identifier}"),
+        comments: None,
+        attachments: None,
+    }
+}
+
+fn team(key: &str) -> Team {
+    Team {
+        id: format!("team-{key}"),
+        key: key.to_string(),
+        name: key.to_string(),
+        description: None,
+        color: None,
+        icon: None,
+        private: false,
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+    }
+}
+
+fn teams() -> MockTeamClient {
+    MockTeamClient {
+        result: Err(CliError::General("not used".to_string())),
+        list_result: Ok(vec![team("ENG"), team("OPS")]),
+    }
+}
+
+/// Knows the issues in `existing`; `linked` maps branch names to identifiers
+struct VcsIssueClient {
+    existing: Vec<&'static str>,
+    linked: HashMap<&'static str, &'static str>,
+    requested: Mutex<Vec<String>>,
+}
+
+impl VcsIssueClient {
+    fn new(existing: &[&'static str]) -> Self {
+        Self {
+            existing: existing.to_vec(),
+            linked: HashMap::new(),
+            requested: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl IssueClient for VcsIssueClient {
+    fn get_issue(&self, _token: &str, identifier: &str) -> Result<Issue, CliError> {
+        self.requested.lock().unwrap().push(identifier.to_string());
+        if self.existing.contains(&identifier) {
+            Ok(issue(identifier))
+        } else {
+            Err(CliError::NotFound(format!("Issue {identifier} not found")))
+        }
+    }
+
+    fn list_issues(
+        &self,
+        _token: &str,
+        _assignee: Option<String>,
+        _project: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<Issue>, CliError> {
+        unreachable!("not used")
+    }
+
+    fn find_issue_by_branch(&self, _token: &str, branch: &str) -> Result<Issue, CliError> {
+        self.linked
+            .get(branch)
+            .map(|identifier| issue(identifier))
+            .ok_or_else(|| CliError::NotFound(format!("No issue found for branch '{branch}'")))
+    }
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+fn commit(dir: &Path, message: &str) {
+    git(dir, &["commit", "--quiet", "--allow-empty", "-m", message]);
+}
+
+/// A repository tagged `v1` after its first commit, with release commits on top
+fn repo() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    git(dir.path(), &["init", "--quiet", "--initial-branch=main"]);
+    commit(dir.path(), "Initial import (ENG-1)");
+    git(dir.path(), &["tag", "v1"]);
+    commit(dir.path(), "Fix login redirect\n\nFixes ENG-12, refs ops-3");
+    commit(dir.path(), "Bump UTF-8 decoder and SHA-256 hashing");
+    commit(
+        dir.path(),
+        "[ENG-40] Add audit log\n\nAlso touches ENG-12 and XYZ-9",
+    );
+    commit(dir.path(), "Remove ENG-404 leftovers");
+    dir
+}
+
+#[test]
+fn test_identifiers_in_commit_messages() {
+    let found: Vec<String> =
+        issue_identifiers("[ENG-40] fix (ops-3), see UTF-8 and 42ENG-1, ENG-12abc").collect();
+    assert_eq!(found, vec!["ENG-40", "OPS-3", "UTF-8"]);
+}
+
+#[test]
+fn test_commit_messages_in_range() {
+    let dir = repo();
+    let messages = GitRepo::at(dir.path()).commit_messages("v1..HEAD").unwrap();
+    assert_eq!(messages.len(), 4);
+    assert_eq!(messages[0], "Remove ENG-404 leftovers");
+    assert_eq!(
+        messages[3],
+        "Fix login redirect\n\nFixes ENG-12, refs ops-3"
+    );
+
+    let repo = GitRepo::at(dir.path());
+    assert!(matches!(
+        repo.commit_messages("no-such-tag..HEAD").unwrap_err(),
+        CliError::General(_)
+    ));
+    assert!(matches!(
+        repo.commit_messages("--all").unwrap_err(),
+        CliError::InvalidArgs(_)
+    ));
+}
+
+#[test]
+fn test_from_commits_lists_referenced_issues_once() {
+    let dir = repo();
+    let client = VcsIssueClient::new(&["ENG-1", "ENG-12", "ENG-40", "OPS-3"]);
+    let io = MockIo::new();
+
+    handle_from_commits(
+        "v1..HEAD",
+        &[],
+        &GitRepo::at(dir.path()),
+        &client,
+        &teams(),
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    // Newest mention first; UTF-8, SHA-256 and XYZ-9 are not team keys
+    assert_eq!(
+        *client.requested.lock().unwrap(),
+        vec!["ENG-404", "ENG-40", "ENG-12", "OPS-3"]
+    );
+    let json: Value = serde_json::from_str(&io.stdout_lines().join("\n")).unwrap();
+    let identifiers: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["identifier"].as_str().unwrap())
+        .collect();
+    assert_eq!(identifiers, vec!["ENG-40", "ENG-12", "OPS-3"]);
+    assert_eq!(io.stderr_lines(), vec!["Skipping ENG-404: issue not found"]);
+}
+
+#[test]
+fn test_from_commits_restricts_to_given_teams() {
+    let dir = repo();
+    let client = VcsIssueClient::new(&["ENG-1", "ENG-12", "ENG-40", "OPS-3"]);
+    let io = MockIo::new();
+
+    handle_from_commits(
+        "HEAD",
+        &["ops".to_string()],
+        &GitRepo::at(dir.path()),
+        &client,
+        &teams(),
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Csv),
+    )
+    .unwrap();
+
+    assert_eq!(*client.requested.lock().unwrap(), vec!["OPS-3"]);
+    let csv = io.stdout_lines().join("\n");
+    assert_eq!(csv.lines().count(), 2);
+    assert!(csv.contains("OPS-3"));
+}
+
+#[test]
+fn test_from_branch_uses_branch_search_then_identifier() {
+    let mut client = VcsIssueClient::new(&["ENG-7"]);
+    client.linked.insert("alice/fix-login", "ENG-5");
+    let show = |branch: &str| {
+        let io = MockIo::new();
+        handle_from_branch(
+            branch,
+            &client,
+            &config(),
+            &TestStorage,
+            &io,
+            Some(OutputFormat::Json),
+        )
+        .map(|()| {
+            let json: Value = serde_json::from_str(&io.stdout_lines().join("\n")).unwrap();
+            json["identifier"].as_str().unwrap().to_string()
+        })
+    };
+
+    assert_eq!(show("alice/fix-login").unwrap(), "ENG-5");
+    assert_eq!(show("feature/eng-7-retry").unwrap(), "ENG-7");
+    let err = show("main").unwrap_err();
+    assert!(matches!(err, CliError::NotFound(ref msg) if msg.contains("'main'")));
+}
+
+#[test]
+fn test_vcs_commands_parse() {
+    let cli = Cli::parse_from([
+        "linear",
+        "issue",
+        "from-commits",
+        "v1.2.0..HEAD",
+        "--team",
+        "ENG",
+        "--team",
+        "OPS",
+        "--markdown",
+    ]);
+    let Commands::Issue {
+        action: IssueCommands::FromCommits { range, teams, .. },
+    } = cli.command
+    else {
+        panic!("expected issue from-commits command");
+    };
+    assert_eq!(range, "v1.2.0..HEAD");
+    assert_eq!(teams, vec!["ENG", "OPS"]);
+
+    let cli = Cli::parse_from(["linear", "issue", "from-branch", "alice/eng-1-fix"]);
+    assert!(matches!(
+        cli.command,
+        Commands::Issue {
+            action: IssueCommands::FromBranch { ref branch, .. }
+        } if branch == "alice/eng-1-fix"
+    ));
+}
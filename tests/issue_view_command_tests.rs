@@ -94,6 +94,14 @@ fn test_view_returns_issue_details_for_valid_identifier() {
         url: "https://linear.app/company/issue/ENG-123".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     let mut config_values = HashMap::new();
@@ -120,6 +128,7 @@ fn test_view_returns_issue_details_for_valid_identifier() {
     let deps = ViewDeps {
         issue_client: &issue_client,
         comment_client: &comment_client,
+        attachment_client: None,
         config: &config,
         storage: &storage,
         io: &io,
@@ -165,6 +174,7 @@ fn test_view_returns_not_found_error_for_invalid_identifier() {
     let deps = ViewDeps {
         issue_client: &issue_client,
         comment_client: &comment_client,
+        attachment_client: None,
         config: &config,
         storage: &storage,
         io: &io,
@@ -210,6 +220,14 @@ fn test_view_returns_auth_error_when_no_token() {
         url: "https://linear.app/company/issue/ENG-123".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     let issue_client = MockIssueClient {
@@ -228,6 +246,7 @@ fn test_view_returns_auth_error_when_no_token() {
     let deps = ViewDeps {
         issue_client: &issue_client,
         comment_client: &comment_client,
+        attachment_client: None,
         config: &config,
         storage: &storage,
         io: &io,
@@ -268,6 +287,7 @@ fn test_view_returns_network_error_on_api_failure() {
     let deps = ViewDeps {
         issue_client: &issue_client,
         comment_client: &comment_client,
+        attachment_client: None,
         config: &config,
         storage: &storage,
         io: &io,
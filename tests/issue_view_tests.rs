@@ -29,6 +29,14 @@ fn test_issue_serializes_to_json() {
         url: "https://linear.app/company/issue/ENG-123".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     let json = serde_json::to_string(&issue).expect("Failed to serialize");
@@ -63,6 +71,14 @@ fn test_issue_with_no_assignee_serializes() {
         url: "https://linear.app/company/issue/ENG-124".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     let json = serde_json::to_string(&issue).expect("Failed to serialize");
@@ -95,6 +111,14 @@ fn test_mock_issue_client_returns_configured_issue() {
         url: "https://linear.app/company/issue/ENG-999".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     };
 
     let mock_client = MockIssueClient {
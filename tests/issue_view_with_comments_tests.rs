@@ -13,6 +13,10 @@ fn create_test_issue_with_comments() -> Issue {
             updated_at: "2024-01-15T10:00:00Z".to_string(),
             edited_at: None,
             issue_identifier: Some("ENG-123".to_string()),
+            resolved_at: None,
+            parent_id: None,
+            replies: Vec::new(),
+            reactions: Vec::new(),
         },
         Comment {
             id: "comment-2".to_string(),
@@ -23,6 +27,10 @@ fn create_test_issue_with_comments() -> Issue {
             updated_at: "2024-01-15T11:00:00Z".to_string(),
             edited_at: None,
             issue_identifier: Some("ENG-123".to_string()),
+            resolved_at: None,
+            parent_id: None,
+            replies: Vec::new(),
+            reactions: Vec::new(),
         },
     ];
 
@@ -51,6 +59,14 @@ fn create_test_issue_with_comments() -> Issue {
         updated_at: "2024-01-15T12:00:00Z".to_string(),
         url: "https://linear.app/team/issue/ENG-123".to_string(),
         comments: Some(comments),
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -76,6 +92,14 @@ fn create_test_issue_without_comments() -> Issue {
         url: "https://linear.app/team/issue/ENG-124".to_string(),
         project: None,
         comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
     }
 }
 
@@ -165,3 +189,25 @@ fn test_issue_with_comments_csv_shows_comment_count() {
     // CSV should include comment count in a field
     assert!(csv.contains("comment_count") || csv.contains("2"));
 }
+
+#[test]
+fn test_issue_markdown_quotes_threaded_replies() {
+    let mut issue = create_test_issue_with_comments();
+    let comments = issue.comments.as_mut().unwrap();
+    let mut reply = comments.remove(1);
+    reply.parent_id = Some("comment-1".to_string());
+    comments[0].replies.push(reply);
+    comments[0].resolved_at = Some("2024-01-16T00:00:00Z".to_string());
+
+    let markdown = issue.to_markdown().unwrap();
+    assert!(markdown.contains(
+        "**Alice** (2024-01-15T10:00:00Z) · resolved\nFirst comment on this issue\n\n\
+         > **Bob** (2024-01-15T11:00:00Z)\n> Second comment with more details\n"
+    ));
+
+    // Replies count towards the total
+    let table = issue.to_table().unwrap();
+    assert!(table.contains('2'));
+    let json: serde_json::Value = serde_json::from_str(&issue.to_json().unwrap()).unwrap();
+    assert_eq!(json["comments"][0]["replies"][0]["id"], "comment-2");
+}
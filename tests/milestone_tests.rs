@@ -0,0 +1,327 @@
+use clap::Parser;
+use cynic::{MutationBuilder, QueryBuilder};
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{
+    Cli, Commands, IssueCommands, IssueUpdatePatchArgs, ProjectCommands, ProjectMilestoneCommands,
+};
+use linear_cli::client::milestones::{
+    CreateMilestoneInput, MockMilestoneClient, UpdateMilestoneInput,
+};
+use linear_cli::client::queries::{
+    ProjectMilestoneCreateInput, ProjectMilestoneCreateMutation,
+    ProjectMilestoneCreateMutationVariables, ProjectMilestonesQuery,
+    ProjectMilestonesQueryVariables, TimelessDate,
+};
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::milestones::commands::{handle_create, handle_delete, handle_list, handle_update};
+use linear_cli::milestones::{MilestoneList, ProjectMilestone};
+use linear_cli::output::{Formattable, OutputFormat};
+use linear_cli::projects::types::Project;
+use serde_json::Value;
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn milestone(name: &str, status: &str, progress: f64) -> ProjectMilestone {
+    ProjectMilestone {
+        id: format!("ms-{}", name.to_lowercase()),
+        name: name.to_string(),
+        description: None,
+        target_date: Some("2026-06-30".to_string()),
+        status: status.to_string(),
+        progress,
+    }
+}
+
+fn project(milestones: Vec<ProjectMilestone>) -> Project {
+    Project {
+        id: "project-1".to_string(),
+        name: "Platform".to_string(),
+        description: "Platform work".to_string(),
+        content: None,
+        slug_id: "a1b2c3".to_string(),
+        url: "https://linear.app/acme/project/platform-a1b2c3".to_string(),
+        color: "#2563eb".to_string(),
+        icon: None,
+        status_name: "In Progress".to_string(),
+        status_type: "started".to_string(),
+        status_color: "#60a5fa".to_string(),
+        progress: 0.5,
+        priority: 2,
+        priority_label: "High".to_string(),
+        start_date: None,
+        target_date: None,
+        created_at: "2026-02-01T00:00:00Z".to_string(),
+        updated_at: "2026-02-24T00:00:00Z".to_string(),
+        lead_name: None,
+        milestones,
+        issue_progress: None,
+        open_issues: None,
+        members: None,
+    }
+}
+
+#[test]
+fn test_project_view_lists_milestones_with_progress() {
+    let project = project(vec![
+        milestone("Alpha", "done", 1.0),
+        milestone("Beta", "next", 0.375),
+    ]);
+
+    let markdown = project.to_markdown().unwrap();
+    assert!(markdown.contains(
+        "## Milestones (2)\n\n| Milestone | Status | Progress | Target |\n|---|---|---|---|\n"
+    ));
+    assert!(markdown.contains("| Alpha | done | 100% | 2026-06-30 |"));
+    assert!(markdown.contains("| Beta | next | 38% | 2026-06-30 |"));
+
+    let table = project.to_table().unwrap();
+    assert!(table.contains("Beta (next, 38%)"));
+}
+
+#[test]
+fn test_project_without_milestones_omits_section() {
+    let markdown = project(Vec::new()).to_markdown().unwrap();
+    assert!(!markdown.contains("Milestones"));
+}
+
+#[test]
+fn test_milestone_list_formats() {
+    let list = MilestoneList(vec![milestone("Beta", "overdue", 0.5)]);
+
+    let csv = list.to_csv().unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "id,name,status,progress,target_date");
+    assert_eq!(lines[1], "ms-beta,Beta,overdue,0.50,2026-06-30");
+
+    assert!(
+        list.to_markdown()
+            .unwrap()
+            .starts_with("## Milestones (1)\n")
+    );
+
+    let json: Value = serde_json::from_str(&list.to_json().unwrap()).unwrap();
+    assert_eq!(json[0]["status"], "overdue");
+}
+
+#[test]
+fn test_list_prints_project_milestones() {
+    let client = MockMilestoneClient::new(milestone("Beta", "next", 0.25));
+    let io = MockIo::new();
+
+    handle_list(
+        "platform",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(value[0]["name"], "Beta");
+}
+
+#[test]
+fn test_create_validates_target_date() {
+    let client = MockMilestoneClient::new(milestone("Beta", "unstarted", 0.0));
+    let input = CreateMilestoneInput {
+        project: "platform".to_string(),
+        name: "Beta".to_string(),
+        description: None,
+        target_date: Some("2026-06-30".to_string()),
+    };
+
+    handle_create(
+        input.clone(),
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+    assert_eq!(*client.creates.lock().unwrap(), vec![input.clone()]);
+
+    let err = handle_create(
+        CreateMilestoneInput {
+            target_date: Some("30/06/2026".to_string()),
+            ..input
+        },
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("YYYY-MM-DD")));
+    assert_eq!(client.creates.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_update_requires_a_change() {
+    let client = MockMilestoneClient::new(milestone("Beta", "next", 0.25));
+
+    let err = handle_update(
+        "ms-beta",
+        UpdateMilestoneInput::default(),
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(_)));
+
+    handle_update(
+        "ms-beta",
+        UpdateMilestoneInput {
+            name: Some("Beta 2".to_string()),
+            ..Default::default()
+        },
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+    let updates = client.updates.lock().unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].0, "ms-beta");
+}
+
+#[test]
+fn test_delete_reports_deleted_id() {
+    let client = MockMilestoneClient::new(milestone("Beta", "next", 0.25));
+    let io = MockIo::new();
+
+    handle_delete(
+        "ms-beta",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Table(Default::default())),
+    )
+    .unwrap();
+
+    assert_eq!(*client.deletes.lock().unwrap(), vec!["ms-beta".to_string()]);
+    assert_eq!(io.stdout_lines().concat(), "Deleted milestone ms-beta");
+
+    let io = MockIo::new();
+    handle_delete(
+        "ms-beta",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(value["deleted"], true);
+}
+
+#[test]
+fn test_milestone_operations_serialize_variables() {
+    let operation = ProjectMilestonesQuery::build(ProjectMilestonesQueryVariables { filter: None });
+    let json = serde_json::to_value(&operation).unwrap();
+    assert!(
+        json["query"]
+            .as_str()
+            .unwrap()
+            .contains("projectMilestones")
+    );
+
+    let operation =
+        ProjectMilestoneCreateMutation::build(ProjectMilestoneCreateMutationVariables {
+            input: ProjectMilestoneCreateInput {
+                name: "Beta".to_string(),
+                project_id: "project-1".to_string(),
+                description: None,
+                target_date: Some(TimelessDate("2026-06-30".to_string())),
+            },
+        });
+    let json = serde_json::to_value(&operation).unwrap();
+    assert_eq!(json["variables"]["input"]["projectId"], "project-1");
+    assert_eq!(json["variables"]["input"]["targetDate"], "2026-06-30");
+}
+
+#[test]
+fn test_milestone_cli_commands_parse() {
+    let cli = Cli::parse_from([
+        "linear",
+        "project",
+        "milestone",
+        "create",
+        "--project",
+        "platform",
+        "--name",
+        "Beta",
+        "--target-date",
+        "2026-06-30",
+    ]);
+    let Commands::Project {
+        action:
+            ProjectCommands::Milestone {
+                action:
+                    ProjectMilestoneCommands::Create {
+                        project,
+                        name,
+                        target_date,
+                        ..
+                    },
+            },
+    } = cli.command
+    else {
+        panic!("expected project milestone create command");
+    };
+    assert_eq!((project.as_str(), name.as_str()), ("platform", "Beta"));
+    assert_eq!(target_date.as_deref(), Some("2026-06-30"));
+
+    let cli = Cli::parse_from(["linear", "issue", "update", "ENG-1", "--milestone", "ms-1"]);
+    let Commands::Issue {
+        action:
+            IssueCommands::Update {
+                patch: IssueUpdatePatchArgs { milestone, .. },
+                ..
+            },
+    } = cli.command
+    else {
+        panic!("expected issue update command");
+    };
+    assert_eq!(milestone.as_deref(), Some("ms-1"));
+
+    assert!(Cli::try_parse_from(["linear", "project", "milestone", "delete"]).is_err());
+}
@@ -0,0 +1,511 @@
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use chrono::{TimeZone, Utc};
+use clap::Parser;
+use cynic::QueryBuilder;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands};
+use linear_cli::client::comments::CommentClient;
+use linear_cli::client::cycles::CycleClient;
+use linear_cli::client::issues::{CreateIssueInput, IssueClient};
+use linear_cli::client::projects::ProjectClient;
+use linear_cli::client::queries::{SyncIssuesQuery, SyncQueryVariables};
+use linear_cli::client::search::SearchClient;
+use linear_cli::client::states::StateClient;
+use linear_cli::client::sync::{MockSyncClient, SyncRecords};
+use linear_cli::client::teams::TeamClient;
+use linear_cli::comments::types::Comment;
+use linear_cli::cycles::types::Cycle;
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::types::{Issue, IssueProject, IssueState, Priority, User};
+use linear_cli::mirror::client::MirrorClient;
+use linear_cli::mirror::commands::handle_sync;
+use linear_cli::mirror::sync::run_sync;
+use linear_cli::mirror::{Mirror, SyncResource};
+use linear_cli::output::OutputFormat;
+use linear_cli::states::types::WorkflowState;
+use linear_cli::teams::types::Team;
+use predicates::prelude::*;
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn user(id: &str, name: &str) -> User {
+    User {
+        id: id.to_string(),
+        name: name.to_string(),
+        email: format!("{}@example.com", name.to_lowercase()),
+    }
+}
+
+fn issue(identifier: &str, title: &str, assignee: Option<User>, created_at: &str) -> Issue {
+    Issue {
+        id: format!("id-{identifier}"),
+        identifier: identifier.to_string(),
+        title: title.to_string(),
+        description: Some(format!("Details for {title}")),
+        state: IssueState {
+            id: "state-todo".to_string(),
+            name: "Todo".to_string(),
+        },
+        priority: Priority::High,
+        assignee,
+        creator: user("user-alice", "Alice"),
+        project: Some(IssueProject {
+            id: "project-1".to_string(),
+            name: "Offline Mode".to_string(),
+            slug_id: "offline-mode".to_string(),
+        }),
+        created_at: created_at.to_string(),
+        updated_at: created_at.to_string(),
+        url: format!("https://linear.app/issue/{identifier}"),
+        comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+    }
+}
+
+fn team(key: &str, name: &str) -> Team {
+    Team {
+        id: format!("team-{key}"),
+        key: key.to_string(),
+        name: name.to_string(),
+        description: None,
+        color: None,
+        icon: None,
+        private: false,
+        created_at: "2025-01-01T00:00:00.000Z".to_string(),
+    }
+}
+
+fn state(name: &str, team_name: &str, position: f64) -> WorkflowState {
+    WorkflowState {
+        id: format!("state-{name}-{team_name}"),
+        name: name.to_string(),
+        state_type: "unstarted".to_string(),
+        color: "#ccc".to_string(),
+        position,
+        description: None,
+        team_name: Some(team_name.to_string()),
+    }
+}
+
+fn comment(id: &str, issue_identifier: &str, body: &str, created_at: &str) -> Comment {
+    Comment {
+        id: id.to_string(),
+        body: body.to_string(),
+        user_name: "Bob".to_string(),
+        user_email: "bob@example.com".to_string(),
+        created_at: created_at.to_string(),
+        updated_at: created_at.to_string(),
+        edited_at: None,
+        issue_identifier: Some(issue_identifier.to_string()),
+        resolved_at: None,
+        parent_id: None,
+        replies: Vec::new(),
+        reactions: Vec::new(),
+    }
+}
+
+fn cycle(id: &str, starts_at: &str, ends_at: &str) -> Cycle {
+    Cycle {
+        id: id.to_string(),
+        name: format!("Cycle {id}"),
+        number: 1.0,
+        description: None,
+        starts_at: starts_at.to_string(),
+        ends_at: ends_at.to_string(),
+        created_at: starts_at.to_string(),
+        completed_at: None,
+        progress: 0.5,
+        is_active: false,
+        is_future: false,
+        is_next: false,
+        is_past: false,
+        is_previous: false,
+        team_name: "Engineering".to_string(),
+        team_key: "ENG".to_string(),
+    }
+}
+
+fn sync_client() -> MockSyncClient {
+    MockSyncClient {
+        pages: vec![
+            SyncRecords::Teams(vec![team("ENG", "Engineering"), team("OPS", "Operations")]),
+            SyncRecords::States(vec![
+                state("Done", "Engineering", 3.0),
+                state("Todo", "Engineering", 1.0),
+                state("Todo", "Operations", 1.0),
+            ]),
+            SyncRecords::Issues(vec![
+                issue(
+                    "ENG-1",
+                    "Cache reference data",
+                    Some(user("user-me", "Me")),
+                    "2025-01-01T00:00:00.000Z",
+                ),
+                issue("ENG-2", "Write docs", None, "2025-01-02T00:00:00.000Z"),
+            ]),
+            // Second page of issues
+            SyncRecords::Issues(vec![issue(
+                "OPS-7",
+                "Rotate certificates",
+                Some(user("user-bob", "Bob")),
+                "2025-01-03T00:00:00.000Z",
+            )]),
+            SyncRecords::Comments(vec![
+                comment("c2", "ENG-1", "Second", "2025-01-05T00:00:00.000Z"),
+                comment(
+                    "c1",
+                    "ENG-1",
+                    "First mention of sqlite",
+                    "2025-01-04T00:00:00.000Z",
+                ),
+                comment("c3", "OPS-7", "Unrelated", "2025-01-06T00:00:00.000Z"),
+            ]),
+            SyncRecords::Cycles(vec![
+                cycle(
+                    "past",
+                    "2025-01-01T00:00:00.000Z",
+                    "2025-01-15T00:00:00.000Z",
+                ),
+                cycle(
+                    "now",
+                    "2025-01-15T00:00:00.000Z",
+                    "2025-01-29T00:00:00.000Z",
+                ),
+            ]),
+        ],
+        viewer: Some(UserInfo {
+            id: "user-me".to_string(),
+            name: "Me".to_string(),
+            email: "me@example.com".to_string(),
+        }),
+        ..MockSyncClient::default()
+    }
+}
+
+fn synced_mirror() -> Mirror {
+    let mut mirror = Mirror::open_in_memory().unwrap();
+    run_sync(
+        &sync_client(),
+        "token",
+        &mut mirror,
+        &SyncResource::ALL,
+        false,
+        Utc.with_ymd_and_hms(2025, 1, 20, 12, 0, 0).unwrap(),
+    )
+    .unwrap();
+    mirror
+}
+
+fn mirror_client() -> MirrorClient {
+    MirrorClient::new(synced_mirror())
+        .with_now(Utc.with_ymd_and_hms(2025, 1, 20, 12, 0, 0).unwrap())
+}
+
+#[test]
+fn test_sync_query_filters_on_updated_at_and_paginates() {
+    let operation = SyncIssuesQuery::build(SyncQueryVariables {
+        first: Some(100),
+        after: None,
+        since: None,
+    });
+
+    assert!(operation.query.contains("updatedAt: {gt: $since}"));
+    assert!(operation.query.contains("after: $after"));
+    assert!(operation.query.contains("endCursor"));
+}
+
+#[test]
+fn test_sync_stores_all_pages_and_records_watermarks() {
+    let client = sync_client();
+    let mut mirror = Mirror::open_in_memory().unwrap();
+
+    let reports = run_sync(
+        &client,
+        "token",
+        &mut mirror,
+        &SyncResource::ALL,
+        false,
+        Utc.with_ymd_and_hms(2025, 1, 20, 12, 0, 0).unwrap(),
+    )
+    .unwrap();
+
+    let issues = reports.iter().find(|r| r.resource == "issues").unwrap();
+    assert_eq!(issues.fetched, 3, "both issue pages should be stored");
+    assert_eq!(issues.total, 3);
+    assert_eq!(issues.since, None);
+    assert_eq!(issues.watermark, "2025-01-20T11:55:00.000Z");
+    assert_eq!(mirror.count(SyncResource::Comments).unwrap(), 3);
+    assert_eq!(
+        mirror.watermark(SyncResource::Teams).unwrap().as_deref(),
+        Some("2025-01-20T11:55:00.000Z")
+    );
+}
+
+#[test]
+fn test_incremental_sync_requests_changes_since_watermark() {
+    let mut mirror = synced_mirror();
+    let client = sync_client();
+
+    let reports = run_sync(
+        &client,
+        "token",
+        &mut mirror,
+        &[SyncResource::Issues],
+        false,
+        Utc.with_ymd_and_hms(2025, 1, 21, 0, 0, 0).unwrap(),
+    )
+    .unwrap();
+
+    let requests = client.requests.lock().unwrap();
+    assert!(
+        requests
+            .iter()
+            .all(|(_, since)| since.as_deref() == Some("2025-01-20T11:55:00.000Z"))
+    );
+    assert_eq!(
+        reports[0].total, 3,
+        "re-fetched records are upserted, not duplicated"
+    );
+}
+
+#[test]
+fn test_full_sync_ignores_watermarks() {
+    let mut mirror = synced_mirror();
+    let client = sync_client();
+
+    run_sync(
+        &client,
+        "token",
+        &mut mirror,
+        &[SyncResource::Teams],
+        true,
+        Utc.with_ymd_and_hms(2025, 1, 21, 0, 0, 0).unwrap(),
+    )
+    .unwrap();
+
+    let requests = client.requests.lock().unwrap();
+    assert_eq!(requests.as_slice(), &[(SyncResource::Teams, None)]);
+}
+
+#[test]
+fn test_mirror_client_gets_issue_by_identifier_case_insensitively() {
+    let client = mirror_client();
+
+    let issue = client.get_issue("", "eng-1").unwrap();
+    assert_eq!(issue.title, "Cache reference data");
+
+    let err = client.get_issue("", "ENG-404").unwrap_err();
+    assert!(matches!(err, CliError::NotFound(ref msg) if msg.contains("linear sync")));
+}
+
+#[test]
+fn test_mirror_client_lists_issues_with_api_filters() {
+    let client = mirror_client();
+
+    let all = client.list_issues("", None, None, 50).unwrap();
+    let identifiers: Vec<&str> = all.iter().map(|i| i.identifier.as_str()).collect();
+    assert_eq!(identifiers, vec!["OPS-7", "ENG-2", "ENG-1"], "newest first");
+
+    let mine = client
+        .list_issues("", Some("@me".to_string()), None, 50)
+        .unwrap();
+    assert_eq!(mine.len(), 1);
+    assert_eq!(mine[0].identifier, "ENG-1");
+
+    let bobs = client
+        .list_issues("", Some("bob@example.com".to_string()), None, 50)
+        .unwrap();
+    assert_eq!(bobs[0].identifier, "OPS-7");
+
+    let by_slug = client
+        .list_issues("", None, Some("offline-mode".to_string()), 2)
+        .unwrap();
+    assert_eq!(by_slug.len(), 2, "limit applies");
+}
+
+#[test]
+fn test_mirror_client_lists_project_issues_for_breakdowns() {
+    let client = mirror_client();
+
+    let issues = client.list_project_issues("", "project-1").unwrap();
+    let identifiers: Vec<&str> = issues.iter().map(|i| i.identifier.as_str()).collect();
+    assert_eq!(identifiers, vec!["ENG-1", "ENG-2", "OPS-7"], "oldest first");
+    assert_eq!(issues[0].assignee_name.as_deref(), Some("Me"));
+    assert_eq!(issues[1].assignee_name, None);
+    assert!(issues.iter().all(|i| i.estimate.is_none()));
+
+    let err = client.list_project_members("", "project-1").unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("--offline")));
+}
+
+#[test]
+fn test_mirror_client_lists_comments_oldest_first() {
+    let client = mirror_client();
+
+    let comments = client.list_comments("", "id-ENG-1", 10).unwrap();
+
+    let ids: Vec<&str> = comments.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["c1", "c2"]);
+}
+
+#[test]
+fn test_mirror_client_searches_titles_descriptions_and_comments() {
+    let client = mirror_client();
+
+    let by_title = client
+        .search_issues("", "certificates", None, false, 10)
+        .unwrap();
+    assert_eq!(by_title[0].identifier, "OPS-7");
+
+    let without_comments = client.search_issues("", "sqlite", None, false, 10).unwrap();
+    assert!(without_comments.is_empty());
+
+    let with_comments = client.search_issues("", "sqlite", None, true, 10).unwrap();
+    assert_eq!(with_comments[0].identifier, "ENG-1");
+
+    let scoped = client
+        .search_issues("", "e", Some("ops"), false, 10)
+        .unwrap();
+    assert!(scoped.iter().all(|i| i.identifier.starts_with("OPS-")));
+}
+
+#[test]
+fn test_mirror_client_resolves_teams_and_states_by_key() {
+    let client = mirror_client();
+
+    assert_eq!(client.get_team("", "eng").unwrap().name, "Engineering");
+
+    let states = client.list_states("", Some("ENG"), 10).unwrap();
+    let names: Vec<&str> = states.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["Todo", "Done"]);
+}
+
+#[test]
+fn test_mirror_client_recomputes_cycle_timing() {
+    let client = mirror_client();
+
+    let cycles = client.list_cycles("", 10).unwrap();
+
+    let current = cycles.iter().find(|c| c.is_active).unwrap();
+    assert_eq!(current.id, "now");
+    assert!(cycles.iter().any(|c| c.id == "past" && c.is_past));
+}
+
+#[test]
+fn test_mirror_client_rejects_writes() {
+    let client = mirror_client();
+
+    let err = client
+        .create_issue("", CreateIssueInput::default())
+        .unwrap_err();
+
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("--offline")));
+}
+
+#[test]
+fn test_handle_sync_writes_mirror_file_and_reports() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested").join("mirror.sqlite3");
+    let config = TestConfigProvider {
+        values: HashMap::from([
+            ("LINEAR_TOKEN".to_string(), "token".to_string()),
+            (
+                "LINEAR_CLI_MIRROR_PATH".to_string(),
+                path.display().to_string(),
+            ),
+        ]),
+    };
+    let io = MockIo::new();
+
+    handle_sync(
+        &[SyncResource::Issues, SyncResource::Teams],
+        false,
+        &sync_client(),
+        &config,
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    let resources: Vec<&str> = json["resources"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["resource"].as_str().unwrap())
+        .collect();
+    assert_eq!(resources, vec!["teams", "issues"], "canonical sync order");
+
+    let offline = MirrorClient::open(&config).unwrap();
+    assert_eq!(offline.list_teams("", 10).unwrap().len(), 2);
+}
+
+#[test]
+fn test_mirror_client_open_reports_missing_mirror() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = TestConfigProvider {
+        values: HashMap::from([(
+            "LINEAR_CLI_MIRROR_PATH".to_string(),
+            dir.path().join("missing.sqlite3").display().to_string(),
+        )]),
+    };
+
+    let err = MirrorClient::open(&config).err().unwrap();
+
+    assert!(matches!(err, CliError::NotFound(ref msg) if msg.contains("linear sync")));
+}
+
+#[test]
+fn test_offline_flag_is_global_and_sync_accepts_only_list() {
+    let cli = Cli::parse_from(["linear", "issue", "list", "--offline"]);
+    assert!(cli.offline);
+
+    let cli = Cli::parse_from(["linear", "sync", "--only", "issues,comments", "--full"]);
+    let Commands::Sync { full, only, .. } = cli.command else {
+        panic!("expected sync command");
+    };
+    assert!(full);
+    assert_eq!(only, vec![SyncResource::Issues, SyncResource::Comments]);
+}
+
+#[test]
+fn test_sync_cannot_run_offline() {
+    Command::cargo_bin("linear-cli")
+        .unwrap()
+        .args(["sync", "--offline"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("--offline"));
+}
@@ -0,0 +1,456 @@
+use chrono::{TimeZone, Utc};
+use clap::Parser;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, NotificationCommands};
+use linear_cli::client::issues::IssueClient;
+use linear_cli::client::notifications::MockNotificationClient;
+use linear_cli::dates::FixedClock;
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::handle_subscribe;
+use linear_cli::issues::types::{Issue, IssueState, Priority, User};
+use linear_cli::notifications::commands::{
+    handle_archive, handle_list, handle_read, handle_snooze,
+};
+use linear_cli::notifications::types::parse_snooze_until;
+use linear_cli::notifications::{Notification, NotificationList};
+use linear_cli::output::{Formattable, OutputFormat};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn notification(id: &str, title: &str, read_at: Option<&str>) -> Notification {
+    Notification {
+        id: id.to_string(),
+        kind: "issueNewComment".to_string(),
+        title: title.to_string(),
+        subtitle: Some("Alice commented".to_string()),
+        url: format!("https://linear.app/acme/notification/{id}"),
+        actor_name: Some("Alice".to_string()),
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        read_at: read_at.map(str::to_string),
+        snoozed_until_at: None,
+    }
+}
+
+fn inbox() -> Vec<Notification> {
+    vec![
+        notification("n1", "ENG-1 Fix login", None),
+        notification("n2", "ENG-2 Slow search", Some("2026-03-02T00:00:00Z")),
+        notification("n3", "ENG-3 Crash on start", None),
+    ]
+}
+
+/// Records subscription changes and echoes back a fixed issue
+#[derive(Default)]
+struct RecordingIssueClient {
+    subscriptions: Mutex<Vec<(String, bool)>>,
+}
+
+impl IssueClient for RecordingIssueClient {
+    fn get_issue(&self, _token: &str, _identifier: &str) -> Result<Issue, CliError> {
+        unreachable!("not used")
+    }
+
+    fn list_issues(
+        &self,
+        _token: &str,
+        _assignee: Option<String>,
+        _project: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<Issue>, CliError> {
+        unreachable!("not used")
+    }
+
+    fn set_issue_subscribed(
+        &self,
+        _token: &str,
+        id: &str,
+        subscribed: bool,
+    ) -> Result<Issue, CliError> {
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .push((id.to_string(), subscribed));
+        Ok(Issue {
+            id: "issue-1".to_string(),
+            identifier: id.to_string(),
+            title: "Fix login".to_string(),
+            description: None,
+            state: IssueState {
+                id: "state-1".to_string(),
+                name: "Todo".to_string(),
+            },
+            priority: Priority::Medium,
+            assignee: None,
+            creator: User {
+                id: "user-1".to_string(),
+                name: "Alice".to_string(),
+                email: "alice@example.com".to_string(),
+            },
+            project: None,
+            created_at: "2026-03-01T00:00:00Z".to_string(),
+            updated_at: "2026-03-01T00:00:00Z".to_string(),
+            url: format!("https://linear.app/acme/issue/{id}"),
+            comments: None,
+            attachments: None,
+            team: None,
+            cycle: None,
+            estimate: None,
+            due_date: None,
+            sort_order: None,
+            subscribers: None,
+            branch_name: None,
+        })
+    }
+}
+
+#[test]
+fn test_subscribe_and_unsubscribe() {
+    let client = RecordingIssueClient::default();
+    let io = MockIo::new();
+
+    for subscribed in [true, false] {
+        handle_subscribe(
+            "ENG-1",
+            subscribed,
+            &client,
+            &config(),
+            &TestStorage,
+            &io,
+            Some(OutputFormat::Markdown(Default::default())),
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        *client.subscriptions.lock().unwrap(),
+        vec![("ENG-1".to_string(), true), ("ENG-1".to_string(), false)]
+    );
+    assert_eq!(
+        io.stdout_lines(),
+        vec!["Subscribed to ENG-1", "Unsubscribed from ENG-1"]
+    );
+}
+
+#[test]
+fn test_list_unread_filters_read_notifications() {
+    let client = MockNotificationClient::new(inbox());
+    let io = MockIo::new();
+
+    handle_list(
+        true,
+        50,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    let ids: Vec<&str> = value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["n1", "n3"]);
+}
+
+#[test]
+fn test_list_unread_finds_notifications_beyond_the_first_page() {
+    let read_at = Some("2026-01-01T00:00:00.000Z");
+    let mut inbox: Vec<Notification> = (1..=300)
+        .map(|n| notification(&format!("read{n}"), "ENG-1 Fix login", read_at))
+        .collect();
+    inbox.extend((1..=3).map(|n| notification(&format!("n{n}"), "ENG-2 Add SSO", None)));
+    let client = MockNotificationClient::new(inbox);
+    let io = MockIo::new();
+
+    handle_list(
+        true,
+        2,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    let ids: Vec<&str> = value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["n1", "n2"]);
+}
+
+#[test]
+fn test_read_single_notification() {
+    let client = MockNotificationClient::new(inbox());
+    let io = MockIo::new();
+
+    handle_read(
+        Some("n1"),
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let updates = client.updates.lock().unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].0, "n1");
+    assert!(updates[0].1.read_at.is_some());
+    assert!(updates[0].1.snoozed_until_at.is_none());
+
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert!(value["read_at"].is_string());
+}
+
+#[test]
+fn test_read_all_marks_only_unread_notifications() {
+    let client = MockNotificationClient::new(inbox());
+    let io = MockIo::new();
+
+    handle_read(
+        None,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    let ids: Vec<String> = client
+        .updates
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(id, _)| id.clone())
+        .collect();
+    assert_eq!(ids, vec!["n1", "n3"]);
+    assert_eq!(io.stdout_lines(), vec!["Marked 2 notification(s) as read"]);
+}
+
+#[test]
+fn test_read_and_archive_all_cover_the_whole_inbox() {
+    let inbox: Vec<Notification> = (1..=600)
+        .map(|n| notification(&format!("n{n}"), "ENG-1 Fix login", None))
+        .collect();
+    let client = MockNotificationClient::new(inbox);
+    let io = MockIo::new();
+
+    handle_read(
+        None,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+    handle_archive(
+        None,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(client.updates.lock().unwrap().len(), 600);
+    assert_eq!(client.archived.lock().unwrap().len(), 600);
+    let lines = io.stdout_lines();
+    assert_eq!(lines[0], r#"{"read":600}"#);
+    assert_eq!(lines[1], r#"{"archived":600}"#);
+}
+
+#[test]
+fn test_archive_single_and_all() {
+    let client = MockNotificationClient::new(inbox());
+    let io = MockIo::new();
+
+    handle_archive(
+        Some("n2"),
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+    handle_archive(
+        None,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *client.archived.lock().unwrap(),
+        vec!["n2", "n1", "n2", "n3"]
+    );
+    let lines = io.stdout_lines();
+    let single: Value = serde_json::from_str(&lines[0]).unwrap();
+    assert_eq!(single["id"], "n2");
+    let all: Value = serde_json::from_str(&lines[1]).unwrap();
+    assert_eq!(all["archived"], 3);
+}
+
+#[test]
+fn test_snooze_sends_resolved_timestamp() {
+    let client = MockNotificationClient::new(inbox());
+    let io = MockIo::new();
+
+    handle_snooze(
+        "n1",
+        "2099-04-01T09:00:00+02:00",
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let updates = client.updates.lock().unwrap();
+    assert_eq!(
+        updates[0].1.snoozed_until_at.as_deref(),
+        Some("2099-04-01T07:00:00.000Z")
+    );
+    assert!(updates[0].1.read_at.is_none());
+}
+
+#[test]
+fn test_snooze_rejects_bad_duration_before_calling_api() {
+    let client = MockNotificationClient::new(inbox());
+
+    let err = handle_snooze(
+        "n1",
+        "soon",
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::InvalidArgs(_)));
+    assert!(client.updates.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_snooze_until() {
+    let clock = FixedClock::utc(Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap());
+
+    assert_eq!(
+        parse_snooze_until("2d", &clock).unwrap(),
+        "2026-03-03T12:00:00.000Z"
+    );
+    assert_eq!(
+        parse_snooze_until("30m", &clock).unwrap(),
+        "2026-03-01T12:30:00.000Z"
+    );
+    assert_eq!(
+        parse_snooze_until("1w", &clock).unwrap(),
+        "2026-03-08T12:00:00.000Z"
+    );
+    assert_eq!(
+        parse_snooze_until("2026-03-05T09:00:00+02:00", &clock).unwrap(),
+        "2026-03-05T07:00:00.000Z"
+    );
+    assert_eq!(
+        parse_snooze_until("monday", &clock).unwrap(),
+        "2026-03-02T00:00:00.000Z"
+    );
+    for bad in ["", "0d", "-1h", "2y", "2日", "yesterday"] {
+        assert!(
+            parse_snooze_until(bad, &clock).is_err(),
+            "{bad} should fail"
+        );
+    }
+}
+
+#[test]
+fn test_notification_list_formats_mark_unread() {
+    let list = NotificationList(inbox());
+
+    let markdown = list.to_markdown().unwrap();
+    assert!(markdown.starts_with("## Notifications (3, 2 unread)\n"));
+    assert!(markdown.contains(
+        "- **[ENG-1 Fix login](https://linear.app/acme/notification/n1)** — Alice commented (`n1`)"
+    ));
+    assert!(markdown.contains("- [ENG-2 Slow search]("));
+
+    let table = list.to_table().unwrap();
+    let row = table.lines().find(|l| l.contains("ENG-1")).unwrap();
+    assert!(row.contains('●'));
+    let row = table.lines().find(|l| l.contains("ENG-2")).unwrap();
+    assert!(!row.contains('●'));
+
+    let csv = list.to_csv().unwrap();
+    assert!(
+        csv.starts_with("id,type,title,subtitle,actor,created_at,read_at,snoozed_until_at,url\n")
+    );
+}
+
+#[test]
+fn test_notification_cli_commands_parse() {
+    let cli = Cli::parse_from(["linear", "notification", "snooze", "n1", "--until", "2d"]);
+    let Commands::Notification {
+        action: NotificationCommands::Snooze { id, until, .. },
+    } = cli.command
+    else {
+        panic!("expected notification snooze command");
+    };
+    assert_eq!((id.as_str(), until.as_str()), ("n1", "2d"));
+
+    assert!(Cli::try_parse_from(["linear", "notification", "read", "--all"]).is_ok());
+    assert!(Cli::try_parse_from(["linear", "notification", "read"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "notification", "archive", "n1", "--all"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "notification", "list", "--unread"]).is_ok());
+    assert!(Cli::try_parse_from(["linear", "issue", "subscribe", "ENG-1"]).is_ok());
+    assert!(Cli::try_parse_from(["linear", "issue", "unsubscribe", "ENG-1"]).is_ok());
+}
@@ -0,0 +1,223 @@
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::client::issues::IssueClient;
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::handle_list;
+use linear_cli::issues::types::{Issue, IssueList, IssueState, Priority, User};
+use linear_cli::output::{OutputFormat, detect_format_with_provider, format_output};
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+/// Issue client that serves results in fixed-size pages
+struct PagedIssueClient {
+    pages: Vec<Vec<Issue>>,
+}
+
+impl IssueClient for PagedIssueClient {
+    fn get_issue(&self, _token: &str, identifier: &str) -> Result<Issue, CliError> {
+        Err(CliError::NotFound(identifier.to_string()))
+    }
+
+    fn list_issues(
+        &self,
+        _token: &str,
+        _assignee: Option<String>,
+        _project: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<Issue>, CliError> {
+        Ok(self.pages.concat())
+    }
+
+    fn list_issues_paged(
+        &self,
+        _token: &str,
+        _assignee: Option<String>,
+        _project: Option<String>,
+        _limit: usize,
+        on_page: &mut dyn FnMut(Vec<Issue>) -> Result<(), CliError>,
+    ) -> Result<(), CliError> {
+        for page in &self.pages {
+            on_page(page.clone())?;
+        }
+        Ok(())
+    }
+}
+
+fn sample_issue(identifier: &str) -> Issue {
+    Issue {
+        id: format!("id-{identifier}"),
+        identifier: identifier.to_string(),
+        title: format!("Issue {identifier}"),
+        description: Some("Multi-line\ndescription".to_string()),
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "Todo".to_string(),
+        },
+        priority: Priority::High,
+        assignee: None,
+        creator: User {
+            id: "user-1".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        },
+        project: None,
+        created_at: "2025-01-01T00:00:00Z".to_string(),
+        updated_at: "2025-01-01T00:00:00Z".to_string(),
+        url: format!("https://linear.app/issue/{identifier}"),
+        comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+    }
+}
+
+fn test_config() -> TestConfigProvider {
+    let mut values = HashMap::new();
+    values.insert("LINEAR_TOKEN".to_string(), "test_token".to_string());
+    TestConfigProvider { values }
+}
+
+#[test]
+fn test_detect_format_returns_jsonl_for_jsonl_and_ndjson() {
+    for value in ["jsonl", "ndjson", "JSONL"] {
+        let mut values = HashMap::new();
+        values.insert("LINEAR_CLI_FORMAT".to_string(), value.to_string());
+        let config = TestConfigProvider { values };
+
+        assert_eq!(
+            detect_format_with_provider(&config),
+            OutputFormat::Jsonl,
+            "LINEAR_CLI_FORMAT={value} should select JSON Lines"
+        );
+    }
+}
+
+#[test]
+fn test_issue_list_jsonl_emits_one_compact_object_per_line() {
+    let list = IssueList(vec![sample_issue("ENG-1"), sample_issue("ENG-2")]);
+
+    let output = format_output(&list, OutputFormat::Jsonl).expect("jsonl should format");
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    for (line, expected) in lines.iter().zip(["ENG-1", "ENG-2"]) {
+        let value: serde_json::Value = serde_json::from_str(line).expect("line should be JSON");
+        assert_eq!(value["identifier"], expected);
+    }
+}
+
+#[test]
+fn test_single_issue_jsonl_ignores_pretty_json_style() {
+    let issue = sample_issue("ENG-1");
+
+    let output = format_output(&issue, OutputFormat::Jsonl).expect("jsonl should format");
+
+    assert_eq!(output.lines().count(), 1);
+    let value: serde_json::Value = serde_json::from_str(&output).expect("output should be JSON");
+    assert_eq!(value["identifier"], "ENG-1");
+}
+
+#[test]
+fn test_issue_list_jsonl_writes_each_page_as_it_arrives() {
+    let config = test_config();
+    let storage = TestStorage;
+    let io = MockIo::new();
+    let client = PagedIssueClient {
+        pages: vec![
+            vec![sample_issue("ENG-1"), sample_issue("ENG-2")],
+            vec![],
+            vec![sample_issue("ENG-3")],
+        ],
+    };
+
+    let result = handle_list(
+        None,
+        None,
+        50,
+        &client,
+        &config,
+        &storage,
+        &io,
+        Some(OutputFormat::Jsonl),
+    );
+
+    assert!(result.is_ok());
+    let chunks = io.stdout_lines();
+    assert_eq!(chunks.len(), 2, "empty pages should not produce output");
+    assert_eq!(chunks[0].lines().count(), 2);
+    assert_eq!(chunks[1].lines().count(), 1);
+
+    let identifiers: Vec<String> = chunks
+        .iter()
+        .flat_map(|chunk| chunk.lines().map(str::to_string).collect::<Vec<_>>())
+        .map(|line| {
+            let value: serde_json::Value = serde_json::from_str(&line).expect("valid JSON");
+            value["identifier"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(identifiers, vec!["ENG-1", "ENG-2", "ENG-3"]);
+}
+
+#[test]
+fn test_issue_list_jsonl_propagates_page_errors() {
+    struct FailingClient;
+
+    impl IssueClient for FailingClient {
+        fn get_issue(&self, _token: &str, identifier: &str) -> Result<Issue, CliError> {
+            Err(CliError::NotFound(identifier.to_string()))
+        }
+
+        fn list_issues(
+            &self,
+            _token: &str,
+            _assignee: Option<String>,
+            _project: Option<String>,
+            _limit: usize,
+        ) -> Result<Vec<Issue>, CliError> {
+            Err(CliError::NetworkError("connection reset".to_string()))
+        }
+    }
+
+    let config = test_config();
+    let io = MockIo::new();
+
+    let result = handle_list(
+        None,
+        None,
+        50,
+        &FailingClient,
+        &config,
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Jsonl),
+    );
+
+    assert!(matches!(result, Err(CliError::NetworkError(_))));
+    assert!(io.stdout_lines().is_empty());
+}
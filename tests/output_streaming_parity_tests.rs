@@ -1,4 +1,4 @@
-use linear_cli::output::{OutputFormat, format_output, format_output_to_writer};
+use linear_cli::output::{OutputFormat, TableLayout, format_output, format_output_to_writer};
 use linear_cli::teams::types::{Team, TeamList};
 
 fn sample_team(id: &str, key: &str, name: &str) -> Team {
@@ -20,14 +20,17 @@ fn test_streaming_single_item_matches_string_output_all_formats() {
 
     for format in [
         OutputFormat::Json,
+        OutputFormat::Jsonl,
         OutputFormat::Csv,
-        OutputFormat::Markdown,
-        OutputFormat::Table,
+        OutputFormat::Markdown(Default::default()),
+        OutputFormat::Table(TableLayout::default()),
+        OutputFormat::Template("{{key}}\t{{name}}".to_string()),
     ] {
-        let expected = format_output(&team, format).expect("string output should format");
+        let expected = format_output(&team, format.clone()).expect("string output should format");
 
         let mut bytes = Vec::new();
-        format_output_to_writer(&team, format, &mut bytes).expect("streaming output should format");
+        format_output_to_writer(&team, format.clone(), &mut bytes)
+            .expect("streaming output should format");
         let actual = String::from_utf8(bytes).expect("streaming output must be utf-8");
 
         assert_eq!(actual, expected, "streaming parity failed for {format:?}");
@@ -43,14 +46,16 @@ fn test_streaming_list_matches_string_output_all_formats() {
 
     for format in [
         OutputFormat::Json,
+        OutputFormat::Jsonl,
         OutputFormat::Csv,
-        OutputFormat::Markdown,
-        OutputFormat::Table,
+        OutputFormat::Markdown(Default::default()),
+        OutputFormat::Table(TableLayout::default()),
+        OutputFormat::Template("{{key}}\t{{name}}".to_string()),
     ] {
-        let expected = format_output(&teams, format).expect("string output should format");
+        let expected = format_output(&teams, format.clone()).expect("string output should format");
 
         let mut bytes = Vec::new();
-        format_output_to_writer(&teams, format, &mut bytes)
+        format_output_to_writer(&teams, format.clone(), &mut bytes)
             .expect("streaming output should format");
         let actual = String::from_utf8(bytes).expect("streaming output must be utf-8");
 
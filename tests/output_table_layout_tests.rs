@@ -0,0 +1,215 @@
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use clap::Parser;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands};
+use linear_cli::client::issues::MockIssueClient;
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::handle_list;
+use linear_cli::issues::types::{Issue, IssueState, Priority, User};
+use linear_cli::output::{OutputFormat, TableLayout};
+use predicates::prelude::*;
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn sample_issue(identifier: &str, title: &str, state: &str, priority: Priority) -> Issue {
+    Issue {
+        id: format!("id-{identifier}"),
+        identifier: identifier.to_string(),
+        title: title.to_string(),
+        description: None,
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: state.to_string(),
+        },
+        priority,
+        assignee: None,
+        creator: User {
+            id: "user-1".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        },
+        project: None,
+        created_at: "2025-01-01T00:00:00Z".to_string(),
+        updated_at: "2025-01-02T00:00:00Z".to_string(),
+        url: format!("https://linear.app/issue/{identifier}"),
+        comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+    }
+}
+
+fn client() -> MockIssueClient {
+    MockIssueClient {
+        result: Err(CliError::NotFound("unused".to_string())),
+        list_result: Ok(vec![
+            sample_issue(
+                "ENG-1",
+                "Investigate intermittent failures in the deploy pipeline on release branches",
+                "In Progress",
+                Priority::Urgent,
+            ),
+            sample_issue("ENG-2", "Write docs", "Todo", Priority::Low),
+        ]),
+    }
+}
+
+fn config(extra: &[(&str, &str)]) -> TestConfigProvider {
+    let mut values = HashMap::new();
+    values.insert("LINEAR_TOKEN".to_string(), "test_token".to_string());
+    for (key, value) in extra {
+        values.insert((*key).to_string(), (*value).to_string());
+    }
+    TestConfigProvider { values }
+}
+
+fn run_list(io: &MockIo, config: &TestConfigProvider, layout: TableLayout) -> String {
+    handle_list(
+        None,
+        None,
+        50,
+        &client(),
+        config,
+        &TestStorage,
+        io,
+        Some(OutputFormat::Table(layout)),
+    )
+    .expect("issue list should succeed");
+    io.stdout_lines().concat()
+}
+
+#[test]
+fn test_issue_list_table_fits_terminal_width_reported_by_io() {
+    let io = MockIo::new().with_terminal_width(60);
+
+    let output = run_list(&io, &config(&[("NO_COLOR", "1")]), TableLayout::default());
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines.len(),
+        3,
+        "one header plus one row per issue: {output}"
+    );
+    assert!(lines[0].starts_with("ID"));
+    assert!(lines[1].contains('…'), "long title should be truncated");
+    assert!(lines.iter().all(|line| line.chars().count() <= 60));
+    assert!(!output.contains("https://"), "URL is a wide-only column");
+}
+
+#[test]
+fn test_issue_list_wide_shows_all_columns_without_truncation() {
+    let io = MockIo::new().with_terminal_width(60);
+    let layout = TableLayout {
+        wide: true,
+        ..TableLayout::default()
+    };
+
+    let output = run_list(&io, &config(&[("NO_COLOR", "1")]), layout);
+
+    assert!(output.contains("on release branches"));
+    assert!(output.contains("https://linear.app/issue/ENG-2"));
+    assert!(output.contains("Creator"));
+}
+
+#[test]
+fn test_issue_list_no_headers_is_awk_friendly() {
+    let io = MockIo::new();
+    let layout = TableLayout {
+        no_headers: true,
+        ..TableLayout::default()
+    };
+
+    let output = run_list(&io, &config(&[]), layout);
+
+    let first_fields: Vec<&str> = output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .collect();
+    assert_eq!(first_fields, vec!["ENG-1", "ENG-2"]);
+    assert!(!output.contains('│'));
+}
+
+#[test]
+fn test_issue_list_colors_only_on_terminal_without_no_color() {
+    let terminal = MockIo::new().with_terminal_width(200);
+    let colored = run_list(&terminal, &config(&[]), TableLayout::default());
+    assert!(
+        colored.contains('\u{1b}'),
+        "terminal output should be colored"
+    );
+
+    let no_color = MockIo::new().with_terminal_width(200);
+    let plain = run_list(
+        &no_color,
+        &config(&[("NO_COLOR", "1")]),
+        TableLayout::default(),
+    );
+    assert!(!plain.contains('\u{1b}'), "NO_COLOR should disable color");
+
+    let piped = MockIo::new();
+    let plain = run_list(&piped, &config(&[]), TableLayout::default());
+    assert!(
+        !plain.contains('\u{1b}'),
+        "piped output should not be colored"
+    );
+}
+
+#[test]
+fn test_wide_and_no_headers_flags_imply_table_layout() {
+    let cli = Cli::parse_from(["linear", "issue", "list", "--wide", "--no-headers"]);
+
+    let Commands::Issue {
+        action: IssueCommands::List { format, .. },
+    } = cli.command
+    else {
+        panic!("expected issue list command");
+    };
+
+    assert_eq!(
+        format.to_format(),
+        Some(OutputFormat::Table(TableLayout {
+            wide: true,
+            no_headers: true,
+            ..TableLayout::default()
+        }))
+    );
+}
+
+#[test]
+fn test_wide_flag_conflicts_with_non_table_formats() {
+    Command::cargo_bin("linear-cli")
+        .unwrap()
+        .args(["issue", "list", "--json", "--wide"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
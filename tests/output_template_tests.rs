@@ -0,0 +1,152 @@
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use linear_cli::cli::Cli;
+use linear_cli::issues::types::{Issue, IssueList, IssueState, Priority, User};
+use linear_cli::output::{OutputFormat, format_output};
+use predicates::prelude::*;
+
+fn sample_issue(identifier: &str, title: &str, priority: Priority) -> Issue {
+    Issue {
+        id: format!("id-{identifier}"),
+        identifier: identifier.to_string(),
+        title: title.to_string(),
+        description: None,
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "In Progress".to_string(),
+        },
+        priority,
+        assignee: None,
+        creator: User {
+            id: "user-1".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        },
+        project: None,
+        created_at: "2025-06-01T12:00:00.000Z".to_string(),
+        updated_at: "2025-06-02T12:00:00.000Z".to_string(),
+        url: format!("https://linear.app/issue/{identifier}"),
+        comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+    }
+}
+
+#[test]
+fn test_issue_list_template_renders_one_line_per_item() {
+    let list = IssueList(vec![
+        sample_issue("ENG-1", "Fix login", Priority::Urgent),
+        sample_issue("ENG-2", "Write docs", Priority::Low),
+    ]);
+
+    let output = format_output(
+        &list,
+        OutputFormat::Template("{{identifier}}\t{{title}}".to_string()),
+    )
+    .expect("template should render");
+
+    assert_eq!(output, "ENG-1\tFix login\nENG-2\tWrite docs");
+}
+
+#[test]
+fn test_issue_template_supports_nested_fields_and_helpers() {
+    let issue = sample_issue("ENG-7", "Investigate flaky deploy pipeline", Priority::High);
+
+    let output = format_output(
+        &issue,
+        OutputFormat::Template(
+            "{{priority_emoji priority}} {{identifier}} [{{state.name}}] {{truncate title 12}} {{date created_at \"%Y-%m-%d\"}}"
+                .to_string(),
+        ),
+    )
+    .expect("template should render");
+
+    assert_eq!(output, "🟠 ENG-7 [In Progress] Investigate… 2025-06-01");
+}
+
+#[test]
+fn test_template_flag_unescapes_tab_sequences() {
+    use clap::Parser;
+    use linear_cli::cli::{Commands, IssueCommands};
+
+    let cli = Cli::parse_from([
+        "linear",
+        "issue",
+        "list",
+        "--template",
+        r"{{identifier}}\t{{title}}",
+    ]);
+
+    let Commands::Issue {
+        action: IssueCommands::List { format, .. },
+    } = cli.command
+    else {
+        panic!("expected issue list command");
+    };
+
+    assert_eq!(
+        format.to_format(),
+        Some(OutputFormat::Template(
+            "{{identifier}}\t{{title}}".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_template_file_flag_reads_template_contents() {
+    use clap::Parser;
+    use linear_cli::cli::{Commands, IssueCommands};
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("issue.hbs");
+    std::fs::write(&path, "{{identifier}}: {{title}}\n").expect("write template");
+
+    let cli = Cli::parse_from([
+        "linear",
+        "issue",
+        "list",
+        "--template-file",
+        path.to_str().unwrap(),
+    ]);
+
+    let Commands::Issue {
+        action: IssueCommands::List { format, .. },
+    } = cli.command
+    else {
+        panic!("expected issue list command");
+    };
+
+    assert_eq!(
+        format.to_format(),
+        Some(OutputFormat::Template(
+            "{{identifier}}: {{title}}".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_template_file_flag_reports_missing_file() {
+    Command::cargo_bin("linear-cli")
+        .unwrap()
+        .args(["issue", "list", "--template-file", "/nonexistent/issue.hbs"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("failed to read template file"));
+}
+
+#[test]
+fn test_template_flag_conflicts_with_other_formats() {
+    Command::cargo_bin("linear-cli")
+        .unwrap()
+        .args(["issue", "list", "--json", "--template", "{{identifier}}"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
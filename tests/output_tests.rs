@@ -29,7 +29,7 @@ fn test_detect_format_returns_markdown_when_env_var_set_to_markdown() {
     let config = TestConfigProvider { values };
 
     let format = detect_format_with_provider(&config);
-    assert!(matches!(format, OutputFormat::Markdown));
+    assert!(matches!(format, OutputFormat::Markdown(_)));
 }
 
 #[test]
@@ -39,7 +39,7 @@ fn test_detect_format_returns_markdown_when_env_var_set_to_md() {
     let config = TestConfigProvider { values };
 
     let format = detect_format_with_provider(&config);
-    assert!(matches!(format, OutputFormat::Markdown));
+    assert!(matches!(format, OutputFormat::Markdown(_)));
 }
 
 #[test]
@@ -49,7 +49,7 @@ fn test_detect_format_returns_table_when_env_var_set_to_table() {
     let config = TestConfigProvider { values };
 
     let format = detect_format_with_provider(&config);
-    assert!(matches!(format, OutputFormat::Table));
+    assert!(matches!(format, OutputFormat::Table(_)));
 }
 
 #[test]
@@ -106,7 +106,7 @@ fn test_get_format_uses_env_when_no_cli_flag() {
     let config = TestConfigProvider { values };
 
     let format = get_format_with_provider(None, &config);
-    assert!(matches!(format, OutputFormat::Markdown));
+    assert!(matches!(format, OutputFormat::Markdown(_)));
 }
 
 #[test]
@@ -117,7 +117,10 @@ fn test_get_format_resolves_auto_when_no_cli_flag_or_env() {
 
     let format = get_format_with_provider(None, &config);
     // Should resolve to either Json or Table (depends on TTY)
-    assert!(matches!(format, OutputFormat::Json | OutputFormat::Table));
+    assert!(matches!(
+        format,
+        OutputFormat::Json | OutputFormat::Table(_)
+    ));
 }
 
 #[test]
@@ -0,0 +1,142 @@
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::cycles::types::Cycle;
+use linear_cli::issues::types::{Issue, IssueList, IssueState, Priority, User};
+use linear_cli::output::{OutputFormat, detect_format_with_provider, format_output};
+use std::collections::HashMap;
+
+fn sample_issue(identifier: &str) -> Issue {
+    Issue {
+        id: format!("id-{identifier}"),
+        identifier: identifier.to_string(),
+        title: "Fix: login: redirect".to_string(),
+        description: None,
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "Todo".to_string(),
+        },
+        priority: Priority::Medium,
+        assignee: None,
+        creator: User {
+            id: "user-1".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        },
+        project: None,
+        created_at: "2025-01-01T00:00:00Z".to_string(),
+        updated_at: "2025-01-01T00:00:00Z".to_string(),
+        url: format!("https://linear.app/issue/{identifier}"),
+        comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+    }
+}
+
+#[test]
+fn test_detect_format_supports_yaml_and_toml() {
+    for (value, expected) in [
+        ("yaml", OutputFormat::Yaml),
+        ("yml", OutputFormat::Yaml),
+        ("toml", OutputFormat::Toml),
+    ] {
+        let mut values = HashMap::new();
+        values.insert("LINEAR_CLI_FORMAT".to_string(), value.to_string());
+        let config = TestConfigProvider { values };
+
+        assert_eq!(detect_format_with_provider(&config), expected);
+    }
+}
+
+#[test]
+fn test_issue_yaml_round_trips_to_same_value_as_json() {
+    let issue = sample_issue("ENG-1");
+
+    let yaml = format_output(&issue, OutputFormat::Yaml).expect("yaml should format");
+    let from_yaml: serde_json::Value = serde_norway::from_str(&yaml).expect("valid YAML");
+
+    assert_eq!(from_yaml, serde_json::to_value(&issue).unwrap());
+    assert_eq!(from_yaml["title"], "Fix: login: redirect");
+}
+
+#[test]
+fn test_issue_list_yaml_is_a_sequence() {
+    let list = IssueList(vec![sample_issue("ENG-1"), sample_issue("ENG-2")]);
+
+    let yaml = format_output(&list, OutputFormat::Yaml).expect("yaml should format");
+    let value: serde_json::Value = serde_norway::from_str(&yaml).expect("valid YAML");
+
+    let items = value.as_array().expect("list should be a YAML sequence");
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1]["identifier"], "ENG-2");
+}
+
+#[test]
+fn test_issue_toml_omits_null_fields() {
+    let issue = sample_issue("ENG-1");
+
+    let output = format_output(&issue, OutputFormat::Toml).expect("toml should format");
+    let table: toml::Table = output.parse().expect("valid TOML");
+
+    assert_eq!(table["identifier"].as_str(), Some("ENG-1"));
+    assert_eq!(table["state"]["name"].as_str(), Some("Todo"));
+    assert!(!table.contains_key("assignee"));
+    assert!(!table.contains_key("description"));
+}
+
+#[test]
+fn test_issue_list_toml_uses_items_array_of_tables() {
+    let list = IssueList(vec![sample_issue("ENG-1"), sample_issue("ENG-2")]);
+
+    let output = format_output(&list, OutputFormat::Toml).expect("toml should format");
+    let table: toml::Table = output.parse().expect("valid TOML");
+
+    let items = table["items"].as_array().expect("items array");
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["identifier"].as_str(), Some("ENG-1"));
+}
+
+#[test]
+fn test_cycle_supports_yaml_without_per_type_code() {
+    let cycle = Cycle {
+        id: "cycle-1".to_string(),
+        name: "Sprint 1".to_string(),
+        number: 1.0,
+        description: None,
+        starts_at: "2025-01-01T00:00:00Z".to_string(),
+        ends_at: "2025-01-14T00:00:00Z".to_string(),
+        created_at: "2024-12-20T00:00:00Z".to_string(),
+        completed_at: None,
+        progress: 0.5,
+        is_active: true,
+        is_future: false,
+        is_next: false,
+        is_past: false,
+        is_previous: false,
+        team_name: "Engineering".to_string(),
+        team_key: "ENG".to_string(),
+    };
+
+    let yaml = format_output(&cycle, OutputFormat::Yaml).expect("yaml should format");
+
+    assert!(yaml.contains("name: Sprint 1"));
+    assert!(yaml.contains("team_key: ENG"));
+}
+
+#[test]
+fn test_yaml_keys_follow_field_order() {
+    let issue = sample_issue("ENG-1");
+
+    let yaml = format_output(&issue, OutputFormat::Yaml).expect("yaml should format");
+    let yaml_keys: Vec<&str> = yaml
+        .lines()
+        .filter(|line| !line.starts_with(' '))
+        .filter_map(|line| line.split(':').next())
+        .take(4)
+        .collect();
+    assert_eq!(yaml_keys, vec!["id", "identifier", "title", "description"]);
+}
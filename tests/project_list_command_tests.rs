@@ -6,7 +6,7 @@ use linear_cli::error::CliError;
 use linear_cli::io::Io;
 use linear_cli::output::OutputFormat;
 use linear_cli::projects::commands::{
-    handle_list as handle_project_list, handle_view as handle_project_view,
+    ProjectViewOptions, handle_list as handle_project_list, handle_view as handle_project_view,
 };
 use linear_cli::projects::types::Project;
 use std::collections::HashMap;
@@ -101,6 +101,10 @@ fn sample_project() -> Project {
         created_at: "2026-02-01T00:00:00Z".to_string(),
         updated_at: "2026-02-24T00:00:00Z".to_string(),
         lead_name: Some("Alice".to_string()),
+        milestones: Vec::new(),
+        issue_progress: None,
+        open_issues: None,
+        members: None,
     }
 }
 
@@ -117,6 +121,8 @@ fn test_project_list_uses_config_provider_json_style_override() {
     let client = MockProjectClient {
         result: Ok(sample_project()),
         list_result: Ok(vec![sample_project()]),
+        issues_result: Ok(Vec::new()),
+        members_result: Ok(Vec::new()),
     };
 
     let result = handle_project_list(
@@ -151,10 +157,13 @@ fn test_project_view_uses_config_provider_json_style_override() {
     let client = MockProjectClient {
         result: Ok(sample_project()),
         list_result: Ok(vec![]),
+        issues_result: Ok(Vec::new()),
+        members_result: Ok(Vec::new()),
     };
 
     let result = handle_project_view(
         "project-1",
+        &ProjectViewOptions::default(),
         &client,
         &config,
         &storage,
@@ -23,6 +23,10 @@ fn create_test_projects() -> Vec<Project> {
             lead_name: Some("John Doe".to_string()),
             created_at: "2024-01-10T10:00:00Z".to_string(),
             updated_at: "2024-02-15T14:30:00Z".to_string(),
+            milestones: Vec::new(),
+            issue_progress: None,
+            open_issues: None,
+            members: None,
         },
         Project {
             id: "project-2".to_string(),
@@ -44,6 +48,10 @@ fn create_test_projects() -> Vec<Project> {
             lead_name: None,
             created_at: "2024-02-01T10:00:00Z".to_string(),
             updated_at: "2024-02-01T10:00:00Z".to_string(),
+            milestones: Vec::new(),
+            issue_progress: None,
+            open_issues: None,
+            members: None,
         },
     ]
 }
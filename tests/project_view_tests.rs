@@ -0,0 +1,332 @@
+use clap::Parser;
+use cynic::QueryBuilder;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, ProjectCommands};
+use linear_cli::client::projects::MockProjectClient;
+use linear_cli::client::queries::{
+    ProjectIssuesQuery, ProjectIssuesQueryVariables, ProjectMembersQuery,
+    ProjectMembersQueryVariables,
+};
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::output::{Formattable, OutputFormat};
+use linear_cli::projects::commands::{ProjectViewOptions, handle_view};
+use linear_cli::projects::types::{
+    Project, ProjectIssue, ProjectIssueProgress, ProjectMember, open_issues_by_assignee,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn project() -> Project {
+    Project {
+        id: "project-1".to_string(),
+        name: "Platform".to_string(),
+        description: "Platform work".to_string(),
+        content: None,
+        slug_id: "a1b2c3".to_string(),
+        url: "https://linear.app/acme/project/platform-a1b2c3".to_string(),
+        color: "#2563eb".to_string(),
+        icon: None,
+        status_name: "In Progress".to_string(),
+        status_type: "started".to_string(),
+        status_color: "#60a5fa".to_string(),
+        progress: 0.5,
+        priority: 2,
+        priority_label: "High".to_string(),
+        start_date: None,
+        target_date: None,
+        created_at: "2026-02-01T00:00:00Z".to_string(),
+        updated_at: "2026-02-24T00:00:00Z".to_string(),
+        lead_name: None,
+        milestones: Vec::new(),
+        issue_progress: None,
+        open_issues: None,
+        members: None,
+    }
+}
+
+fn issue(
+    identifier: &str,
+    state_type: &str,
+    assignee: Option<&str>,
+    estimate: Option<f64>,
+) -> ProjectIssue {
+    ProjectIssue {
+        id: format!("id-{identifier}"),
+        identifier: identifier.to_string(),
+        title: format!("Work on {identifier}"),
+        url: format!("https://linear.app/acme/issue/{identifier}"),
+        state_name: state_type.to_string(),
+        state_type: state_type.to_string(),
+        assignee_name: assignee.map(str::to_string),
+        estimate,
+    }
+}
+
+fn issues() -> Vec<ProjectIssue> {
+    vec![
+        issue("ENG-1", "completed", Some("Bob"), Some(3.0)),
+        issue("ENG-2", "started", Some("Bob"), Some(2.0)),
+        issue("ENG-3", "backlog", None, None),
+        issue("ENG-4", "started", Some("Alice"), Some(1.0)),
+        issue("ENG-5", "canceled", Some("Alice"), Some(8.0)),
+    ]
+}
+
+fn client() -> MockProjectClient {
+    MockProjectClient {
+        result: Ok(project()),
+        list_result: Ok(Vec::new()),
+        issues_result: Ok(issues()),
+        members_result: Ok(vec![ProjectMember {
+            id: "user-1".to_string(),
+            name: "Alice".to_string(),
+            display_name: "alice".to_string(),
+            email: "alice@example.com".to_string(),
+        }]),
+    }
+}
+
+fn all_sections() -> ProjectViewOptions {
+    ProjectViewOptions {
+        issues: true,
+        members: true,
+        progress: true,
+    }
+}
+
+#[test]
+fn test_progress_counts_scope_without_canceled_issues() {
+    let progress = ProjectIssueProgress::from_issues(&issues());
+
+    assert_eq!((progress.scope, progress.completed), (4, 1));
+    assert_eq!(
+        (progress.scope_points, progress.completed_points),
+        (6.0, 3.0)
+    );
+    assert_eq!(progress.completion(), 0.25);
+
+    let counts: Vec<(&str, usize)> = progress
+        .by_state_type
+        .iter()
+        .map(|c| (c.state_type.as_str(), c.count))
+        .collect();
+    assert_eq!(
+        counts,
+        vec![
+            ("triage", 0),
+            ("backlog", 1),
+            ("unstarted", 0),
+            ("started", 2),
+            ("completed", 1),
+            ("canceled", 1),
+        ]
+    );
+}
+
+#[test]
+fn test_open_issues_group_by_assignee_with_unassigned_last() {
+    let groups = open_issues_by_assignee(issues());
+
+    let summary: Vec<(Option<&str>, Vec<&str>)> = groups
+        .iter()
+        .map(|g| {
+            (
+                g.assignee.as_deref(),
+                g.issues.iter().map(|i| i.identifier.as_str()).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (Some("Alice"), vec!["ENG-4"]),
+            (Some("Bob"), vec!["ENG-2"]),
+            (None, vec!["ENG-3"]),
+        ]
+    );
+}
+
+#[test]
+fn test_view_markdown_renders_requested_sections() {
+    let io = MockIo::new();
+
+    handle_view(
+        "Platform",
+        &all_sections(),
+        &client(),
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    let output = io.stdout_lines().concat();
+    assert!(output.contains(
+        "## Progress\n\n**Scope:** 4 issues (6 points)\n**Completed:** 1 issue (3 points) (25%)\n"
+    ));
+    assert!(output.contains("| Started | 2 |"));
+    assert!(output.contains("## Members (1)\n\n- Alice (alice@example.com)"));
+    assert!(output.contains("## Open issues (3)"));
+    assert!(output.contains(
+        "### Bob (1)\n\n- [ENG-2](https://linear.app/acme/issue/ENG-2) Work on ENG-2 — started"
+    ));
+    assert!(output.contains("### Unassigned (1)"));
+}
+
+#[test]
+fn test_view_json_includes_only_requested_sections() {
+    let io = MockIo::new();
+    handle_view(
+        "a1b2c3",
+        &ProjectViewOptions {
+            progress: true,
+            ..Default::default()
+        },
+        &client(),
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let value: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(value["issue_progress"]["scope"], 4);
+    assert!(value.get("open_issues").is_none());
+    assert!(value.get("members").is_none());
+}
+
+#[test]
+fn test_view_without_sections_skips_extra_requests() {
+    let mut client = client();
+    client.issues_result = Err(CliError::General("not expected".to_string()));
+    client.members_result = Err(CliError::General("not expected".to_string()));
+
+    handle_view(
+        "project-1",
+        &ProjectViewOptions::default(),
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_view_csv_and_table_append_sections() {
+    let mut project = project();
+    project.issue_progress = Some(ProjectIssueProgress::from_issues(&issues()));
+    project.open_issues = Some(open_issues_by_assignee(issues()));
+    project.members = client().members_result.unwrap().into();
+
+    let csv = project.to_csv().unwrap();
+    let mut lines = csv.lines();
+    let header = lines.next().unwrap();
+    assert!(header.ends_with(
+        "scope_issues,completed_issues,scope_points,completed_points,issues_triage,issues_backlog,issues_unstarted,issues_started,issues_completed,issues_canceled,members,open_issues"
+    ));
+    assert!(
+        lines
+            .next()
+            .unwrap()
+            .ends_with(",4,1,6,3,0,1,0,2,1,1,alice@example.com,ENG-4; ENG-2; ENG-3")
+    );
+
+    let table = project.to_table().unwrap();
+    assert!(table.contains("Open (Unassigned)"));
+    assert!(table.contains("1 issue (3 points) (25%)"));
+    assert!(!table.contains("Triage"), "empty state types are omitted");
+}
+
+#[test]
+fn test_project_issues_query_pages_and_requests_state_type_and_estimate() {
+    let operation = ProjectIssuesQuery::build(ProjectIssuesQueryVariables {
+        id: "project-1".to_string(),
+        first: Some(250),
+        after: Some("cursor-1".to_string()),
+    });
+    let json = serde_json::to_value(&operation).unwrap();
+    let query = json["query"].as_str().unwrap();
+    assert!(query.contains("issues(first: $first, after: $after)"));
+    assert!(query.contains("hasNextPage"));
+    assert!(query.contains("endCursor"));
+    assert_eq!(json["variables"]["after"], "cursor-1");
+    assert!(query.contains("estimate"));
+    assert!(query.contains("type"));
+    assert_eq!(json["variables"]["id"], "project-1");
+}
+
+#[test]
+fn test_project_members_query_pages() {
+    let operation = ProjectMembersQuery::build(ProjectMembersQueryVariables {
+        id: "project-1".to_string(),
+        first: Some(100),
+        after: Some("cursor-1".to_string()),
+    });
+    let json = serde_json::to_value(&operation).unwrap();
+    let query = json["query"].as_str().unwrap();
+    assert!(query.contains("members(first: $first, after: $after)"));
+    assert!(query.contains("hasNextPage"));
+    assert!(query.contains("endCursor"));
+    assert_eq!(json["variables"]["after"], "cursor-1");
+    assert_eq!(json["variables"]["id"], "project-1");
+}
+
+#[test]
+fn test_project_view_flags_parse() {
+    let cli = Cli::parse_from([
+        "linear",
+        "project",
+        "view",
+        "Platform Revamp",
+        "--issues",
+        "--progress",
+    ]);
+    let Commands::Project {
+        action:
+            ProjectCommands::View {
+                id,
+                issues,
+                members,
+                progress,
+                ..
+            },
+    } = cli.command
+    else {
+        panic!("expected project view command");
+    };
+    assert_eq!(id, "Platform Revamp");
+    assert_eq!((issues, members, progress), (true, false, true));
+}
@@ -0,0 +1,248 @@
+use clap::Parser;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands, IssueCommentCommands};
+use linear_cli::client::queries::{ReactionNode, ReactionUser};
+use linear_cli::client::reactions::{MockReactionClient, ReactionTarget};
+use linear_cli::comments::types::{Comment, CommentList};
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::output::{Formattable, OutputFormat};
+use linear_cli::reactions::ReactionSummary;
+use linear_cli::reactions::commands::handle_react;
+use linear_cli::reactions::emoji::{display_emoji, normalize_emoji};
+use linear_cli::reactions::types::summarize_reactions;
+use serde_json::Value;
+use std::collections::HashMap;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn reaction(emoji: &str) -> ReactionNode {
+    ReactionNode {
+        id: cynic::Id::new(format!("r-{emoji}")),
+        emoji: emoji.to_string(),
+        user: Some(ReactionUser { is_me: false }),
+    }
+}
+
+fn comment_with_reactions() -> Comment {
+    Comment {
+        id: "c1".to_string(),
+        body: "Deployed to staging".to_string(),
+        user_name: "Alice".to_string(),
+        user_email: "alice@example.com".to_string(),
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-01T00:00:00Z".to_string(),
+        edited_at: None,
+        resolved_at: None,
+        issue_identifier: Some("ENG-1".to_string()),
+        parent_id: None,
+        replies: Vec::new(),
+        reactions: vec![
+            ReactionSummary {
+                emoji: "+1".to_string(),
+                count: 2,
+            },
+            ReactionSummary {
+                emoji: "eyes".to_string(),
+                count: 1,
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_normalize_emoji_accepts_shortcodes_and_glyphs() {
+    assert_eq!(normalize_emoji(":eyes:").unwrap(), "eyes");
+    assert_eq!(normalize_emoji("eyes").unwrap(), "eyes");
+    assert_eq!(normalize_emoji("👍").unwrap(), "+1");
+    assert_eq!(normalize_emoji(":thumbsup:").unwrap(), "+1");
+    assert_eq!(normalize_emoji("❤").unwrap(), "heart");
+    assert_eq!(normalize_emoji(":party_parrot:").unwrap(), "party_parrot");
+    assert!(matches!(
+        normalize_emoji("::"),
+        Err(CliError::InvalidArgs(_))
+    ));
+
+    assert_eq!(display_emoji("+1"), "👍");
+    assert_eq!(display_emoji("party_parrot"), ":party_parrot:");
+}
+
+#[test]
+fn test_summarize_reactions_counts_in_first_use_order() {
+    let summary = summarize_reactions(&[reaction("eyes"), reaction("+1"), reaction("eyes")]);
+
+    assert_eq!(
+        summary,
+        vec![
+            ReactionSummary {
+                emoji: "eyes".to_string(),
+                count: 2,
+            },
+            ReactionSummary {
+                emoji: "+1".to_string(),
+                count: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_react_adds_normalized_emoji() {
+    let client = MockReactionClient::default();
+    let io = MockIo::new();
+
+    handle_react(
+        ReactionTarget::Issue("ENG-1".to_string()),
+        ":eyes:",
+        false,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Markdown(Default::default())),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *client.added.lock().unwrap(),
+        vec![(
+            ReactionTarget::Issue("ENG-1".to_string()),
+            "eyes".to_string()
+        )]
+    );
+    assert_eq!(io.stdout_lines(), vec!["Reacted 👀 to ENG-1"]);
+}
+
+#[test]
+fn test_react_remove_on_comment() {
+    let client = MockReactionClient::default();
+    let io = MockIo::new();
+
+    handle_react(
+        ReactionTarget::Comment("c1".to_string()),
+        "👍",
+        true,
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *client.removed.lock().unwrap(),
+        vec![(ReactionTarget::Comment("c1".to_string()), "+1".to_string())]
+    );
+    let printed: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(printed["removed"], 1);
+    assert_eq!(printed["emoji"], "+1");
+    assert_eq!(printed["target"], "c1");
+}
+
+#[test]
+fn test_react_remove_without_own_reaction_is_not_found() {
+    let client = MockReactionClient {
+        remove_result: Ok(0),
+        ..Default::default()
+    };
+
+    let err = handle_react(
+        ReactionTarget::Issue("ENG-1".to_string()),
+        "eyes",
+        true,
+        &client,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::NotFound(ref msg) if msg.contains("👀")));
+}
+
+#[test]
+fn test_comment_list_shows_reaction_counts() {
+    let list = CommentList(vec![comment_with_reactions()]);
+
+    assert!(list.to_table().unwrap().contains("👍 2  👀 1"));
+    assert!(
+        list.to_markdown()
+            .unwrap()
+            .contains("**Created:** 2026-03-01T00:00:00Z | 👍 2  👀 1")
+    );
+    assert!(
+        list.to_csv()
+            .unwrap()
+            .lines()
+            .nth(1)
+            .unwrap()
+            .ends_with(",+1:2 eyes:1")
+    );
+
+    let json: Value = serde_json::from_str(&list.to_json().unwrap()).unwrap();
+    assert_eq!(json[0]["reactions"][0]["emoji"], "+1");
+    assert_eq!(json[0]["reactions"][0]["count"], 2);
+}
+
+#[test]
+fn test_react_cli_commands_parse() {
+    let cli = Cli::parse_from(["linear", "issue", "react", "ENG-1", ":eyes:"]);
+    let Commands::Issue {
+        action:
+            IssueCommands::React {
+                identifier,
+                emoji,
+                remove,
+                ..
+            },
+    } = cli.command
+    else {
+        panic!("expected issue react command");
+    };
+    assert_eq!(
+        (identifier.as_str(), emoji.as_str(), remove),
+        ("ENG-1", ":eyes:", false)
+    );
+
+    let cli = Cli::parse_from([
+        "linear", "issue", "comment", "react", "c1", "👍", "--remove",
+    ]);
+    let Commands::Issue {
+        action:
+            IssueCommands::Comment {
+                action: IssueCommentCommands::React { remove, .. },
+            },
+    } = cli.command
+    else {
+        panic!("expected issue comment react command");
+    };
+    assert!(remove);
+}
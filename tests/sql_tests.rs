@@ -0,0 +1,287 @@
+use chrono::{TimeZone, Utc};
+use clap::Parser;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::cli::{Cli, Commands};
+use linear_cli::client::sync::{MockSyncClient, SyncRecords};
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::types::{Issue, IssueState, Priority, User};
+use linear_cli::mirror::commands::{handle_sql, run_query};
+use linear_cli::mirror::sync::run_sync;
+use linear_cli::mirror::types::SqlResult;
+use linear_cli::mirror::{Mirror, SyncResource};
+use linear_cli::output::{Formattable, OutputFormat};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+
+fn issue(identifier: &str, state: &str) -> Issue {
+    let creator = User {
+        id: "user-alice".to_string(),
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+    };
+    Issue {
+        id: format!("id-{identifier}"),
+        identifier: identifier.to_string(),
+        title: format!("Issue {identifier}"),
+        description: None,
+        state: IssueState {
+            id: format!("state-{state}"),
+            name: state.to_string(),
+        },
+        priority: Priority::Medium,
+        assignee: None,
+        creator,
+        project: None,
+        created_at: "2025-01-01T00:00:00.000Z".to_string(),
+        updated_at: "2025-01-01T00:00:00.000Z".to_string(),
+        url: format!("https://linear.app/acme/issue/{identifier}"),
+        comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+    }
+}
+
+fn sync_into(mirror: &mut Mirror) {
+    let client = MockSyncClient {
+        pages: vec![SyncRecords::Issues(vec![
+            issue("ENG-1", "Todo"),
+            issue("ENG-2", "Todo"),
+            issue("ENG-3", "Done"),
+            issue("OPS-1", "Todo"),
+        ])],
+        viewer: Some(UserInfo {
+            id: "user-alice".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        }),
+        ..MockSyncClient::default()
+    };
+    run_sync(
+        &client,
+        "token",
+        mirror,
+        &[SyncResource::Issues],
+        false,
+        Utc.with_ymd_and_hms(2025, 1, 20, 12, 0, 0).unwrap(),
+    )
+    .unwrap();
+}
+
+fn mirror_file(dir: &Path) -> TestConfigProvider {
+    let path = dir.join("mirror.sqlite3");
+    sync_into(&mut Mirror::open(&path).unwrap());
+    TestConfigProvider {
+        values: HashMap::from([(
+            "LINEAR_CLI_MIRROR_PATH".to_string(),
+            path.display().to_string(),
+        )]),
+    }
+}
+
+#[test]
+fn test_query_groups_by_generated_team_column() {
+    let mut mirror = Mirror::open_in_memory().unwrap();
+    sync_into(&mut mirror);
+
+    let result = run_query(
+        &mirror,
+        "select state_name, count(*) as n from issues where team = 'ENG' group by 1 order by 1",
+    )
+    .unwrap();
+
+    assert_eq!(result.columns, vec!["state_name", "n"]);
+    assert_eq!(
+        result.rows,
+        vec![vec![json!("Done"), json!(1)], vec![json!("Todo"), json!(2)]]
+    );
+}
+
+#[test]
+fn test_query_groups_by_generated_state_column() {
+    let mut mirror = Mirror::open_in_memory().unwrap();
+    sync_into(&mut mirror);
+
+    let result = run_query(
+        &mirror,
+        "select state, count(*) from issues where team='ENG' group by 1",
+    )
+    .unwrap();
+
+    assert_eq!(result.columns, vec!["state", "count(*)"]);
+    assert_eq!(
+        result.rows,
+        vec![vec![json!("Done"), json!(1)], vec![json!("Todo"), json!(2)]]
+    );
+}
+
+#[test]
+fn test_handle_sql_prints_rows_as_json_objects() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = mirror_file(dir.path());
+    let io = MockIo::new();
+
+    handle_sql(
+        Some("select identifier, team from issues where team = 'OPS'"),
+        false,
+        &config,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let json: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    assert_eq!(json, json!([{ "identifier": "OPS-1", "team": "OPS" }]));
+}
+
+#[test]
+fn test_handle_sql_json_keys_follow_selected_columns() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = mirror_file(dir.path());
+    let io = MockIo::new();
+
+    handle_sql(
+        Some("select team, identifier from issues where team = 'OPS'"),
+        false,
+        &config,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    let json: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    let keys: Vec<&String> = json[0].as_object().unwrap().keys().collect();
+    assert_eq!(keys, vec!["team", "identifier"]);
+}
+
+#[test]
+fn test_handle_sql_refuses_writes() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = mirror_file(dir.path());
+
+    for sql in [
+        "delete from issues",
+        "update issues set title = 'x'",
+        "pragma user_version = 7",
+        "attach database ':memory:' as other",
+    ] {
+        let err = handle_sql(Some(sql), false, &config, &MockIo::new(), None).unwrap_err();
+        assert!(matches!(err, CliError::InvalidArgs(_)), "{sql}: {err:?}");
+    }
+
+    let io = MockIo::new();
+    handle_sql(
+        Some("select count(*) as n from issues"),
+        false,
+        &config,
+        &io,
+        Some(OutputFormat::Jsonl),
+    )
+    .unwrap();
+    assert_eq!(io.stdout_lines().concat(), r#"{"n":4}"#);
+}
+
+#[test]
+fn test_handle_sql_rejects_multiple_statements_and_bad_sql() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = mirror_file(dir.path());
+
+    for sql in ["select 1; select 2", "select * from nope"] {
+        let err = handle_sql(Some(sql), false, &config, &MockIo::new(), None).unwrap_err();
+        assert!(matches!(err, CliError::InvalidArgs(_)), "{sql}: {err:?}");
+    }
+}
+
+#[test]
+fn test_handle_sql_schema_lists_tables_and_columns() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = mirror_file(dir.path());
+    let io = MockIo::new();
+
+    handle_sql(None, true, &config, &io, Some(OutputFormat::Json)).unwrap();
+
+    let json: Value = serde_json::from_str(&io.stdout_lines().concat()).unwrap();
+    let columns = json.as_array().unwrap();
+    assert!(columns.contains(&json!({
+        "table": "issues",
+        "column": "team",
+        "type": "TEXT",
+        "nullable": "yes",
+        "note": "generated",
+    })));
+    assert!(columns.contains(&json!({
+        "table": "issues",
+        "column": "identifier",
+        "type": "TEXT",
+        "nullable": "no",
+        "note": "",
+    })));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_mirror_directory_and_database_are_private() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("linear-cli").join("mirror.sqlite3");
+    sync_into(&mut Mirror::open(&path).unwrap());
+
+    let mode = |path: &Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+    assert_eq!(mode(path.parent().unwrap()), 0o700);
+    assert_eq!(mode(&path), 0o600);
+}
+
+#[test]
+fn test_handle_sql_reports_missing_mirror() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = TestConfigProvider {
+        values: HashMap::from([(
+            "LINEAR_CLI_MIRROR_PATH".to_string(),
+            dir.path().join("missing.sqlite3").display().to_string(),
+        )]),
+    };
+
+    let err = handle_sql(Some("select 1"), false, &config, &MockIo::new(), None).unwrap_err();
+
+    assert!(matches!(err, CliError::NotFound(ref msg) if msg.contains("linear sync")));
+    assert!(!dir.path().join("missing.sqlite3").exists());
+}
+
+#[test]
+fn test_sql_result_csv_and_markdown_render_nulls_and_escape_pipes() {
+    let result = SqlResult {
+        columns: vec!["name".to_string(), "note".to_string()],
+        rows: vec![
+            vec![json!("a|b"), Value::Null],
+            vec![json!("c"), json!(1.5)],
+        ],
+    };
+
+    assert_eq!(result.to_csv().unwrap(), "name,note\na|b,\nc,1.5\n");
+    assert_eq!(
+        result.to_markdown().unwrap(),
+        "| name | note |\n|---|---|\n| a\\|b |  |\n| c | 1.5 |\n"
+    );
+}
+
+#[test]
+fn test_sql_requires_query_or_schema() {
+    assert!(Cli::try_parse_from(["linear", "sql"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "sql", "select 1", "--schema"]).is_err());
+
+    let cli = Cli::parse_from(["linear", "sql", "--schema"]);
+    let Commands::Sql { query, schema, .. } = cli.command else {
+        panic!("expected sql command");
+    };
+    assert!(schema);
+    assert_eq!(query, None);
+}
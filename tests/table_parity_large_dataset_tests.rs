@@ -1,4 +1,4 @@
-use linear_cli::output::{Formattable, generic_table_list_formatter};
+use linear_cli::output::{Formattable, TableColumn, TableLayout, generic_table_list_formatter};
 use linear_cli::projects::types::{Project, ProjectList};
 use linear_cli::teams::types::{Team, TeamList};
 
@@ -36,6 +36,10 @@ fn make_project(index: usize) -> Project {
         created_at: "2026-02-01T00:00:00Z".to_string(),
         updated_at: "2026-02-24T00:00:00Z".to_string(),
         lead_name: Some("Alice".to_string()),
+        milestones: Vec::new(),
+        issue_progress: None,
+        open_issues: None,
+        members: None,
     }
 }
 
@@ -46,16 +50,25 @@ fn test_large_team_table_parity_with_generic_list_formatter() {
         .to_table()
         .expect("existing team list formatter should succeed");
 
-    let actual =
-        generic_table_list_formatter(&teams, &["Key", "Name", "Private", "Description"], |team| {
+    let actual = generic_table_list_formatter(
+        &teams,
+        &[
+            TableColumn::new("Key"),
+            TableColumn::new("Name"),
+            TableColumn::new("Private"),
+            TableColumn::new("Description").flexible(),
+        ],
+        &TableLayout::default(),
+        |team| {
             vec![
                 team.key.clone(),
                 team.name.clone(),
                 if team.private { "Yes" } else { "No" }.to_string(),
                 team.description.clone().unwrap_or_else(|| "—".to_string()),
             ]
-        })
-        .expect("generic list formatter should succeed");
+        },
+    )
+    .expect("generic list formatter should succeed");
 
     assert_eq!(actual, expected);
 }
@@ -69,10 +82,16 @@ fn test_large_project_table_parity_with_generic_list_formatter() {
 
     let actual = generic_table_list_formatter(
         &projects,
-        &["ID", "Name", "Status", "Progress", "Priority", "Lead"],
+        &[
+            TableColumn::new("Name").flexible(),
+            TableColumn::new("Status"),
+            TableColumn::new("Progress"),
+            TableColumn::new("Priority"),
+            TableColumn::new("Lead"),
+        ],
+        &TableLayout::default(),
         |project| {
             vec![
-                project.id.clone(),
                 project.name.clone(),
                 project.status_name.clone(),
                 format!("{:.0}%", project.progress * 100.0),
@@ -0,0 +1,319 @@
+use clap::Parser;
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands};
+use linear_cli::client::issues::{IssueClient, UpdateIssueInput};
+use linear_cli::error::CliError;
+use linear_cli::io::terminal::create_editor_file;
+use linear_cli::io::{MockIo, TextSource};
+use linear_cli::issues::commands::IssueFields;
+use linear_cli::issues::commands::update::handle_update;
+use linear_cli::issues::types::{Issue, IssueState, Priority, User};
+use linear_cli::output::OutputFormat;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+fn issue(description: Option<&str>) -> Issue {
+    Issue {
+        id: "issue-1".to_string(),
+        identifier: "ENG-1".to_string(),
+        title: "Fix login".to_string(),
+        description: description.map(str::to_string),
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "Todo".to_string(),
+        },
+        priority: Priority::Medium,
+        assignee: None,
+        creator: User {
+            id: "user-1".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        },
+        project: None,
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-01T00:00:00Z".to_string(),
+        url: "https://linear.app/acme/issue/ENG-1".to_string(),
+        comments: None,
+        attachments: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+    }
+}
+
+/// Serves the current issue and records update payloads
+struct RecordingIssueClient {
+    current: Issue,
+    gets: Mutex<usize>,
+    updates: Mutex<Vec<UpdateIssueInput>>,
+}
+
+impl RecordingIssueClient {
+    fn new(current: Issue) -> Self {
+        Self {
+            current,
+            gets: Mutex::new(0),
+            updates: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl IssueClient for RecordingIssueClient {
+    fn get_issue(&self, _token: &str, _identifier: &str) -> Result<Issue, CliError> {
+        *self.gets.lock().unwrap() += 1;
+        Ok(self.current.clone())
+    }
+
+    fn list_issues(
+        &self,
+        _token: &str,
+        _assignee: Option<String>,
+        _project: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<Issue>, CliError> {
+        unreachable!("not used")
+    }
+
+    fn update_issue(
+        &self,
+        _token: &str,
+        _id: &str,
+        input: UpdateIssueInput,
+    ) -> Result<Issue, CliError> {
+        let updated = Issue {
+            description: input.description.clone(),
+            ..self.current.clone()
+        };
+        self.updates.lock().unwrap().push(input);
+        Ok(updated)
+    }
+}
+
+#[test]
+fn test_from_flags_prefers_editor_then_file() {
+    assert_eq!(
+        TextSource::from_flags(Some("a".into()), Some("f".into()), true),
+        Some(TextSource::Editor)
+    );
+    assert_eq!(
+        TextSource::from_flags(None, Some("f".into()), false),
+        Some(TextSource::File("f".into()))
+    );
+    assert_eq!(
+        TextSource::from_flags(Some("a".into()), None, false),
+        Some(TextSource::Inline("a".into()))
+    );
+    assert_eq!(TextSource::from_flags(None, None, false), None);
+}
+
+#[test]
+fn test_read_file_and_stdin() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("description.md");
+    std::fs::write(&path, "# Steps\n\n1. Log in\n").unwrap();
+
+    let io = MockIo::with_input("from stdin\n".to_string());
+    let file = TextSource::File(path.to_string_lossy().into_owned());
+    assert_eq!(file.read(&io, "").unwrap(), "# Steps\n\n1. Log in\n");
+    assert_eq!(
+        TextSource::File("-".to_string()).read(&io, "").unwrap(),
+        "from stdin\n"
+    );
+
+    let err = TextSource::File("/nonexistent/description.md".to_string())
+        .read(&io, "")
+        .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("Failed to read")));
+}
+
+#[test]
+fn test_read_editor_trims_and_rejects_empty_text() {
+    let io = MockIo::new().with_editor_output("Edited text\n\n");
+    assert_eq!(
+        TextSource::Editor.read(&io, "Original").unwrap(),
+        "Edited text"
+    );
+    assert_eq!(*io.editor_input.lock().unwrap(), vec!["Original"]);
+
+    let io = MockIo::new().with_editor_output("  \n");
+    let err = TextSource::Editor.read(&io, "").unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("empty")));
+
+    // An editor that exits with an error aborts the command
+    assert!(TextSource::Editor.read(&MockIo::new(), "").is_err());
+}
+
+#[test]
+fn test_update_editor_prefills_current_description() {
+    let client = RecordingIssueClient::new(issue(Some("Old steps")));
+    let io = MockIo::new().with_editor_output("New steps\n");
+
+    handle_update(
+        "ENG-1",
+        None,
+        Some(TextSource::Editor),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        IssueFields::default(),
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(*io.editor_input.lock().unwrap(), vec!["Old steps"]);
+    let updates = client.updates.lock().unwrap();
+    assert_eq!(updates[0].description.as_deref(), Some("New steps"));
+}
+
+#[test]
+fn test_update_description_file_skips_issue_fetch() {
+    let client = RecordingIssueClient::new(issue(None));
+    let io = MockIo::with_input("Piped description".to_string());
+
+    handle_update(
+        "ENG-1",
+        None,
+        Some(TextSource::File("-".to_string())),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        IssueFields::default(),
+        &client,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(*client.gets.lock().unwrap(), 0);
+    assert_eq!(
+        client.updates.lock().unwrap()[0].description.as_deref(),
+        Some("Piped description")
+    );
+}
+
+#[test]
+fn test_body_source_flags_parse() {
+    let cli = Cli::parse_from([
+        "linear",
+        "issue",
+        "update",
+        "ENG-1",
+        "--description-file",
+        "-",
+    ]);
+    let Commands::Issue {
+        action: IssueCommands::Update { patch, .. },
+    } = cli.command
+    else {
+        panic!("expected issue update command");
+    };
+    assert_eq!(patch.description_file.as_deref(), Some("-"));
+    assert!(patch.has_any_field());
+
+    assert!(
+        Cli::try_parse_from([
+            "linear",
+            "issue",
+            "create",
+            "--team",
+            "ENG",
+            "--title",
+            "T",
+            "--description",
+            "inline",
+            "--editor",
+        ])
+        .is_err()
+    );
+    assert!(
+        Cli::try_parse_from([
+            "linear",
+            "issue",
+            "comment",
+            "add",
+            "ENG-1",
+            "--body-file",
+            "notes.md",
+        ])
+        .is_ok()
+    );
+    assert!(
+        Cli::try_parse_from([
+            "linear",
+            "issue",
+            "comment",
+            "add",
+            "ENG-1",
+            "--body",
+            "x",
+            "--body-file",
+            "notes.md",
+        ])
+        .is_err()
+    );
+}
+
+#[test]
+fn test_editor_file_is_new_and_private() {
+    let first = create_editor_file("Private description").unwrap();
+    let second = create_editor_file("").unwrap();
+    assert_ne!(first, second);
+    assert_eq!(
+        std::fs::read_to_string(&first).unwrap(),
+        "Private description"
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&first).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    std::fs::remove_file(first).unwrap();
+    std::fs::remove_file(second).unwrap();
+}
@@ -0,0 +1,229 @@
+use chrono::DateTime;
+use clap::Parser;
+use linear_cli::auth::config::{CliConfigProvider, ConfigProvider, TestConfigProvider};
+use linear_cli::cli::Cli;
+use linear_cli::cycles::types::Cycle;
+use linear_cli::dates::FixedClock;
+use linear_cli::error::CliError;
+use linear_cli::issues::types::{Issue, IssueState, Priority, User};
+use linear_cli::output::{
+    Formattable, OutputFormat, TableLayout, TimeDisplay, TimeFormat, format_output,
+    get_format_with_provider,
+};
+use std::collections::HashMap;
+
+/// 2026-10-19 12:00 UTC, 14:00 in the clock's UTC+2
+fn clock() -> FixedClock {
+    FixedClock(DateTime::parse_from_rfc3339("2026-10-19T14:00:00+02:00").unwrap())
+}
+
+fn display(format: TimeFormat, utc: bool) -> TimeDisplay {
+    TimeDisplay { format, utc }
+}
+
+fn config(vars: &[(&str, &str)]) -> TestConfigProvider {
+    TestConfigProvider {
+        values: vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+#[test]
+fn test_relative_times() {
+    let relative = display(TimeFormat::Relative, false);
+    let cases = [
+        ("2026-10-19T11:59:30Z", "just now"),
+        ("2026-10-19T11:15:00Z", "45 minutes ago"),
+        ("2026-10-19T13:00:00Z", "in 1 hour"),
+        ("2026-10-16T12:00:00.000Z", "3 days ago"),
+        ("2026-10-21T12:00:00Z", "in 2 days"),
+        ("2026-08-01T12:00:00Z", "2 months ago"),
+        ("2024-10-01T12:00:00Z", "2 years ago"),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(relative.render(value, &clock()), expected, "{value}");
+    }
+}
+
+#[test]
+fn test_local_and_iso_times() {
+    let value = "2026-10-16T09:30:00.000Z";
+    assert_eq!(
+        display(TimeFormat::Local, false).render(value, &clock()),
+        "2026-10-16 11:30"
+    );
+    assert_eq!(
+        display(TimeFormat::Local, true).render(value, &clock()),
+        "2026-10-16 09:30 UTC"
+    );
+    assert_eq!(
+        display(TimeFormat::Iso, false).render("2026-10-16T11:30:00+02:00", &clock()),
+        "2026-10-16T11:30:00+02:00"
+    );
+    assert_eq!(
+        display(TimeFormat::Iso, true).render("2026-10-16T11:30:00+02:00", &clock()),
+        "2026-10-16T09:30:00Z"
+    );
+    // Values that are not timestamps pass through untouched
+    assert_eq!(
+        display(TimeFormat::Relative, false).render("2026-11-01", &clock()),
+        "2026-11-01"
+    );
+}
+
+#[test]
+fn test_event_times_add_verbs_only_when_relative() {
+    let relative = display(TimeFormat::Relative, false);
+    assert_eq!(
+        relative.render_event("2026-10-21T12:00:00Z", "ends", "ended", &clock()),
+        "ends in 2 days"
+    );
+    assert_eq!(
+        relative.render_event("2026-10-16T12:00:00Z", "ends", "ended", &clock()),
+        "ended 3 days ago"
+    );
+    assert_eq!(
+        display(TimeFormat::Local, true).render_event(
+            "2026-10-21T12:00:00Z",
+            "ends",
+            "ended",
+            &clock()
+        ),
+        "2026-10-21 12:00 UTC"
+    );
+}
+
+#[test]
+fn test_time_display_from_config() {
+    assert_eq!(
+        TimeDisplay::from_config(&config(&[])).unwrap(),
+        display(TimeFormat::Relative, false)
+    );
+    assert_eq!(
+        TimeDisplay::from_config(&config(&[
+            ("LINEAR_CLI_TIME_FORMAT", "Local"),
+            ("LINEAR_CLI_UTC", "1")
+        ]))
+        .unwrap(),
+        display(TimeFormat::Local, true)
+    );
+    assert_eq!(
+        TimeDisplay::from_config(&config(&[
+            ("LINEAR_CLI_TIME_FORMAT", "iso"),
+            ("LINEAR_CLI_UTC", "")
+        ]))
+        .unwrap(),
+        TimeDisplay::ISO
+    );
+    let err =
+        TimeDisplay::from_config(&config(&[("LINEAR_CLI_TIME_FORMAT", "fuzzy")])).unwrap_err();
+    assert!(
+        matches!(err, CliError::InvalidArgs(ref msg) if msg.contains("LINEAR_CLI_TIME_FORMAT"))
+    );
+
+    let cli = Cli::parse_from(["linear", "issue", "view", "ENG-1", "--utc"]);
+    assert!(cli.utc);
+    let cli_config = CliConfigProvider { utc: cli.utc };
+    assert_eq!(cli_config.get_var("LINEAR_CLI_UTC").as_deref(), Some("1"));
+}
+
+fn issue() -> Issue {
+    let alice = User {
+        id: "user-1".to_string(),
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+    };
+    Issue {
+        id: "issue-1".to_string(),
+        identifier: "ENG-1".to_string(),
+        title: "Fix login".to_string(),
+        description: None,
+        state: IssueState {
+            id: "state-1".to_string(),
+            name: "Todo".to_string(),
+        },
+        priority: Priority::Medium,
+        assignee: None,
+        creator: alice,
+        project: None,
+        team: None,
+        cycle: None,
+        estimate: None,
+        due_date: None,
+        sort_order: None,
+        subscribers: None,
+        branch_name: None,
+        created_at: "2026-03-01T08:00:00.000Z".to_string(),
+        updated_at: "2026-03-02T17:45:00.000Z".to_string(),
+        url: "https://linear.app/acme/issue/ENG-1".to_string(),
+        comments: None,
+        attachments: None,
+    }
+}
+
+fn cycle() -> Cycle {
+    Cycle {
+        id: "cycle-1".to_string(),
+        name: "Sprint 12".to_string(),
+        number: 12.0,
+        description: None,
+        starts_at: "2026-03-02T00:00:00.000Z".to_string(),
+        ends_at: "2026-03-16T00:00:00.000Z".to_string(),
+        created_at: "2026-02-20T10:00:00.000Z".to_string(),
+        completed_at: None,
+        progress: 0.5,
+        is_active: true,
+        is_future: false,
+        is_past: false,
+        is_next: false,
+        is_previous: false,
+        team_name: "Engineering".to_string(),
+        team_key: "ENG".to_string(),
+    }
+}
+
+#[test]
+fn test_human_formats_use_display_and_machine_formats_stay_iso() {
+    let config = config(&[("LINEAR_CLI_TIME_FORMAT", "local"), ("LINEAR_CLI_UTC", "1")]);
+    let markdown_format = get_format_with_provider(
+        Some(OutputFormat::Markdown(TimeDisplay::default())),
+        &config,
+    );
+    assert_eq!(
+        markdown_format,
+        OutputFormat::Markdown(display(TimeFormat::Local, true))
+    );
+    let table_format =
+        get_format_with_provider(Some(OutputFormat::Table(TableLayout::default())), &config);
+
+    let issue = issue();
+    let markdown = format_output(&issue, markdown_format.clone()).unwrap();
+    assert!(markdown.contains("- **Created:** 2026-03-01 08:00 UTC\n"));
+    assert!(markdown.contains("- **Updated:** 2026-03-02 17:45 UTC\n"));
+    let table = format_output(&issue, table_format).unwrap();
+    assert!(table.contains("2026-03-02 17:45 UTC"));
+    // Formats that were not given a display keep the API values
+    assert!(
+        issue
+            .to_markdown()
+            .unwrap()
+            .contains("- **Created:** 2026-03-01T08:00:00.000Z\n")
+    );
+
+    let cycle = cycle();
+    let markdown = format_output(&cycle, markdown_format).unwrap();
+    assert!(markdown.contains("**Start Date:** 2026-03-02 00:00 UTC\n"));
+    assert!(markdown.contains("**End Date:** 2026-03-16 00:00 UTC\n"));
+
+    let json = format_output(
+        &issue,
+        get_format_with_provider(Some(OutputFormat::Json), &config),
+    )
+    .unwrap();
+    assert!(json.contains("2026-03-02T17:45:00.000Z"));
+    let csv = issue.to_csv().unwrap();
+    assert!(csv.contains("2026-03-01T08:00:00.000Z,2026-03-02T17:45:00.000Z"));
+    assert!(cycle.to_csv().unwrap().contains("2026-03-16T00:00:00.000Z"));
+}
@@ -0,0 +1,339 @@
+use clap::Parser;
+use linear_cli::attachments::commands::handle_attach_file;
+use linear_cli::attachments::types::Attachment;
+use linear_cli::attachments::upload::{UploadedFile, content_type_for};
+use linear_cli::auth::UserInfo;
+use linear_cli::auth::config::TestConfigProvider;
+use linear_cli::auth::storage::TokenStorage;
+use linear_cli::cli::{Cli, Commands, IssueCommands, IssueCommentCommands};
+use linear_cli::client::attachments::{
+    AttachmentLinkKind, LinkAttachmentInput, MockAttachmentClient,
+};
+use linear_cli::client::comments::{CommentClient, CreateCommentInput};
+use linear_cli::client::uploads::{MockUploadClient, UploadRequest, UploadTarget, put_file};
+use linear_cli::comments::types::Comment;
+use linear_cli::error::CliError;
+use linear_cli::io::MockIo;
+use linear_cli::issues::commands::handle_comment_add_with_files;
+use linear_cli::output::OutputFormat;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+struct TestStorage;
+
+impl TokenStorage for TestStorage {
+    fn get_token(&self) -> Result<Option<String>, CliError> {
+        Ok(None)
+    }
+
+    fn get_user_info(&self) -> Result<Option<UserInfo>, CliError> {
+        Ok(None)
+    }
+
+    fn store_auth(&self, _token: &str, _user_info: &UserInfo) -> Result<(), CliError> {
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), CliError> {
+        Ok(())
+    }
+}
+
+fn config() -> TestConfigProvider {
+    TestConfigProvider {
+        values: HashMap::from([("LINEAR_TOKEN".to_string(), "token".to_string())]),
+    }
+}
+
+/// Records the body of each created comment
+#[derive(Default)]
+struct RecordingCommentClient {
+    bodies: Mutex<Vec<String>>,
+}
+
+impl CommentClient for RecordingCommentClient {
+    fn list_comments(
+        &self,
+        _token: &str,
+        _issue_id: &str,
+        _limit: usize,
+    ) -> Result<Vec<Comment>, CliError> {
+        unreachable!("not used")
+    }
+
+    fn create_comment(&self, _token: &str, input: CreateCommentInput) -> Result<Comment, CliError> {
+        self.bodies.lock().unwrap().push(input.body.clone());
+        Ok(Comment {
+            id: "comment-1".to_string(),
+            body: input.body,
+            user_name: "Alice".to_string(),
+            user_email: "alice@example.com".to_string(),
+            created_at: "2026-02-24T00:00:00Z".to_string(),
+            updated_at: "2026-02-24T00:00:00Z".to_string(),
+            edited_at: None,
+            issue_identifier: Some(input.issue_id),
+            resolved_at: None,
+            parent_id: None,
+            replies: Vec::new(),
+            reactions: Vec::new(),
+        })
+    }
+}
+
+fn write_file(dir: &Path, name: &str, contents: &[u8]) -> String {
+    let path = dir.join(name);
+    std::fs::File::create(&path)
+        .unwrap()
+        .write_all(contents)
+        .unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+fn uploaded_attachment() -> Attachment {
+    Attachment {
+        id: "att-1".to_string(),
+        title: "screenshot.png".to_string(),
+        subtitle: None,
+        url: "https://uploads.linear.app/asset/screenshot.png".to_string(),
+        source_type: None,
+        creator_name: None,
+        created_at: "2026-02-24T00:00:00Z".to_string(),
+        updated_at: "2026-02-24T00:00:00Z".to_string(),
+    }
+}
+
+#[test]
+fn test_put_file_streams_bytes_with_signed_headers() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_file(dir.path(), "log.txt", b"line one\nline two\n");
+
+    let mut server = mockito::Server::new();
+    let upload = server
+        .mock("PUT", "/signed/log.txt")
+        .match_header("content-type", "text/plain")
+        .match_header("content-length", "18")
+        .match_header("x-goog-meta-issue", "ENG-1")
+        .match_body("line one\nline two\n")
+        .with_status(200)
+        .create();
+
+    let target = UploadTarget {
+        upload_url: format!("{}/signed/log.txt", server.url()),
+        asset_url: "https://uploads.linear.app/asset/log.txt".to_string(),
+        headers: vec![("x-goog-meta-issue".to_string(), "ENG-1".to_string())],
+    };
+
+    put_file(
+        &reqwest::blocking::Client::new(),
+        &target,
+        Path::new(&path),
+        "text/plain",
+    )
+    .unwrap();
+
+    upload.assert();
+}
+
+#[test]
+fn test_put_file_reports_rejected_upload() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_file(dir.path(), "log.txt", b"data");
+
+    let mut server = mockito::Server::new();
+    server.mock("PUT", "/signed").with_status(403).create();
+
+    let target = UploadTarget {
+        upload_url: format!("{}/signed", server.url()),
+        asset_url: String::new(),
+        headers: Vec::new(),
+    };
+
+    let err = put_file(
+        &reqwest::blocking::Client::new(),
+        &target,
+        Path::new(&path),
+        "text/plain",
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::NetworkError(ref msg) if msg.contains("403")));
+}
+
+#[test]
+fn test_attach_file_uploads_and_links_asset() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_file(dir.path(), "screenshot.png", b"\x89PNG fake");
+    let uploads = MockUploadClient::new("https://uploads.linear.app/asset/screenshot.png");
+    let attachments = MockAttachmentClient::new(vec![uploaded_attachment()]);
+    let io = MockIo::new();
+
+    handle_attach_file(
+        "ENG-1",
+        Path::new(&path),
+        None,
+        &attachments,
+        &uploads,
+        &config(),
+        &TestStorage,
+        &io,
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *uploads.requests.lock().unwrap(),
+        vec![UploadRequest {
+            filename: "screenshot.png".to_string(),
+            content_type: "image/png".to_string(),
+            size: 9,
+        }]
+    );
+    assert_eq!(
+        *attachments.links.lock().unwrap(),
+        vec![LinkAttachmentInput {
+            issue_id: "ENG-1".to_string(),
+            url: "https://uploads.linear.app/asset/screenshot.png".to_string(),
+            title: Some("screenshot.png".to_string()),
+            kind: AttachmentLinkKind::Upload,
+        }]
+    );
+}
+
+#[test]
+fn test_attach_file_missing_file_is_invalid_args() {
+    let uploads = MockUploadClient::new("https://uploads.linear.app/asset/x");
+    let attachments = MockAttachmentClient::new(vec![uploaded_attachment()]);
+
+    let err = handle_attach_file(
+        "ENG-1",
+        Path::new("/nonexistent/screenshot.png"),
+        None,
+        &attachments,
+        &uploads,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::InvalidArgs(_)));
+    assert!(uploads.requests.lock().unwrap().is_empty());
+    assert!(attachments.links.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_comment_add_embeds_uploaded_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let image = write_file(dir.path(), "crash.png", b"png");
+    let log = write_file(dir.path(), "app.log", b"log");
+    let uploads = MockUploadClient::new("https://uploads.linear.app/asset/1");
+    let comments = RecordingCommentClient::default();
+
+    handle_comment_add_with_files(
+        "ENG-1",
+        Some("Repro attached\n"),
+        &[image, log],
+        &comments,
+        &uploads,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        Some(OutputFormat::Json),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *comments.bodies.lock().unwrap(),
+        vec![
+            "Repro attached\n\n\
+             ![crash.png](https://uploads.linear.app/asset/1)\n\
+             [app.log](https://uploads.linear.app/asset/1)"
+        ]
+    );
+    assert_eq!(uploads.puts.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_comment_add_does_not_post_when_upload_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = write_file(dir.path(), "app.log", b"log");
+    let mut uploads = MockUploadClient::new("https://uploads.linear.app/asset/1");
+    uploads.put_result = Err(CliError::NetworkError("Failed to upload".to_string()));
+    let comments = RecordingCommentClient::default();
+
+    let err = handle_comment_add_with_files(
+        "ENG-1",
+        None,
+        &[log],
+        &comments,
+        &uploads,
+        &config(),
+        &TestStorage,
+        &MockIo::new(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, CliError::NetworkError(_)));
+    assert!(comments.bodies.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_uploaded_file_markdown() {
+    let file = |name: &str| UploadedFile {
+        filename: name.to_string(),
+        content_type: content_type_for(Path::new(name)).to_string(),
+        asset_url: "https://a/1".to_string(),
+    };
+
+    assert_eq!(file("Shot.JPG").to_markdown(), "![Shot.JPG](https://a/1)");
+    assert_eq!(file("trace.bin").to_markdown(), "[trace.bin](https://a/1)");
+    assert_eq!(content_type_for(Path::new("notes.log")), "text/plain");
+}
+
+#[test]
+fn test_upload_cli_flags() {
+    let cli = Cli::parse_from(["linear", "issue", "attach", "ENG-1", "--file", "a.png"]);
+    let Commands::Issue {
+        action: IssueCommands::Attach { file, url, .. },
+    } = cli.command
+    else {
+        panic!("expected issue attach command");
+    };
+    assert_eq!(file.as_deref(), Some("a.png"));
+    assert_eq!(url, None);
+
+    assert!(
+        Cli::try_parse_from([
+            "linear",
+            "issue",
+            "attach",
+            "ENG-1",
+            "--file",
+            "a.png",
+            "--url",
+            "https://a",
+        ])
+        .is_err()
+    );
+
+    let cli = Cli::parse_from([
+        "linear", "issue", "comment", "add", "ENG-1", "--attach", "a.log", "--attach", "b.png",
+    ]);
+    let Commands::Issue {
+        action:
+            IssueCommands::Comment {
+                action: IssueCommentCommands::Add { body, attach, .. },
+            },
+    } = cli.command
+    else {
+        panic!("expected issue comment add command");
+    };
+    assert_eq!(body, None);
+    assert_eq!(attach, vec!["a.log", "b.png"]);
+
+    assert!(Cli::try_parse_from(["linear", "issue", "comment", "add", "ENG-1"]).is_err());
+}